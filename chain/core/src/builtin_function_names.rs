@@ -13,3 +13,23 @@ pub const CLAIM_DEVELOPER_REWARDS_FUNC_NAME: &str = "ClaimDeveloperRewards";
 pub const SET_USERNAME_FUNC_NAME: &str = "SetUserName";
 pub const MIGRATE_USERNAME_FUNC_NAME: &str = "migrateUserName";
 pub const UPGRADE_CONTRACT_FUNC_NAME: &str = "upgradeContract";
+
+/// Contract endpoints cannot have any of these names,
+/// since calls to them are handled by the protocol and never reach the contract.
+pub const BUILTIN_FUNCTION_NAMES: &[&str] = &[
+    ESDT_LOCAL_MINT_FUNC_NAME,
+    ESDT_LOCAL_BURN_FUNC_NAME,
+    ESDT_MULTI_TRANSFER_FUNC_NAME,
+    ESDT_NFT_TRANSFER_FUNC_NAME,
+    ESDT_NFT_CREATE_FUNC_NAME,
+    ESDT_NFT_ADD_QUANTITY_FUNC_NAME,
+    ESDT_NFT_ADD_URI_FUNC_NAME,
+    ESDT_NFT_UPDATE_ATTRIBUTES_FUNC_NAME,
+    ESDT_NFT_BURN_FUNC_NAME,
+    ESDT_TRANSFER_FUNC_NAME,
+    CHANGE_OWNER_BUILTIN_FUNC_NAME,
+    CLAIM_DEVELOPER_REWARDS_FUNC_NAME,
+    SET_USERNAME_FUNC_NAME,
+    MIGRATE_USERNAME_FUNC_NAME,
+    UPGRADE_CONTRACT_FUNC_NAME,
+];
@@ -0,0 +1,146 @@
+use adder::ProxyTrait as _;
+use multiversx_sc_snippets::{
+    erdrs::wallet::Wallet,
+    multiversx_sc::{
+        storage::mappers::SingleValue,
+        types::{Address, CodeMetadata},
+    },
+    multiversx_sc_scenario::{
+        num_bigint::BigUint, scenario_model::*, ContractInfo, DebugApi, ScenarioWorld,
+    },
+    tokio, InteractorResult, MockGateway,
+};
+
+const ADDER_PATH_EXPR: &str = "file:output/adder.wasm";
+const OWNER_PRIVATE_KEY: &str = "413f42575f7f26fad3317a778771212fdb80245850981e48b58a4f25e344e8f9";
+
+fn start_gateway(owner_address: Address) -> MockGateway {
+    MockGateway::start(move || {
+        let mut world = ScenarioWorld::new();
+        world.set_current_dir_from_workspace("contracts/examples/adder");
+        world.register_contract(ADDER_PATH_EXPR, adder::ContractBuilder);
+        world.set_state_step(SetStateStep::new().put_account(
+            &owner_address,
+            Account::new().nonce(0).balance("1,000,000,000,000,000,000"),
+        ));
+        world
+    })
+}
+
+#[tokio::test]
+async fn adder_mock_gateway_test() {
+    let _ = DebugApi::dummy();
+    let wallet = Wallet::from_private_key(OWNER_PRIVATE_KEY).unwrap();
+    let gateway = start_gateway(wallet.address().to_bytes().into());
+    let mut interactor = gateway.interactor().await;
+    let owner_address = interactor.register_wallet(wallet);
+
+    let mut adder_contract = ContractInfo::<adder::Proxy<DebugApi>>::new("sc:adder");
+    let deploy_result: InteractorResult<()> = interactor
+        .sc_deploy(
+            adder_contract
+                .init(5u32)
+                .into_blockchain_call()
+                .from(&owner_address)
+                .code_metadata(CodeMetadata::all())
+                .contract_code(ADDER_PATH_EXPR, gateway.interpreter_context())
+                .gas_limit("5,000,000"),
+        )
+        .await;
+    let adder_address = deploy_result.new_deployed_address();
+    adder_contract = ContractInfo::new(&adder_address);
+
+    let add_result: InteractorResult<()> = interactor
+        .sc_call_get_result(
+            adder_contract
+                .add(3u32)
+                .into_blockchain_call()
+                .from(&owner_address)
+                .gas_limit("5,000,000"),
+        )
+        .await;
+    add_result.value();
+
+    // batched, all executed in order
+    let add_steps: Vec<ScCallStep> = (0..3)
+        .map(|_| {
+            adder_contract
+                .add(1u32)
+                .into_blockchain_call()
+                .from(&owner_address)
+                .gas_limit("5,000,000")
+                .into()
+        })
+        .collect();
+    let add_results = interactor
+        .multiple_sc_calls_get_raw_results(&add_steps)
+        .await
+        .unwrap();
+    assert_eq!(add_results.len(), 3);
+
+    let sum: SingleValue<BigUint> = interactor.vm_query(adder_contract.sum()).await;
+    assert_eq!(sum.into(), BigUint::from(11u32));
+
+    let owner_key = AddressKey::from(&owner_address);
+    let adder_key = AddressKey::from(&adder_address);
+    gateway.with_world(move |world| {
+        world.check_state_step(
+            CheckStateStep::new()
+                .put_account(owner_key, CheckAccount::new().nonce("5"))
+                .put_account(
+                    adder_key,
+                    CheckAccount::new().check_storage("str:sum", "11"),
+                ),
+        );
+    });
+}
+
+#[tokio::test]
+async fn adder_mock_gateway_failed_call_test() {
+    let _ = DebugApi::dummy();
+    let wallet = Wallet::from_private_key(OWNER_PRIVATE_KEY).unwrap();
+    let gateway = start_gateway(wallet.address().to_bytes().into());
+    let mut interactor = gateway.interactor().await;
+    let owner_address = interactor.register_wallet(wallet);
+
+    let mut adder_contract = ContractInfo::<adder::Proxy<DebugApi>>::new("sc:adder");
+    let deploy_result: InteractorResult<()> = interactor
+        .sc_deploy(
+            adder_contract
+                .init(5u32)
+                .into_blockchain_call()
+                .from(&owner_address)
+                .contract_code(ADDER_PATH_EXPR, gateway.interpreter_context())
+                .gas_limit("5,000,000"),
+        )
+        .await;
+    let adder_address = deploy_result.new_deployed_address();
+
+    let call_result = interactor
+        .sc_call_get_raw_result(
+            ScCallStep::new()
+                .from(&owner_address)
+                .to(&adder_address)
+                .function("missingEndpoint")
+                .gas_limit("5,000,000"),
+        )
+        .await;
+    assert!(call_result.find_log("signalError").is_some());
+    assert_eq!(
+        call_result.error_message(),
+        Some(b"invalid function (not found)".to_vec())
+    );
+
+    // the nonce was used and the whole gas limit paid for even though the call failed
+    let owner_key = AddressKey::from(&owner_address);
+    gateway.with_world(move |world| {
+        world.check_state_step(
+            CheckStateStep::new().put_account(
+                owner_key,
+                CheckAccount::new()
+                    .nonce("2")
+                    .balance("990,000,000,000,000,000"),
+            ),
+        );
+    });
+}
@@ -129,6 +129,9 @@ impl State {
             steps.push(self.perform_action_step(last_index, "30,000,000"));
         }
 
-        self.interactor.multiple_sc_calls(steps.as_slice()).await;
+        self.interactor
+            .multiple_sc_calls(steps.as_slice())
+            .await
+            .expect("failed to send the multisig actions");
     }
 }
@@ -0,0 +1,291 @@
+// Code generated by the multiversx-sc proxy generator. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Contract:                             AbiTester
+// Types:                                26
+// Endpoints:                            29
+
+#![no_std]
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use multiversx_sc::types::heap::{Box, Vec};
+
+/// Its only purpose is to test that the ABI generator works fine.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub enum AbiEnum {
+    Nothing,
+    Something(i32),
+    SomethingMore(u8, OnlyShowsUpAsNested08),
+    SomeStruct { a: u16, b: OnlyShowsUpAsNested09 },
+}
+
+/// Its only purpose is to test that the ABI generator works fine.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct AbiManagedType<M: ManagedTypeApi> {
+    pub big_uint: BigUint<M>,
+    pub integer: i32,
+    pub managed_buffer: ManagedBuffer<M>,
+}
+
+/// Its only purpose is to test that the ABI generator works fine.
+#[derive(
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    TypeAbi,
+    ManagedVecItem,
+    Clone,
+    PartialEq,
+    Debug,
+)]
+pub struct AbiManagedVecItem {
+    pub value1: u32,
+    pub value2: u32,
+}
+
+/// Its only purpose is to test that the ABI generator works fine.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct AbiTestType {
+    /// This type should only appear here.
+    pub nested: OnlyShowsUpAsNested01,
+    /// Tests that recursive types will not send the ABI generator into an infinite loop.
+    pub next: Option<Box<AbiTestType>>,
+    /// Tests that tuples tell the ABI of their component types even if they appear nowhere else.
+    /// Also, just like above, recursive types need to work even when nested into a tuple.
+    pub tuple_madness: (OnlyShowsUpAsNested02, Option<Box<AbiTestType>>),
+}
+
+/// Variants with pinned discriminants keep their encoding when others are added or removed.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub enum ExplicitDiscriminant {
+    Data,
+    #[codec(discriminant = 30)]
+    Something {
+        a: u64,
+    },
+    SomethingElse,
+}
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct OnlyShowsUpAsNested01 {}
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct OnlyShowsUpAsNested02 {
+    pub something: [u8; 0],
+}
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct OnlyShowsUpAsNested03 {}
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct OnlyShowsUpAsNested04 {}
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct OnlyShowsUpAsNested05 {}
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct OnlyShowsUpAsNested06 {}
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct OnlyShowsUpAsNested07 {}
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct OnlyShowsUpAsNested08 {}
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct OnlyShowsUpAsNested09 {}
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct OnlyShowsUpAsNestedInArray {}
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct OnlyShowsUpAsNestedInArrayVec {}
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct OnlyShowsUpAsNestedInBox {}
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct OnlyShowsUpAsNestedInBoxedSlice {}
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct OnlyShowsUpAsNestedInOption {}
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct OnlyShowsUpAsNestedInRef {}
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct OnlyShowsUpAsNestedInSingleValueMapper {}
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct OnlyShowsUpAsNestedInSlice {}
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct OnlyShowsUpAsNestedInVec {}
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct OnlyShowsUpInConstructor {
+    pub something: (),
+}
+
+/// Tests that the ABI generator also fetches types that only appear in storage.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct OnlyShowsUpInStorage {
+    pub something: u32,
+}
+
+/// Encoded with 2-byte discriminants, even though all of them would fit in one byte.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+#[codec(discriminant_type = "u16")]
+pub enum WideDiscriminant {
+    First,
+    Second(u8),
+}
+
+/// Contract whose sole purpose is to verify that
+/// the ABI generation framework works sa expected.
+///
+/// Note: any change in this contract must also be reflected in `abi_test_expected.abi.json`,
+/// including Rust docs.
+#[multiversx_sc::proxy]
+pub trait AbiTester {
+    /// Contract constructor.
+    #[init]
+    #[payable("EGLD")]
+    fn init(&self, _constructor_arg_1: i32, _constructor_arg_2: OnlyShowsUpInConstructor);
+
+    /// Upgrade constructor.
+    #[upgrade]
+    fn upgrade(&self, _upgrade_arg: u32);
+
+    /// Example endpoint docs.
+    #[endpoint(echo_abi_test_type)]
+    fn echo_abi_test_type(&self, att: AbiTestType) -> AbiTestType;
+
+    #[endpoint(echo_enum)]
+    fn echo_enum(&self, e: AbiEnum) -> AbiEnum;
+
+    #[endpoint(take_managed_type)]
+    fn take_managed_type(&self, _arg: AbiManagedType<Self::Api>);
+
+    #[endpoint(multi_result_3)]
+    fn multi_result_3(&self) -> MultiValue3<i32, [u8; 3], ManagedBuffer<Self::Api>>;
+
+    #[endpoint(multi_result_4)]
+    fn multi_result_4(
+        &self,
+    ) -> MultiValue4<i32, [u8; 3], ManagedBuffer<Self::Api>, OnlyShowsUpAsNested03>;
+
+    #[endpoint(var_args)]
+    fn var_args(
+        &self,
+        _simple_arg: u32,
+        _var_args: MultiValueEncoded<Self::Api, MultiValue2<OnlyShowsUpAsNested04, i32>>,
+    );
+
+    #[endpoint(multi_result_vec)]
+    fn multi_result_vec(
+        &self,
+    ) -> MultiValueEncoded<Self::Api, MultiValue3<OnlyShowsUpAsNested05, bool, ()>>;
+
+    #[endpoint(optional_arg)]
+    fn optional_arg(&self, _simple_arg: u32, _opt_args: OptionalValue<OnlyShowsUpAsNested06>);
+
+    #[endpoint(optional_result)]
+    fn optional_result(&self) -> OptionalValue<OnlyShowsUpAsNested07>;
+
+    #[endpoint(address_vs_h256)]
+    fn address_vs_h256(
+        &self,
+        address: ManagedAddress<Self::Api>,
+        h256: ManagedByteArray<Self::Api, 32>,
+    ) -> MultiValue2<ManagedAddress<Self::Api>, ManagedByteArray<Self::Api, 32>>;
+
+    #[endpoint(managed_address_vs_byte_array)]
+    fn managed_address_vs_byte_array(
+        &self,
+        address: ManagedAddress<Self::Api>,
+        byte_array: [u8; 32],
+    ) -> MultiValue2<ManagedAddress<Self::Api>, [u8; 32]>;
+
+    #[endpoint(esdt_local_role)]
+    fn esdt_local_role(&self) -> EsdtLocalRole;
+
+    #[endpoint(esdt_token_payment)]
+    fn esdt_token_payment(&self) -> EsdtTokenPayment<Self::Api>;
+
+    #[endpoint(esdt_token_data)]
+    fn esdt_token_data(&self) -> EsdtTokenData<Self::Api>;
+
+    #[endpoint(sample_storage_mapper)]
+    fn sample_storage_mapper(&self) -> OnlyShowsUpAsNestedInSingleValueMapper;
+
+    #[endpoint(item_for_vec)]
+    fn item_for_vec(&self) -> Vec<OnlyShowsUpAsNestedInVec>;
+
+    #[endpoint(item_for_array_vec)]
+    fn item_for_array_vec(&self) -> Vec<OnlyShowsUpAsNestedInArrayVec>;
+
+    #[endpoint(item_for_managed_vec)]
+    fn item_for_managed_vec(&self) -> ManagedVec<Self::Api, AbiManagedVecItem>;
+
+    #[endpoint(item_for_array)]
+    fn item_for_array(&self, _array: [OnlyShowsUpAsNestedInArray; 5]);
+
+    #[endpoint(item_for_box)]
+    fn item_for_box(&self) -> OnlyShowsUpAsNestedInBox;
+
+    #[endpoint(item_for_boxed_slice)]
+    fn item_for_boxed_slice(&self) -> Vec<OnlyShowsUpAsNestedInBoxedSlice>;
+
+    #[endpoint(item_for_ref)]
+    fn item_for_ref(&self, _ref: OnlyShowsUpAsNestedInRef);
+
+    #[endpoint(item_for_slice)]
+    fn item_for_slice(&self, _ref: Vec<OnlyShowsUpAsNestedInSlice>);
+
+    #[endpoint(item_for_option)]
+    fn item_for_option(&self) -> Option<OnlyShowsUpAsNestedInOption>;
+
+    #[payable("EGLD")]
+    #[endpoint(payable_egld)]
+    fn payable_egld(&self);
+
+    #[payable("TOKEN-FOR-ABI")]
+    #[endpoint(payable_some_token)]
+    fn payable_some_token(&self);
+
+    #[payable("*")]
+    #[endpoint(payable_any_token)]
+    fn payable_any_token(&self);
+
+    #[endpoint(call_guards)]
+    fn call_guards(&self);
+
+    #[endpoint(only_callback_context)]
+    fn only_callback_context(&self);
+}
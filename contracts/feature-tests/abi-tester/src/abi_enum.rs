@@ -9,3 +9,24 @@ pub enum AbiEnum {
     SomethingMore(u8, OnlyShowsUpAsNested08),
     SomeStruct { a: u16, b: OnlyShowsUpAsNested09 },
 }
+
+/// Variants with pinned discriminants keep their encoding when others are added or removed.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub enum ExplicitDiscriminant {
+    Data,
+    #[codec(discriminant = 30)]
+    Something {
+        a: u64,
+        #[codec(skip)]
+        cached: u32,
+    },
+    SomethingElse,
+}
+
+/// Encoded with 2-byte discriminants, even though all of them would fit in one byte.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+#[codec(discriminant_type = "u16")]
+pub enum WideDiscriminant {
+    First,
+    Second(u8),
+}
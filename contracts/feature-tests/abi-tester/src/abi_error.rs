@@ -0,0 +1,16 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+/// Tests that the error codes and messages show up in the ABI.
+#[derive(ContractError)]
+pub enum AbiTesterError<M: ManagedTypeApi> {
+    /// Example error docs.
+    #[error(code = 100, message = "value {value} exceeds {limit}")]
+    ValueTooLarge { value: BigUint<M>, limit: u64 },
+
+    #[error(code = 101, message = "token {0} not allowed")]
+    TokenNotAllowed(TokenIdentifier<M>),
+
+    #[error(code = 102, message = "the contract is closed")]
+    Closed,
+}
@@ -3,10 +3,12 @@
 multiversx_sc::imports!();
 
 mod abi_enum;
+mod abi_error;
 mod abi_test_type;
 mod only_nested;
 
 use abi_enum::*;
+use abi_error::*;
 use abi_test_type::*;
 use only_nested::*;
 
@@ -16,12 +18,17 @@ use only_nested::*;
 /// Note: any change in this contract must also be reflected in `abi_test_expected.abi.json`,
 /// including Rust docs.
 #[multiversx_sc::contract]
+#[errors(AbiTesterError<Self::Api>)]
 pub trait AbiTester {
     /// Contract constructor.
     #[init]
     #[payable("EGLD")]
     fn init(&self, _constructor_arg_1: i32, _constructor_arg_2: OnlyShowsUpInConstructor) {}
 
+    /// Upgrade constructor.
+    #[upgrade]
+    fn upgrade(&self, _upgrade_arg: u32) {}
+
     /// Example endpoint docs.
     #[endpoint]
     #[output_name("single output")]
@@ -57,6 +64,7 @@ pub trait AbiTester {
     }
 
     #[endpoint]
+    #[max_gas(20_000_000)]
     fn var_args(
         &self,
         _simple_arg: u32,
@@ -65,6 +73,7 @@ pub trait AbiTester {
     }
 
     #[endpoint]
+    #[max_return_items(10)]
     fn multi_result_vec(&self) -> MultiValueVec<MultiValue3<OnlyShowsUpAsNested05, bool, ()>> {
         MultiValueVec::new()
     }
@@ -111,6 +120,25 @@ pub trait AbiTester {
     #[storage_mapper("sample_storage_mapper")]
     fn sample_storage_mapper(&self) -> SingleValueMapper<OnlyShowsUpAsNestedInSingleValueMapper>;
 
+    /// Storage layouts show up in the ABI, including the types of the key arguments.
+    #[storage_mapper("sample_map_mapper")]
+    fn sample_map_mapper(&self, prefix: u32) -> MapMapper<ManagedAddress, OnlyShowsUpInStorage>;
+
+    #[storage_mapper("sample_no_type_abi")]
+    fn sample_no_type_abi(&self) -> SingleValueMapper<StoredWithoutTypeAbi>;
+
+    #[storage_mapper("sample_explicit_discriminant")]
+    fn sample_explicit_discriminant(&self) -> SingleValueMapper<ExplicitDiscriminant>;
+
+    #[storage_mapper("sample_wide_discriminant")]
+    fn sample_wide_discriminant(&self) -> SingleValueMapper<WideDiscriminant>;
+
+    #[storage_get("sample_raw_storage")]
+    fn get_sample_raw_storage(&self) -> u64;
+
+    #[storage_set("sample_raw_storage")]
+    fn set_sample_raw_storage(&self, value: u64);
+
     #[view]
     fn item_for_vec(&self) -> Vec<OnlyShowsUpAsNestedInVec> {
         Vec::new()
@@ -165,6 +193,15 @@ pub trait AbiTester {
     #[payable("*")]
     fn payable_any_token(&self) {}
 
+    #[endpoint]
+    #[non_reentrant]
+    #[not_from_contract]
+    fn call_guards(&self) {}
+
+    #[endpoint]
+    #[only_callback_context]
+    fn only_callback_context(&self) {}
+
     #[endpoint]
     #[label("test-external-view")]
     fn external_view(&self) {}
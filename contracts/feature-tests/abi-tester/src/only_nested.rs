@@ -79,3 +79,15 @@ pub struct OnlyShowsUpAsNestedInSlice;
 /// Tests that the ABI generator also fetches types that only appear as fields.
 #[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
 pub struct OnlyShowsUpAsNestedInOption;
+
+/// Tests that the ABI generator also fetches types that only appear in storage.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct OnlyShowsUpInStorage {
+    pub something: u32,
+}
+
+/// Storage values are not required to implement `TypeAbi`, they show up as "unknown" in the storage layout.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
+pub struct StoredWithoutTypeAbi {
+    pub something: u32,
+}
@@ -19,8 +19,10 @@ fn abi_tester_abi_generated_ok() {
 
     let main_contract = multi_contract_config.find_contract("abi-tester");
     assert!(!main_contract.settings.external_view);
+    assert_eq!(main_contract.abi.upgrade_constructors.len(), 1);
     let view_contract = multi_contract_config.find_contract("abi-tester-ev");
     assert!(view_contract.settings.external_view);
+    assert!(view_contract.abi.upgrade_constructors.is_empty());
     assert_eq!(
         view_contract.endpoint_names(),
         vec!["external_view", "payable_any_token", "label_a"]
@@ -66,3 +68,16 @@ fn check_multi_contract_config() {
         vec!["external_view", "payable_any_token", "label_a"]
     );
 }
+
+#[test]
+fn abi_tester_abi_rules_ok() {
+    use multiversx_sc::contract_base::ContractAbiProvider;
+
+    let abi = abi_tester::AbiProvider::abi();
+    assert!(multiversx_sc_meta::meta_validate_abi::validate_abi_rules(&abi).is_empty());
+
+    let endpoint_location = abi.endpoints[0].location.unwrap();
+    assert!(endpoint_location.file.ends_with("abi_tester.rs"));
+    assert!(endpoint_location.line > 0);
+    assert!(abi.events.iter().all(|event| event.location.is_some()));
+}
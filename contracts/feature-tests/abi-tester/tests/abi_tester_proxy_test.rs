@@ -0,0 +1,55 @@
+use abi_tester_proxy::*;
+use multiversx_sc::{
+    abi::{TypeAbi, TypeDescriptionContainer, TypeDescriptionContainerImpl},
+    codec::top_encode_to_vec_u8_or_panic,
+    contract_base::ContractAbiProvider,
+};
+use multiversx_sc_scenario::DebugApi;
+
+/// The proxy crate is generated from `abi_tester_expected_main.abi.json`,
+/// using `sc-meta generate-proxy`.
+#[test]
+fn abi_tester_proxy_types_describe_as_original() {
+    let mut proxy_types = TypeDescriptionContainerImpl::new();
+    AbiEnum::provide_type_descriptions(&mut proxy_types);
+    AbiManagedType::<DebugApi>::provide_type_descriptions(&mut proxy_types);
+    AbiManagedVecItem::provide_type_descriptions(&mut proxy_types);
+    AbiTestType::provide_type_descriptions(&mut proxy_types);
+    ExplicitDiscriminant::provide_type_descriptions(&mut proxy_types);
+    OnlyShowsUpInConstructor::provide_type_descriptions(&mut proxy_types);
+    OnlyShowsUpInStorage::provide_type_descriptions(&mut proxy_types);
+
+    let original_types = abi_tester::AbiProvider::abi().type_descriptions;
+    for (type_name, proxy_description) in proxy_types.0.iter() {
+        let original_description = original_types
+            .0
+            .get(type_name)
+            .unwrap_or_else(|| panic!("type {type_name} missing from the original ABI"));
+        assert_eq!(
+            format!("{proxy_description:?}"),
+            format!("{original_description:?}"),
+            "type {type_name} differs"
+        );
+    }
+    assert!(proxy_types.contains_type("OnlyShowsUpAsNested02"));
+}
+
+#[test]
+fn abi_tester_proxy_explicit_discriminant_encoding() {
+    assert_eq!(
+        top_encode_to_vec_u8_or_panic(&ExplicitDiscriminant::Something { a: 5 }),
+        [30, 0, 0, 0, 0, 0, 0, 0, 5]
+    );
+    assert_eq!(
+        top_encode_to_vec_u8_or_panic(&ExplicitDiscriminant::SomethingElse),
+        [31]
+    );
+}
+
+#[test]
+fn abi_tester_proxy_wide_discriminant_encoding() {
+    assert_eq!(
+        top_encode_to_vec_u8_or_panic(&WideDiscriminant::Second(5)),
+        [0, 1, 5]
+    );
+}
@@ -5,9 +5,10 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                           27
+// Upgrade:                              1
+// Endpoints:                           29
 // Async Callback (empty):               1
-// Total number of exported functions:  29
+// Total number of exported functions:  32
 
 #![no_std]
 #![feature(alloc_error_handler, lang_items)]
@@ -18,6 +19,7 @@ multiversx_sc_wasm_adapter::panic_handler!();
 multiversx_sc_wasm_adapter::endpoints! {
     abi_tester
     (
+        upgrade
         echo_abi_test_type
         echo_enum
         take_managed_type
@@ -45,6 +47,8 @@ multiversx_sc_wasm_adapter::endpoints! {
         payable_egld
         payable_some_token
         payable_any_token
+        call_guards
+        only_callback_context
     )
 }
 
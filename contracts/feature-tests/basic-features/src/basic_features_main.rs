@@ -8,10 +8,12 @@ pub mod big_num_operators;
 pub mod block_info_features;
 pub mod blockchain_api_features;
 pub mod codec_err_test;
+pub mod contract_error_features;
 pub mod crypto_features;
 pub mod echo;
 pub mod echo_managed;
 pub mod elliptic_curve_features;
+pub mod endpoint_budget_features;
 pub mod event_features;
 pub mod macro_features;
 pub mod managed_address_features;
@@ -32,6 +34,7 @@ pub mod storage_mapper_token_attributes;
 pub mod storage_mapper_unique_id_mapper;
 pub mod storage_mapper_vec;
 pub mod storage_mapper_whitelist;
+pub mod storage_migrations;
 pub mod storage_raw_api_features;
 pub mod struct_eq;
 pub mod token_identifier_features;
@@ -44,10 +47,12 @@ pub trait BasicFeatures:
     + block_info_features::BlockInfoFeatures
     + blockchain_api_features::BlockchainApiFeatures
     + codec_err_test::CodecErrorTest
+    + contract_error_features::ContractErrorFeatures
     + crypto_features::CryptoFeatures
     + echo::EchoTypes
     + echo_managed::EchoManagedTypes
     + elliptic_curve_features::EllipticCurveFeatures
+    + endpoint_budget_features::EndpointBudgetFeatures
     + event_features::EventFeatures
     + macro_features::Macros
     + managed_address_features::ManagedAddressFeatures
@@ -68,6 +73,7 @@ pub trait BasicFeatures:
     + storage_mapper_fungible_token::FungibleTokenMapperFeatures
     + storage_mapper_non_fungible_token::NonFungibleTokenMapperFeatures
     + storage_mapper_unique_id_mapper::UniqueIdMapperFeatures
+    + storage_migrations::StorageMigrationsFeatures
     + struct_eq::StructEquals
     + token_identifier_features::TokenIdentifierFeatures
     + non_zero_features::TypeFeatures
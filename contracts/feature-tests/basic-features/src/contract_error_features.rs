@@ -0,0 +1,42 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+/// Errors with stable codes, decodable off-chain.
+#[derive(ContractError)]
+pub enum PaymentError<M: ManagedTypeApi> {
+    /// The payment did not reach the minimum amount.
+    #[error(code = 1, message = "payment too low, needed {needed}")]
+    PaymentTooLow { needed: BigUint<M> },
+
+    #[error(code = 2, message = "token {0} not accepted")]
+    TokenNotAccepted(TokenIdentifier<M>),
+
+    #[error(code = 3, message = "contract is paused")]
+    Paused,
+}
+
+/// Typed errors signalled via `require!` and `sc_panic!`.
+#[multiversx_sc::module]
+#[errors(PaymentError<Self::Api>)]
+pub trait ContractErrorFeatures {
+    #[view]
+    fn require_min_amount(&self, amount: BigUint, min_amount: BigUint) {
+        require!(
+            amount >= min_amount,
+            PaymentError::PaymentTooLow { needed: min_amount }
+        );
+    }
+
+    #[view]
+    fn require_token(&self, token: TokenIdentifier, accepted_token: TokenIdentifier) {
+        require!(
+            token == accepted_token,
+            PaymentError::TokenNotAccepted(token)
+        );
+    }
+
+    #[view]
+    fn signal_paused(&self) {
+        sc_panic!(PaymentError::<Self::Api>::Paused);
+    }
+}
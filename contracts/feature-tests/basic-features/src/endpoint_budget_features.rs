@@ -0,0 +1,47 @@
+multiversx_sc::imports!();
+
+/// Endpoints declaring gas and result size limits, flagged by the scenario executors when exceeded.
+#[multiversx_sc::module]
+pub trait EndpointBudgetFeatures {
+    #[view]
+    #[max_return_items(3)]
+    fn budget_first_numbers(&self, count: u32) -> MultiValueEncoded<u32> {
+        let mut result = MultiValueEncoded::new();
+        for number in 0..count {
+            result.push(number);
+        }
+        result
+    }
+
+    /// Each pair counts as a single item, although it takes up 2 results.
+    #[view]
+    #[max_return_items(2)]
+    fn budget_number_pairs(&self, count: u32) -> MultiValueEncoded<MultiValue2<u32, u32>> {
+        let mut result = MultiValueEncoded::new();
+        for number in 0..count {
+            result.push((number, number * number).into());
+        }
+        result
+    }
+
+    /// Each element of the list counts as an item, although the list is a single result.
+    #[view]
+    #[max_return_items(3)]
+    fn budget_number_list(&self, count: u32) -> ManagedVec<u32> {
+        let mut result = ManagedVec::new();
+        for number in 0..count {
+            result.push(number);
+        }
+        result
+    }
+
+    #[endpoint]
+    #[max_gas(5_000_000)]
+    fn budget_store_value(&self, value: u32) {
+        self.budget_value().set(value);
+    }
+
+    #[view]
+    #[storage_mapper("budget_value")]
+    fn budget_value(&self) -> SingleValueMapper<u32>;
+}
@@ -0,0 +1,41 @@
+multiversx_sc::imports!();
+
+use multiversx_sc::contract_base::MigrationStep;
+
+/// Migration step that only records its version in the log.
+pub struct LogMigration<'a, C: StorageMigrationsFeatures> {
+    contract: &'a C,
+    version: u32,
+}
+
+impl<'a, C: StorageMigrationsFeatures> MigrationStep for LogMigration<'a, C> {
+    fn version(&self) -> u32 {
+        self.version
+    }
+
+    fn migrate(self) {
+        self.contract.migration_log().push(&self.version);
+    }
+}
+
+/// Storage migrations test.
+#[multiversx_sc::module]
+pub trait StorageMigrationsFeatures {
+    /// Declares one migration step for each of the given versions.
+    /// Each step that actually runs records its version in the log.
+    #[endpoint]
+    fn run_migrations(&self, versions: MultiValueEncoded<u32>) -> u32 {
+        let mut migrations = self.migrations();
+        for version in versions {
+            migrations = migrations.step(LogMigration {
+                contract: self,
+                version,
+            });
+        }
+        migrations.schema_version()
+    }
+
+    #[view]
+    #[storage_mapper("migration_log")]
+    fn migration_log(&self) -> VecMapper<u32>;
+}
@@ -0,0 +1,110 @@
+use basic_features::contract_error_features::PaymentError;
+use multiversx_sc_scenario::{
+    multiversx_sc::types::{BigUint, ContractError, TokenIdentifier},
+    scenario_model::*,
+    *,
+};
+
+const BASIC_FEATURES_PATH_EXPR: &str = "file:output/basic-features.wasm";
+
+fn world() -> ScenarioWorld {
+    let mut world = ScenarioWorld::new();
+    world.set_current_dir_from_workspace("contracts/feature-tests/basic-features");
+    world.register_contract(BASIC_FEATURES_PATH_EXPR, basic_features::ContractBuilder);
+    let ic = world.interpreter_context();
+    world
+        .set_state_step(
+            SetStateStep::new()
+                .put_account("address:an_account", Account::new().nonce(1))
+                .new_address("address:an_account", 1, "sc:basic-features"),
+        )
+        .sc_deploy_step(
+            ScDeployStep::new()
+                .from("address:an_account")
+                .contract_code(BASIC_FEATURES_PATH_EXPR, &ic)
+                .expect(TxExpect::ok().no_result()),
+        );
+    world
+}
+
+#[test]
+fn basic_features_contract_error_decode_test() {
+    let _ = DebugApi::dummy();
+    let mut world = world();
+
+    let error: PaymentError<DebugApi> = world.sc_call_get_error(
+        ScCallStep::new()
+            .from("address:an_account")
+            .to("sc:basic-features")
+            .function("require_min_amount")
+            .argument("99")
+            .argument("100"),
+    );
+    assert_eq!(error.error_code(), 1);
+    match error {
+        PaymentError::PaymentTooLow { needed } => assert_eq!(needed, BigUint::from(100u32)),
+        _ => panic!("PaymentTooLow expected"),
+    }
+
+    let error: PaymentError<DebugApi> = world.sc_call_get_error(
+        ScCallStep::new()
+            .from("address:an_account")
+            .to("sc:basic-features")
+            .function("require_token")
+            .argument("str:OTHER-123456")
+            .argument("str:TOKEN-123456"),
+    );
+    match error {
+        PaymentError::TokenNotAccepted(token) => {
+            assert_eq!(token, TokenIdentifier::from("OTHER-123456"))
+        },
+        _ => panic!("TokenNotAccepted expected"),
+    }
+}
+
+#[test]
+fn basic_features_contract_error_expect_test() {
+    let _ = DebugApi::dummy();
+    let mut world = world();
+
+    world.sc_call_step(
+        ScCallStep::new()
+            .from("address:an_account")
+            .to("sc:basic-features")
+            .function("signal_paused")
+            .expect(TxExpect::contract_error(&PaymentError::<DebugApi>::Paused)),
+    );
+    world.sc_call_step(
+        ScCallStep::new()
+            .from("address:an_account")
+            .to("sc:basic-features")
+            .function("require_min_amount")
+            .argument("5")
+            .argument("1000")
+            .expect(TxExpect::contract_error(
+                &PaymentError::<DebugApi>::PaymentTooLow {
+                    needed: BigUint::from(1000u32),
+                },
+            )),
+    );
+}
+
+#[test]
+fn basic_features_contract_error_abi_test() {
+    let abi = <basic_features::AbiProvider as multiversx_sc_scenario::multiversx_sc::contract_base::ContractAbiProvider>::abi();
+    let codes: Vec<(u32, &str, &str)> = abi
+        .errors
+        .iter()
+        .map(|error| (error.code, error.name, error.message))
+        .collect();
+    assert_eq!(
+        codes,
+        vec![
+            (1, "PaymentTooLow", "payment too low, needed {needed}"),
+            (2, "TokenNotAccepted", "token {0} not accepted"),
+            (3, "Paused", "contract is paused"),
+        ]
+    );
+    assert_eq!(abi.errors[0].fields[0].type_name, "BigUint");
+    assert_eq!(abi.errors[1].fields[0].name, "0");
+}
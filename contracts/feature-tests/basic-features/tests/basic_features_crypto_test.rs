@@ -0,0 +1,33 @@
+use multiversx_sc::types::ManagedBuffer;
+use multiversx_sc_scenario::*;
+use multiversx_sdk::{crypto::signable_message::SignableMessage, wallet::Wallet};
+
+use basic_features::crypto_features::CryptoFeatures;
+
+const ALICE_SECRET_KEY: &str = "413f42575f7f26fad3317a778771212fdb80245850981e48b58a4f25e344e8f9";
+
+/// Signatures produced off-chain by the SDK validate with `verify_ed25519` in contracts,
+/// as long as the contract checks the serialized signable message.
+#[test]
+fn test_verify_off_chain_signed_message() {
+    let _ = DebugApi::dummy();
+    let bf = basic_features::contract_obj::<DebugApi>();
+
+    let wallet = Wallet::from_private_key(ALICE_SECRET_KEY).unwrap();
+    let signature = wallet.sign_message(b"hello");
+    let key = wallet.address().to_bytes();
+
+    let signed_bytes = SignableMessage::new(b"hello").serialize_for_signing();
+    assert!(bf.verify_ed25519_signature(
+        ManagedBuffer::from(&key[..]),
+        ManagedBuffer::from(&signed_bytes[..]),
+        ManagedBuffer::from(&signature[..]),
+    ));
+
+    let other_bytes = SignableMessage::new(b"hello!").serialize_for_signing();
+    assert!(!bf.verify_ed25519_signature(
+        ManagedBuffer::from(&key[..]),
+        ManagedBuffer::from(&other_bytes[..]),
+        ManagedBuffer::from(&signature[..]),
+    ));
+}
@@ -0,0 +1,108 @@
+use multiversx_sc_scenario::{scenario_model::*, *};
+
+const BASIC_FEATURES_PATH_EXPR: &str = "file:output/basic-features.wasm";
+
+fn world() -> ScenarioWorld {
+    let mut world = ScenarioWorld::new();
+    world.set_current_dir_from_workspace("contracts/feature-tests/basic-features");
+    world.register_contract_with_budgets::<basic_features::AbiProvider, _>(
+        BASIC_FEATURES_PATH_EXPR,
+        basic_features::ContractBuilder,
+    );
+    let ic = world.interpreter_context();
+    world
+        .set_state_step(
+            SetStateStep::new()
+                .put_account("address:an_account", Account::new().nonce(1))
+                .new_address("address:an_account", 1, "sc:basic-features"),
+        )
+        .sc_deploy_step(
+            ScDeployStep::new()
+                .from("address:an_account")
+                .contract_code(BASIC_FEATURES_PATH_EXPR, &ic)
+                .expect(TxExpect::ok().no_result()),
+        );
+    world
+}
+
+#[test]
+fn basic_features_endpoint_budget_within_limits_test() {
+    let mut world = world();
+
+    world
+        .sc_query_step(
+            ScQueryStep::new()
+                .to("sc:basic-features")
+                .function("budget_first_numbers")
+                .argument("3")
+                .expect(TxExpect::ok().result("0").result("1").result("2")),
+        )
+        .sc_query_step(
+            ScQueryStep::new()
+                .to("sc:basic-features")
+                .function("budget_number_pairs")
+                .argument("2")
+                .expect(
+                    TxExpect::ok()
+                        .result("0")
+                        .result("0")
+                        .result("1")
+                        .result("1"),
+                ),
+        )
+        .sc_call_step(
+            ScCallStep::new()
+                .from("address:an_account")
+                .to("sc:basic-features")
+                .function("budget_store_value")
+                .argument("7")
+                .gas_limit("5,000,000")
+                .expect(TxExpect::ok().no_result()),
+        );
+}
+
+#[test]
+#[should_panic(
+    expected = "Endpoint `budget_first_numbers` returned 4 items, max_return_items is 3"
+)]
+fn basic_features_endpoint_budget_return_items_exceeded_test() {
+    let mut world = world();
+
+    world.sc_query_step(
+        ScQueryStep::new()
+            .to("sc:basic-features")
+            .function("budget_first_numbers")
+            .argument("4"),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Endpoint `budget_number_list` returned 4 items, max_return_items is 3")]
+fn basic_features_endpoint_budget_list_items_exceeded_test() {
+    let mut world = world();
+
+    world.sc_query_step(
+        ScQueryStep::new()
+            .to("sc:basic-features")
+            .function("budget_number_list")
+            .argument("4"),
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "Endpoint `budget_store_value` is called with a gas limit of 10000000, max_gas is 5000000"
+)]
+fn basic_features_endpoint_budget_gas_exceeded_test() {
+    let mut world = world();
+
+    world.sc_call_step(
+        ScCallStep::new()
+            .from("address:an_account")
+            .to("sc:basic-features")
+            .function("budget_store_value")
+            .argument("7")
+            .gas_limit("10,000,000")
+            .expect(TxExpect::ok().no_result()),
+    );
+}
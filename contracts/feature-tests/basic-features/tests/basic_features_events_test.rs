@@ -0,0 +1,211 @@
+use basic_features::event_features::EventFeatures;
+use multiversx_sc_scenario::{
+    event_decoder::{AbiValue, EventDecoder},
+    multiversx_sc::types::{BigUint, ManagedAddress, ManagedBuffer, ManagedVec},
+    scenario_model::*,
+    *,
+};
+
+const BASIC_FEATURES_PATH_EXPR: &str = "file:output/basic-features.wasm";
+
+fn world() -> ScenarioWorld {
+    let mut world = ScenarioWorld::new();
+    world.set_current_dir_from_workspace("contracts/feature-tests/basic-features");
+    world.register_contract(BASIC_FEATURES_PATH_EXPR, basic_features::ContractBuilder);
+    deploy(world)
+}
+
+/// The ABI lets the scenario runner show mismatched logs as event fields.
+fn world_with_abi() -> ScenarioWorld {
+    let mut world = ScenarioWorld::new();
+    world.set_current_dir_from_workspace("contracts/feature-tests/basic-features");
+    world.register_contract_with_budgets::<basic_features::AbiProvider, _>(
+        BASIC_FEATURES_PATH_EXPR,
+        basic_features::ContractBuilder,
+    );
+    deploy(world)
+}
+
+fn deploy(mut world: ScenarioWorld) -> ScenarioWorld {
+    let ic = world.interpreter_context();
+    world
+        .set_state_step(
+            SetStateStep::new()
+                .put_account("address:an_account", Account::new().nonce(1))
+                .new_address("address:an_account", 1, "sc:basic-features"),
+        )
+        .sc_deploy_step(
+            ScDeployStep::new()
+                .from("address:an_account")
+                .contract_code(BASIC_FEATURES_PATH_EXPR, &ic)
+                .expect(TxExpect::ok().no_result()),
+        );
+    world
+}
+
+#[test]
+fn basic_features_decoded_events_test() {
+    let mut world = world();
+    let decoder = EventDecoder::from_contract::<basic_features::AbiProvider>();
+
+    let events = world.sc_call_get_events(
+        ScCallStep::new()
+            .from("address:an_account")
+            .to("sc:basic-features")
+            .function("logEventB")
+            .argument("1234")
+            .argument("address:an_account")
+            .argument("str:a")
+            .argument("str:bc")
+            .expect(TxExpect::ok().no_result()),
+        &decoder,
+    );
+
+    assert_eq!(events.len(), 1);
+    let event = &events[0];
+    assert_eq!(event.identifier, "event_b");
+    assert_eq!(event.field("arg1"), Some(&AbiValue::from(1234u32)));
+    assert_eq!(
+        event.field("arg2").and_then(AbiValue::as_bytes),
+        Some(AddressValue::from("address:an_account").value.as_bytes())
+    );
+    assert_eq!(
+        event.field("data"),
+        Some(&AbiValue::List(vec![
+            AbiValue::Bytes(b"a".to_vec()),
+            AbiValue::Bytes(b"bc".to_vec()),
+        ]))
+    );
+
+    let events = world.sc_call_get_events(
+        ScCallStep::new()
+            .from("address:an_account")
+            .to("sc:basic-features")
+            .function("logEventARepeat")
+            .argument("3"),
+        &decoder,
+    );
+    let values: Vec<u64> = events
+        .iter()
+        .map(|event| event.field("data").unwrap().as_u64().unwrap())
+        .collect();
+    assert_eq!(values, vec![0, 1, 2]);
+}
+
+#[test]
+fn basic_features_expect_events_test() {
+    let _ = DebugApi::dummy();
+    let mut world = world();
+    let contract = basic_features::contract_obj::<DebugApi>();
+
+    world.sc_call_step(
+        ScCallStep::new()
+            .from("address:an_account")
+            .to("sc:basic-features")
+            .function("logEventARepeat")
+            .argument("2")
+            .expect(
+                TxExpect::ok()
+                    .no_result()
+                    .event("sc:basic-features", "logEventARepeat", || {
+                        contract.event_a(0)
+                    })
+                    .event("sc:basic-features", "logEventARepeat", || {
+                        contract.event_a(1)
+                    }),
+            ),
+    );
+
+    let address = ManagedAddress::from(AddressValue::from("address:an_account").value);
+    let mut data = ManagedVec::new();
+    data.push(ManagedBuffer::from("a"));
+    data.push(ManagedBuffer::from("bc"));
+    world.sc_call_step(
+        ScCallStep::new()
+            .from("address:an_account")
+            .to("sc:basic-features")
+            .function("logEventB")
+            .argument("1234")
+            .argument("address:an_account")
+            .argument("str:a")
+            .argument("str:bc")
+            .expect(
+                TxExpect::ok()
+                    .no_result()
+                    .event("sc:basic-features", "logEventB", || {
+                        contract.event_b(&BigUint::from(1234u32), &address, data)
+                    }),
+            ),
+    );
+}
+
+#[test]
+#[should_panic(expected = "topics[1]: want 0x04d2 (1234), have 0x03e8 (1000)")]
+fn basic_features_expect_events_mismatch_test() {
+    let _ = DebugApi::dummy();
+    let mut world = world();
+    let contract = basic_features::contract_obj::<DebugApi>();
+
+    let address = ManagedAddress::from(AddressValue::from("address:an_account").value);
+    world.sc_call_step(
+        ScCallStep::new()
+            .from("address:an_account")
+            .to("sc:basic-features")
+            .function("logEventB")
+            .argument("1000")
+            .argument("address:an_account")
+            .expect(
+                TxExpect::ok()
+                    .no_result()
+                    .event("sc:basic-features", "logEventB", || {
+                        contract.event_b(&BigUint::from(1234u32), &address, ManagedVec::new())
+                    }),
+            ),
+    );
+}
+
+#[test]
+#[should_panic(expected = "event_b.arg1: want 1234, have 1000")]
+fn basic_features_expect_events_decoded_mismatch_test() {
+    let _ = DebugApi::dummy();
+    let mut world = world_with_abi();
+    let contract = basic_features::contract_obj::<DebugApi>();
+
+    let address = ManagedAddress::from(AddressValue::from("address:an_account").value);
+    world.sc_call_step(
+        ScCallStep::new()
+            .from("address:an_account")
+            .to("sc:basic-features")
+            .function("logEventB")
+            .argument("1000")
+            .argument("address:an_account")
+            .expect(
+                TxExpect::ok()
+                    .no_result()
+                    .event("sc:basic-features", "logEventB", || {
+                        contract.event_b(&BigUint::from(1234u32), &address, ManagedVec::new())
+                    }),
+            ),
+    );
+}
+
+#[test]
+#[should_panic(expected = "endpoint: want \"str:logEventA\", have logEventARepeat")]
+fn basic_features_expect_events_wrong_endpoint_test() {
+    let _ = DebugApi::dummy();
+    let mut world = world();
+    let contract = basic_features::contract_obj::<DebugApi>();
+
+    world.sc_call_step(
+        ScCallStep::new()
+            .from("address:an_account")
+            .to("sc:basic-features")
+            .function("logEventARepeat")
+            .argument("1")
+            .expect(
+                TxExpect::ok()
+                    .no_result()
+                    .event("sc:basic-features", "logEventA", || contract.event_a(0)),
+            ),
+    );
+}
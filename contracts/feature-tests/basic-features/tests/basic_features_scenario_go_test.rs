@@ -43,6 +43,16 @@ fn codec_err_go() {
     multiversx_sc_scenario::run_go("scenarios/codec_err.scen.json");
 }
 
+#[test]
+fn contract_errors_go() {
+    multiversx_sc_scenario::run_go("scenarios/contract_errors.scen.json");
+}
+
+#[test]
+fn endpoint_budget_go() {
+    multiversx_sc_scenario::run_go("scenarios/endpoint_budget.scen.json");
+}
+
 #[test]
 fn count_ones_go() {
     multiversx_sc_scenario::run_go("scenarios/count_ones.scen.json");
@@ -388,6 +398,11 @@ fn storage_mapper_whitelist_go() {
     multiversx_sc_scenario::run_go("scenarios/storage_mapper_whitelist.scen.json");
 }
 
+#[test]
+fn storage_migrations_go() {
+    multiversx_sc_scenario::run_go("scenarios/storage_migrations.scen.json");
+}
+
 #[test]
 fn storage_opt_managed_addr_go() {
     multiversx_sc_scenario::run_go("scenarios/storage_opt_managed_addr.scen.json");
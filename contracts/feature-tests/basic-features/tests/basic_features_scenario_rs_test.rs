@@ -4,7 +4,7 @@ fn world() -> ScenarioWorld {
     let mut blockchain = ScenarioWorld::new();
     blockchain.set_current_dir_from_workspace("contracts/feature-tests/basic-features");
 
-    blockchain.register_contract(
+    blockchain.register_contract_with_budgets::<basic_features::AbiProvider, _>(
         "file:output/basic-features.wasm",
         basic_features::ContractBuilder,
     );
@@ -61,6 +61,16 @@ fn codec_err_rs() {
     multiversx_sc_scenario::run_rs("scenarios/codec_err.scen.json", world());
 }
 
+#[test]
+fn contract_errors_rs() {
+    multiversx_sc_scenario::run_rs("scenarios/contract_errors.scen.json", world());
+}
+
+#[test]
+fn endpoint_budget_rs() {
+    multiversx_sc_scenario::run_rs("scenarios/endpoint_budget.scen.json", world());
+}
+
 #[test]
 fn count_ones_rs() {
     multiversx_sc_scenario::run_rs("scenarios/count_ones.scen.json", world());
@@ -438,6 +448,11 @@ fn storage_mapper_whitelist_rs() {
     multiversx_sc_scenario::run_rs("scenarios/storage_mapper_whitelist.scen.json", world());
 }
 
+#[test]
+fn storage_migrations_rs() {
+    multiversx_sc_scenario::run_rs("scenarios/storage_migrations.scen.json", world());
+}
+
 #[test]
 fn storage_opt_managed_addr_rs() {
     multiversx_sc_scenario::run_rs("scenarios/storage_opt_managed_addr.scen.json", world());
@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                          340
+// Endpoints:                          350
 // Async Callback:                       1
-// Total number of exported functions: 342
+// Total number of exported functions: 352
 
 #![no_std]
 #![feature(alloc_error_handler, lang_items)]
@@ -135,6 +135,9 @@ multiversx_sc_wasm_adapter::endpoints! {
         codec_err_event_data
         codec_err_contract_init
         codec_err_contract_call
+        require_min_amount
+        require_token
+        signal_paused
         compute_sha256_legacy_managed
         compute_sha256
         compute_keccak256_legacy_managed
@@ -192,6 +195,11 @@ multiversx_sc_wasm_adapter::endpoints! {
         compute_unmarshal_ec
         compute_unmarshal_compressed_ec
         compute_generate_key_ec
+        budget_first_numbers
+        budget_number_pairs
+        budget_number_list
+        budget_store_value
+        budget_value
         logEventA
         logEventARepeat
         logEventB
@@ -352,6 +360,8 @@ multiversx_sc_wasm_adapter::endpoints! {
         unique_id_mapper_swap_remove
         unique_id_mapper_set
         unique_id_mapper
+        run_migrations
+        migration_log
         managed_struct_eq
         token_identifier_egld
         token_identifier_is_valid_1
@@ -365,6 +365,35 @@ pub trait ForwarderRaw {
         self.execute_on_dest_context_result(result);
     }
 
+    // CALL GUARDS
+
+    /// Re-entering this endpoint from the contract it calls fails, no matter how deep in the call stack.
+    #[endpoint]
+    #[non_reentrant]
+    fn call_execute_on_dest_context_non_reentrant(
+        &self,
+        to: ManagedAddress,
+        endpoint_name: ManagedBuffer,
+        args: MultiValueEncoded<ManagedBuffer>,
+    ) {
+        let half_gas = self.blockchain().get_gas_left() / 2;
+        let result = self.send_raw().execute_on_dest_context_raw(
+            half_gas,
+            &to,
+            &BigUint::zero(),
+            &endpoint_name,
+            &args.to_arg_buffer(),
+        );
+
+        self.execute_on_dest_context_result(result);
+    }
+
+    #[endpoint]
+    #[not_from_contract]
+    fn not_from_contract_endpoint(&self) -> ManagedAddress {
+        self.blockchain().get_caller()
+    }
+
     #[event("execute_on_dest_context_result")]
     fn execute_on_dest_context_result(&self, result: ManagedVec<Self::Api, ManagedBuffer>);
 
@@ -406,7 +435,7 @@ pub trait ForwarderRaw {
     }
 
     #[endpoint]
-    fn upgrade(
+    fn call_upgrade(
         &self,
         child_sc_address: &ManagedAddress,
         new_code: &ManagedBuffer,
@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                           25
+// Endpoints:                           27
 // Async Callback:                       1
-// Total number of exported functions:  27
+// Total number of exported functions:  29
 
 #![no_std]
 #![feature(alloc_error_handler, lang_items)]
@@ -39,9 +39,11 @@ multiversx_sc_wasm_adapter::endpoints! {
         call_execute_on_dest_context_twice
         call_execute_on_same_context
         call_execute_on_dest_context_readonly
+        call_execute_on_dest_context_non_reentrant
+        not_from_contract_endpoint
         deploy_contract
         deploy_from_source
-        upgrade
+        call_upgrade
         upgrade_from_source
         callBack
     )
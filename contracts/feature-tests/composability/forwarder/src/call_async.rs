@@ -196,6 +196,43 @@ pub trait ForwarderAsyncCallModule {
             .call_and_exit()
     }
 
+    #[endpoint]
+    #[only_callback_context]
+    fn callback_context_endpoint(&self) -> ManagedAddress {
+        self.blockchain().get_caller()
+    }
+
+    /// The callback calls `callback_context_endpoint` on the contract itself, synchronously.
+    #[endpoint]
+    fn call_callback_context_endpoint_from_callback(&self, to: ManagedAddress) {
+        self.vault_proxy()
+            .contract(to)
+            .echo_arguments_without_storage(MultiValueEncoded::new())
+            .async_call()
+            .with_callback(self.callbacks().callback_context_callback())
+            .call_and_exit();
+    }
+
+    #[callback]
+    fn callback_context_callback(&self) {
+        let own_address = self.blockchain().get_sc_address();
+        let half_gas = self.blockchain().get_gas_left() / 2;
+        let result = self.send_raw().execute_on_dest_context_raw(
+            half_gas,
+            &own_address,
+            &BigUint::zero(),
+            &ManagedBuffer::from(b"callback_context_endpoint"),
+            &ManagedArgBuffer::new(),
+        );
+        if let Some(caller) = result.try_get(0) {
+            self.callback_context_endpoint_result().set(&*caller);
+        }
+    }
+
+    #[view]
+    #[storage_mapper("callback_context_endpoint_result")]
+    fn callback_context_endpoint_result(&self) -> SingleValueMapper<ManagedBuffer>;
+
     #[view]
     #[storage_mapper("callback_data")]
     fn callback_data(&self) -> VecMapper<CallbackData<Self::Api>>;
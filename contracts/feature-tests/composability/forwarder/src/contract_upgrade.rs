@@ -13,7 +13,7 @@ pub trait UpgradeContractModule {
         opt_arg: OptionalValue<ManagedBuffer>,
     ) {
         self.vault_proxy(child_sc_address)
-            .init(opt_arg)
+            .upgrade(opt_arg)
             .upgrade_contract(&new_code, CodeMetadata::UPGRADEABLE);
     }
 
@@ -25,7 +25,7 @@ pub trait UpgradeContractModule {
         opt_arg: OptionalValue<ManagedBuffer>,
     ) {
         self.vault_proxy(child_sc_address)
-            .init(opt_arg)
+            .upgrade(opt_arg)
             .upgrade_from_source(&source_address, CodeMetadata::DEFAULT)
     }
 }
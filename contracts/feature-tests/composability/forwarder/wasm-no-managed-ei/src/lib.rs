@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                           69
+// Endpoints:                           72
 // Async Callback:                       1
-// Total number of exported functions:  71
+// Total number of exported functions:  74
 
 #![no_std]
 #![feature(alloc_error_handler, lang_items)]
@@ -35,6 +35,9 @@ multiversx_sc_wasm_adapter::endpoints! {
         forward_async_retrieve_funds
         send_funds_twice
         send_async_accept_multi_transfer
+        callback_context_endpoint
+        call_callback_context_endpoint_from_callback
+        callback_context_endpoint_result
         callback_data
         callback_data_at_index
         clear_callback_data
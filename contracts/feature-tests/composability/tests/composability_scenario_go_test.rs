@@ -41,6 +41,11 @@ fn forw_raw_call_async_retrieve_multi_transfer_go() {
     );
 }
 
+#[test]
+fn forw_raw_call_guards_go() {
+    multiversx_sc_scenario::run_go("scenarios/forw_raw_call_guards.scen.json");
+}
+
 #[test]
 fn forw_raw_contract_deploy_go() {
     multiversx_sc_scenario::run_go("scenarios/forw_raw_contract_deploy.scen.json");
@@ -151,6 +156,11 @@ fn forwarder_call_async_accept_nft_go() {
     multiversx_sc_scenario::run_go("scenarios/forwarder_call_async_accept_nft.scen.json");
 }
 
+#[test]
+fn forwarder_call_async_callback_context_go() {
+    multiversx_sc_scenario::run_go("scenarios/forwarder_call_async_callback_context.scen.json");
+}
+
 #[test]
 fn forwarder_call_async_multi_transfer_go() {
     multiversx_sc_scenario::run_go("scenarios/forwarder_call_async_multi_transfer.scen.json");
@@ -469,3 +479,9 @@ fn send_egld_go() {
 fn send_esdt_go() {
     multiversx_sc_scenario::run_go("scenarios/send_esdt.scen.json");
 }
+
+#[ignore]
+#[test]
+fn vault_upgrade_go() {
+    multiversx_sc_scenario::run_go("scenarios/vault_upgrade.scen.json");
+}
@@ -79,6 +79,11 @@ fn forw_raw_call_async_retrieve_multi_transfer_rs() {
     );
 }
 
+#[test]
+fn forw_raw_call_guards_rs() {
+    multiversx_sc_scenario::run_rs("scenarios/forw_raw_call_guards.scen.json", world());
+}
+
 #[test]
 fn forw_raw_contract_deploy_rs() {
     multiversx_sc_scenario::run_rs("scenarios/forw_raw_contract_deploy.scen.json", world());
@@ -222,6 +227,14 @@ fn forwarder_call_async_accept_nft_rs() {
     );
 }
 
+#[test]
+fn forwarder_call_async_callback_context_rs() {
+    multiversx_sc_scenario::run_rs(
+        "scenarios/forwarder_call_async_callback_context.scen.json",
+        world(),
+    );
+}
+
 #[test]
 fn forwarder_call_async_multi_transfer_rs() {
     multiversx_sc_scenario::run_rs(
@@ -643,3 +656,8 @@ fn send_egld_rs() {
 fn send_esdt_rs() {
     multiversx_sc_scenario::run_rs("scenarios/send_esdt.scen.json", world());
 }
+
+#[test]
+fn vault_upgrade_rs() {
+    multiversx_sc_scenario::run_rs("scenarios/vault_upgrade.scen.json", world());
+}
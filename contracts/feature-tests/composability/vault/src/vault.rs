@@ -14,6 +14,18 @@ pub trait Vault {
         opt_arg_to_echo
     }
 
+    #[upgrade]
+    fn upgrade(
+        &self,
+        opt_arg_to_echo: OptionalValue<ManagedBuffer>,
+    ) -> OptionalValue<ManagedBuffer> {
+        self.upgraded_event();
+        opt_arg_to_echo
+    }
+
+    #[event("upgraded")]
+    fn upgraded_event(&self);
+
     #[endpoint]
     fn echo_arguments(
         &self,
@@ -0,0 +1,10 @@
+use multiversx_sc_modules::access_control;
+
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait AccessControlTestModule: access_control::AccessControlModule {
+    #[only_role("MINTER")]
+    #[endpoint]
+    fn only_minter_mod_endpoint(&self) {}
+}
@@ -1,5 +1,6 @@
 #![no_std]
 
+mod access_control_mod;
 mod contract_base_full_path_mod;
 mod contract_base_mod;
 mod internal_mod_a;
@@ -23,6 +24,9 @@ multiversx_sc::imports!();
 /// - EsdtModule
 /// - GovernanceModule
 /// - PauseModule
+/// - AccessControlModule
+/// - TimelockModule
+/// - VestingModule
 #[multiversx_sc::contract]
 pub trait UseModule:
     ContractBase
@@ -32,6 +36,7 @@ pub trait UseModule:
     + internal_mod_b::InternalModuleB
     + internal_mod_c::InternalModuleC
     + internal_mod_init::InternalModuleInit
+    + access_control_mod::AccessControlTestModule
     + only_owner_mod::OnlyOwnerTestModule
     + only_owner_derived_mod::OnlyOwnerDerivedTestModule
     + only_admin_mod::OnlyAdminTestModule
@@ -51,6 +56,10 @@ pub trait UseModule:
     + multiversx_sc_modules::token_merge::merged_token_setup::MergedTokenSetupModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + multiversx_sc_modules::only_admin::OnlyAdminModule
+    + multiversx_sc_modules::access_control::AccessControlModule
+    + multiversx_sc_modules::timelock::TimelockModule
+    + multiversx_sc_modules::vesting::VestingModule
+    + multiversx_sc_modules::vesting::vesting_events::VestingEventsModule
     + multiversx_sc_modules::ongoing_operation::OngoingOperationModule
 {
     /// Validates that the "featureName" feature is on.
@@ -0,0 +1,51 @@
+use multiversx_sc::types::{ContractCall, ContractCallNoPayment};
+use multiversx_sc_modules::timelock::TimelockModule;
+use multiversx_sc_scenario::{managed_address, rust_biguint, testing_framework::*, DebugApi};
+
+const MIN_DELAY: u64 = 100;
+const START_TIMESTAMP: u64 = 1_000;
+
+/// Scheduling calls built with proxies is not an endpoint, the rest is covered by `use_module_timelock.scen.json`.
+#[test]
+fn timelock_schedule_contract_call_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_zero);
+    let timelock_wrapper = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        use_module::contract_obj,
+        "timelock path",
+    );
+    let sc_address = timelock_wrapper.address_ref().clone();
+    b_mock.set_block_timestamp(START_TIMESTAMP);
+
+    let mut operation_id = 0;
+    b_mock
+        .execute_tx(&owner, &timelock_wrapper, &rust_zero, |sc| {
+            sc.init_timelock_module(MIN_DELAY);
+
+            let mut contract_call = ContractCallNoPayment::<DebugApi, ()>::new(
+                managed_address!(&sc_address),
+                "updateMinDelay",
+            )
+            .with_gas_limit(1_000_000);
+            contract_call.proxy_arg(&300u64);
+
+            operation_id = sc.schedule_contract_call(contract_call, MIN_DELAY);
+        })
+        .assert_ok();
+
+    b_mock.set_block_timestamp(START_TIMESTAMP + MIN_DELAY);
+    b_mock
+        .execute_tx(&owner, &timelock_wrapper, &rust_zero, |sc| {
+            sc.execute_operation(operation_id);
+        })
+        .assert_ok();
+    b_mock
+        .execute_query(&timelock_wrapper, |sc| {
+            assert_eq!(sc.min_delay().get(), 300);
+            assert!(!sc.is_operation_pending(operation_id));
+        })
+        .assert_ok();
+}
@@ -1,3 +1,8 @@
+#[test]
+fn use_module_access_control_go() {
+    multiversx_sc_scenario::run_go("scenarios/use_module_access_control.scen.json");
+}
+
 #[test]
 fn use_module_claim_developer_rewards_go() {
     multiversx_sc_scenario::run_go("scenarios/use_module_claim_developer_rewards.scen.json");
@@ -38,6 +43,16 @@ fn use_module_pause_go() {
     multiversx_sc_scenario::run_go("scenarios/use_module_pause.scen.json");
 }
 
+#[test]
+fn use_module_timelock_go() {
+    multiversx_sc_scenario::run_go("scenarios/use_module_timelock.scen.json");
+}
+
+#[test]
+fn use_module_vesting_go() {
+    multiversx_sc_scenario::run_go("scenarios/use_module_vesting.scen.json");
+}
+
 #[test]
 fn use_module_ongoing_operation_go() {
     multiversx_sc_scenario::run_go("scenarios/use_module_ongoing_operation_example.scen.json");
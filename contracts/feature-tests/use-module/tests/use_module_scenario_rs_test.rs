@@ -43,6 +43,11 @@ fn world() -> ScenarioWorld {
     blockchain
 }
 
+#[test]
+fn use_module_access_control_rs() {
+    multiversx_sc_scenario::run_rs("scenarios/use_module_access_control.scen.json", world());
+}
+
 #[test]
 fn use_module_claim_developer_rewards_rs() {
     multiversx_sc_scenario::run_rs(
@@ -86,6 +91,16 @@ fn use_module_pause_rs() {
     multiversx_sc_scenario::run_rs("scenarios/use_module_pause.scen.json", world());
 }
 
+#[test]
+fn use_module_timelock_rs() {
+    multiversx_sc_scenario::run_rs("scenarios/use_module_timelock.scen.json", world());
+}
+
+#[test]
+fn use_module_vesting_rs() {
+    multiversx_sc_scenario::run_rs("scenarios/use_module_vesting.scen.json", world());
+}
+
 /// Will not work in scenarios-rs, since there is no gas usage
 #[test]
 #[ignore]
@@ -0,0 +1,56 @@
+use multiversx_sc::types::EsdtTokenPayment;
+use multiversx_sc_modules::vesting::{vesting_schedule::ReleaseCurve, VestingModule};
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, managed_token_id, rust_biguint, testing_framework::*,
+};
+
+static VESTED_TOKEN_ID: &[u8] = b"VESTED-123456";
+
+/// Granting schedules from within the contract is not an endpoint, the rest is covered by `use_module_vesting.scen.json`.
+#[test]
+fn vesting_create_schedule_from_contract_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_zero);
+    let beneficiary = b_mock.create_user_account(&rust_zero);
+    let vesting_wrapper = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        use_module::contract_obj,
+        "vesting path",
+    );
+    b_mock.set_esdt_balance(
+        vesting_wrapper.address_ref(),
+        VESTED_TOKEN_ID,
+        &rust_biguint!(1_000),
+    );
+    b_mock.set_block_timestamp(1_500);
+
+    b_mock
+        .execute_tx(&owner, &vesting_wrapper, &rust_zero, |sc| {
+            // started in the past, half of it is already vested
+            let schedule_id = sc.create_vesting_schedule(
+                managed_address!(&beneficiary),
+                EsdtTokenPayment::new(
+                    managed_token_id!(VESTED_TOKEN_ID),
+                    0,
+                    managed_biguint!(1_000),
+                ),
+                1_000,
+                0,
+                1_000,
+                ReleaseCurve::Linear,
+                false,
+                false,
+            );
+            assert_eq!(sc.get_claimable_amount(schedule_id), managed_biguint!(500));
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_tx(&beneficiary, &vesting_wrapper, &rust_zero, |sc| {
+            sc.claim_vested_tokens();
+        })
+        .assert_ok();
+    b_mock.check_esdt_balance(&beneficiary, VESTED_TOKEN_ID, &rust_biguint!(500));
+}
@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                           62
+// Endpoints:                           89
 // Async Callback:                       1
-// Total number of exported functions:  64
+// Total number of exported functions:  91
 
 #![no_std]
 #![feature(alloc_error_handler, lang_items)]
@@ -25,6 +25,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         call_mod_a
         call_mod_b
         call_mod_c
+        only_minter_mod_endpoint
         only_owner_mod_endpoint
         call_derived_not_owner_only
         only_admin_mod_endpoint
@@ -80,6 +81,32 @@ multiversx_sc_wasm_adapter::endpoints! {
         addAdmin
         removeAdmin
         getAdmins
+        hasRole
+        getRoleAdmin
+        getRoleMembers
+        grantRole
+        revokeRole
+        renounceRole
+        setRoleAdmin
+        scheduleOperation
+        cancelOperation
+        executeOperation
+        updateMinDelay
+        isOperationPending
+        isOperationReady
+        getOperation
+        getPendingOperations
+        getMinDelay
+        issueVestingPositionToken
+        grantVestingSchedule
+        claimVestedTokens
+        claimWithVestingPosition
+        revokeVestingSchedule
+        getVestingScheduleIds
+        getVestingSchedule
+        getVestedAmount
+        getClaimableAmount
+        getVestingPositionTokenId
         callBack
     )
 }
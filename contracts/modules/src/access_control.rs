@@ -0,0 +1,136 @@
+multiversx_sc::imports!();
+
+/// The role that administers all roles that do not have a custom admin role configured.
+pub const DEFAULT_ADMIN_ROLE: &[u8] = b"DEFAULT_ADMIN";
+
+/// Standard smart contract module that, when added to a smart contract, offers role-based access control.
+///
+/// Roles are identified by name (e.g. `"MINTER"`). Each role has an admin role,
+/// whose members are allowed to grant and revoke it.
+/// Unless configured otherwise, the admin of every role is `DEFAULT_ADMIN`.
+/// The contract owner can always grant and revoke any role, so that the first admins can be set up.
+///
+/// It offers:
+/// * endpoints to grant, revoke and renounce roles
+/// * an owner-only endpoint to change the admin role of a role
+/// * views to check role membership
+/// * the `#[only_role("ROLE_NAME")]` endpoint annotation, which relies on this module
+///
+#[multiversx_sc::module]
+pub trait AccessControlModule {
+    #[view(hasRole)]
+    fn has_role(&self, role: ManagedBuffer, address: ManagedAddress) -> bool {
+        self.role_members(&role).contains(&address)
+    }
+
+    #[view(getRoleAdmin)]
+    fn get_role_admin(&self, role: ManagedBuffer) -> ManagedBuffer {
+        let admin_role_mapper = self.role_admin(&role);
+        if admin_role_mapper.is_empty() {
+            ManagedBuffer::new_from_bytes(DEFAULT_ADMIN_ROLE)
+        } else {
+            admin_role_mapper.get()
+        }
+    }
+
+    #[view(getRoleMembers)]
+    fn get_role_members(&self, role: ManagedBuffer) -> MultiValueEncoded<ManagedAddress> {
+        self.role_members(&role).iter().collect()
+    }
+
+    #[endpoint(grantRole)]
+    fn grant_role(&self, role: ManagedBuffer, address: ManagedAddress) {
+        self.require_caller_can_administer_role(&role);
+
+        if self.role_members(&role).insert(address.clone()) {
+            let caller = self.blockchain().get_caller();
+            self.role_granted_event(&role, &address, &caller);
+        }
+    }
+
+    #[endpoint(revokeRole)]
+    fn revoke_role(&self, role: ManagedBuffer, address: ManagedAddress) {
+        self.require_caller_can_administer_role(&role);
+
+        if self.role_members(&role).swap_remove(&address) {
+            let caller = self.blockchain().get_caller();
+            self.role_revoked_event(&role, &address, &caller);
+        }
+    }
+
+    /// Allows the caller to give up one of its own roles.
+    #[endpoint(renounceRole)]
+    fn renounce_role(&self, role: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.role_members(&role).swap_remove(&caller),
+            "Caller does not have the role"
+        );
+
+        self.role_revoked_event(&role, &caller, &caller);
+    }
+
+    #[only_owner]
+    #[endpoint(setRoleAdmin)]
+    fn set_role_admin(&self, role: ManagedBuffer, admin_role: ManagedBuffer) {
+        let previous_admin_role = self.get_role_admin(role.clone());
+        self.role_admin(&role).set(&admin_role);
+
+        self.role_admin_changed_event(&role, &previous_admin_role, &admin_role);
+    }
+
+    /// Called by the code generated for endpoints annotated with `#[only_role("...")]`.
+    fn require_caller_has_role(&self, role: &[u8]) {
+        let role = ManagedBuffer::new_from_bytes(role);
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.role_members(&role).contains(&caller),
+            "Endpoint can only be called by members of role {}",
+            role
+        );
+    }
+
+    fn require_caller_can_administer_role(&self, role: &ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        if caller == self.blockchain().get_owner_address() {
+            return;
+        }
+
+        let admin_role = self.get_role_admin(role.clone());
+        require!(
+            self.role_members(&admin_role).contains(&caller),
+            "Caller is not an admin of role {}",
+            role
+        );
+    }
+
+    #[event("roleGranted")]
+    fn role_granted_event(
+        &self,
+        #[indexed] role: &ManagedBuffer,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] caller: &ManagedAddress,
+    );
+
+    #[event("roleRevoked")]
+    fn role_revoked_event(
+        &self,
+        #[indexed] role: &ManagedBuffer,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] caller: &ManagedAddress,
+    );
+
+    #[event("roleAdminChanged")]
+    fn role_admin_changed_event(
+        &self,
+        #[indexed] role: &ManagedBuffer,
+        #[indexed] previous_admin_role: &ManagedBuffer,
+        #[indexed] new_admin_role: &ManagedBuffer,
+    );
+
+    #[storage_mapper("access_control_module:role_members")]
+    fn role_members(&self, role: &ManagedBuffer) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("access_control_module:role_admin")]
+    fn role_admin(&self, role: &ManagedBuffer) -> SingleValueMapper<ManagedBuffer>;
+}
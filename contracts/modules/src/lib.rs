@@ -1,6 +1,7 @@
 #![no_std]
 #![feature(trait_alias)]
 
+pub mod access_control;
 pub mod bonding_curve;
 pub mod claim_developer_rewards;
 pub mod default_issue_callbacks;
@@ -12,6 +13,8 @@ pub mod ongoing_operation;
 pub mod only_admin;
 pub mod pause;
 pub mod staking;
+pub mod timelock;
 pub mod token_merge;
 pub mod transfer_role_proxy;
 pub mod users;
+pub mod vesting;
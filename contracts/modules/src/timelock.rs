@@ -0,0 +1,266 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::access_control;
+
+/// Members of this role can schedule and cancel operations.
+pub const TIMELOCK_PROPOSER_ROLE: &[u8] = b"TIMELOCK_PROPOSER";
+
+/// Members of this role can execute operations, once they are ready.
+pub const TIMELOCK_EXECUTOR_ROLE: &[u8] = b"TIMELOCK_EXECUTOR";
+
+const MAX_GAS_LIMIT_PER_BLOCK: u64 = 600_000_000;
+
+pub type OperationId = usize;
+
+pub type TimelockOperationAsMultiArg<M> = MultiValue2<OperationId, TimelockOperation<M>>;
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct TimelockOperation<M: ManagedTypeApi> {
+    pub proposer: ManagedAddress<M>,
+    pub ready_timestamp: u64,
+    pub gas_limit: u64,
+    pub dest_address: ManagedAddress<M>,
+    pub function_name: ManagedBuffer<M>,
+    pub arguments: ManagedVec<M, ManagedBuffer<M>>,
+}
+
+/// Standard smart contract module that, when added to a smart contract, puts arbitrary calls behind a delay.
+///
+/// Operations are synchronous contract calls without payment, scheduled by the owner or by members of the `TIMELOCK_PROPOSER` role.
+/// They can only be executed after a minimum delay, by the owner or by members of the `TIMELOCK_EXECUTOR` role.
+/// Until then, they can be cancelled by the owner or by any proposer.
+///
+/// The minimum delay can only be changed by the contract itself, i.e. through a scheduled operation.
+///
+/// It offers:
+/// * endpoints to schedule, cancel and execute operations
+/// * `schedule_contract_call`, to schedule calls built with proxies from within the contract
+/// * views of the pending operations
+/// * events for all of the above
+///
+#[multiversx_sc::module]
+pub trait TimelockModule: access_control::AccessControlModule {
+    fn init_timelock_module(&self, min_delay: u64) {
+        self.min_delay().set(min_delay);
+    }
+
+    #[endpoint(scheduleOperation)]
+    fn schedule_operation_endpoint(
+        &self,
+        dest_address: ManagedAddress,
+        function_name: ManagedBuffer,
+        gas_limit: u64,
+        delay: u64,
+        arguments: MultiValueEncoded<ManagedBuffer>,
+    ) -> OperationId {
+        self.require_caller_is_proposer();
+
+        self.schedule_operation(
+            dest_address,
+            function_name,
+            gas_limit,
+            delay,
+            arguments.to_vec(),
+        )
+    }
+
+    /// The call needs an explicit gas limit, which will be used when executing it.
+    fn schedule_contract_call<R>(
+        &self,
+        contract_call: ContractCallNoPayment<Self::Api, R>,
+        delay: u64,
+    ) -> OperationId {
+        self.schedule_operation(
+            contract_call.to,
+            contract_call.endpoint_name,
+            contract_call.explicit_gas_limit,
+            delay,
+            contract_call.arg_buffer.into_multi_value_encoded().to_vec(),
+        )
+    }
+
+    fn schedule_operation(
+        &self,
+        dest_address: ManagedAddress,
+        function_name: ManagedBuffer,
+        gas_limit: u64,
+        delay: u64,
+        arguments: ManagedVec<ManagedBuffer>,
+    ) -> OperationId {
+        require!(
+            delay >= self.min_delay().get(),
+            "Delay is lower than the minimum delay"
+        );
+        require!(
+            gas_limit <= MAX_GAS_LIMIT_PER_BLOCK,
+            "Gas limit too high for a single operation"
+        );
+
+        let ready_timestamp = self
+            .blockchain()
+            .get_block_timestamp()
+            .checked_add(delay)
+            .unwrap_or_else(|| sc_panic!("delay overflow"));
+
+        let operation_id = self.last_operation_id().update(|last_id| {
+            *last_id += 1;
+            *last_id
+        });
+        let operation = TimelockOperation {
+            proposer: self.blockchain().get_caller(),
+            ready_timestamp,
+            gas_limit,
+            dest_address,
+            function_name,
+            arguments,
+        };
+        self.operation_scheduled_event(
+            operation_id,
+            &operation.dest_address,
+            operation.ready_timestamp,
+            &operation,
+        );
+        self.operations().insert(operation_id, operation);
+
+        operation_id
+    }
+
+    #[endpoint(cancelOperation)]
+    fn cancel_operation(&self, operation_id: OperationId) {
+        self.require_caller_is_proposer();
+        require!(
+            self.operations().remove(&operation_id).is_some(),
+            "Operation does not exist"
+        );
+
+        self.operation_cancelled_event(operation_id);
+    }
+
+    /// The call is synchronous, so the destination needs to be in the same shard.
+    /// If it fails, the whole execution fails and the operation stays pending.
+    #[endpoint(executeOperation)]
+    fn execute_operation(&self, operation_id: OperationId) {
+        self.require_caller_is_executor();
+
+        let operation = match self.operations().get(&operation_id) {
+            Some(operation) => operation,
+            None => sc_panic!("Operation does not exist"),
+        };
+        require!(
+            self.blockchain().get_block_timestamp() >= operation.ready_timestamp,
+            "Operation is not ready yet"
+        );
+        require!(
+            self.blockchain().get_gas_left() > operation.gas_limit,
+            "Not enough gas to execute operation"
+        );
+
+        // removed before the call, so that the operation cannot be re-entered
+        self.operations().remove(&operation_id);
+
+        let mut contract_call = self
+            .send()
+            .contract_call::<()>(operation.dest_address, operation.function_name)
+            .with_gas_limit(operation.gas_limit);
+        for arg in &operation.arguments {
+            contract_call.push_raw_argument(arg);
+        }
+        let _: IgnoreValue = contract_call.execute_on_dest_context();
+
+        self.operation_executed_event(operation_id);
+    }
+
+    /// Can only be called by the contract itself, so changing the delay also needs to go through the timelock.
+    #[endpoint(updateMinDelay)]
+    fn update_min_delay(&self, new_min_delay: u64) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            caller == self.blockchain().get_sc_address(),
+            "Only the SC itself may call this function"
+        );
+
+        let old_min_delay = self.min_delay().get();
+        self.min_delay().set(new_min_delay);
+
+        self.min_delay_changed_event(old_min_delay, new_min_delay);
+    }
+
+    #[view(isOperationPending)]
+    fn is_operation_pending(&self, operation_id: OperationId) -> bool {
+        self.operations().contains_key(&operation_id)
+    }
+
+    #[view(isOperationReady)]
+    fn is_operation_ready(&self, operation_id: OperationId) -> bool {
+        match self.operations().get(&operation_id) {
+            Some(operation) => self.blockchain().get_block_timestamp() >= operation.ready_timestamp,
+            None => false,
+        }
+    }
+
+    #[view(getOperation)]
+    fn get_operation(
+        &self,
+        operation_id: OperationId,
+    ) -> OptionalValue<TimelockOperation<Self::Api>> {
+        self.operations().get(&operation_id).into()
+    }
+
+    #[view(getPendingOperations)]
+    fn get_pending_operations(&self) -> MultiValueEncoded<TimelockOperationAsMultiArg<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        for (operation_id, operation) in self.operations().iter() {
+            result.push((operation_id, operation).into());
+        }
+        result
+    }
+
+    fn require_caller_is_proposer(&self) {
+        require!(
+            self.caller_has_timelock_role(TIMELOCK_PROPOSER_ROLE),
+            "Caller is neither the owner nor a proposer"
+        );
+    }
+
+    fn require_caller_is_executor(&self) {
+        require!(
+            self.caller_has_timelock_role(TIMELOCK_EXECUTOR_ROLE),
+            "Caller is neither the owner nor an executor"
+        );
+    }
+
+    fn caller_has_timelock_role(&self, role: &[u8]) -> bool {
+        let caller = self.blockchain().get_caller();
+        caller == self.blockchain().get_owner_address()
+            || self.has_role(ManagedBuffer::new_from_bytes(role), caller)
+    }
+
+    #[event("operationScheduled")]
+    fn operation_scheduled_event(
+        &self,
+        #[indexed] operation_id: OperationId,
+        #[indexed] dest_address: &ManagedAddress,
+        #[indexed] ready_timestamp: u64,
+        operation: &TimelockOperation<Self::Api>,
+    );
+
+    #[event("operationCancelled")]
+    fn operation_cancelled_event(&self, #[indexed] operation_id: OperationId);
+
+    #[event("operationExecuted")]
+    fn operation_executed_event(&self, #[indexed] operation_id: OperationId);
+
+    #[event("minDelayChanged")]
+    fn min_delay_changed_event(&self, #[indexed] old_min_delay: u64, #[indexed] new_min_delay: u64);
+
+    #[view(getMinDelay)]
+    #[storage_mapper("timelock_module:min_delay")]
+    fn min_delay(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("timelock_module:last_operation_id")]
+    fn last_operation_id(&self) -> SingleValueMapper<OperationId>;
+
+    #[storage_mapper("timelock_module:operations")]
+    fn operations(&self) -> MapMapper<OperationId, TimelockOperation<Self::Api>>;
+}
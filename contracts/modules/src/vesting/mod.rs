@@ -0,0 +1,326 @@
+multiversx_sc::imports!();
+
+pub mod vesting_events;
+pub mod vesting_schedule;
+
+use vesting_schedule::*;
+
+/// Standard smart contract module that, when added to a smart contract, locks tokens and releases them over time.
+///
+/// The owner grants vesting schedules to beneficiaries, by sending the tokens to be locked.
+/// Both fungible ESDTs and meta-ESDTs can be vested, and a beneficiary can have any number of schedules.
+/// Nothing is released before the end of the cliff, then tokens are released either linearly or in equal steps,
+/// until the end of the schedule.
+///
+/// Revocable schedules can be stopped by the owner at any time:
+/// what had vested until then can still be claimed, the rest is returned to the owner.
+///
+/// Optionally, the locked amount of a schedule can be represented by a meta-ESDT position,
+/// sent to the beneficiary on grant. Whoever holds the position can then claim the vested tokens, by sending it back.
+/// This requires the position token to be issued first, and the `DefaultIssueCallbacksModule` to be part of the contract.
+///
+/// It offers:
+/// * owner-only endpoints to issue the position token, grant and revoke schedules
+/// * `create_vesting_schedule`, to grant schedules from within the contract
+/// * claim endpoints, both for beneficiaries and for position holders
+/// * views of the schedules and of the vested and claimable amounts
+/// * events for all of the above
+///
+#[multiversx_sc::module]
+pub trait VestingModule: vesting_events::VestingEventsModule {
+    #[only_owner]
+    #[payable("EGLD")]
+    #[endpoint(issueVestingPositionToken)]
+    fn issue_vesting_position_token(
+        &self,
+        token_display_name: ManagedBuffer,
+        token_ticker: ManagedBuffer,
+        num_decimals: usize,
+    ) {
+        let payment_amount = self.call_value().egld_value();
+        self.vesting_position_token().issue_and_set_all_roles(
+            EsdtTokenType::Meta,
+            payment_amount,
+            token_display_name,
+            token_ticker,
+            num_decimals,
+            None,
+        );
+    }
+
+    /// Locks the received tokens in a new vesting schedule.
+    /// The schedule starts at `start_timestamp`, which can also be in the past.
+    #[only_owner]
+    #[payable("*")]
+    #[endpoint(grantVestingSchedule)]
+    fn grant_vesting_schedule(
+        &self,
+        beneficiary: ManagedAddress,
+        start_timestamp: u64,
+        cliff_duration: u64,
+        duration: u64,
+        release_curve: ReleaseCurve,
+        revocable: bool,
+        with_position: bool,
+    ) -> ScheduleId {
+        let payment = self.call_value().single_esdt();
+        self.create_vesting_schedule(
+            beneficiary,
+            payment,
+            start_timestamp,
+            cliff_duration,
+            duration,
+            release_curve,
+            revocable,
+            with_position,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn create_vesting_schedule(
+        &self,
+        beneficiary: ManagedAddress,
+        payment: EsdtTokenPayment,
+        start_timestamp: u64,
+        cliff_duration: u64,
+        duration: u64,
+        release_curve: ReleaseCurve,
+        revocable: bool,
+        with_position: bool,
+    ) -> ScheduleId {
+        require!(payment.amount > 0u32, "Cannot vest zero tokens");
+        require!(duration > 0, "Duration must be greater than zero");
+        require!(
+            cliff_duration <= duration,
+            "Cliff cannot be longer than the schedule"
+        );
+        require!(
+            start_timestamp.checked_add(duration).is_some(),
+            "Vesting schedule end overflow"
+        );
+        if let ReleaseCurve::Step { step_duration } = release_curve {
+            require!(
+                step_duration > 0 && duration % step_duration == 0,
+                "Duration must be a multiple of the step duration"
+            );
+        }
+
+        let schedule_id = self.last_schedule_id().update(|last_id| {
+            *last_id += 1;
+            *last_id
+        });
+        let schedule = VestingSchedule {
+            beneficiary,
+            token_identifier: payment.token_identifier,
+            token_nonce: payment.token_nonce,
+            total_amount: payment.amount,
+            claimed_amount: BigUint::zero(),
+            start_timestamp,
+            cliff_duration,
+            duration,
+            release_curve,
+            revocable,
+            revoked: false,
+            has_position: with_position,
+        };
+
+        if with_position {
+            let attributes = VestingPositionAttributes { schedule_id };
+            let _ = self.vesting_position_token().nft_create_and_send(
+                &schedule.beneficiary,
+                schedule.total_amount.clone(),
+                &attributes,
+            );
+        }
+
+        self.vesting_schedule_created_event(schedule_id, &schedule.beneficiary, &schedule);
+        self.beneficiary_schedules(&schedule.beneficiary)
+            .insert(schedule_id);
+        self.schedules().insert(schedule_id, schedule);
+
+        schedule_id
+    }
+
+    /// Sends the caller all the tokens vested so far, from all of its schedules that are not represented by a position.
+    #[endpoint(claimVestedTokens)]
+    fn claim_vested_tokens(&self) {
+        let caller = self.blockchain().get_caller();
+        let current_timestamp = self.blockchain().get_block_timestamp();
+
+        let schedule_ids: ManagedVec<ScheduleId> =
+            self.beneficiary_schedules(&caller).iter().collect();
+        for schedule_id in &schedule_ids {
+            let mut schedule = self.schedules().get(&schedule_id).unwrap();
+            if schedule.has_position {
+                continue;
+            }
+
+            let claimable_amount = schedule.claimable_amount(current_timestamp);
+            if claimable_amount == 0u32 {
+                continue;
+            }
+
+            self.send().direct_esdt(
+                &caller,
+                &schedule.token_identifier,
+                schedule.token_nonce,
+                &claimable_amount,
+            );
+            self.vested_tokens_claimed_event(schedule_id, &caller, &claimable_amount);
+
+            schedule.claimed_amount += claimable_amount;
+            self.update_schedule(schedule_id, schedule);
+        }
+    }
+
+    /// Receives vesting positions and sends back the vested tokens they represent.
+    /// The positions are burned for the amount released, the rest is returned to the caller.
+    #[payable("*")]
+    #[endpoint(claimWithVestingPosition)]
+    fn claim_with_vesting_position(&self) {
+        let payments = self.call_value().all_esdt_transfers();
+        require!(!payments.is_empty(), "No vesting position received");
+
+        let caller = self.blockchain().get_caller();
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        let position_mapper = self.vesting_position_token();
+
+        for payment in &payments {
+            position_mapper.require_same_token(&payment.token_identifier);
+
+            let attributes: VestingPositionAttributes =
+                position_mapper.get_token_attributes(payment.token_nonce);
+            let schedule_id = attributes.schedule_id;
+            let mut schedule = match self.schedules().get(&schedule_id) {
+                Some(schedule) => schedule,
+                None => {
+                    // the schedule was fully claimed and removed,
+                    // positions left over after a revoke no longer represent any tokens
+                    position_mapper.nft_burn(payment.token_nonce, &payment.amount);
+                    continue;
+                },
+            };
+
+            let claimable_amount = schedule.claimable_amount(current_timestamp);
+            let released_amount = core::cmp::min(claimable_amount, payment.amount.clone());
+            schedule.claimed_amount += &released_amount;
+
+            if schedule.is_fully_claimed() {
+                // positions left over after a revoke no longer represent any tokens
+                position_mapper.nft_burn(payment.token_nonce, &payment.amount);
+            } else {
+                position_mapper.nft_burn(payment.token_nonce, &released_amount);
+                self.send().direct_non_zero_esdt_payment(
+                    &caller,
+                    &EsdtTokenPayment::new(
+                        payment.token_identifier.clone(),
+                        payment.token_nonce,
+                        &payment.amount - &released_amount,
+                    ),
+                );
+            }
+
+            if released_amount > 0u32 {
+                self.send().direct_esdt(
+                    &caller,
+                    &schedule.token_identifier,
+                    schedule.token_nonce,
+                    &released_amount,
+                );
+                self.vested_tokens_claimed_event(schedule_id, &caller, &released_amount);
+            }
+
+            self.update_schedule(schedule_id, schedule);
+        }
+    }
+
+    /// Stops a revocable schedule. The tokens that have not vested yet are returned to the owner.
+    #[only_owner]
+    #[endpoint(revokeVestingSchedule)]
+    fn revoke_vesting_schedule(&self, schedule_id: ScheduleId) {
+        let mut schedule = self.require_schedule_exists(schedule_id);
+        require!(schedule.revocable, "Vesting schedule is not revocable");
+        require!(!schedule.revoked, "Vesting schedule already revoked");
+
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        let vested_amount = schedule.vested_amount(current_timestamp);
+        let returned_amount = &schedule.total_amount - &vested_amount;
+        schedule.total_amount = vested_amount;
+        schedule.revoked = true;
+
+        if returned_amount > 0u32 {
+            let caller = self.blockchain().get_caller();
+            self.send().direct_esdt(
+                &caller,
+                &schedule.token_identifier,
+                schedule.token_nonce,
+                &returned_amount,
+            );
+        }
+        self.vesting_schedule_revoked_event(schedule_id, &returned_amount);
+
+        self.update_schedule(schedule_id, schedule);
+    }
+
+    /// Fully claimed schedules are removed from storage.
+    /// Positions of a removed schedule can still be sent back, they only get burned.
+    fn update_schedule(&self, schedule_id: ScheduleId, schedule: VestingSchedule<Self::Api>) {
+        if schedule.is_fully_claimed() {
+            self.beneficiary_schedules(&schedule.beneficiary)
+                .swap_remove(&schedule_id);
+            self.schedules().remove(&schedule_id);
+        } else {
+            self.schedules().insert(schedule_id, schedule);
+        }
+    }
+
+    fn require_schedule_exists(&self, schedule_id: ScheduleId) -> VestingSchedule<Self::Api> {
+        match self.schedules().get(&schedule_id) {
+            Some(schedule) => schedule,
+            None => sc_panic!("Vesting schedule does not exist"),
+        }
+    }
+
+    #[view(getVestingScheduleIds)]
+    fn get_vesting_schedule_ids(
+        &self,
+        beneficiary: ManagedAddress,
+    ) -> MultiValueEncoded<ScheduleId> {
+        self.beneficiary_schedules(&beneficiary).iter().collect()
+    }
+
+    #[view(getVestingSchedule)]
+    fn get_vesting_schedule(
+        &self,
+        schedule_id: ScheduleId,
+    ) -> OptionalValue<VestingSchedule<Self::Api>> {
+        self.schedules().get(&schedule_id).into()
+    }
+
+    /// Total amount released so far, including what was already claimed.
+    #[view(getVestedAmount)]
+    fn get_vested_amount(&self, schedule_id: ScheduleId) -> BigUint {
+        let schedule = self.require_schedule_exists(schedule_id);
+        schedule.vested_amount(self.blockchain().get_block_timestamp())
+    }
+
+    #[view(getClaimableAmount)]
+    fn get_claimable_amount(&self, schedule_id: ScheduleId) -> BigUint {
+        let schedule = self.require_schedule_exists(schedule_id);
+        schedule.claimable_amount(self.blockchain().get_block_timestamp())
+    }
+
+    #[view(getVestingPositionTokenId)]
+    #[storage_mapper("vesting_module:position_token")]
+    fn vesting_position_token(&self) -> NonFungibleTokenMapper<Self::Api>;
+
+    #[storage_mapper("vesting_module:last_schedule_id")]
+    fn last_schedule_id(&self) -> SingleValueMapper<ScheduleId>;
+
+    #[storage_mapper("vesting_module:schedules")]
+    fn schedules(&self) -> MapMapper<ScheduleId, VestingSchedule<Self::Api>>;
+
+    #[storage_mapper("vesting_module:beneficiary_schedules")]
+    fn beneficiary_schedules(&self, beneficiary: &ManagedAddress)
+        -> UnorderedSetMapper<ScheduleId>;
+}
@@ -0,0 +1,29 @@
+multiversx_sc::imports!();
+
+use super::vesting_schedule::{ScheduleId, VestingSchedule};
+
+#[multiversx_sc::module]
+pub trait VestingEventsModule {
+    #[event("vestingScheduleCreated")]
+    fn vesting_schedule_created_event(
+        &self,
+        #[indexed] schedule_id: ScheduleId,
+        #[indexed] beneficiary: &ManagedAddress,
+        schedule: &VestingSchedule<Self::Api>,
+    );
+
+    #[event("vestedTokensClaimed")]
+    fn vested_tokens_claimed_event(
+        &self,
+        #[indexed] schedule_id: ScheduleId,
+        #[indexed] claimer: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    #[event("vestingScheduleRevoked")]
+    fn vesting_schedule_revoked_event(
+        &self,
+        #[indexed] schedule_id: ScheduleId,
+        returned_amount: &BigUint,
+    );
+}
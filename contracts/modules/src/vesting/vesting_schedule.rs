@@ -0,0 +1,79 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+pub type ScheduleId = usize;
+
+/// How the locked amount is released between the end of the cliff and the end of the schedule.
+#[derive(
+    TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug,
+)]
+pub enum ReleaseCurve {
+    /// Continuous release, proportional to the time elapsed since the start.
+    Linear,
+
+    /// Equal tranches, one every `step_duration` seconds since the start.
+    Step { step_duration: u64 },
+}
+
+#[derive(
+    TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug,
+)]
+pub struct VestingSchedule<M: ManagedTypeApi> {
+    pub beneficiary: ManagedAddress<M>,
+    pub token_identifier: TokenIdentifier<M>,
+    pub token_nonce: u64,
+    pub total_amount: BigUint<M>,
+    pub claimed_amount: BigUint<M>,
+    pub start_timestamp: u64,
+    pub cliff_duration: u64,
+    pub duration: u64,
+    pub release_curve: ReleaseCurve,
+    pub revocable: bool,
+    pub revoked: bool,
+    pub has_position: bool,
+}
+
+/// Attributes of the meta-ESDT position that represents the locked amount of a schedule.
+#[derive(
+    TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug,
+)]
+pub struct VestingPositionAttributes {
+    pub schedule_id: ScheduleId,
+}
+
+impl<M: ManagedTypeApi> VestingSchedule<M> {
+    /// Total amount released by the schedule at the given moment, including what was already claimed.
+    pub fn vested_amount(&self, timestamp: u64) -> BigUint<M> {
+        if self.revoked {
+            // revoking caps the total amount to what had vested at that moment
+            return self.total_amount.clone();
+        }
+        match self.start_timestamp.checked_add(self.cliff_duration) {
+            Some(cliff_end) if timestamp >= cliff_end => {},
+            _ => return BigUint::zero(),
+        }
+
+        let elapsed = timestamp - self.start_timestamp;
+        if elapsed >= self.duration {
+            return self.total_amount.clone();
+        }
+
+        match self.release_curve {
+            ReleaseCurve::Linear => &self.total_amount * elapsed / self.duration,
+            ReleaseCurve::Step { step_duration } => {
+                let elapsed_steps = elapsed / step_duration;
+                let total_steps = self.duration / step_duration;
+                &self.total_amount * elapsed_steps / total_steps
+            },
+        }
+    }
+
+    /// Amount that can be claimed at the given moment.
+    pub fn claimable_amount(&self, timestamp: u64) -> BigUint<M> {
+        self.vested_amount(timestamp) - &self.claimed_amount
+    }
+
+    pub fn is_fully_claimed(&self) -> bool {
+        self.claimed_amount == self.total_amount
+    }
+}
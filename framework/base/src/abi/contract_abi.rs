@@ -1,4 +1,5 @@
 use super::*;
+use crate::types::ContractError;
 use alloc::{string::String, vec::Vec};
 
 #[derive(Debug, Default, Clone)]
@@ -7,9 +8,12 @@ pub struct ContractAbi {
     pub docs: &'static [&'static str],
     pub name: &'static str,
     pub constructors: Vec<EndpointAbi>,
+    pub upgrade_constructors: Vec<EndpointAbi>,
     pub endpoints: Vec<EndpointAbi>,
     pub promise_callbacks: Vec<EndpointAbi>,
     pub events: Vec<EventAbi>,
+    pub storage: Vec<StorageAbi>,
+    pub errors: Vec<ErrorAbi>,
     pub has_callback: bool,
     pub type_descriptions: TypeDescriptionContainerImpl,
 }
@@ -18,14 +22,51 @@ impl ContractAbi {
     pub fn coalesce(&mut self, other: Self) {
         self.constructors
             .extend_from_slice(other.constructors.as_slice());
+        self.upgrade_constructors
+            .extend_from_slice(other.upgrade_constructors.as_slice());
         self.endpoints.extend_from_slice(other.endpoints.as_slice());
         self.events.extend_from_slice(other.events.as_slice());
+        for storage_abi in other.storage {
+            self.add_storage(storage_abi);
+        }
+        for error_abi in other.errors {
+            self.add_error(error_abi);
+        }
         self.promise_callbacks
             .extend_from_slice(other.promise_callbacks.as_slice());
         self.has_callback |= other.has_callback;
         self.type_descriptions.insert_all(&other.type_descriptions);
     }
 
+    /// Getters and setters of the same key only get described once.
+    pub fn add_storage(&mut self, storage_abi: StorageAbi) {
+        if !self
+            .storage
+            .iter()
+            .any(|existing| existing.same_layout(&storage_abi))
+        {
+            self.storage.push(storage_abi);
+        }
+    }
+
+    /// The same error enum can be registered by several modules, codes only get described once.
+    pub fn add_error(&mut self, error_abi: ErrorAbi) {
+        if !self
+            .errors
+            .iter()
+            .any(|existing| existing.code == error_abi.code && existing.name == error_abi.name)
+        {
+            self.errors.push(error_abi);
+        }
+    }
+
+    pub fn add_errors<E: ContractError>(&mut self) {
+        for error_abi in E::error_abis() {
+            self.add_error(error_abi);
+        }
+        E::provide_error_type_descriptions(&mut self.type_descriptions);
+    }
+
     /// A type can provide more than 1 type descripions.
     /// For instance, a struct can also provide the descriptions of its fields.
     pub fn add_type_descriptions<T: TypeAbi>(&mut self) {
@@ -26,6 +26,15 @@ pub enum EndpointMutabilityAbi {
     Pure,
 }
 
+/// Where an endpoint or event is declared in the contract code.
+///
+/// Only used to report problems found in the ABI, it is not part of the ABI JSON.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SourceLocationAbi {
+    pub file: &'static str,
+    pub line: u32,
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct EndpointAbi {
     pub docs: &'static [&'static str],
@@ -33,11 +42,20 @@ pub struct EndpointAbi {
     pub rust_method_name: &'static str,
     pub only_owner: bool,
     pub only_admin: bool,
+    pub only_role: Option<&'static str>,
+    pub only_callback_context: bool,
+    pub not_from_contract: bool,
+    pub non_reentrant: bool,
+    /// Gas budget declared with `#[max_gas]`.
+    pub max_gas: Option<u64>,
+    /// Limit on the number of results, declared with `#[max_return_items]`.
+    pub max_return_items: Option<usize>,
     pub labels: &'static [&'static str],
     pub mutability: EndpointMutabilityAbi,
     pub payable_in_tokens: &'static [&'static str],
     pub inputs: Vec<InputAbi>,
     pub outputs: OutputAbis,
+    pub location: Option<SourceLocationAbi>,
 }
 
 impl EndpointAbi {
@@ -0,0 +1,30 @@
+use super::*;
+use alloc::vec::Vec;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ErrorFieldAbi {
+    pub name: &'static str,
+    pub type_name: TypeName,
+}
+
+/// Describes one variant of a `#[derive(ContractError)]` enum.
+///
+/// The message template references the fields by name, e.g. `needed {needed}`,
+/// and does not contain the `E<code>: ` prefix.
+#[derive(Clone, Debug)]
+pub struct ErrorAbi {
+    pub docs: &'static [&'static str],
+    pub name: &'static str,
+    pub code: u32,
+    pub message: &'static str,
+    pub fields: Vec<ErrorFieldAbi>,
+}
+
+impl ErrorAbi {
+    pub fn add_field<T: TypeAbi>(&mut self, name: &'static str) {
+        self.fields.push(ErrorFieldAbi {
+            name,
+            type_name: T::type_name(),
+        });
+    }
+}
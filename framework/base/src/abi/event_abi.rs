@@ -13,6 +13,7 @@ pub struct EventAbi {
     pub docs: &'static [&'static str],
     pub identifier: &'static str,
     pub inputs: Vec<EventInputAbi>,
+    pub location: Option<SourceLocationAbi>,
 }
 
 impl EventAbi {
@@ -1,7 +1,9 @@
 mod build_info_abi;
 mod contract_abi;
 mod endpoint_abi;
+mod error_abi;
 mod event_abi;
+mod storage_abi;
 mod type_abi;
 mod type_abi_impl_basic;
 mod type_abi_impl_codec_multi;
@@ -11,7 +13,9 @@ mod type_description_container;
 pub use build_info_abi::*;
 pub use contract_abi::*;
 pub use endpoint_abi::*;
+pub use error_abi::*;
 pub use event_abi::*;
+pub use storage_abi::*;
 pub use type_abi::*;
 pub use type_description::*;
 pub use type_description_container::*;
@@ -0,0 +1,81 @@
+use super::*;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+/// Type name used in the storage layout for values that do not implement `TypeAbi`.
+pub const STORAGE_TYPE_NAME_UNKNOWN: &str = "unknown";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StorageKeyArgAbi {
+    pub arg_name: &'static str,
+    pub type_name: TypeName,
+}
+
+/// Describes one storage entry of a contract.
+///
+/// `#[storage_get]` and `#[storage_set]` have the same layout as a `SingleValueMapper`,
+/// so they are described as such.
+#[derive(Clone, Debug)]
+pub struct StorageAbi {
+    pub docs: &'static [&'static str],
+    pub key: &'static str,
+    pub mapper: &'static str,
+    pub key_args: Vec<StorageKeyArgAbi>,
+    pub value_types: Vec<TypeName>,
+}
+
+impl StorageAbi {
+    pub fn add_key_arg(&mut self, arg_name: &'static str, type_name: TypeName) {
+        self.key_args.push(StorageKeyArgAbi {
+            arg_name,
+            type_name,
+        });
+    }
+
+    pub fn add_value_type(&mut self, type_name: TypeName) {
+        self.value_types.push(type_name);
+    }
+
+    /// Two entries describing the same key and layout, e.g. a getter and a setter.
+    pub fn same_layout(&self, other: &StorageAbi) -> bool {
+        self.key == other.key
+            && self.mapper == other.mapper
+            && self.key_args == other.key_args
+            && self.value_types == other.value_types
+    }
+}
+
+/// Storage values are not required to implement `TypeAbi`.
+///
+/// The generated ABI code calls `storage_type_name` on a reference to this probe.
+/// If the type implements `TypeAbi`, `StorageTypeAbiProvider` gets picked by method resolution,
+/// otherwise it falls back to `StorageTypeAbiFallback`.
+pub struct StorageTypeAbiProbe<T>(PhantomData<T>);
+
+impl<T> StorageTypeAbiProbe<T> {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        StorageTypeAbiProbe(PhantomData)
+    }
+}
+
+pub trait StorageTypeAbiProvider {
+    fn storage_type_name(&self, type_descriptions: &mut TypeDescriptionContainerImpl) -> TypeName;
+}
+
+impl<T: TypeAbi> StorageTypeAbiProvider for StorageTypeAbiProbe<T> {
+    fn storage_type_name(&self, type_descriptions: &mut TypeDescriptionContainerImpl) -> TypeName {
+        T::provide_type_descriptions(type_descriptions);
+        T::type_name()
+    }
+}
+
+pub trait StorageTypeAbiFallback {
+    fn storage_type_name(&self, type_descriptions: &mut TypeDescriptionContainerImpl) -> TypeName;
+}
+
+impl<T> StorageTypeAbiFallback for &StorageTypeAbiProbe<T> {
+    fn storage_type_name(&self, _type_descriptions: &mut TypeDescriptionContainerImpl) -> TypeName {
+        TypeName::from(STORAGE_TYPE_NAME_UNKNOWN)
+    }
+}
@@ -22,7 +22,10 @@ impl TypeDescription {
 #[derive(Clone, Debug)]
 pub enum TypeContents {
     NotSpecified,
-    Enum(Vec<EnumVariantDescription>),
+    Enum {
+        discriminant_type: EnumDiscriminantType,
+        variants: Vec<EnumVariantDescription>,
+    },
     Struct(Vec<StructFieldDescription>),
 }
 
@@ -32,6 +35,23 @@ impl TypeContents {
     }
 }
 
+/// How the variant discriminant is encoded, as set by `#[codec(discriminant_type = "...")]`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum EnumDiscriminantType {
+    #[default]
+    U8,
+    U16,
+}
+
+impl EnumDiscriminantType {
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            EnumDiscriminantType::U8 => "u8",
+            EnumDiscriminantType::U16 => "u16",
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct EnumVariantDescription {
     pub docs: &'static [&'static str],
@@ -1,5 +1,4 @@
 mod blockchain_api;
-mod builtin_function_names;
 mod call_value_api;
 mod composite_api;
 mod crypto_api;
@@ -16,7 +15,7 @@ pub mod uncallable;
 mod vm_api;
 
 pub use blockchain_api::*;
-pub use builtin_function_names::*;
+pub use multiversx_chain_core::builtin_function_names::*;
 pub use call_value_api::*;
 pub use composite_api::*;
 pub use crypto_api::*;
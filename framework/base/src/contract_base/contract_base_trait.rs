@@ -1,6 +1,7 @@
 use super::{
-    BlockchainWrapper, CallValueWrapper, CryptoWrapper, ErrorHelper, ManagedSerializer,
-    SendRawWrapper, SendWrapper, StorageRawWrapper,
+    BlockchainWrapper, CallValueWrapper, CallbackContext, CryptoWrapper, ErrorHelper,
+    ManagedSerializer, ReentrancyGuard, SchemaMigrations, SendRawWrapper, SendWrapper,
+    StorageRawWrapper,
 };
 use crate::api::VMApi;
 
@@ -62,4 +63,22 @@ pub trait ContractBase: Sized {
     fn storage_raw(&self) -> StorageRawWrapper<Self::Api> {
         StorageRawWrapper::new()
     }
+
+    /// Versioned storage migrations, to be run from the `#[upgrade]` endpoint.
+    #[inline]
+    fn migrations(&self) -> SchemaMigrations<Self::Api> {
+        SchemaMigrations::new()
+    }
+
+    /// The storage lock behind `#[non_reentrant]` endpoints.
+    #[inline]
+    fn reentrancy_guard(&self) -> ReentrancyGuard<Self::Api> {
+        ReentrancyGuard::new()
+    }
+
+    /// The storage flag behind `#[only_callback_context]` endpoints.
+    #[inline]
+    fn callback_context(&self) -> CallbackContext<Self::Api> {
+        CallbackContext::new()
+    }
 }
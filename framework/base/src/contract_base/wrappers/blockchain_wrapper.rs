@@ -84,6 +84,7 @@ where
         }
     }
 
+    /// Used by both `#[only_user_account]` and `#[not_from_contract]`.
     pub fn check_caller_is_user_account(&self) {
         let mbuf_temp_1: A::ManagedBufferHandle = use_raw_handle(const_handles::MBUF_TEMPORARY_1);
         A::blockchain_api_impl().load_caller_managed(mbuf_temp_1.clone());
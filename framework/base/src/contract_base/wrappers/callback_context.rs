@@ -0,0 +1,81 @@
+use core::marker::PhantomData;
+
+use crate::{
+    api::{BlockchainApi, ErrorApiImpl, StorageMapperApi},
+    contract_base::BlockchainWrapper,
+    err_msg,
+    storage::{
+        mappers::{SingleValueMapper, StorageMapper},
+        protected_keys::CALLBACK_CONTEXT_KEY,
+        StorageKey,
+    },
+    types::ManagedBuffer,
+};
+
+/// Storage flag, set while one of the callbacks of the contract is executing,
+/// checked by the `#[only_callback_context]` endpoints.
+///
+/// Synchronous calls made from the callback run in new instances, which only share the storage
+/// with the callback, so the flag has to live in storage.
+/// The generated callback code only sets it in contracts that have `#[only_callback_context]` endpoints.
+///
+/// The flag holds the transaction hash rather than a plain `true`.
+/// A callback that ends in a legacy async call never gets to clear it,
+/// but the leftover value no longer matches once the next transaction comes in.
+pub struct CallbackContext<A>
+where
+    A: StorageMapperApi + BlockchainApi,
+{
+    _phantom: PhantomData<A>,
+}
+
+impl<A> CallbackContext<A>
+where
+    A: StorageMapperApi + BlockchainApi,
+{
+    pub fn new() -> Self {
+        CallbackContext {
+            _phantom: PhantomData,
+        }
+    }
+
+    fn flag_mapper(&self) -> SingleValueMapper<A, ManagedBuffer<A>> {
+        SingleValueMapper::new(StorageKey::new_reserved(CALLBACK_CONTEXT_KEY))
+    }
+
+    fn current_tx_hash(&self) -> ManagedBuffer<A> {
+        BlockchainWrapper::<A>::new()
+            .get_tx_hash()
+            .as_managed_buffer()
+            .clone()
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.flag_mapper().get() == self.current_tx_hash()
+    }
+
+    /// Signals an error unless a callback of the contract is executing further up the call stack.
+    pub fn require_active(&self) {
+        if !self.is_active() {
+            A::error_api_impl().signal_error(err_msg::ONLY_CALLBACK_CONTEXT);
+        }
+    }
+
+    pub fn enter(&self) {
+        self.flag_mapper().set(self.current_tx_hash());
+    }
+
+    /// Clears the flag key completely, so it leaves no trace in storage once the callback is done.
+    pub fn exit(&self) {
+        self.flag_mapper().clear();
+    }
+}
+
+impl<A> Default for CallbackContext<A>
+where
+    A: StorageMapperApi + BlockchainApi,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,8 +1,11 @@
 mod blockchain_wrapper;
 mod call_value_wrapper;
 mod callback_args_wrapper;
+mod callback_context;
 mod crypto_wrapper;
 mod error_helper;
+mod reentrancy_guard;
+mod schema_migrations;
 mod send_raw_wrapper;
 mod send_wrapper;
 mod serializer;
@@ -11,8 +14,11 @@ mod storage_raw_wrapper;
 pub use blockchain_wrapper::BlockchainWrapper;
 pub use call_value_wrapper::CallValueWrapper;
 pub use callback_args_wrapper::CallbackArgApiWrapper;
+pub use callback_context::CallbackContext;
 pub use crypto_wrapper::CryptoWrapper;
-pub use error_helper::ErrorHelper;
+pub use error_helper::{ErrorHelper, IntoSignalError};
+pub use reentrancy_guard::ReentrancyGuard;
+pub use schema_migrations::{MigrationStep, SchemaMigrations};
 pub(crate) use send_raw_wrapper::SendRawWrapper;
 pub use send_wrapper::SendWrapper;
 pub use serializer::{ExitCodecErrorHandler, ManagedSerializer};
@@ -0,0 +1,64 @@
+use core::marker::PhantomData;
+
+use crate::{
+    api::{ErrorApiImpl, StorageMapperApi},
+    err_msg,
+    storage::{
+        mappers::{SingleValueMapper, StorageMapper},
+        protected_keys::REENTRANCY_LOCK_KEY,
+        StorageKey,
+    },
+};
+
+/// Storage-based lock, shared by all the `#[non_reentrant]` endpoints of a contract.
+///
+/// It is kept in storage rather than in a static, because a contract re-entered via a synchronous call
+/// runs in a new instance, which only shares the storage with the instance that made the call.
+/// If the endpoint fails, the lock gets reverted together with the rest of the storage changes.
+pub struct ReentrancyGuard<A>
+where
+    A: StorageMapperApi,
+{
+    _phantom: PhantomData<A>,
+}
+
+impl<A> ReentrancyGuard<A>
+where
+    A: StorageMapperApi,
+{
+    pub fn new() -> Self {
+        ReentrancyGuard {
+            _phantom: PhantomData,
+        }
+    }
+
+    fn lock_mapper(&self) -> SingleValueMapper<A, bool> {
+        SingleValueMapper::new(StorageKey::new_reserved(REENTRANCY_LOCK_KEY))
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.lock_mapper().get()
+    }
+
+    /// Signals an error if a non-reentrant endpoint is already executing further up the call stack.
+    pub fn lock(&self) {
+        if self.is_locked() {
+            A::error_api_impl().signal_error(err_msg::REENTRANT_CALL);
+        }
+        self.lock_mapper().set(true);
+    }
+
+    /// Clears the lock key completely, so it leaves no trace in storage once the endpoint is done.
+    pub fn unlock(&self) {
+        self.lock_mapper().clear();
+    }
+}
+
+impl<A> Default for ReentrancyGuard<A>
+where
+    A: StorageMapperApi,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
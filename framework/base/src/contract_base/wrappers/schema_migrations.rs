@@ -0,0 +1,108 @@
+use core::marker::PhantomData;
+
+use crate::{
+    api::{ErrorApiImpl, StorageMapperApi},
+    storage::{
+        mappers::{SingleValueMapper, StorageMapper},
+        protected_keys::SCHEMA_VERSION_KEY,
+        StorageKey,
+    },
+};
+
+const STEPS_NOT_ORDERED_ERR_MSG: &[u8] = b"migration steps must have increasing versions";
+
+/// A single storage migration, bringing the storage to the schema version it declares.
+pub trait MigrationStep {
+    /// The schema version reached once this step has run.
+    fn version(&self) -> u32;
+
+    fn migrate(self);
+}
+
+/// Runs storage migrations on upgrade, each of them exactly once.
+///
+/// The current schema version is kept under a framework reserved storage key.
+/// Migration steps need to be declared in increasing version order,
+/// only the ones newer than the stored schema version get run.
+///
+/// ```ignore
+/// struct MigrateToV1<'a, C: MyContract>(&'a C);
+///
+/// impl<'a, C: MyContract> MigrationStep for MigrateToV1<'a, C> {
+///     fn version(&self) -> u32 {
+///         1
+///     }
+///
+///     fn migrate(self) {
+///         self.0.migrate_to_v1();
+///     }
+/// }
+///
+/// #[upgrade]
+/// fn upgrade(&self) {
+///     self.migrations()
+///         .step(MigrateToV1(self))
+///         .step(MigrateToV2(self));
+/// }
+/// ```
+pub struct SchemaMigrations<A>
+where
+    A: StorageMapperApi,
+{
+    last_step_version: u32,
+    _phantom: PhantomData<A>,
+}
+
+impl<A> SchemaMigrations<A>
+where
+    A: StorageMapperApi,
+{
+    pub fn new() -> Self {
+        SchemaMigrations {
+            last_step_version: 0,
+            _phantom: PhantomData,
+        }
+    }
+
+    fn version_mapper(&self) -> SingleValueMapper<A, u32> {
+        SingleValueMapper::new(StorageKey::new_reserved(SCHEMA_VERSION_KEY))
+    }
+
+    /// The version of the last migration that ran. 0 if none did.
+    pub fn schema_version(&self) -> u32 {
+        self.version_mapper().get()
+    }
+
+    /// Freshly deployed contracts already have the latest storage layout,
+    /// so the constructor should mark all migrations up to that version as done.
+    pub fn set_schema_version(&self, version: u32) {
+        self.version_mapper().set(version);
+    }
+
+    /// Runs the step if the stored schema is older than its version, then records that version as the current one.
+    pub fn step<S>(mut self, step: S) -> Self
+    where
+        S: MigrationStep,
+    {
+        let version = step.version();
+        if version <= self.last_step_version {
+            A::error_api_impl().signal_error(STEPS_NOT_ORDERED_ERR_MSG);
+        }
+        self.last_step_version = version;
+
+        if self.schema_version() < version {
+            step.migrate();
+            self.set_schema_version(version);
+        }
+        self
+    }
+}
+
+impl<A> Default for SchemaMigrations<A>
+where
+    A: StorageMapperApi,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
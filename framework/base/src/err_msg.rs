@@ -25,6 +25,7 @@ pub const STORAGE_VALUE_OUT_OF_RANGE: &[u8] = b"storage value out of range";
 pub const STORAGE_DECODE_ERROR: &[u8] = b"storage decode error: ";
 pub const STORAGE_ENCODE_ERROR: &[u8] = b"storage encode error: ";
 pub const STORAGE_KEY_ENCODE_ERROR: &[u8] = b"storage key encode error: ";
+pub const STORAGE_KEY_RESERVED: &[u8] = b"storage key reserved by the framework";
 pub const STORAGE_VALUE_EXCEEDS_BUFFER: &[u8] = b"storage value exceeds buffer";
 pub const FINISH_ENCODE_ERROR: &[u8] = b"endpoint result encode error: ";
 pub const SERIALIZER_DECODE_ERROR: &[u8] = b"serializer decode error: ";
@@ -59,6 +60,8 @@ pub const CALLBACK_BAD_FUNC: &[u8] = b"no callback function with that name exist
 pub const RECIPIENT_ADDRESS_NOT_SET: &str = "recipient address not set";
 pub static ONLY_OWNER_CALLER: &[u8] = b"Endpoint can only be called by owner";
 pub static ONLY_USER_ACCOUNT_CALLER: &[u8] = b"Endpoint can only be called by user accounts";
+pub static ONLY_CALLBACK_CONTEXT: &[u8] = b"Endpoint can only be called from a callback";
+pub static REENTRANT_CALL: &[u8] = b"Reentrant call to a non-reentrant endpoint";
 
 pub const STORAGE_NOT_I64: &[u8] = b"storage not i64";
 pub const STORAGE_NOT_32_BYTES: &[u8] = b"32 bytes of data expected in storage at key";
@@ -37,6 +37,12 @@ pub fn external_view_contract_constructor_abi() -> EndpointAbi {
         rust_method_name: "",
         only_owner: false,
         only_admin: false,
+        only_role: None,
+        only_callback_context: false,
+        not_from_contract: false,
+        non_reentrant: false,
+        max_gas: None,
+        max_return_items: None,
         labels: &[],
         mutability: EndpointMutabilityAbi::Mutable,
         payable_in_tokens: &[],
@@ -46,5 +52,6 @@ pub fn external_view_contract_constructor_abi() -> EndpointAbi {
             multi_arg: false,
         }].to_vec(),
         outputs: OutputAbis::new(),
+        location: None,
     }
 }
@@ -49,7 +49,7 @@ macro_rules! derive_imports {
                 NestedDecode, NestedEncode, TopDecode, TopDecodeOrDefault, TopEncode,
                 TopEncodeOrDefault,
             },
-            derive::{ManagedVecItem, TypeAbi},
+            derive::{ContractError, ManagedVecItem, TypeAbi},
         };
     };
 }
@@ -105,7 +105,8 @@ macro_rules! sc_panic {
 ///
 /// The most common way to use it is to provide a string message with optional format arguments.
 ///
-/// It is also possible to give the error as a variable of types such as `&str`, `&[u8]` or `ManagedBuffer`.
+/// It is also possible to give the error as a variable of types such as `&str`, `&[u8]` or `ManagedBuffer`,
+/// or as a variant of an enum that derives `ContractError`.
 ///
 /// Examples:
 ///
@@ -128,6 +129,11 @@ macro_rules! sc_panic {
 /// ```
 #[macro_export]
 macro_rules! require {
+    ($expression:expr, $error:expr $(,)?) => {
+        if (!($expression)) {
+            multiversx_sc::sc_panic!($error);
+        }
+    };
     ($expression:expr, $($msg_tokens:tt),+  $(,)?) => {
         if (!($expression)) {
             multiversx_sc::sc_panic!($($msg_tokens),+);
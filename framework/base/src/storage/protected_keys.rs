@@ -1,2 +1,15 @@
 pub static ELROND_REWARD_KEY: &[u8] = b"ELRONDreward";
 pub static ELROND_ESDT_LOCAL_ROLES_KEY: &[u8] = b"ELRONDroleesdt";
+
+/// Keys under this prefix belong to the framework.
+/// Storage keys created by contract code with this prefix are rejected.
+pub static FRAMEWORK_RESERVED_KEY_PREFIX: &[u8] = b"__sc:";
+
+/// Holds the storage schema version, managed by `SchemaMigrations`.
+pub static SCHEMA_VERSION_KEY: &[u8] = b"__sc:schemaVersion";
+
+/// Set while a `#[non_reentrant]` endpoint is executing, managed by `ReentrancyGuard`.
+pub static REENTRANCY_LOCK_KEY: &[u8] = b"__sc:reentrancyLock";
+
+/// Holds the hash of the transaction whose callback is executing, managed by `CallbackContext`.
+pub static CALLBACK_CONTEXT_KEY: &[u8] = b"__sc:callbackContext";
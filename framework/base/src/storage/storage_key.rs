@@ -1,7 +1,8 @@
 use crate::{
-    api::{ErrorApi, ManagedTypeApi},
+    api::{ErrorApi, ErrorApiImpl, ManagedTypeApi},
     codec::*,
     contract_base::ExitCodecErrorHandler,
+    storage::protected_keys::FRAMEWORK_RESERVED_KEY_PREFIX,
     types::{heap::BoxedBytes, ManagedBuffer, ManagedByteArray, ManagedType},
     *,
 };
@@ -39,8 +40,18 @@ impl<A> StorageKey<A>
 where
     A: ManagedTypeApi + ErrorApi + 'static,
 {
-    #[inline]
+    /// Keys under the framework reserved prefix are rejected,
+    /// so that no mapper can overwrite the framework's own storage.
     pub fn new(base_key: &[u8]) -> Self {
+        if base_key.starts_with(FRAMEWORK_RESERVED_KEY_PREFIX) {
+            A::error_api_impl().signal_error(err_msg::STORAGE_KEY_RESERVED);
+        }
+        Self::new_reserved(base_key)
+    }
+
+    /// Skips the reserved prefix check, only for the framework's own keys.
+    #[inline]
+    pub(crate) fn new_reserved(base_key: &[u8]) -> Self {
         StorageKey {
             buffer: ManagedBuffer::new_from_bytes(base_key),
         }
@@ -0,0 +1,219 @@
+use alloc::vec::Vec;
+
+use crate::{
+    abi::{ErrorAbi, TypeDescriptionContainer},
+    api::{ErrorApiImpl, ManagedTypeApi},
+    formatter::FormatBuffer,
+    types::{
+        BigInt, BigUint, EgldOrEsdtTokenIdentifier, ManagedBuffer, ManagedBufferCachedBuilder,
+        ManagedType, TokenIdentifier,
+    },
+};
+
+/// Separates the error code from the rest of the message, e.g. `E12: not enough funds`.
+pub const CONTRACT_ERROR_CODE_PREFIX: &[u8] = b"E";
+pub const CONTRACT_ERROR_CODE_SEPARATOR: &[u8] = b": ";
+
+/// Implemented by `#[derive(ContractError)]`.
+///
+/// Every variant has a stable numeric code and a message template.
+/// The error is signalled as `E<code>: <message>`,
+/// which allows off-chain tools to decode it back into the typed error.
+pub trait ContractError: Sized {
+    fn error_code(&self) -> u32;
+
+    /// Writes the full error message, code prefix included.
+    fn format_error_message<F: FormatBuffer>(&self) -> F;
+
+    /// Parses a signalled error message back into the typed error.
+    ///
+    /// Returns `None` if the message was not produced by this error type.
+    fn decode_error_message(message: &[u8]) -> Option<Self>;
+
+    /// One entry per variant.
+    fn error_abis() -> Vec<ErrorAbi>;
+
+    /// Types of the fields, for the ABI.
+    fn provide_error_type_descriptions<TDC: TypeDescriptionContainer>(_accumulator: &mut TDC) {}
+
+    fn error_message<M: ManagedTypeApi>(&self) -> ManagedBuffer<M> {
+        self.format_error_message::<ManagedBufferCachedBuilder<M>>()
+            .into_managed_buffer()
+    }
+}
+
+/// Used by the generated `IntoSignalError` implementations.
+pub fn signal_contract_error<M, E>(error: &E) -> !
+where
+    M: ManagedTypeApi,
+    E: ContractError,
+{
+    let message = error.error_message::<M>();
+    M::error_api_impl().signal_error_from_buffer(message.get_handle())
+}
+
+/// Extracts the code from a message formatted as `E<code>: <message>`.
+pub fn contract_error_code(message: &[u8]) -> Option<u32> {
+    let rest = message.strip_prefix(CONTRACT_ERROR_CODE_PREFIX)?;
+    let separator_index = find_subslice(rest, CONTRACT_ERROR_CODE_SEPARATOR)?;
+    u32::parse_error_arg(&rest[..separator_index])
+}
+
+/// Splits the fields out of an error message, given the static parts of its template.
+///
+/// The template `E3: needed {needed}, got {got}` has the static parts
+/// `["E3: needed ", ", got ", ""]`, and there is always one more static part than there are fields.
+/// Fields are matched lazily, up to the next occurrence of the following static part.
+pub fn match_contract_error_template<'a>(
+    static_parts: &[&[u8]],
+    message: &'a [u8],
+) -> Option<Vec<&'a [u8]>> {
+    let (first, rest) = static_parts.split_first()?;
+    let mut remaining = message.strip_prefix(*first)?;
+    let mut fields = Vec::with_capacity(rest.len());
+    for (index, part) in rest.iter().enumerate() {
+        let field_len = if index == rest.len() - 1 {
+            if !remaining.ends_with(part) {
+                return None;
+            }
+            remaining.len() - part.len()
+        } else {
+            find_subslice(remaining, part)?
+        };
+        fields.push(&remaining[..field_len]);
+        remaining = &remaining[field_len + part.len()..];
+    }
+    if rest.is_empty() && !remaining.is_empty() {
+        return None;
+    }
+    Some(fields)
+}
+
+fn find_subslice(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    if needle.is_empty() {
+        return Some(0);
+    }
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+/// Field types allowed in a `#[derive(ContractError)]` enum.
+///
+/// Parses a field back from its `SCDisplay` representation.
+pub trait ContractErrorArg: Sized {
+    fn parse_error_arg(bytes: &[u8]) -> Option<Self>;
+}
+
+fn parse_decimal_u64(bytes: &[u8]) -> Option<u64> {
+    if bytes.is_empty() {
+        return None;
+    }
+    let mut result = 0u64;
+    for byte in bytes {
+        if !byte.is_ascii_digit() {
+            return None;
+        }
+        result = result.checked_mul(10)?.checked_add((byte - b'0') as u64)?;
+    }
+    Some(result)
+}
+
+macro_rules! contract_error_arg_unsigned {
+    ($num_ty:ty) => {
+        impl ContractErrorArg for $num_ty {
+            fn parse_error_arg(bytes: &[u8]) -> Option<Self> {
+                <$num_ty>::try_from(parse_decimal_u64(bytes)?).ok()
+            }
+        }
+    };
+}
+
+contract_error_arg_unsigned! {u64}
+contract_error_arg_unsigned! {u32}
+contract_error_arg_unsigned! {usize}
+contract_error_arg_unsigned! {u16}
+contract_error_arg_unsigned! {u8}
+
+macro_rules! contract_error_arg_signed {
+    ($num_ty:ty) => {
+        impl ContractErrorArg for $num_ty {
+            fn parse_error_arg(bytes: &[u8]) -> Option<Self> {
+                if let Some(abs_bytes) = bytes.strip_prefix(b"-") {
+                    let abs = i64::try_from(parse_decimal_u64(abs_bytes)?).ok()?;
+                    <$num_ty>::try_from(-abs).ok()
+                } else {
+                    <$num_ty>::try_from(parse_decimal_u64(bytes)?).ok()
+                }
+            }
+        }
+    };
+}
+
+contract_error_arg_signed! {i64}
+contract_error_arg_signed! {i32}
+contract_error_arg_signed! {isize}
+contract_error_arg_signed! {i16}
+contract_error_arg_signed! {i8}
+
+impl ContractErrorArg for bool {
+    fn parse_error_arg(bytes: &[u8]) -> Option<Self> {
+        match bytes {
+            b"true" => Some(true),
+            b"false" => Some(false),
+            _ => None,
+        }
+    }
+}
+
+impl<M: ManagedTypeApi> ContractErrorArg for BigUint<M> {
+    fn parse_error_arg(bytes: &[u8]) -> Option<Self> {
+        if bytes.is_empty() {
+            return None;
+        }
+        let mut result = BigUint::zero();
+        for byte in bytes {
+            if !byte.is_ascii_digit() {
+                return None;
+            }
+            result *= 10u32;
+            result += (byte - b'0') as u32;
+        }
+        Some(result)
+    }
+}
+
+impl<M: ManagedTypeApi> ContractErrorArg for BigInt<M> {
+    fn parse_error_arg(bytes: &[u8]) -> Option<Self> {
+        if let Some(abs_bytes) = bytes.strip_prefix(b"-") {
+            let abs = BigUint::<M>::parse_error_arg(abs_bytes)?;
+            Some(-BigInt::from(abs))
+        } else {
+            BigUint::<M>::parse_error_arg(bytes).map(BigInt::from)
+        }
+    }
+}
+
+impl<M: ManagedTypeApi> ContractErrorArg for ManagedBuffer<M> {
+    fn parse_error_arg(bytes: &[u8]) -> Option<Self> {
+        Some(ManagedBuffer::new_from_bytes(bytes))
+    }
+}
+
+impl<M: ManagedTypeApi> ContractErrorArg for TokenIdentifier<M> {
+    fn parse_error_arg(bytes: &[u8]) -> Option<Self> {
+        Some(TokenIdentifier::from(bytes))
+    }
+}
+
+impl<M: ManagedTypeApi> ContractErrorArg for EgldOrEsdtTokenIdentifier<M> {
+    fn parse_error_arg(bytes: &[u8]) -> Option<Self> {
+        if bytes == &EgldOrEsdtTokenIdentifier::<M>::EGLD_REPRESENTATION[..] {
+            Some(EgldOrEsdtTokenIdentifier::egld())
+        } else {
+            Some(EgldOrEsdtTokenIdentifier::esdt(TokenIdentifier::from(
+                bytes,
+            )))
+        }
+    }
+}
@@ -4,7 +4,7 @@ use crate::codec::{
 };
 
 use crate::{
-    abi::{TypeAbi, TypeName},
+    abi::{TypeAbi, TypeDescriptionContainer, TypeName},
     api::ManagedTypeApi,
     types::{BigUint, EsdtTokenPayment, ManagedVecItem, TokenIdentifier},
 };
@@ -106,6 +106,10 @@ where
         MultiValue3::<TokenIdentifier<M>, u64, BigUint<M>>::type_name()
     }
 
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        MultiValue3::<TokenIdentifier<M>, u64, BigUint<M>>::provide_type_descriptions(accumulator);
+    }
+
     fn is_variadic() -> bool {
         true
     }
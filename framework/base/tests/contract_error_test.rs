@@ -0,0 +1,53 @@
+use multiversx_sc::types::{contract_error_code, match_contract_error_template, ContractErrorArg};
+
+#[test]
+fn test_contract_error_code() {
+    assert_eq!(contract_error_code(b"E12: not enough funds"), Some(12));
+    assert_eq!(contract_error_code(b"E0: "), Some(0));
+    assert_eq!(contract_error_code(b"not enough funds"), None);
+    assert_eq!(contract_error_code(b"Eabc: not enough funds"), None);
+}
+
+#[test]
+fn test_match_contract_error_template() {
+    let static_parts: &[&[u8]] = &[b"E3: needed ", b", got ", b""];
+    assert_eq!(
+        match_contract_error_template(static_parts, b"E3: needed 100, got 5"),
+        Some(vec![&b"100"[..], &b"5"[..]])
+    );
+    assert_eq!(
+        match_contract_error_template(static_parts, b"E4: needed 100, got 5"),
+        None
+    );
+
+    let static_parts: &[&[u8]] = &[b"E1: token ", b" not accepted"];
+    assert_eq!(
+        match_contract_error_template(static_parts, b"E1: token ABC-123456 not accepted"),
+        Some(vec![&b"ABC-123456"[..]])
+    );
+    assert_eq!(
+        match_contract_error_template(static_parts, b"E1: token ABC-123456 accepted"),
+        None
+    );
+
+    let static_parts: &[&[u8]] = &[b"E2: paused"];
+    assert_eq!(
+        match_contract_error_template(static_parts, b"E2: paused"),
+        Some(Vec::new())
+    );
+    assert_eq!(
+        match_contract_error_template(static_parts, b"E2: paused!"),
+        None
+    );
+}
+
+#[test]
+fn test_contract_error_arg() {
+    assert_eq!(u64::parse_error_arg(b"1234"), Some(1234));
+    assert_eq!(u8::parse_error_arg(b"256"), None);
+    assert_eq!(u32::parse_error_arg(b""), None);
+    assert_eq!(i32::parse_error_arg(b"-17"), Some(-17));
+    assert_eq!(i8::parse_error_arg(b"-129"), None);
+    assert_eq!(bool::parse_error_arg(b"true"), Some(true));
+    assert_eq!(bool::parse_error_arg(b"yes"), None);
+}
@@ -0,0 +1,204 @@
+use quote::quote;
+
+static ATTR_CODEC: &str = "codec";
+static ATTR_DISCRIMINANT: &str = "discriminant";
+static ATTR_DISCRIMINANT_TYPE: &str = "discriminant_type";
+static ATTR_SKIP: &str = "skip";
+static ATTR_DEFAULT: &str = "default";
+static ATTR_WITH: &str = "with";
+
+/// All `#[codec(...)]` arguments of an item, variant or field.
+fn codec_attr_args(attrs: &[syn::Attribute]) -> Vec<syn::Meta> {
+    let mut result = Vec::new();
+    for attr in attrs {
+        if !attr.path.is_ident(ATTR_CODEC) {
+            continue;
+        }
+
+        match attr.parse_meta() {
+            Ok(syn::Meta::List(meta_list)) => {
+                for nested in meta_list.nested {
+                    match nested {
+                        syn::NestedMeta::Meta(meta) => result.push(meta),
+                        syn::NestedMeta::Lit(_) => {
+                            panic!("unexpected literal in codec attribute")
+                        },
+                    }
+                }
+            },
+            _ => panic!("codec attribute must be of the form #[codec(...)]"),
+        }
+    }
+    result
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum DiscriminantType {
+    U8,
+    U16,
+}
+
+impl DiscriminantType {
+    pub fn parse(attrs: &[syn::Attribute]) -> Self {
+        let mut result = DiscriminantType::U8;
+        for meta in codec_attr_args(attrs) {
+            match &meta {
+                syn::Meta::NameValue(name_value)
+                    if name_value.path.is_ident(ATTR_DISCRIMINANT_TYPE) =>
+                {
+                    result = match &name_value.lit {
+                        syn::Lit::Str(lit_str) if lit_str.value() == "u8" => DiscriminantType::U8,
+                        syn::Lit::Str(lit_str) if lit_str.value() == "u16" => DiscriminantType::U16,
+                        _ => panic!("discriminant_type must be either \"u8\" or \"u16\""),
+                    };
+                },
+                _ => panic!("unknown codec attribute on enum"),
+            }
+        }
+        result
+    }
+
+    fn max_value(&self) -> u64 {
+        match self {
+            DiscriminantType::U8 => u8::MAX as u64,
+            DiscriminantType::U16 => u16::MAX as u64,
+        }
+    }
+
+    pub fn type_tokens(&self) -> proc_macro2::TokenStream {
+        match self {
+            DiscriminantType::U8 => quote! { u8 },
+            DiscriminantType::U16 => quote! { u16 },
+        }
+    }
+
+    pub fn literal(&self, discriminant: u64) -> proc_macro2::Literal {
+        match self {
+            DiscriminantType::U8 => proc_macro2::Literal::u8_suffixed(discriminant as u8),
+            DiscriminantType::U16 => proc_macro2::Literal::u16_suffixed(discriminant as u16),
+        }
+    }
+}
+
+fn explicit_variant_discriminant(variant: &syn::Variant) -> Option<u64> {
+    let mut result = None;
+    for meta in codec_attr_args(&variant.attrs) {
+        match &meta {
+            syn::Meta::NameValue(name_value) if name_value.path.is_ident(ATTR_DISCRIMINANT) => {
+                match &name_value.lit {
+                    syn::Lit::Int(lit_int) => {
+                        result = Some(
+                            lit_int
+                                .base10_parse::<u64>()
+                                .expect("invalid discriminant value"),
+                        )
+                    },
+                    _ => panic!("discriminant must be an integer literal"),
+                }
+            },
+            _ => panic!("unknown codec attribute on enum variant"),
+        }
+    }
+    result
+}
+
+/// Variants are numbered in declaration order, starting from 0,
+/// unless a discriminant is given explicitly with `#[codec(discriminant = N)]`.
+/// Variants without one follow the previous variant, same as Rust discriminants.
+pub fn variant_discriminants(
+    data_enum: &syn::DataEnum,
+    discriminant_type: DiscriminantType,
+) -> Vec<u64> {
+    let mut discriminants: Vec<u64> = Vec::new();
+    let mut next_discriminant = 0u64;
+    for variant in &data_enum.variants {
+        let discriminant = explicit_variant_discriminant(variant).unwrap_or(next_discriminant);
+        assert!(
+            discriminant <= discriminant_type.max_value(),
+            "discriminant {discriminant} of variant {} does not fit, use #[codec(discriminant_type = \"u16\")] for large enums",
+            variant.ident
+        );
+        assert!(
+            !discriminants.contains(&discriminant),
+            "duplicate discriminant {discriminant} for variant {}",
+            variant.ident
+        );
+        discriminants.push(discriminant);
+        next_discriminant = discriminant + 1;
+    }
+    discriminants
+}
+
+/// How a struct or variant field is serialized.
+pub struct FieldCodec {
+    /// Not serialized at all, always deserialized as `Default::default()`.
+    pub skip: bool,
+
+    /// Deserialized as `Default::default()` if the input is depleted.
+    /// Allows appending fields to types that are already stored.
+    /// Only supported by `TopDecode`, nested inputs continue with the next item.
+    pub default_if_missing: bool,
+
+    /// Module providing `dep_encode_or_handle_err` and `dep_decode_or_handle_err`
+    /// functions, used instead of the field type's own codec.
+    pub with: Option<syn::Path>,
+}
+
+impl FieldCodec {
+    pub fn parse(field: &syn::Field) -> Self {
+        let mut field_codec = FieldCodec {
+            skip: false,
+            default_if_missing: false,
+            with: None,
+        };
+        for meta in codec_attr_args(&field.attrs) {
+            match &meta {
+                syn::Meta::Path(path) if path.is_ident(ATTR_SKIP) => field_codec.skip = true,
+                syn::Meta::Path(path) if path.is_ident(ATTR_DEFAULT) => {
+                    field_codec.default_if_missing = true
+                },
+                syn::Meta::NameValue(name_value) if name_value.path.is_ident(ATTR_WITH) => {
+                    match &name_value.lit {
+                        syn::Lit::Str(lit_str) => {
+                            field_codec.with =
+                                Some(lit_str.parse().expect("invalid codec module path"))
+                        },
+                        _ => panic!("codec `with` must be a string containing a module path"),
+                    }
+                },
+                _ => panic!("unknown codec attribute on field"),
+            }
+        }
+        field_codec
+    }
+}
+
+/// Fields that default when missing from the input only make sense at the end.
+pub fn check_trailing_default_fields(fields: &syn::Fields) {
+    let mut default_found = false;
+    for field in fields {
+        let field_codec = FieldCodec::parse(field);
+        if field_codec.skip {
+            continue;
+        }
+        if field_codec.default_if_missing {
+            default_found = true;
+        } else {
+            assert!(
+                !default_found,
+                "fields marked #[codec(default)] can only be followed by other default or skipped fields"
+            );
+        }
+    }
+}
+
+/// When nested, the input continues with whatever follows the value,
+/// e.g. the next item in a list, so a missing field cannot be detected.
+pub fn check_no_default_fields(fields: &syn::Fields) {
+    for field in fields {
+        assert!(
+            !FieldCodec::parse(field).default_if_missing,
+            "#[codec(default)] is only supported by TopDecode, it cannot be used with NestedDecode"
+        );
+    }
+}
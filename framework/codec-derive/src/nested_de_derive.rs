@@ -1,7 +1,7 @@
 use proc_macro::TokenStream;
 use quote::quote;
 
-use crate::util::*;
+use crate::{codec_attr::*, util::*};
 
 pub fn dep_decode_snippet(
     _index: usize,
@@ -9,34 +9,60 @@ pub fn dep_decode_snippet(
     input_value: &proc_macro2::TokenStream,
 ) -> proc_macro2::TokenStream {
     let ty = &field.ty;
+    let field_codec = FieldCodec::parse(field);
+    let value = if field_codec.skip {
+        quote! { core::default::Default::default() }
+    } else {
+        let decode = match &field_codec.with {
+            Some(codec_module) => quote! {
+                #codec_module::dep_decode_or_handle_err(#input_value, h)?
+            },
+            None => quote! {
+                <#ty as codec::NestedDecode>::dep_decode_or_handle_err(#input_value, h)?
+            },
+        };
+        if field_codec.default_if_missing {
+            quote! {
+                if codec::NestedDecodeInput::is_depleted(&*#input_value) {
+                    core::default::Default::default()
+                } else {
+                    #decode
+                }
+            }
+        } else {
+            decode
+        }
+    };
+
     if let Some(ident) = &field.ident {
         quote! {
-            #ident: <#ty as codec::NestedDecode>::dep_decode_or_handle_err(#input_value, h)?
+            #ident: #value
         }
     } else {
-        quote! {
-            <#ty as codec::NestedDecode>::dep_decode_or_handle_err(#input_value, h)?
-        }
+        value
     }
 }
 
 pub fn variant_dep_decode_snippets(
     name: &syn::Ident,
     data_enum: &syn::DataEnum,
+    discriminant_type: DiscriminantType,
     input_value: &proc_macro2::TokenStream,
 ) -> Vec<proc_macro2::TokenStream> {
+    let discriminants = variant_discriminants(data_enum, discriminant_type);
     data_enum
 		.variants
 		.iter()
-		.enumerate()
-		.map(|(variant_index, variant)| {
-			let variant_index_u8 = variant_index as u8;
+		.zip(discriminants)
+		.map(|(variant, discriminant)| {
+			check_trailing_default_fields(&variant.fields);
+			let discriminant_literal = discriminant_type.literal(discriminant);
 			let variant_ident = &variant.ident;
 			let variant_field_snippets = fields_decl_syntax(&variant.fields, |index, field| {
 				dep_decode_snippet(index, field, input_value)
 			});
 			quote! {
-				#variant_index_u8 => core::result::Result::Ok( #name::#variant_ident #variant_field_snippets ),
+				#discriminant_literal => core::result::Result::Ok( #name::#variant_ident #variant_field_snippets ),
 			}
 		})
 		.collect()
@@ -47,6 +73,7 @@ pub fn nested_decode_impl(ast: &syn::DeriveInput) -> TokenStream {
     let (impl_generics, ty_generics, where_clause) = &ast.generics.split_for_impl();
     let gen = match &ast.data {
         syn::Data::Struct(data_struct) => {
+            check_no_default_fields(&data_struct.fields);
             let field_dep_decode_snippets =
                 fields_decl_syntax(&data_struct.fields, |index, field| {
                     dep_decode_snippet(index, field, &quote! {input})
@@ -66,12 +93,13 @@ pub fn nested_decode_impl(ast: &syn::DeriveInput) -> TokenStream {
             }
         },
         syn::Data::Enum(data_enum) => {
-            assert!(
-                data_enum.variants.len() < 256,
-                "enums with more than 256 variants not supported"
-            );
+            for variant in &data_enum.variants {
+                check_no_default_fields(&variant.fields);
+            }
+            let discriminant_type = DiscriminantType::parse(&ast.attrs);
+            let discriminant_type_tokens = discriminant_type.type_tokens();
             let variant_dep_decode_snippets =
-                variant_dep_decode_snippets(name, data_enum, &quote! {input});
+                variant_dep_decode_snippets(name, data_enum, discriminant_type, &quote! {input});
 
             quote! {
                 impl #impl_generics codec::NestedDecode for #name #ty_generics #where_clause {
@@ -80,7 +108,7 @@ pub fn nested_decode_impl(ast: &syn::DeriveInput) -> TokenStream {
                         I: codec::NestedDecodeInput,
                         H: codec::DecodeErrorHandler,
                     {
-                        match <u8 as codec::NestedDecode>::dep_decode_or_handle_err(input, h)? {
+                        match <#discriminant_type_tokens as codec::NestedDecode>::dep_decode_or_handle_err(input, h)? {
                             #(#variant_dep_decode_snippets)*
                             _ => core::result::Result::Err(h.handle_error(codec::DecodeError::INVALID_VALUE)),
                         }
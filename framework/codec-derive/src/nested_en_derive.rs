@@ -1,32 +1,59 @@
-use crate::util::*;
+use crate::{codec_attr::*, util::*};
 use proc_macro::TokenStream;
 use quote::quote;
 
-pub fn dep_encode_snippet(value: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+/// `value_ref` needs to be a reference to the field value.
+pub fn dep_encode_snippet(
+    field: &syn::Field,
+    value_ref: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let field_codec = FieldCodec::parse(field);
+    if field_codec.skip {
+        return quote! {};
+    }
+
+    match &field_codec.with {
+        Some(codec_module) => quote! {
+            #codec_module::dep_encode_or_handle_err(#value_ref, dest, h)?;
+        },
+        None => quote! {
+            codec::NestedEncode::dep_encode_or_handle_err(#value_ref, dest, h)?;
+        },
+    }
+}
+
+pub fn discriminant_dep_encode_snippet(
+    discriminant_type: DiscriminantType,
+    discriminant: u64,
+) -> proc_macro2::TokenStream {
+    let discriminant_literal = discriminant_type.literal(discriminant);
     quote! {
-        codec::NestedEncode::dep_encode_or_handle_err(&#value, dest, h)?;
+        codec::NestedEncode::dep_encode_or_handle_err(&#discriminant_literal, dest, h)?;
     }
 }
 
 fn variant_dep_encode_snippets(
     name: &syn::Ident,
     data_enum: &syn::DataEnum,
+    discriminant_type: DiscriminantType,
 ) -> Vec<proc_macro2::TokenStream> {
+    let discriminants = variant_discriminants(data_enum, discriminant_type);
     data_enum
         .variants
         .iter()
-        .enumerate()
-        .map(|(variant_index, variant)| {
-            let variant_index_u8 = variant_index as u8;
+        .zip(discriminants)
+        .map(|(variant, discriminant)| {
             let variant_ident = &variant.ident;
             let local_var_declarations =
-                fields_decl_syntax(&variant.fields, local_variable_for_field);
+                fields_decl_syntax(&variant.fields, local_variable_pattern_for_field);
+            let discriminant_snippet =
+                discriminant_dep_encode_snippet(discriminant_type, discriminant);
             let variant_field_snippets = fields_snippets(&variant.fields, |index, field| {
-                dep_encode_snippet(&local_variable_for_field(index, field))
+                dep_encode_snippet(field, &local_variable_for_field(index, field))
             });
             quote! {
                 #name::#variant_ident #local_var_declarations => {
-                    codec::NestedEncode::dep_encode_or_handle_err(&#variant_index_u8, dest, h)?;
+                    #discriminant_snippet
                     #(#variant_field_snippets)*
                 },
             }
@@ -40,7 +67,8 @@ pub fn nested_encode_impl(ast: &syn::DeriveInput) -> TokenStream {
     let gen = match &ast.data {
         syn::Data::Struct(data_struct) => {
             let field_dep_encode_snippets = fields_snippets(&data_struct.fields, |index, field| {
-                dep_encode_snippet(&self_field_expr(index, field))
+                let field_expr = self_field_expr(index, field);
+                dep_encode_snippet(field, &quote! { &#field_expr })
             });
             quote! {
                 impl #impl_generics codec::NestedEncode for #name #ty_generics #where_clause {
@@ -56,11 +84,9 @@ pub fn nested_encode_impl(ast: &syn::DeriveInput) -> TokenStream {
             }
         },
         syn::Data::Enum(data_enum) => {
-            assert!(
-                data_enum.variants.len() < 256,
-                "enums with more than 256 variants not supported"
-            );
-            let variant_dep_encode_snippets = variant_dep_encode_snippets(name, data_enum);
+            let discriminant_type = DiscriminantType::parse(&ast.attrs);
+            let variant_dep_encode_snippets =
+                variant_dep_encode_snippets(name, data_enum, discriminant_type);
 
             quote! {
                 impl #impl_generics codec::NestedEncode for #name #ty_generics #where_clause {
@@ -1,28 +1,30 @@
 use proc_macro::TokenStream;
 use quote::quote;
 
-use crate::{nested_de_derive::*, util::*};
+use crate::{codec_attr::*, nested_de_derive::*, util::*};
 
 fn fieldless_enum_match_arm_result_ok(
     name: &syn::Ident,
     data_enum: &syn::DataEnum,
+    discriminant_type: DiscriminantType,
 ) -> Vec<proc_macro2::TokenStream> {
+    let discriminants = variant_discriminants(data_enum, discriminant_type);
     data_enum
         .variants
         .iter()
-        .enumerate()
-        .map(|(variant_index, variant)| {
-            let variant_index_u8 = variant_index as u8;
+        .zip(discriminants)
+        .map(|(variant, discriminant)| {
+            let discriminant_literal = discriminant_type.literal(discriminant);
             let variant_ident = &variant.ident;
             quote! {
-                #variant_index_u8 => core::result::Result::Ok( #name::#variant_ident ),
+                #discriminant_literal => core::result::Result::Ok( #name::#variant_ident ),
             }
         })
         .collect()
 }
 
 /// Generates a default-value deserializer snippet automatically.
-/// Currently only does so for enums whose first variant is fieldless.
+/// Currently only does so for enums whose variant with discriminant 0 is fieldless.
 /// Not called for TopDecodeOrDefault, since that one already provides an explicit default.
 fn auto_default(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
     let name = &ast.ident;
@@ -31,15 +33,23 @@ fn auto_default(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
             !data_enum.variants.is_empty(),
             "cannot deserialize enums without variants"
         );
-        let first_variant = &data_enum.variants[0];
-        if first_variant.fields.is_empty() {
-            let first_variant_ident = &first_variant.ident;
-            let auto_default = quote! {
-                if top_input.byte_len() == 0 {
-                    return core::result::Result::Ok(#name::#first_variant_ident);
-                }
-            };
-            return auto_default;
+        let discriminants = variant_discriminants(data_enum, DiscriminantType::parse(&ast.attrs));
+        let zero_variant = data_enum
+            .variants
+            .iter()
+            .zip(discriminants)
+            .find(|(_, discriminant)| *discriminant == 0)
+            .map(|(variant, _)| variant);
+        if let Some(zero_variant) = zero_variant {
+            if zero_variant.fields.is_empty() {
+                let zero_variant_ident = &zero_variant.ident;
+                let auto_default = quote! {
+                    if top_input.byte_len() == 0 {
+                        return core::result::Result::Ok(#name::#zero_variant_ident);
+                    }
+                };
+                return auto_default;
+            }
         }
     }
 
@@ -52,6 +62,7 @@ fn top_decode_method_body(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
     let name = &ast.ident;
     match &ast.data {
         syn::Data::Struct(data_struct) => {
+            check_trailing_default_fields(&data_struct.fields);
             let field_dep_decode_snippets =
                 fields_decl_syntax(&data_struct.fields, |index, field| {
                     dep_decode_snippet(index, field, &quote! {&mut nested_buffer})
@@ -67,26 +78,29 @@ fn top_decode_method_body(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
             }
         },
         syn::Data::Enum(data_enum) => {
-            assert!(
-                data_enum.variants.len() < 256,
-                "enums with more than 256 variants not supported"
-            );
+            let discriminant_type = DiscriminantType::parse(&ast.attrs);
+            let discriminant_type_tokens = discriminant_type.type_tokens();
             if is_fieldless_enum(data_enum) {
-                // fieldless enums are special, they can be top-decoded as u8 directly
-                let top_decode_arms = fieldless_enum_match_arm_result_ok(name, data_enum);
+                // fieldless enums are special, they can be top-decoded as u8/u16 directly
+                let top_decode_arms =
+                    fieldless_enum_match_arm_result_ok(name, data_enum, discriminant_type);
                 quote! {
-                    match <u8 as codec::TopDecode>::top_decode_or_handle_err(top_input, h)? {
+                    match <#discriminant_type_tokens as codec::TopDecode>::top_decode_or_handle_err(top_input, h)? {
                         #(#top_decode_arms)*
                         _ => core::result::Result::Err(h.handle_error(codec::DecodeError::INVALID_VALUE)),
                     }
                 }
             } else {
-                let variant_dep_decode_snippets =
-                    variant_dep_decode_snippets(name, data_enum, &quote! {&mut nested_buffer});
+                let variant_dep_decode_snippets = variant_dep_decode_snippets(
+                    name,
+                    data_enum,
+                    discriminant_type,
+                    &quote! {&mut nested_buffer},
+                );
 
                 quote! {
                     let mut nested_buffer = top_input.into_nested_buffer();
-                    let result = match <u8 as codec::NestedDecode>::dep_decode_or_handle_err(&mut nested_buffer, h)? {
+                    let result = match <#discriminant_type_tokens as codec::NestedDecode>::dep_decode_or_handle_err(&mut nested_buffer, h)? {
                         #(#variant_dep_decode_snippets)*
                         _ => core::result::Result::Err(
                             h.handle_error(codec::DecodeError::INVALID_VALUE),
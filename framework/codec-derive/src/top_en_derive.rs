@@ -1,37 +1,48 @@
 use proc_macro::TokenStream;
 use quote::quote;
 
-use crate::{nested_en_derive::dep_encode_snippet, util::*};
+use crate::{
+    codec_attr::*,
+    nested_en_derive::{dep_encode_snippet, discriminant_dep_encode_snippet},
+    util::*,
+};
 
 pub fn variant_top_encode_snippets(
     name: &syn::Ident,
     data_enum: &syn::DataEnum,
+    discriminant_type: DiscriminantType,
 ) -> Vec<proc_macro2::TokenStream> {
+    let discriminants = variant_discriminants(data_enum, discriminant_type);
+    // with u16 discriminants, decoding mixed enums expects all discriminants nested-encoded
+    let top_encode_fieldless_variants =
+        discriminant_type == DiscriminantType::U8 || is_fieldless_enum(data_enum);
     data_enum
         .variants
         .iter()
-        .enumerate()
-        .map(|(variant_index, variant)| {
-            let variant_index_u8 = variant_index as u8;
+        .zip(discriminants)
+        .map(|(variant, discriminant)| {
             let variant_ident = &variant.ident;
-            if variant.fields.is_empty() {
+            if variant.fields.is_empty() && top_encode_fieldless_variants {
                 // top-encode discriminant directly
+                let discriminant_literal = discriminant_type.literal(discriminant);
                 quote! {
                     #name::#variant_ident =>
-                        codec::TopEncode::top_encode_or_handle_err(&#variant_index_u8, output, h),
+                        codec::TopEncode::top_encode_or_handle_err(&#discriminant_literal, output, h),
                 }
             } else {
                 // dep-encode to buffer first
                 let local_var_declarations =
-                    fields_decl_syntax(&variant.fields, local_variable_for_field);
+                    fields_decl_syntax(&variant.fields, local_variable_pattern_for_field);
+                let discriminant_snippet =
+                    discriminant_dep_encode_snippet(discriminant_type, discriminant);
                 let variant_field_snippets = fields_snippets(&variant.fields, |index, field| {
-                    dep_encode_snippet(&local_variable_for_field(index, field))
+                    dep_encode_snippet(field, &local_variable_for_field(index, field))
                 });
                 quote! {
                     #name::#variant_ident #local_var_declarations => {
                         let mut buffer = output.start_nested_encode();
                         let dest = &mut buffer;
-                        codec::NestedEncode::dep_encode_or_handle_err(&#variant_index_u8, dest, h)?;
+                        #discriminant_snippet
                         #(#variant_field_snippets)*
                         output.finalize_nested_encode(buffer);
                         core::result::Result::Ok(())
@@ -48,7 +59,8 @@ fn top_encode_method_body(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
     match &ast.data {
         syn::Data::Struct(data_struct) => {
             let field_dep_encode_snippets = fields_snippets(&data_struct.fields, |index, field| {
-                dep_encode_snippet(&self_field_expr(index, field))
+                let field_expr = self_field_expr(index, field);
+                dep_encode_snippet(field, &quote! { &#field_expr })
             });
             quote! {
                 let mut buffer = output.start_nested_encode();
@@ -59,11 +71,9 @@ fn top_encode_method_body(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
             }
         },
         syn::Data::Enum(data_enum) => {
-            assert!(
-                data_enum.variants.len() < 256,
-                "enums with more than 256 variants not supported"
-            );
-            let variant_top_encode_snippets = variant_top_encode_snippets(name, data_enum);
+            let discriminant_type = DiscriminantType::parse(&ast.attrs);
+            let variant_top_encode_snippets =
+                variant_top_encode_snippets(name, data_enum, discriminant_type);
 
             quote! {
                 match self {
@@ -1,5 +1,7 @@
 use quote::quote;
 
+use crate::codec_attr::FieldCodec;
+
 pub fn is_fieldless_enum(data_enum: &syn::DataEnum) -> bool {
     data_enum
         .variants
@@ -34,6 +36,26 @@ pub fn local_variable_for_field(index: usize, field: &syn::Field) -> proc_macro2
     }
 }
 
+/// Same as `local_variable_for_field`, but ignores the fields that are skipped by the codec.
+pub fn local_variable_pattern_for_field(
+    index: usize,
+    field: &syn::Field,
+) -> proc_macro2::TokenStream {
+    if !FieldCodec::parse(field).skip {
+        return local_variable_for_field(index, field);
+    }
+
+    if let Some(ident) = &field.ident {
+        quote! {
+            #ident: _
+        }
+    } else {
+        quote! {
+            _
+        }
+    }
+}
+
 pub fn fields_snippets<F>(fields: &syn::Fields, field_mapper: F) -> Vec<proc_macro2::TokenStream>
 where
     F: Fn(usize, &syn::Field) -> proc_macro2::TokenStream,
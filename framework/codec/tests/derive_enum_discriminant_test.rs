@@ -0,0 +1,90 @@
+use multiversx_sc_codec as codec;
+use multiversx_sc_codec_derive::*;
+
+use codec::test_util::{check_dep_encode_decode, check_top_decode, check_top_encode_decode};
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Eq, Clone, Debug)]
+enum PinnedFieldless {
+    #[codec(discriminant = 7)]
+    Seven,
+    Eight,
+    #[codec(discriminant = 0)]
+    Zero,
+    #[codec(discriminant = 255)]
+    Max,
+}
+
+#[test]
+fn pinned_fieldless_enum() {
+    check_top_encode_decode(PinnedFieldless::Seven, &[7]);
+    check_top_encode_decode(PinnedFieldless::Eight, &[8]);
+    check_top_encode_decode(PinnedFieldless::Zero, &[]);
+    check_top_encode_decode(PinnedFieldless::Max, &[255]);
+
+    check_dep_encode_decode(PinnedFieldless::Seven, &[7]);
+    check_dep_encode_decode(PinnedFieldless::Eight, &[8]);
+    check_dep_encode_decode(PinnedFieldless::Zero, &[0]);
+    check_dep_encode_decode(PinnedFieldless::Max, &[255]);
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Eq, Clone, Debug)]
+enum PinnedWithFields {
+    #[codec(discriminant = 3)]
+    Amount(u32),
+    #[codec(discriminant = 1)]
+    Nothing,
+    Named {
+        x: u8,
+    },
+}
+
+#[test]
+fn pinned_field_enum() {
+    check_top_encode_decode(PinnedWithFields::Amount(5), &[3, 0, 0, 0, 5]);
+    check_top_encode_decode(PinnedWithFields::Nothing, &[1]);
+    check_top_encode_decode(PinnedWithFields::Named { x: 9 }, &[2, 9]);
+
+    check_dep_encode_decode(PinnedWithFields::Amount(5), &[3, 0, 0, 0, 5]);
+    check_dep_encode_decode(PinnedWithFields::Nothing, &[1]);
+    check_dep_encode_decode(PinnedWithFields::Named { x: 9 }, &[2, 9]);
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Eq, Clone, Debug)]
+#[codec(discriminant_type = "u16")]
+enum LargeFieldless {
+    First,
+    #[codec(discriminant = 300)]
+    Large,
+}
+
+#[test]
+fn u16_fieldless_enum() {
+    check_top_encode_decode(LargeFieldless::First, &[]);
+    check_top_encode_decode(LargeFieldless::Large, &[1, 44]);
+
+    check_dep_encode_decode(LargeFieldless::First, &[0, 0]);
+    check_dep_encode_decode(LargeFieldless::Large, &[1, 44]);
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Eq, Clone, Debug)]
+#[codec(discriminant_type = "u16")]
+enum LargeWithFields {
+    Empty,
+    #[codec(discriminant = 1000)]
+    Value(u8),
+    Other,
+}
+
+#[test]
+fn u16_field_enum() {
+    check_top_encode_decode(LargeWithFields::Empty, &[0, 0]);
+    check_top_encode_decode(LargeWithFields::Value(5), &[3, 232, 5]);
+    check_top_encode_decode(LargeWithFields::Other, &[3, 233]);
+
+    check_dep_encode_decode(LargeWithFields::Empty, &[0, 0]);
+    check_dep_encode_decode(LargeWithFields::Value(5), &[3, 232, 5]);
+    check_dep_encode_decode(LargeWithFields::Other, &[3, 233]);
+
+    // also allowed
+    assert_eq!(LargeWithFields::Empty, check_top_decode(&[]));
+}
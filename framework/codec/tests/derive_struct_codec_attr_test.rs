@@ -0,0 +1,147 @@
+use multiversx_sc_codec as codec;
+use multiversx_sc_codec_derive::*;
+
+use codec::{
+    multi_types::MultiValue3,
+    test_util::{
+        check_dep_encode_decode, check_top_decode, check_top_encode, check_top_encode_decode,
+    },
+    TopDecodeMulti,
+};
+
+/// Encodes a `u64` as 4 bytes, for a field that is known to never need more.
+mod compact_u64 {
+    use multiversx_sc_codec as codec;
+
+    pub fn dep_encode_or_handle_err<O, H>(
+        value: &u64,
+        dest: &mut O,
+        h: H,
+    ) -> Result<(), H::HandledErr>
+    where
+        O: codec::NestedEncodeOutput,
+        H: codec::EncodeErrorHandler,
+    {
+        codec::NestedEncode::dep_encode_or_handle_err(&(*value as u32), dest, h)
+    }
+
+    pub fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<u64, H::HandledErr>
+    where
+        I: codec::NestedDecodeInput,
+        H: codec::DecodeErrorHandler,
+    {
+        let value = <u32 as codec::NestedDecode>::dep_decode_or_handle_err(input, h)?;
+        Ok(value as u64)
+    }
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+struct StructWithCodecAttributes {
+    int: u16,
+    #[codec(skip)]
+    cached: u32,
+    #[codec(with = "compact_u64")]
+    timestamp: u64,
+}
+
+#[test]
+fn struct_codec_attributes() {
+    let s = StructWithCodecAttributes {
+        int: 1,
+        cached: 0,
+        timestamp: 2,
+    };
+    check_top_encode_decode(s.clone(), &[0, 1, 0, 0, 0, 2]);
+    check_dep_encode_decode(s, &[0, 1, 0, 0, 0, 2]);
+}
+
+#[test]
+fn struct_codec_skip_not_encoded() {
+    let s = StructWithCodecAttributes {
+        int: 1,
+        cached: 100,
+        timestamp: 2,
+    };
+    assert_eq!(check_top_encode(&s), &[0, 1, 0, 0, 0, 2]);
+}
+
+/// `#[codec(default)]` is only supported top-level, so no `NestedDecode`.
+#[derive(TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+struct StructWithAppendedField {
+    int: u16,
+    #[codec(default)]
+    appended: u8,
+}
+
+#[test]
+fn struct_codec_default_when_missing() {
+    let s = StructWithAppendedField {
+        int: 1,
+        appended: 3,
+    };
+    check_top_encode_decode(s, &[0, 1, 3]);
+
+    // data stored before the field was appended
+    let decoded: StructWithAppendedField = check_top_decode(&[0, 1]);
+    assert_eq!(
+        decoded,
+        StructWithAppendedField {
+            int: 1,
+            appended: 0,
+        }
+    );
+}
+
+#[test]
+fn struct_codec_default_in_list() {
+    // lists of such items are stored one item per value, e.g. in a VecMapper or as variadic arguments,
+    // so each old-format item gets its own default
+    let mut old_format_items = vec![vec![0, 1], vec![0, 2], vec![0, 3, 4]];
+    let decoded = MultiValue3::<
+        StructWithAppendedField,
+        StructWithAppendedField,
+        StructWithAppendedField,
+    >::multi_decode(&mut old_format_items)
+    .unwrap();
+    assert_eq!(
+        decoded.into_tuple(),
+        (
+            StructWithAppendedField {
+                int: 1,
+                appended: 0,
+            },
+            StructWithAppendedField {
+                int: 2,
+                appended: 0,
+            },
+            StructWithAppendedField {
+                int: 3,
+                appended: 4,
+            },
+        )
+    );
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+enum EnumWithCodecAttributes {
+    Tuple(#[codec(skip)] u8, u8),
+    Struct {
+        #[codec(with = "compact_u64")]
+        timestamp: u64,
+        #[codec(skip)]
+        cached: u8,
+    },
+}
+
+#[test]
+fn enum_codec_attributes() {
+    check_top_encode_decode(EnumWithCodecAttributes::Tuple(0, 5), &[0, 5]);
+    check_dep_encode_decode(EnumWithCodecAttributes::Tuple(0, 5), &[0, 5]);
+
+    let s = EnumWithCodecAttributes::Struct {
+        timestamp: 7,
+        cached: 0,
+    };
+    check_top_encode_decode(s.clone(), &[1, 0, 0, 0, 7]);
+    check_dep_encode_decode(s, &[1, 0, 0, 0, 7]);
+}
@@ -0,0 +1,316 @@
+use super::parse::attributes::{
+    error_variant_attribute, extract_doc, parse_error_message_template, ErrorMessageTemplate,
+};
+use proc_macro::TokenStream;
+use quote::quote;
+
+/// A field of an error variant, as referenced from the message template.
+struct ErrorField {
+    placeholder: String,
+    binding: syn::Ident,
+    ty: syn::Type,
+}
+
+struct ErrorVariant {
+    variant: syn::Variant,
+    code: u32,
+    message: String,
+    template: ErrorMessageTemplate,
+    fields: Vec<ErrorField>,
+}
+
+fn error_fields(variant: &syn::Variant) -> Vec<ErrorField> {
+    match &variant.fields {
+        syn::Fields::Named(fields_named) => fields_named
+            .named
+            .iter()
+            .map(|field| {
+                let ident = field.ident.clone().unwrap();
+                ErrorField {
+                    placeholder: ident.to_string(),
+                    binding: ident,
+                    ty: field.ty.clone(),
+                }
+            })
+            .collect(),
+        syn::Fields::Unnamed(fields_unnamed) => fields_unnamed
+            .unnamed
+            .iter()
+            .enumerate()
+            .map(|(index, field)| ErrorField {
+                placeholder: index.to_string(),
+                binding: format_ident!("field_{}", index),
+                ty: field.ty.clone(),
+            })
+            .collect(),
+        syn::Fields::Unit => Vec::new(),
+    }
+}
+
+fn parse_error_variant(variant: &syn::Variant) -> ErrorVariant {
+    let attr = error_variant_attribute(variant);
+    let template = parse_error_message_template(&attr.message);
+    let fields = error_fields(variant);
+    let variant_name = &variant.ident;
+    for field in &fields {
+        let occurrences = template
+            .placeholders
+            .iter()
+            .filter(|placeholder| **placeholder == field.placeholder)
+            .count();
+        assert!(
+            occurrences == 1,
+            "`{variant_name}`: field `{}` must appear exactly once in the error message",
+            field.placeholder
+        );
+    }
+    for placeholder in &template.placeholders {
+        assert!(
+            fields.iter().any(|field| field.placeholder == *placeholder),
+            "`{variant_name}`: unknown field `{placeholder}` in the error message"
+        );
+    }
+    ErrorVariant {
+        variant: variant.clone(),
+        code: attr.code,
+        message: attr.message,
+        template,
+        fields,
+    }
+}
+
+fn code_prefix(code: u32) -> String {
+    format!("E{code}: ")
+}
+
+fn byte_str(s: &str) -> syn::LitByteStr {
+    syn::LitByteStr::new(s.as_bytes(), proc_macro2::Span::call_site())
+}
+
+/// Static parts as they appear in the signalled message, the first one includes the code prefix.
+fn full_static_parts(error_variant: &ErrorVariant) -> Vec<syn::LitByteStr> {
+    error_variant
+        .template
+        .static_parts
+        .iter()
+        .enumerate()
+        .map(|(index, part)| {
+            if index == 0 {
+                byte_str(&format!("{}{part}", code_prefix(error_variant.code)))
+            } else {
+                byte_str(part)
+            }
+        })
+        .collect()
+}
+
+fn variant_pattern(error_variant: &ErrorVariant) -> proc_macro2::TokenStream {
+    let variant_name = &error_variant.variant.ident;
+    let bindings = error_variant.fields.iter().map(|field| &field.binding);
+    match &error_variant.variant.fields {
+        syn::Fields::Named(_) => quote! { Self::#variant_name { #(#bindings),* } },
+        syn::Fields::Unnamed(_) => quote! { Self::#variant_name ( #(#bindings),* ) },
+        syn::Fields::Unit => quote! { Self::#variant_name },
+    }
+}
+
+fn error_code_arm(error_variant: &ErrorVariant) -> proc_macro2::TokenStream {
+    let variant_name = &error_variant.variant.ident;
+    let code = error_variant.code;
+    let pattern = match &error_variant.variant.fields {
+        syn::Fields::Named(_) => quote! { Self::#variant_name { .. } },
+        syn::Fields::Unnamed(_) => quote! { Self::#variant_name ( .. ) },
+        syn::Fields::Unit => quote! { Self::#variant_name },
+    };
+    quote! {
+        #pattern => #code,
+    }
+}
+
+fn format_arm(error_variant: &ErrorVariant) -> proc_macro2::TokenStream {
+    let pattern = variant_pattern(error_variant);
+    let static_parts = full_static_parts(error_variant);
+    let mut append_snippets = Vec::new();
+    for (index, static_part) in static_parts.iter().enumerate() {
+        append_snippets.push(quote! {
+            multiversx_sc::formatter::FormatBuffer::append_ascii(&mut buffer, #static_part);
+        });
+        if let Some(placeholder) = error_variant.template.placeholders.get(index) {
+            let field = error_variant
+                .fields
+                .iter()
+                .find(|field| field.placeholder == *placeholder)
+                .unwrap();
+            let binding = &field.binding;
+            append_snippets.push(quote! {
+                multiversx_sc::formatter::FormatBuffer::append_display(&mut buffer, #binding);
+            });
+        }
+    }
+    quote! {
+        #pattern => {
+            #(#append_snippets)*
+        },
+    }
+}
+
+fn decode_snippet(error_variant: &ErrorVariant) -> proc_macro2::TokenStream {
+    let variant_name = &error_variant.variant.ident;
+    let static_parts = full_static_parts(error_variant);
+    let field_values: Vec<proc_macro2::TokenStream> = error_variant
+        .fields
+        .iter()
+        .map(|field| {
+            let field_index = error_variant
+                .template
+                .placeholders
+                .iter()
+                .position(|placeholder| *placeholder == field.placeholder)
+                .unwrap();
+            let ty = &field.ty;
+            quote! {
+                <#ty as multiversx_sc::types::ContractErrorArg>::parse_error_arg(fields[#field_index])?
+            }
+        })
+        .collect();
+    let construct = match &error_variant.variant.fields {
+        syn::Fields::Named(_) => {
+            let names = error_variant.fields.iter().map(|field| &field.binding);
+            quote! { Self::#variant_name { #(#names: #field_values),* } }
+        },
+        syn::Fields::Unnamed(_) => quote! { Self::#variant_name ( #(#field_values),* ) },
+        syn::Fields::Unit => quote! { Self::#variant_name },
+    };
+    quote! {
+        if let Some(fields) = multiversx_sc::types::match_contract_error_template(&[#(&#static_parts[..]),*], message) {
+            let _ = &fields;
+            return Some(#construct);
+        }
+    }
+}
+
+fn error_abi_snippet(error_variant: &ErrorVariant) -> proc_macro2::TokenStream {
+    let docs = extract_doc(error_variant.variant.attrs.as_slice());
+    let name_str = error_variant.variant.ident.to_string();
+    let code = error_variant.code;
+    let message = &error_variant.message;
+    let field_snippets = error_variant.fields.iter().map(|field| {
+        let placeholder = &field.placeholder;
+        let ty = &field.ty;
+        quote! {
+            error_abi.add_field::<#ty>(#placeholder);
+        }
+    });
+    quote! {
+        let mut error_abi = multiversx_sc::abi::ErrorAbi {
+            docs: &[ #(#docs),* ],
+            name: #name_str,
+            code: #code,
+            message: #message,
+            fields: multiversx_sc::types::heap::Vec::new(),
+        };
+        #(#field_snippets)*
+        error_abis.push(error_abi);
+    }
+}
+
+fn type_descriptions_snippet(error_variant: &ErrorVariant) -> proc_macro2::TokenStream {
+    let field_snippets = error_variant.fields.iter().map(|field| {
+        let ty = &field.ty;
+        quote! {
+            <#ty as multiversx_sc::abi::TypeAbi>::provide_type_descriptions(accumulator);
+        }
+    });
+    quote! {
+        #(#field_snippets)*
+    }
+}
+
+/// The enum can be generic over the managed type API, which then also parametrizes `IntoSignalError`.
+fn signal_error_impl(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = &ast.generics.split_for_impl();
+    let type_params: Vec<&syn::TypeParam> = ast.generics.type_params().collect();
+    match type_params.as_slice() {
+        [] => quote! {
+            impl<M: multiversx_sc::api::ManagedTypeApi> multiversx_sc::contract_base::IntoSignalError<M> for #name #where_clause {
+                fn signal_error_with_message(self) -> ! {
+                    multiversx_sc::types::signal_contract_error::<M, Self>(&self)
+                }
+            }
+        },
+        [api_param] => {
+            let api_ident = &api_param.ident;
+            quote! {
+                impl #impl_generics multiversx_sc::contract_base::IntoSignalError<#api_ident> for #name #ty_generics #where_clause {
+                    fn signal_error_with_message(self) -> ! {
+                        multiversx_sc::types::signal_contract_error::<#api_ident, Self>(&self)
+                    }
+                }
+            }
+        },
+        _ => panic!("ContractError only supports enums with at most one type parameter, the managed type API"),
+    }
+}
+
+pub fn contract_error_derive(ast: &syn::DeriveInput) -> TokenStream {
+    let syn::Data::Enum(data_enum) = &ast.data else {
+        panic!("ContractError can only be derived for enums");
+    };
+    let error_variants: Vec<ErrorVariant> =
+        data_enum.variants.iter().map(parse_error_variant).collect();
+    for (index, error_variant) in error_variants.iter().enumerate() {
+        assert!(
+            error_variants[..index]
+                .iter()
+                .all(|previous| previous.code != error_variant.code),
+            "duplicate error code {}",
+            error_variant.code
+        );
+    }
+
+    let error_code_arms = error_variants.iter().map(error_code_arm);
+    let format_arms = error_variants.iter().map(format_arm);
+    let decode_snippets = error_variants.iter().map(decode_snippet);
+    let error_abi_snippets = error_variants.iter().map(error_abi_snippet);
+    let type_descriptions_snippets = error_variants.iter().map(type_descriptions_snippet);
+    let signal_error_impl = signal_error_impl(ast);
+
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = &ast.generics.split_for_impl();
+    let contract_error_impl = quote! {
+        impl #impl_generics multiversx_sc::types::ContractError for #name #ty_generics #where_clause {
+            fn error_code(&self) -> u32 {
+                match self {
+                    #(#error_code_arms)*
+                }
+            }
+
+            fn format_error_message<F: multiversx_sc::formatter::FormatBuffer>(&self) -> F {
+                let mut buffer = F::default();
+                match self {
+                    #(#format_arms)*
+                }
+                buffer
+            }
+
+            fn decode_error_message(message: &[u8]) -> Option<Self> {
+                #(#decode_snippets)*
+                None
+            }
+
+            fn error_abis() -> multiversx_sc::types::heap::Vec<multiversx_sc::abi::ErrorAbi> {
+                let mut error_abis = multiversx_sc::types::heap::Vec::new();
+                #(#error_abi_snippets)*
+                error_abis
+            }
+
+            fn provide_error_type_descriptions<TDC: multiversx_sc::abi::TypeDescriptionContainer>(accumulator: &mut TDC) {
+                #(#type_descriptions_snippets)*
+            }
+        }
+
+        #signal_error_impl
+    };
+    contract_error_impl.into()
+}
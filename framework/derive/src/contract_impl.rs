@@ -4,7 +4,7 @@ use crate::{
         auto_impl::generate_auto_impls, auto_impl_proxy::generate_all_proxy_trait_imports,
         callback_gen::*, contract_gen::*, endpoints_mod_gen::generate_endpoints_mod,
         function_selector::generate_function_selector_body, proxy_callback_gen::*, proxy_gen,
-        supertrait_gen,
+        restricted_caller_gen::generate_callback_context_snippets, supertrait_gen,
     },
     model::ContractTrait,
 };
@@ -25,6 +25,8 @@ pub fn contract_implementation(
     let endpoints_mod = generate_endpoints_mod(contract, is_contract_main);
     let function_selector_body = generate_function_selector_body(contract);
     let (callback_selector_body, callback_body) = generate_callback_selector_and_main(contract);
+    let callback_context_required = generate_callback_context_required(contract);
+    let (callback_context_enter, callback_context_exit) = generate_callback_context_snippets();
     let (callbacks_def, callbacks_impl, callback_proxies_obj) = generate_callback_proxies(contract);
 
     // this definition is common to release and debug mode
@@ -73,6 +75,10 @@ pub fn contract_implementation(
             + #trait_name_ident
             #(#endpoint_wrapper_supertrait_decl)*
         {
+            /// Whether callbacks need to set the flag checked by `#[only_callback_context]` endpoints.
+            /// Covers the endpoints of this trait and of all its modules.
+            const CALLBACK_CONTEXT_REQUIRED: bool = #callback_context_required;
+
             #(#call_methods)*
 
             fn call(&self, fn_name: &str) -> bool {
@@ -84,7 +90,9 @@ pub fn contract_implementation(
             }
 
             fn callback(&self) {
+                #callback_context_enter
                 #callback_body
+                #callback_context_exit
             }
         }
 
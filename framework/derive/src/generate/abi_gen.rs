@@ -1,13 +1,35 @@
 use super::util::*;
 use crate::model::{
-    AutoImpl, ContractTrait, EndpointMutabilityMetadata, Method, MethodImpl, PublicRole,
+    AutoImpl, ContractTrait, EndpointMutabilityMetadata, Method, MethodArgument, MethodImpl,
+    PublicRole,
 };
 
+/// Raw storage getters and setters have the same layout as a `SingleValueMapper`.
+const STORAGE_RAW_MAPPER_NAME: &str = "SingleValueMapper";
+
+/// `file!()` and `line!()` take the span of the method name, so they point to the method declaration.
+fn source_location_snippet(m: &Method) -> proc_macro2::TokenStream {
+    quote_spanned! {m.name.span()=>
+        Some(multiversx_sc::abi::SourceLocationAbi {
+            file: file!(),
+            line: line!(),
+        })
+    }
+}
+
+fn option_tokens<T: quote::ToTokens>(value: Option<T>) -> proc_macro2::TokenStream {
+    match value {
+        Some(value) => quote! { Some(#value) },
+        None => quote! { None },
+    }
+}
+
 fn generate_endpoint_snippet(
     m: &Method,
     endpoint_name: &str,
     only_owner: bool,
     only_admin: bool,
+    only_role: Option<&str>,
     mutability: EndpointMutabilityMetadata,
 ) -> proc_macro2::TokenStream {
     let endpoint_docs = &m.docs;
@@ -48,6 +70,28 @@ fn generate_endpoint_snippet(
 
     let label_names = &m.label_names;
     let mutability_tokens = mutability.to_tokens();
+    let only_role_tokens = match only_role {
+        Some(role) => quote! { Some(#role) },
+        None => quote! { None },
+    };
+
+    let (only_callback_context, not_from_contract, non_reentrant) = match &m.public_role {
+        PublicRole::Endpoint(endpoint_metadata) => (
+            endpoint_metadata.only_callback_context,
+            endpoint_metadata.not_from_contract,
+            endpoint_metadata.non_reentrant,
+        ),
+        _ => (false, false, false),
+    };
+    let (max_gas, max_return_items) = match &m.public_role {
+        PublicRole::Endpoint(endpoint_metadata) => (
+            option_tokens(endpoint_metadata.max_gas),
+            option_tokens(endpoint_metadata.max_return_items),
+        ),
+        _ => (quote! { None }, quote! { None }),
+    };
+
+    let location_snippet = source_location_snippet(m);
 
     quote! {
         let mut endpoint_abi = multiversx_sc::abi::EndpointAbi{
@@ -56,11 +100,18 @@ fn generate_endpoint_snippet(
             rust_method_name: #rust_method_name,
             only_owner: #only_owner,
             only_admin: #only_admin,
+            only_role: #only_role_tokens,
+            only_callback_context: #only_callback_context,
+            not_from_contract: #not_from_contract,
+            non_reentrant: #non_reentrant,
+            max_gas: #max_gas,
+            max_return_items: #max_return_items,
             mutability: #mutability_tokens,
             payable_in_tokens: &[ #(#payable_in_tokens),* ],
             inputs: multiversx_sc::types::heap::Vec::new(),
             outputs: multiversx_sc::types::heap::Vec::new(),
             labels: &[ #(#label_names),* ],
+            location: #location_snippet,
         };
         #(#input_snippets)*
         #output_snippet
@@ -78,6 +129,7 @@ fn generate_endpoint_snippets(contract: &ContractTrait) -> Vec<proc_macro2::Toke
                     "init",
                     false,
                     false,
+                    None,
                     EndpointMutabilityMetadata::Mutable,
                 );
                 Some(quote! {
@@ -85,12 +137,27 @@ fn generate_endpoint_snippets(contract: &ContractTrait) -> Vec<proc_macro2::Toke
                     contract_abi.constructors.push(endpoint_abi);
                 })
             },
+            PublicRole::Upgrade(_) => {
+                let endpoint_def = generate_endpoint_snippet(
+                    m,
+                    "upgrade",
+                    true,
+                    false,
+                    None,
+                    EndpointMutabilityMetadata::Mutable,
+                );
+                Some(quote! {
+                    #endpoint_def
+                    contract_abi.upgrade_constructors.push(endpoint_abi);
+                })
+            },
             PublicRole::Endpoint(endpoint_metadata) => {
                 let endpoint_def = generate_endpoint_snippet(
                     m,
                     &endpoint_metadata.public_name.to_string(),
                     endpoint_metadata.only_owner,
                     endpoint_metadata.only_admin,
+                    endpoint_metadata.only_role.as_deref(),
                     endpoint_metadata.mutability.clone(),
                 );
                 Some(quote! {
@@ -104,6 +171,7 @@ fn generate_endpoint_snippets(contract: &ContractTrait) -> Vec<proc_macro2::Toke
                     &callback_metadata.callback_name.to_string(),
                     false,
                     false,
+                    None,
                     EndpointMutabilityMetadata::Mutable,
                 );
                 Some(quote! {
@@ -134,11 +202,14 @@ fn generate_event_snippet(m: &Method, event_name: &str) -> proc_macro2::TokenStr
         })
         .collect();
 
+    let location_snippet = source_location_snippet(m);
+
     quote! {
         let mut event_abi = multiversx_sc::abi::EventAbi{
             docs: &[ #(#event_docs),* ],
             identifier: #event_name,
             inputs: multiversx_sc::types::heap::Vec::new(),
+            location: #location_snippet,
         };
         #(#input_snippets)*
     }
@@ -162,6 +233,148 @@ fn generate_event_snippets(contract: &ContractTrait) -> Vec<proc_macro2::TokenSt
         .collect()
 }
 
+fn generate_error_snippets(contract: &ContractTrait) -> Vec<proc_macro2::TokenStream> {
+    contract
+        .trait_attributes
+        .errors
+        .iter()
+        .map(|error_type| {
+            quote! {
+                contract_abi.add_errors::<#error_type>();
+            }
+        })
+        .collect()
+}
+
+fn storage_type_name_snippet(ty: &syn::Type) -> proc_macro2::TokenStream {
+    let mut ty = ty.clone();
+    clear_all_type_lifetimes(&mut ty);
+    quote! {
+        (&multiversx_sc::abi::StorageTypeAbiProbe::<#ty>::new())
+            .storage_type_name(&mut contract_abi.type_descriptions)
+    }
+}
+
+/// The storage mapper name, and the types it stores, i.e. all generic arguments except the API.
+fn storage_mapper_layout(mapper_type: &syn::Type) -> (String, Vec<syn::Type>) {
+    let last_segment = match mapper_type {
+        syn::Type::Path(type_path) => type_path.path.segments.last(),
+        _ => None,
+    }
+    .expect("storage mapper type must be a path");
+
+    let mut value_types = Vec::new();
+    if let syn::PathArguments::AngleBracketed(generic_args) = &last_segment.arguments {
+        for generic_arg in &generic_args.args {
+            if let syn::GenericArgument::Type(generic_type) = generic_arg {
+                if quote! { #generic_type }.to_string() != "Self :: Api" {
+                    value_types.push(generic_type.clone());
+                }
+            }
+        }
+    }
+
+    (last_segment.ident.to_string(), value_types)
+}
+
+fn generate_storage_snippet(
+    m: &Method,
+    identifier: &str,
+    mapper_name: &str,
+    key_args: &[MethodArgument],
+    value_types: &[syn::Type],
+) -> proc_macro2::TokenStream {
+    let storage_docs = &m.docs;
+    let key_arg_snippets: Vec<proc_macro2::TokenStream> = key_args
+        .iter()
+        .map(|arg| {
+            let arg_name = &arg.pat;
+            let arg_name_str = quote! { #arg_name }.to_string();
+            let type_name_snippet = storage_type_name_snippet(&arg.ty);
+            quote! {
+                storage_abi.add_key_arg(#arg_name_str, #type_name_snippet);
+            }
+        })
+        .collect();
+    let value_type_snippets: Vec<proc_macro2::TokenStream> = value_types
+        .iter()
+        .map(|ty| {
+            let type_name_snippet = storage_type_name_snippet(ty);
+            quote! {
+                storage_abi.add_value_type(#type_name_snippet);
+            }
+        })
+        .collect();
+
+    quote! {
+        let mut storage_abi = multiversx_sc::abi::StorageAbi{
+            docs: &[ #(#storage_docs),* ],
+            key: #identifier,
+            mapper: #mapper_name,
+            key_args: multiversx_sc::types::heap::Vec::new(),
+            value_types: multiversx_sc::types::heap::Vec::new(),
+        };
+        {
+            use multiversx_sc::abi::{StorageTypeAbiFallback as _, StorageTypeAbiProvider as _};
+            #(#key_arg_snippets)*
+            #(#value_type_snippets)*
+        }
+        contract_abi.add_storage(storage_abi);
+    }
+}
+
+fn generate_storage_snippets(contract: &ContractTrait) -> Vec<proc_macro2::TokenStream> {
+    contract
+        .methods
+        .iter()
+        .filter_map(|m| {
+            let return_type = match &m.return_type {
+                syn::ReturnType::Type(_, ty) => Some(ty.as_ref()),
+                syn::ReturnType::Default => None,
+            };
+            match &m.implementation {
+                MethodImpl::Generated(AutoImpl::StorageMapper { identifier }) => {
+                    let (mapper_name, value_types) = storage_mapper_layout(
+                        return_type.expect("storage mapper must have a return type"),
+                    );
+                    Some(generate_storage_snippet(
+                        m,
+                        identifier,
+                        &mapper_name,
+                        &m.method_args,
+                        &value_types,
+                    ))
+                },
+                MethodImpl::Generated(AutoImpl::StorageGetter { identifier }) => {
+                    Some(generate_storage_snippet(
+                        m,
+                        identifier,
+                        STORAGE_RAW_MAPPER_NAME,
+                        &m.method_args,
+                        &[return_type
+                            .expect("storage getter must have a return type")
+                            .clone()],
+                    ))
+                },
+                MethodImpl::Generated(AutoImpl::StorageSetter { identifier }) => {
+                    let (value_arg, key_args) = m
+                        .method_args
+                        .split_last()
+                        .expect("storage setter must have at least one argument");
+                    Some(generate_storage_snippet(
+                        m,
+                        identifier,
+                        STORAGE_RAW_MAPPER_NAME,
+                        key_args,
+                        core::slice::from_ref(&value_arg.ty),
+                    ))
+                },
+                _ => None,
+            }
+        })
+        .collect()
+}
+
 fn has_callback(contract: &ContractTrait) -> bool {
     contract.methods.iter().any(|m| {
         matches!(
@@ -192,6 +405,8 @@ fn generate_abi_method_body(
     let contract_name = &contract.trait_name.to_string();
     let endpoint_snippets = generate_endpoint_snippets(contract);
     let event_snippets = generate_event_snippets(contract);
+    let storage_snippets = generate_storage_snippets(contract);
+    let error_snippets = generate_error_snippets(contract);
     let has_callbacks = has_callback(contract);
     let supertrait_snippets: Vec<proc_macro2::TokenStream> = if is_contract_main {
         generate_supertrait_snippets(contract)
@@ -212,14 +427,19 @@ fn generate_abi_method_body(
             docs: &[ #(#contract_docs),* ],
             name: #contract_name,
             constructors: multiversx_sc::types::heap::Vec::new(),
+            upgrade_constructors: multiversx_sc::types::heap::Vec::new(),
             endpoints: multiversx_sc::types::heap::Vec::new(),
             promise_callbacks: multiversx_sc::types::heap::Vec::new(),
             events: multiversx_sc::types::heap::Vec::new(),
+            storage: multiversx_sc::types::heap::Vec::new(),
+            errors: multiversx_sc::types::heap::Vec::new(),
             has_callback: #has_callbacks,
             type_descriptions: <multiversx_sc::abi::TypeDescriptionContainerImpl as multiversx_sc::abi::TypeDescriptionContainer>::new(),
         };
         #(#endpoint_snippets)*
         #(#event_snippets)*
+        #(#storage_snippets)*
+        #(#error_snippets)*
         #(#supertrait_snippets)*
         contract_abi
     }
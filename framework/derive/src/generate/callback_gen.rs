@@ -61,6 +61,22 @@ pub fn generate_callback_selector_and_main(
     }
 }
 
+/// True if any endpoint of the trait is `#[only_callback_context]`, or if any of its modules requires it.
+pub fn generate_callback_context_required(contract: &ContractTrait) -> proc_macro2::TokenStream {
+    let own_endpoints = contract.methods.iter().any(|m| {
+        matches!(&m.public_role, PublicRole::Endpoint(endpoint_metadata) if endpoint_metadata.only_callback_context)
+    });
+    let module_flags = contract.supertraits.iter().map(|supertrait| {
+        let module_path = &supertrait.module_path;
+        quote! {
+            || <Self as #module_path EndpointWrappers>::CALLBACK_CONTEXT_REQUIRED
+        }
+    });
+    quote! {
+        #own_endpoints #(#module_flags)*
+    }
+}
+
 fn find_raw_callback(methods: &[Method]) -> Option<Method> {
     methods
         .iter()
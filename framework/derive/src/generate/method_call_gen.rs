@@ -67,6 +67,10 @@ pub fn generate_endpoint_call_method_body(m: &Method) -> proc_macro2::TokenStrea
     let only_owner_snippet = generate_only_owner_snippet(m);
     let only_admin_snippet = generate_only_admin_snippet(m);
     let only_user_account_snippet = generate_only_user_account_snippet(m);
+    let only_callback_context_snippet = generate_only_callback_context_snippet(m);
+    let not_from_contract_snippet = generate_not_from_contract_snippet(m);
+    let only_role_snippet = generate_only_role_snippet(m);
+    let (lock_snippet, unlock_snippet) = generate_non_reentrant_snippets(m);
     let arg_load = generate_call_method_arg_load(m);
 
     let call = generate_call_to_method_expr(m);
@@ -78,8 +82,13 @@ pub fn generate_endpoint_call_method_body(m: &Method) -> proc_macro2::TokenStrea
         #only_owner_snippet
         #only_admin_snippet
         #only_user_account_snippet
+        #only_callback_context_snippet
+        #not_from_contract_snippet
+        #only_role_snippet
+        #lock_snippet
         #arg_load
         #body_with_result
+        #unlock_snippet
     }
 }
 
@@ -88,6 +97,7 @@ pub fn generate_promises_callback_call_method_body(m: &Method) -> proc_macro2::T
     let payable_snippet = generate_payable_snippet(m);
     let cb_closure_args_snippet = load_cb_closure_args_snippet(m);
     let call_result_args_snippet = load_call_result_args_snippet(m);
+    let (enter_snippet, exit_snippet) = generate_callback_context_snippets();
 
     let call = generate_call_to_method_expr(m);
     let body_with_result = generate_body_with_result(&m.return_type, &call);
@@ -97,6 +107,8 @@ pub fn generate_promises_callback_call_method_body(m: &Method) -> proc_macro2::T
         #payable_snippet
         #cb_closure_args_snippet
         #call_result_args_snippet
+        #enter_snippet
         #body_with_result
+        #exit_snippet
     }
 }
@@ -238,8 +238,14 @@ pub fn generate_proxy_deploy(init_method: &Method) -> proc_macro2::TokenStream {
         payment_count <= 1,
         "No more than one payment argument allowed in call proxy"
     );
-    assert!(token_count == 0, "No ESDT payment allowed in #[init]");
-    assert!(nonce_count == 0, "No SFT/NFT payment allowed in #[init]");
+    assert!(
+        token_count == 0,
+        "No ESDT payment allowed in #[init] or #[upgrade]"
+    );
+    assert!(
+        nonce_count == 0,
+        "No SFT/NFT payment allowed in #[init] or #[upgrade]"
+    );
 
     let sig = quote! {
         #[allow(clippy::too_many_arguments)]
@@ -263,6 +269,7 @@ pub fn generate_method_impl(contract_trait: &ContractTrait) -> Vec<proc_macro2::
         .iter()
         .filter_map(|m| match &m.public_role {
             PublicRole::Init(_) => Some(generate_proxy_deploy(m)),
+            PublicRole::Upgrade(_) => Some(generate_proxy_deploy(m)),
             PublicRole::Endpoint(endpoint_metadata) => Some(generate_proxy_endpoint(
                 m,
                 endpoint_metadata.public_name.to_string(),
@@ -1,14 +1,18 @@
+use super::util::byte_str_literal;
 use crate::model::{Method, PublicRole};
 
+/// The upgrade endpoint is always owner-only: the protocol only lets the owner upgrade,
+/// but the endpoint can also be called directly, as any other one.
 pub fn generate_only_owner_snippet(m: &Method) -> proc_macro2::TokenStream {
-    if let PublicRole::Endpoint(endpoint_metadata) = &m.public_role {
-        if endpoint_metadata.only_owner {
-            return quote! {
-                self.blockchain().check_caller_is_owner();
-            };
-        }
+    match &m.public_role {
+        PublicRole::Endpoint(endpoint_metadata) if endpoint_metadata.only_owner => quote! {
+            self.blockchain().check_caller_is_owner();
+        },
+        PublicRole::Upgrade(_) => quote! {
+            self.blockchain().check_caller_is_owner();
+        },
+        _ => quote! {},
     }
-    quote! {}
 }
 
 pub fn generate_only_admin_snippet(m: &Method) -> proc_macro2::TokenStream {
@@ -32,3 +36,74 @@ pub fn generate_only_user_account_snippet(m: &Method) -> proc_macro2::TokenStrea
     }
     quote! {}
 }
+
+pub fn generate_only_callback_context_snippet(m: &Method) -> proc_macro2::TokenStream {
+    if let PublicRole::Endpoint(endpoint_metadata) = &m.public_role {
+        if endpoint_metadata.only_callback_context {
+            return quote! {
+                self.callback_context().require_active();
+            };
+        }
+    }
+    quote! {}
+}
+
+pub fn generate_not_from_contract_snippet(m: &Method) -> proc_macro2::TokenStream {
+    if let PublicRole::Endpoint(endpoint_metadata) = &m.public_role {
+        if endpoint_metadata.not_from_contract {
+            return quote! {
+                self.blockchain().check_caller_is_user_account();
+            };
+        }
+    }
+    quote! {}
+}
+
+/// Callbacks only set the callback context flag if the contract has `#[only_callback_context]` endpoints,
+/// which `EndpointWrappers::CALLBACK_CONTEXT_REQUIRED` tells at compile time.
+pub fn generate_callback_context_snippets() -> (proc_macro2::TokenStream, proc_macro2::TokenStream)
+{
+    (
+        quote! {
+            if <Self as EndpointWrappers>::CALLBACK_CONTEXT_REQUIRED {
+                self.callback_context().enter();
+            }
+        },
+        quote! {
+            if <Self as EndpointWrappers>::CALLBACK_CONTEXT_REQUIRED {
+                self.callback_context().exit();
+            }
+        },
+    )
+}
+
+/// The lock and unlock snippets go around the whole endpoint body.
+pub fn generate_non_reentrant_snippets(
+    m: &Method,
+) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    if let PublicRole::Endpoint(endpoint_metadata) = &m.public_role {
+        if endpoint_metadata.non_reentrant {
+            return (
+                quote! {
+                    self.reentrancy_guard().lock();
+                },
+                quote! {
+                    self.reentrancy_guard().unlock();
+                },
+            );
+        }
+    }
+    (quote! {}, quote! {})
+}
+
+pub fn generate_only_role_snippet(m: &Method) -> proc_macro2::TokenStream {
+    if let PublicRole::Endpoint(endpoint_metadata) = &m.public_role {
+        if let Some(role) = &endpoint_metadata.only_role {
+            let role_literal = byte_str_literal(role.as_bytes());
+            return quote! {
+                self.require_caller_has_role(#role_literal);
+            };
+        }
+    }
+    quote! {}
+}
@@ -9,6 +9,7 @@ extern crate syn;
 #[macro_use]
 extern crate quote;
 
+mod contract_error_derive;
 mod contract_impl;
 mod format;
 mod generate;
@@ -46,13 +47,20 @@ pub fn proxy(
     macro_proxy::process_proxy(args, input)
 }
 
-#[proc_macro_derive(TypeAbi)]
+#[proc_macro_derive(TypeAbi, attributes(codec))]
 pub fn type_abi_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let ast = syn::parse(input).unwrap();
 
     type_abi_derive::type_abi_derive(&ast)
 }
 
+#[proc_macro_derive(ContractError, attributes(error))]
+pub fn contract_error_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = syn::parse(input).unwrap();
+
+    contract_error_derive::contract_error_derive(&ast)
+}
+
 #[proc_macro_derive(ManagedVecItem)]
 pub fn managed_vec_item_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let ast = syn::parse(input).unwrap();
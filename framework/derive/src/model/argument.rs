@@ -59,4 +59,6 @@ pub struct TraitProperties {
     pub only_owner: bool,
     pub only_admin: bool,
     pub only_user_account: bool,
+    /// Error enums registered with `#[errors(...)]`, described in the ABI.
+    pub errors: Vec<syn::Type>,
 }
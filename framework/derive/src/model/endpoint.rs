@@ -12,6 +12,12 @@ pub struct EndpointMetadata {
     pub only_owner: bool,
     pub only_admin: bool,
     pub only_user_account: bool,
+    pub only_callback_context: bool,
+    pub not_from_contract: bool,
+    pub non_reentrant: bool,
+    pub only_role: Option<String>,
+    pub max_gas: Option<u64>,
+    pub max_return_items: Option<usize>,
     pub mutability: EndpointMutabilityMetadata,
 }
 
@@ -26,6 +32,9 @@ pub enum PublicRole {
     /// The smart contract constructor. There can be only one.
     Init(InitMetadata),
 
+    /// Called instead of the constructor when the contract code gets upgraded. There can be at most one.
+    Upgrade(InitMetadata),
+
     /// Means it gets a smart contract function generated for it
     Endpoint(EndpointMetadata),
 
@@ -77,7 +77,9 @@ impl Method {
 
     pub fn is_payable(&self) -> bool {
         match &self.public_role {
-            PublicRole::Init(init_metadata) => init_metadata.payable.is_payable(),
+            PublicRole::Init(init_metadata) | PublicRole::Upgrade(init_metadata) => {
+                init_metadata.payable.is_payable()
+            },
             PublicRole::Endpoint(endpoint_metadata) => endpoint_metadata.payable.is_payable(),
             PublicRole::Callback(_) | PublicRole::CallbackRaw | PublicRole::CallbackPromise(_) => {
                 true
@@ -88,7 +90,9 @@ impl Method {
 
     pub fn payable_metadata(&self) -> MethodPayableMetadata {
         match &self.public_role {
-            PublicRole::Init(init_metadata) => init_metadata.payable.clone(),
+            PublicRole::Init(init_metadata) | PublicRole::Upgrade(init_metadata) => {
+                init_metadata.payable.clone()
+            },
             PublicRole::Endpoint(endpoint_metadata) => endpoint_metadata.payable.clone(),
             PublicRole::Callback(_) | PublicRole::CallbackRaw | PublicRole::CallbackPromise(_) => {
                 MethodPayableMetadata::AnyToken
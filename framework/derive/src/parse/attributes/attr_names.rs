@@ -2,6 +2,12 @@ pub(super) static ATTR_PAYABLE: &str = "payable";
 pub(super) static ATTR_ONLY_OWNER: &str = "only_owner";
 pub(super) static ATTR_ONLY_ADMIN: &str = "only_admin";
 pub(super) static ATTR_ONLY_USER_ACCOUNT: &str = "only_user_account";
+pub(super) static ATTR_ONLY_CALLBACK_CONTEXT: &str = "only_callback_context";
+pub(super) static ATTR_NOT_FROM_CONTRACT: &str = "not_from_contract";
+pub(super) static ATTR_NON_REENTRANT: &str = "non_reentrant";
+pub(super) static ATTR_ONLY_ROLE: &str = "only_role";
+pub(super) static ATTR_MAX_GAS: &str = "max_gas";
+pub(super) static ATTR_MAX_RETURN_ITEMS: &str = "max_return_items";
 pub(super) static ATTR_OUTPUT_NAME: &str = "output_name";
 pub(super) static ATTR_PAYMENT: &str = "payment"; // synonymous with `payment_amount`
 pub(super) static ATTR_PAYMENT_AMOUNT: &str = "payment_amount";
@@ -9,6 +15,7 @@ pub(super) static ATTR_PAYMENT_TOKEN: &str = "payment_token";
 pub(super) static ATTR_PAYMENT_NONCE: &str = "payment_nonce";
 pub(super) static ATTR_PAYMENT_MULTI: &str = "payment_multi";
 pub(super) static ATTR_INIT: &str = "init";
+pub(super) static ATTR_UPGRADE: &str = "upgrade";
 pub(super) static ATTR_VIEW: &str = "view";
 pub(super) static ATTR_EXTERNAL_VIEW: &str = "external_view";
 pub(super) static ATTR_LEGACY_EVENT: &str = "legacy_event";
@@ -0,0 +1,86 @@
+static ATTR_CODEC: &str = "codec";
+static ATTR_DISCRIMINANT: &str = "discriminant";
+static ATTR_DISCRIMINANT_TYPE: &str = "discriminant_type";
+static ATTR_SKIP: &str = "skip";
+
+/// The `#[codec(...)]` attributes are interpreted by the codec derives.
+/// Only the parts that affect the ABI are read here,
+/// but malformed attributes are rejected, same as in the codec derives.
+fn codec_attr_args(attrs: &[syn::Attribute]) -> Vec<syn::Meta> {
+    let mut result = Vec::new();
+    for attr in attrs {
+        if !attr.path.is_ident(ATTR_CODEC) {
+            continue;
+        }
+
+        match attr.parse_meta() {
+            Ok(syn::Meta::List(meta_list)) => {
+                for nested in meta_list.nested {
+                    match nested {
+                        syn::NestedMeta::Meta(meta) => result.push(meta),
+                        syn::NestedMeta::Lit(_) => {
+                            panic!("unexpected literal in codec attribute")
+                        },
+                    }
+                }
+            },
+            _ => panic!("codec attribute must be of the form #[codec(...)]"),
+        }
+    }
+    result
+}
+
+/// True for enums annotated with `#[codec(discriminant_type = "u16")]`, `u8` is the default.
+pub fn has_u16_discriminants(attrs: &[syn::Attribute]) -> bool {
+    let mut result = false;
+    for meta in codec_attr_args(attrs) {
+        match &meta {
+            syn::Meta::NameValue(name_value)
+                if name_value.path.is_ident(ATTR_DISCRIMINANT_TYPE) =>
+            {
+                result = match &name_value.lit {
+                    syn::Lit::Str(lit_str) if lit_str.value() == "u8" => false,
+                    syn::Lit::Str(lit_str) if lit_str.value() == "u16" => true,
+                    _ => panic!("discriminant_type must be either \"u8\" or \"u16\""),
+                };
+            },
+            _ => panic!("unknown codec attribute on enum"),
+        }
+    }
+    result
+}
+
+/// Same numbering as in the codec derives: declaration order,
+/// unless pinned with `#[codec(discriminant = N)]`.
+pub fn variant_discriminants(data_enum: &syn::DataEnum) -> Vec<usize> {
+    let mut discriminants = Vec::new();
+    let mut next_discriminant = 0usize;
+    for variant in &data_enum.variants {
+        let mut discriminant = next_discriminant;
+        for meta in codec_attr_args(&variant.attrs) {
+            match &meta {
+                syn::Meta::NameValue(name_value) if name_value.path.is_ident(ATTR_DISCRIMINANT) => {
+                    match &name_value.lit {
+                        syn::Lit::Int(lit_int) => {
+                            discriminant = lit_int
+                                .base10_parse::<usize>()
+                                .expect("invalid discriminant value")
+                        },
+                        _ => panic!("discriminant must be an integer literal"),
+                    }
+                },
+                _ => panic!("unknown codec attribute on enum variant"),
+            }
+        }
+        discriminants.push(discriminant);
+        next_discriminant = discriminant + 1;
+    }
+    discriminants
+}
+
+/// Skipped fields are not serialized, so they do not show up in the ABI.
+pub fn is_codec_skipped_field(field: &syn::Field) -> bool {
+    codec_attr_args(&field.attrs)
+        .iter()
+        .any(|meta| matches!(meta, syn::Meta::Path(path) if path.is_ident(ATTR_SKIP)))
+}
@@ -4,6 +4,10 @@ pub fn is_init(attr: &syn::Attribute) -> bool {
     is_attribute_with_no_args(attr, ATTR_INIT)
 }
 
+pub fn is_upgrade(attr: &syn::Attribute) -> bool {
+    is_attribute_with_no_args(attr, ATTR_UPGRADE)
+}
+
 pub fn is_only_owner(attr: &syn::Attribute) -> bool {
     is_attribute_with_no_args(attr, ATTR_ONLY_OWNER)
 }
@@ -16,6 +20,68 @@ pub fn is_only_user_account(attr: &syn::Attribute) -> bool {
     is_attribute_with_no_args(attr, ATTR_ONLY_USER_ACCOUNT)
 }
 
+pub fn is_only_callback_context(attr: &syn::Attribute) -> bool {
+    is_attribute_with_no_args(attr, ATTR_ONLY_CALLBACK_CONTEXT)
+}
+
+pub fn is_not_from_contract(attr: &syn::Attribute) -> bool {
+    is_attribute_with_no_args(attr, ATTR_NOT_FROM_CONTRACT)
+}
+
+pub fn is_non_reentrant(attr: &syn::Attribute) -> bool {
+    is_attribute_with_no_args(attr, ATTR_NON_REENTRANT)
+}
+
+pub struct OnlyRoleAttribute {
+    pub role: String,
+}
+
+impl OnlyRoleAttribute {
+    pub fn parse(attr: &syn::Attribute) -> Option<Self> {
+        is_attr_one_string_arg(attr, ATTR_ONLY_ROLE).map(|role| OnlyRoleAttribute { role })
+    }
+}
+
+/// Parses the integer argument of `#[max_gas(...)]` and `#[max_return_items(...)]`.
+fn attr_one_int_arg<T>(attr: &syn::Attribute, attr_name: &str) -> Option<T>
+where
+    T: core::str::FromStr,
+    T::Err: core::fmt::Display,
+{
+    match is_attr_with_one_opt_token_tree_arg(attr, attr_name)? {
+        Some(proc_macro2::TokenTree::Literal(literal)) => match syn::Lit::new(literal) {
+            syn::Lit::Int(lit_int) => Some(
+                lit_int
+                    .base10_parse::<T>()
+                    .unwrap_or_else(|err| panic!("invalid `{attr_name}` argument: {err}")),
+            ),
+            _ => panic!("`{attr_name}` expects an integer argument"),
+        },
+        _ => panic!("`{attr_name}` expects an integer argument"),
+    }
+}
+
+pub struct MaxGasAttribute {
+    pub max_gas: u64,
+}
+
+impl MaxGasAttribute {
+    pub fn parse(attr: &syn::Attribute) -> Option<Self> {
+        attr_one_int_arg(attr, ATTR_MAX_GAS).map(|max_gas| MaxGasAttribute { max_gas })
+    }
+}
+
+pub struct MaxReturnItemsAttribute {
+    pub max_return_items: usize,
+}
+
+impl MaxReturnItemsAttribute {
+    pub fn parse(attr: &syn::Attribute) -> Option<Self> {
+        attr_one_int_arg(attr, ATTR_MAX_RETURN_ITEMS)
+            .map(|max_return_items| MaxReturnItemsAttribute { max_return_items })
+    }
+}
+
 pub fn is_callback_raw(attr: &syn::Attribute) -> bool {
     is_attribute_with_no_args(attr, ATTR_CALLBACK_RAW_DECL)
 }
@@ -0,0 +1,94 @@
+static ATTR_ERROR: &str = "error";
+static ATTR_ERROR_CODE: &str = "code";
+static ATTR_ERROR_MESSAGE: &str = "message";
+
+/// Contents of `#[error(code = N, message = "...")]` on a `ContractError` variant.
+pub struct ErrorVariantAttribute {
+    pub code: u32,
+    pub message: String,
+}
+
+pub fn error_variant_attribute(variant: &syn::Variant) -> ErrorVariantAttribute {
+    let variant_name = &variant.ident;
+    let mut code = None;
+    let mut message = None;
+    for attr in variant
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident(ATTR_ERROR))
+    {
+        let nested = match attr.parse_meta() {
+            Ok(syn::Meta::List(meta_list)) => meta_list.nested,
+            _ => panic!("`{variant_name}`: expected `#[error(code = N, message = \"...\")]`"),
+        };
+        for nested_meta in nested {
+            let syn::NestedMeta::Meta(syn::Meta::NameValue(name_value)) = nested_meta else {
+                panic!("`{variant_name}`: only `code` and `message` allowed in `#[error(...)]`");
+            };
+            match &name_value.lit {
+                syn::Lit::Int(lit_int) if name_value.path.is_ident(ATTR_ERROR_CODE) => {
+                    code = Some(
+                        lit_int
+                            .base10_parse::<u32>()
+                            .unwrap_or_else(|_| panic!("`{variant_name}`: invalid error code")),
+                    );
+                },
+                syn::Lit::Str(lit_str) if name_value.path.is_ident(ATTR_ERROR_MESSAGE) => {
+                    message = Some(lit_str.value());
+                },
+                _ => {
+                    panic!("`{variant_name}`: only `code` and `message` allowed in `#[error(...)]`")
+                },
+            }
+        }
+    }
+    ErrorVariantAttribute {
+        code: code.unwrap_or_else(|| panic!("`{variant_name}`: missing error code")),
+        message: message.unwrap_or_else(|| panic!("`{variant_name}`: missing error message")),
+    }
+}
+
+/// A message template, split around its `{field}` placeholders.
+///
+/// There is always one more static part than there are placeholders.
+pub struct ErrorMessageTemplate {
+    pub static_parts: Vec<String>,
+    pub placeholders: Vec<String>,
+}
+
+pub fn parse_error_message_template(message: &str) -> ErrorMessageTemplate {
+    let mut static_parts = Vec::new();
+    let mut placeholders = Vec::new();
+    let mut rest = message;
+    while let Some(open_index) = rest.find('{') {
+        let close_index = rest[open_index..]
+            .find('}')
+            .map(|index| open_index + index)
+            .unwrap_or_else(|| panic!("unclosed placeholder in error message `{message}`"));
+        let placeholder = rest[open_index + 1..close_index].trim();
+        assert!(
+            !placeholder.is_empty() && !placeholder.contains('{'),
+            "invalid placeholder in error message `{message}`"
+        );
+        static_parts.push(rest[..open_index].to_string());
+        placeholders.push(placeholder.to_string());
+        rest = &rest[close_index + 1..];
+    }
+    assert!(
+        !rest.contains('}'),
+        "unmatched `}}` in error message `{message}`"
+    );
+    static_parts.push(rest.to_string());
+
+    for (index, static_part) in static_parts.iter().enumerate() {
+        assert!(
+            index == 0 || index == placeholders.len() || !static_part.is_empty(),
+            "placeholders must be separated by some text, otherwise the error cannot be decoded: `{message}`"
+        );
+    }
+
+    ErrorMessageTemplate {
+        static_parts,
+        placeholders,
+    }
+}
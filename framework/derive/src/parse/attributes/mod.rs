@@ -1,7 +1,9 @@
 mod argument_attr;
 mod attr_names;
+mod codec_attr;
 mod doc_attr;
 mod endpoint_attr;
+mod error_attr;
 mod event_attr;
 mod label_attr;
 mod payable_attr;
@@ -11,8 +13,10 @@ mod trait_prop_names;
 mod util;
 
 pub use argument_attr::*;
+pub use codec_attr::*;
 pub use doc_attr::{extract_doc, OutputNameAttribute};
 pub use endpoint_attr::*;
+pub use error_attr::*;
 pub use event_attr::*;
 pub use label_attr::*;
 pub use payable_attr::*;
@@ -7,3 +7,18 @@ pub fn is_only_owner_prop(attr: &syn::Attribute) -> bool {
 pub fn is_only_admin_prop(attr: &syn::Attribute) -> bool {
     is_attribute_with_no_args(attr, PROP_ADMIN_OWNER)
 }
+
+/// Parses `#[errors(MyError, OtherError<Self::Api>)]`.
+pub fn errors_prop(attr: &syn::Attribute) -> Option<Vec<syn::Type>> {
+    if !attr.path.is_ident(PROP_ERRORS) {
+        return None;
+    }
+    let error_types = attr
+        .parse_args_with(syn::punctuated::Punctuated::<syn::Type, syn::Token![,]>::parse_terminated)
+        .expect("`errors` attribute expects a list of error types");
+    assert!(
+        !error_types.is_empty(),
+        "`errors` attribute expects at least one error type"
+    );
+    Some(error_types.into_iter().collect())
+}
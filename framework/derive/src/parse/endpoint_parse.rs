@@ -5,16 +5,27 @@ use crate::model::{
 
 use super::{
     attributes::{
-        is_callback_raw, is_init, is_only_admin, is_only_owner, is_only_user_account,
+        is_callback_raw, is_init, is_non_reentrant, is_not_from_contract, is_only_admin,
+        is_only_callback_context, is_only_owner, is_only_user_account, is_upgrade,
         CallbackAttribute, EndpointAttribute, ExternalViewAttribute, LabelAttribute,
-        OutputNameAttribute, PromisesCallbackAttribute, ViewAttribute,
+        MaxGasAttribute, MaxReturnItemsAttribute, OnlyRoleAttribute, OutputNameAttribute,
+        PromisesCallbackAttribute, ViewAttribute,
     },
     MethodAttributesPass1,
 };
 
+/// The lock is written to storage, which views are not meant to do.
+fn check_view_not_non_reentrant(pass_1_data: &MethodAttributesPass1) {
+    assert!(
+        !pass_1_data.non_reentrant,
+        "`#[non_reentrant]` cannot be used on views. Method '{}' is a view.",
+        pass_1_data.method_name
+    );
+}
+
 fn check_single_role(method: &Method) {
     assert!(matches!(method.public_role, PublicRole::Private),
-		"Can only annotate with one of the following arguments: `#[init]`, `#[endpoint]`, `#[view]`, `#[callback]`, `#[callback_raw]`."
+		"Can only annotate with one of the following arguments: `#[init]`, `#[upgrade]`, `#[endpoint]`, `#[view]`, `#[callback]`, `#[callback_raw]`."
 	);
 }
 
@@ -34,6 +45,22 @@ pub fn process_init_attribute(
     }
 }
 
+pub fn process_upgrade_attribute(
+    attr: &syn::Attribute,
+    pass_1_data: &MethodAttributesPass1,
+    method: &mut Method,
+) -> bool {
+    if is_upgrade(attr) {
+        check_single_role(&*method);
+        method.public_role = PublicRole::Upgrade(InitMetadata {
+            payable: pass_1_data.payable.clone(),
+        });
+        true
+    } else {
+        false
+    }
+}
+
 pub fn process_only_owner_attribute(
     attr: &syn::Attribute,
     pass_1_data: &mut MethodAttributesPass1,
@@ -67,6 +94,87 @@ pub fn process_only_user_account_attribute(
     is_only_user_account
 }
 
+pub fn process_only_callback_context_attribute(
+    attr: &syn::Attribute,
+    pass_1_data: &mut MethodAttributesPass1,
+) -> bool {
+    let is_only_callback_context = is_only_callback_context(attr);
+    if is_only_callback_context {
+        pass_1_data.only_callback_context = true;
+    }
+    is_only_callback_context
+}
+
+pub fn process_not_from_contract_attribute(
+    attr: &syn::Attribute,
+    pass_1_data: &mut MethodAttributesPass1,
+) -> bool {
+    let is_not_from_contract = is_not_from_contract(attr);
+    if is_not_from_contract {
+        pass_1_data.not_from_contract = true;
+    }
+    is_not_from_contract
+}
+
+pub fn process_non_reentrant_attribute(
+    attr: &syn::Attribute,
+    pass_1_data: &mut MethodAttributesPass1,
+) -> bool {
+    let is_non_reentrant = is_non_reentrant(attr);
+    if is_non_reentrant {
+        pass_1_data.non_reentrant = true;
+    }
+    is_non_reentrant
+}
+
+pub fn process_only_role_attribute(
+    attr: &syn::Attribute,
+    pass_1_data: &mut MethodAttributesPass1,
+) -> bool {
+    OnlyRoleAttribute::parse(attr)
+        .map(|only_role_attr| {
+            assert!(
+                pass_1_data.only_role.is_none(),
+                "Only one `#[only_role]` annotation allowed per endpoint. Method '{}' has more.",
+                pass_1_data.method_name
+            );
+            pass_1_data.only_role = Some(only_role_attr.role);
+        })
+        .is_some()
+}
+
+pub fn process_max_gas_attribute(
+    attr: &syn::Attribute,
+    pass_1_data: &mut MethodAttributesPass1,
+) -> bool {
+    MaxGasAttribute::parse(attr)
+        .map(|max_gas_attr| {
+            assert!(
+                pass_1_data.max_gas.is_none(),
+                "Only one `#[max_gas]` annotation allowed per endpoint. Method '{}' has more.",
+                pass_1_data.method_name
+            );
+            pass_1_data.max_gas = Some(max_gas_attr.max_gas);
+        })
+        .is_some()
+}
+
+pub fn process_max_return_items_attribute(
+    attr: &syn::Attribute,
+    pass_1_data: &mut MethodAttributesPass1,
+) -> bool {
+    MaxReturnItemsAttribute::parse(attr)
+        .map(|max_return_items_attr| {
+            assert!(
+                pass_1_data.max_return_items.is_none(),
+                "Only one `#[max_return_items]` annotation allowed per endpoint. Method '{}' has more.",
+                pass_1_data.method_name
+            );
+            pass_1_data.max_return_items = Some(max_return_items_attr.max_return_items);
+        })
+        .is_some()
+}
+
 pub fn process_endpoint_attribute(
     attr: &syn::Attribute,
     pass_1_data: &MethodAttributesPass1,
@@ -85,6 +193,12 @@ pub fn process_endpoint_attribute(
                 only_owner: pass_1_data.only_owner,
                 only_admin: pass_1_data.only_admin,
                 only_user_account: pass_1_data.only_user_account,
+                only_callback_context: pass_1_data.only_callback_context,
+                not_from_contract: pass_1_data.not_from_contract,
+                non_reentrant: pass_1_data.non_reentrant,
+                only_role: pass_1_data.only_role.clone(),
+                max_gas: pass_1_data.max_gas,
+                max_return_items: pass_1_data.max_return_items,
                 mutability: EndpointMutabilityMetadata::Mutable,
             });
         })
@@ -99,6 +213,7 @@ pub fn process_view_attribute(
     ViewAttribute::parse(attr)
         .map(|view_attribute| {
             check_single_role(&*method);
+            check_view_not_non_reentrant(pass_1_data);
             let view_ident = match view_attribute.view_name {
                 Some(ident) => ident,
                 None => method.name.clone(),
@@ -109,6 +224,12 @@ pub fn process_view_attribute(
                 only_owner: pass_1_data.only_owner,
                 only_admin: pass_1_data.only_admin,
                 only_user_account: pass_1_data.only_user_account,
+                only_callback_context: pass_1_data.only_callback_context,
+                not_from_contract: pass_1_data.not_from_contract,
+                non_reentrant: pass_1_data.non_reentrant,
+                only_role: pass_1_data.only_role.clone(),
+                max_gas: pass_1_data.max_gas,
+                max_return_items: pass_1_data.max_return_items,
                 mutability: EndpointMutabilityMetadata::Readonly,
             });
         })
@@ -123,6 +244,7 @@ pub fn process_external_view_attribute(
     ExternalViewAttribute::parse(attr)
         .map(|external_view_attribute| {
             check_single_role(&*method);
+            check_view_not_non_reentrant(pass_1_data);
             let view_ident = match external_view_attribute.view_name {
                 Some(ident) => ident,
                 None => method.name.clone(),
@@ -133,6 +255,12 @@ pub fn process_external_view_attribute(
                 only_owner: pass_1_data.only_owner,
                 only_admin: pass_1_data.only_admin,
                 only_user_account: pass_1_data.only_user_account,
+                only_callback_context: pass_1_data.only_callback_context,
+                not_from_contract: pass_1_data.not_from_contract,
+                non_reentrant: pass_1_data.non_reentrant,
+                only_role: pass_1_data.only_role.clone(),
+                max_gas: pass_1_data.max_gas,
+                max_return_items: pass_1_data.max_return_items,
                 mutability: EndpointMutabilityMetadata::Readonly,
             });
         })
@@ -10,9 +10,12 @@ use super::{
     },
     extract_method_args, process_callback_attribute, process_callback_raw_attribute,
     process_endpoint_attribute, process_external_view_attribute, process_init_attribute,
-    process_label_names_attribute, process_only_admin_attribute, process_only_owner_attribute,
-    process_only_user_account_attribute, process_output_names_attribute, process_payable_attribute,
-    process_promises_callback_attribute, process_view_attribute,
+    process_label_names_attribute, process_max_gas_attribute, process_max_return_items_attribute,
+    process_non_reentrant_attribute, process_not_from_contract_attribute,
+    process_only_admin_attribute, process_only_callback_context_attribute,
+    process_only_owner_attribute, process_only_role_attribute, process_only_user_account_attribute,
+    process_output_names_attribute, process_payable_attribute, process_promises_callback_attribute,
+    process_upgrade_attribute, process_view_attribute,
 };
 pub struct MethodAttributesPass1 {
     pub method_name: String,
@@ -20,6 +23,12 @@ pub struct MethodAttributesPass1 {
     pub only_owner: bool,
     pub only_admin: bool,
     pub only_user_account: bool,
+    pub only_callback_context: bool,
+    pub not_from_contract: bool,
+    pub non_reentrant: bool,
+    pub only_role: Option<String>,
+    pub max_gas: Option<u64>,
+    pub max_return_items: Option<usize>,
 }
 
 pub fn process_method(m: &syn::TraitItemMethod, trait_attributes: &TraitProperties) -> Method {
@@ -37,6 +46,12 @@ pub fn process_method(m: &syn::TraitItemMethod, trait_attributes: &TraitProperti
         only_owner: trait_attributes.only_owner,
         only_admin: trait_attributes.only_admin,
         only_user_account: trait_attributes.only_user_account,
+        only_callback_context: false,
+        not_from_contract: false,
+        non_reentrant: false,
+        only_role: None,
+        max_gas: None,
+        max_return_items: None,
     };
     let mut first_pass_unprocessed_attributes = Vec::new();
 
@@ -91,6 +106,12 @@ fn process_attribute_first_pass(
         || process_only_owner_attribute(attr, first_pass_data)
         || process_only_admin_attribute(attr, first_pass_data)
         || process_only_user_account_attribute(attr, first_pass_data)
+        || process_only_callback_context_attribute(attr, first_pass_data)
+        || process_not_from_contract_attribute(attr, first_pass_data)
+        || process_non_reentrant_attribute(attr, first_pass_data)
+        || process_only_role_attribute(attr, first_pass_data)
+        || process_max_gas_attribute(attr, first_pass_data)
+        || process_max_return_items_attribute(attr, first_pass_data)
 }
 
 fn process_attributes_second_pass(
@@ -112,6 +133,7 @@ fn process_attribute_second_pass(
     method: &mut Method,
 ) -> bool {
     process_init_attribute(attr, first_pass_data, method)
+        || process_upgrade_attribute(attr, first_pass_data, method)
         || process_endpoint_attribute(attr, first_pass_data, method)
         || process_view_attribute(attr, first_pass_data, method)
         || process_external_view_attribute(attr, first_pass_data, method)
@@ -134,9 +156,9 @@ fn validate_method(method: &Method) {
     assert!(
         matches!(
             method.public_role,
-            PublicRole::Init(_) | PublicRole::Endpoint(_)
+            PublicRole::Init(_) | PublicRole::Upgrade(_) | PublicRole::Endpoint(_)
         ) || method.label_names.is_empty(),
-        "Labels can only be placed on endpoints and constructors. Method '{}' is neither.",
+        "Labels can only be placed on endpoints, constructors and upgrades. Method '{}' is none of these.",
         &method.name.to_string()
     )
 }
@@ -1,4 +1,6 @@
-use super::parse::attributes::extract_doc;
+use super::parse::attributes::{
+    extract_doc, has_u16_discriminants, is_codec_skipped_field, variant_discriminants,
+};
 use proc_macro::TokenStream;
 use quote::quote;
 
@@ -26,12 +28,14 @@ fn fields_snippets(fields: &syn::Fields) -> Vec<proc_macro2::TokenStream> {
             .named
             .iter()
             .enumerate()
+            .filter(|(_, field)| !is_codec_skipped_field(field))
             .map(|(index, field)| field_snippet(index, field))
             .collect(),
         syn::Fields::Unnamed(fields_unnamed) => fields_unnamed
             .unnamed
             .iter()
             .enumerate()
+            .filter(|(_, field)| !is_codec_skipped_field(field))
             .map(|(index, field)| field_snippet(index, field))
             .collect(),
         syn::Fields::Unit => Vec::new(),
@@ -66,8 +70,8 @@ pub fn type_abi_derive(ast: &syn::DeriveInput) -> TokenStream {
             let enum_variant_snippets: Vec<proc_macro2::TokenStream> = data_enum
                 .variants
                 .iter()
-                .enumerate()
-                .map(|(variant_index, variant)| {
+                .zip(variant_discriminants(data_enum))
+                .map(|(variant, discriminant)| {
                     let variant_docs = extract_doc(variant.attrs.as_slice());
                     let variant_name_str = variant.ident.to_string();
                     let variant_field_snippets = fields_snippets(&variant.fields);
@@ -76,13 +80,18 @@ pub fn type_abi_derive(ast: &syn::DeriveInput) -> TokenStream {
                         #(#variant_field_snippets)*
                         variant_descriptions.push(multiversx_sc::abi::EnumVariantDescription {
                             docs: &[ #(#variant_docs),* ],
-                            discriminant: #variant_index,
+                            discriminant: #discriminant,
                             name: #variant_name_str,
                             fields: field_descriptions,
                         });
                     }
                 })
                 .collect();
+            let discriminant_type = if has_u16_discriminants(&ast.attrs) {
+                quote! { multiversx_sc::abi::EnumDiscriminantType::U16 }
+            } else {
+                quote! { multiversx_sc::abi::EnumDiscriminantType::U8 }
+            };
             quote! {
                 fn provide_type_descriptions<TDC: multiversx_sc::abi::TypeDescriptionContainer>(accumulator: &mut TDC) {
                     let type_name = Self::type_name();
@@ -95,7 +104,10 @@ pub fn type_abi_derive(ast: &syn::DeriveInput) -> TokenStream {
                             multiversx_sc::abi::TypeDescription {
                                 docs: &[ #(#type_docs),* ],
                                 name: type_name,
-                                contents: multiversx_sc::abi::TypeContents::Enum(variant_descriptions),
+                                contents: multiversx_sc::abi::TypeContents::Enum {
+                                    discriminant_type: #discriminant_type,
+                                    variants: variant_descriptions,
+                                },
                             },
                         );
                     }
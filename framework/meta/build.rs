@@ -0,0 +1,73 @@
+//! Embeds the contract templates, so that `sc-meta new` works without a checkout of the framework repository.
+
+use std::{
+    env,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+#[allow(dead_code)]
+pub struct ContractTemplate {
+    name: &'static str,
+    path: &'static str,
+}
+
+include!("src/template/template_list.rs");
+
+/// Build outputs can get large, and they never end up in a new contract anyway.
+const SKIPPED_DIR_NAMES: &[&str] = &["target", "output"];
+
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let mut paths: Vec<PathBuf> = fs::read_dir(dir)
+        .expect("error reading template directory")
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    paths.sort();
+    for path in paths {
+        if !path.is_dir() {
+            files.push(path);
+            continue;
+        }
+        let dir_name = path.file_name().unwrap().to_string_lossy();
+        if !SKIPPED_DIR_NAMES.contains(&dir_name.as_ref()) {
+            collect_files(&path, files);
+        }
+    }
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/template/template_list.rs");
+
+    let repo_path = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("../..");
+    let mut code = String::from("pub static EMBEDDED_TEMPLATE_FILES: &[(&str, &[u8])] = &[\n");
+    for template in CONTRACT_TEMPLATES {
+        let template_dir = repo_path.join(template.path);
+        // not there when building the published crate, `sc-meta new` then needs `--repo-path`
+        if !template_dir.is_dir() {
+            continue;
+        }
+        println!("cargo:rerun-if-changed={}", template_dir.display());
+
+        let mut files = Vec::new();
+        collect_files(&template_dir, &mut files);
+        for file in files {
+            let relative_path = file
+                .strip_prefix(&repo_path)
+                .unwrap()
+                .to_string_lossy()
+                .replace('\\', "/");
+            let absolute_path = file.canonicalize().unwrap();
+            writeln!(
+                code,
+                "    ({relative_path:?}, include_bytes!({absolute_path:?})),"
+            )
+            .unwrap();
+        }
+    }
+    code.push_str("];\n");
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("embedded_templates.rs");
+    fs::write(out_path, code).expect("failed to write the embedded templates");
+}
@@ -0,0 +1,712 @@
+use crate::{
+    abi_json::{
+        read_abi_json, split_type_name, ConstructorAbiJson, ContractAbiJson, EndpointAbiJson,
+        EndpointMutabilityAbiJson, ErrorAbiJson, EventAbiJson, InputAbiJson, OutputAbiJson,
+    },
+    cli_args::AbiDiffArgs,
+    storage_diff::{StorageChangeSeverity, TypeComparator},
+};
+use std::collections::{BTreeMap, BTreeSet};
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum AbiChangeSeverity {
+    /// Changes that existing callers, indexers and deploy scripts can ignore.
+    NonBreaking,
+
+    /// Changes that make existing calls fail, or their results and events undecodable.
+    Breaking,
+}
+
+impl AbiChangeSeverity {
+    fn label(&self) -> &'static str {
+        match self {
+            AbiChangeSeverity::NonBreaking => "non-breaking",
+            AbiChangeSeverity::Breaking => "BREAKING",
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct AbiChange {
+    pub severity: AbiChangeSeverity,
+
+    /// The changed ABI item, e.g. `endpoint "add"`, `event "transfer"` or `type "MyStruct"`.
+    pub item: String,
+
+    pub message: String,
+}
+
+#[derive(Default, Debug)]
+pub struct AbiDiffReport {
+    pub changes: Vec<AbiChange>,
+}
+
+impl AbiDiffReport {
+    pub fn has_breaking_changes(&self) -> bool {
+        self.changes
+            .iter()
+            .any(|change| change.severity == AbiChangeSeverity::Breaking)
+    }
+
+    pub fn print(&self) {
+        if self.changes.is_empty() {
+            println!("No ABI changes.");
+            return;
+        }
+
+        for change in &self.changes {
+            println!(
+                "[{}] {}: {}",
+                change.severity.label(),
+                change.item,
+                change.message
+            );
+        }
+    }
+
+    fn add(&mut self, severity: AbiChangeSeverity, item: &str, message: String) {
+        self.changes.push(AbiChange {
+            severity,
+            item: item.to_string(),
+            message,
+        });
+    }
+}
+
+pub fn abi_diff(args: &AbiDiffArgs) {
+    let old_abi = read_abi_json(&args.old);
+    let new_abi = read_abi_json(&args.new);
+
+    let report = compare_abi(&old_abi, &new_abi);
+    report.print();
+
+    if args.fail_on_breaking && report.has_breaking_changes() {
+        println!("Breaking ABI changes found.");
+        std::process::exit(1);
+    }
+}
+
+/// Compares the public interface of two versions of a contract: constructors, endpoints, events, errors and types.
+///
+/// Argument, result and event types are compared by name,
+/// changes inside the described types are reported once per type.
+pub fn compare_abi(old_abi: &ContractAbiJson, new_abi: &ContractAbiJson) -> AbiDiffReport {
+    let mut report = AbiDiffReport::default();
+
+    compare_constructors(
+        &mut report,
+        "constructor",
+        &old_abi.constructor,
+        &new_abi.constructor,
+        AbiChangeSeverity::Breaking,
+    );
+    compare_constructors(
+        &mut report,
+        "upgrade constructor",
+        &old_abi.upgrade_constructor,
+        &new_abi.upgrade_constructor,
+        AbiChangeSeverity::NonBreaking,
+    );
+    compare_endpoints(&mut report, old_abi, new_abi);
+    compare_events(&mut report, old_abi, new_abi);
+    compare_errors(&mut report, old_abi, new_abi);
+    compare_types(&mut report, old_abi, new_abi);
+
+    report
+}
+
+/// Deploy and upgrade scripts break when the constructor they call disappears.
+/// A new upgrade constructor only gets called by the upgrades that come after it, so adding it breaks nothing.
+fn compare_constructors(
+    report: &mut AbiDiffReport,
+    item: &str,
+    old_constructor: &Option<ConstructorAbiJson>,
+    new_constructor: &Option<ConstructorAbiJson>,
+    added_severity: AbiChangeSeverity,
+) {
+    match (old_constructor, new_constructor) {
+        (Some(old_constructor), Some(new_constructor)) => {
+            compare_inputs(
+                report,
+                item,
+                &old_constructor.inputs,
+                &new_constructor.inputs,
+            );
+            compare_payable(
+                report,
+                item,
+                &old_constructor.payable_in_tokens,
+                &new_constructor.payable_in_tokens,
+            );
+        },
+        (Some(_), None) => report.add(AbiChangeSeverity::Breaking, item, "removed".to_string()),
+        (None, Some(_)) => report.add(added_severity, item, "added".to_string()),
+        (None, None) => {},
+    }
+}
+
+fn endpoints_by_name(abi: &ContractAbiJson) -> BTreeMap<&str, &EndpointAbiJson> {
+    abi.endpoints
+        .iter()
+        .map(|endpoint| (endpoint.name.as_str(), endpoint))
+        .collect()
+}
+
+fn same_signature(endpoint: &EndpointAbiJson, other: &EndpointAbiJson) -> bool {
+    let input_types = |endpoint: &EndpointAbiJson| -> Vec<String> {
+        endpoint
+            .inputs
+            .iter()
+            .map(|input| input.type_name.clone())
+            .collect()
+    };
+    let output_types = |endpoint: &EndpointAbiJson| -> Vec<String> {
+        endpoint
+            .outputs
+            .iter()
+            .map(|output| output.type_name.clone())
+            .collect()
+    };
+    input_types(endpoint) == input_types(other) && output_types(endpoint) == output_types(other)
+}
+
+fn compare_endpoints(
+    report: &mut AbiDiffReport,
+    old_abi: &ContractAbiJson,
+    new_abi: &ContractAbiJson,
+) {
+    let old_endpoints = endpoints_by_name(old_abi);
+    let new_endpoints = endpoints_by_name(new_abi);
+    let added: Vec<&EndpointAbiJson> = new_endpoints
+        .iter()
+        .filter(|(name, _)| !old_endpoints.contains_key(*name))
+        .map(|(_, endpoint)| *endpoint)
+        .collect();
+
+    for (name, old_endpoint) in &old_endpoints {
+        let item = format!("endpoint \"{name}\"");
+        let Some(new_endpoint) = new_endpoints.get(name) else {
+            // an added endpoint with the same signature is most likely the same one, renamed
+            let message = match added
+                .iter()
+                .find(|new_endpoint| same_signature(old_endpoint, new_endpoint))
+            {
+                Some(renamed) => format!("removed, possibly renamed to \"{}\"", renamed.name),
+                None => "removed".to_string(),
+            };
+            report.add(AbiChangeSeverity::Breaking, &item, message);
+            continue;
+        };
+
+        compare_inputs(report, &item, &old_endpoint.inputs, &new_endpoint.inputs);
+        compare_outputs(report, &item, &old_endpoint.outputs, &new_endpoint.outputs);
+        compare_mutability(
+            report,
+            &item,
+            &old_endpoint.mutability,
+            &new_endpoint.mutability,
+        );
+        compare_payable(
+            report,
+            &item,
+            &old_endpoint.payable_in_tokens,
+            &new_endpoint.payable_in_tokens,
+        );
+        compare_access(report, &item, old_endpoint, new_endpoint);
+    }
+
+    for new_endpoint in added {
+        report.add(
+            AbiChangeSeverity::NonBreaking,
+            &format!("endpoint \"{}\"", new_endpoint.name),
+            "added".to_string(),
+        );
+    }
+}
+
+/// Trailing optional and variadic arguments can be added, existing callers simply do not provide them.
+fn can_be_omitted(type_name: &str) -> bool {
+    type_name.starts_with("optional<") || type_name.starts_with("variadic<")
+}
+
+fn compare_inputs(
+    report: &mut AbiDiffReport,
+    item: &str,
+    old_inputs: &[InputAbiJson],
+    new_inputs: &[InputAbiJson],
+) {
+    for (index, old_input) in old_inputs.iter().enumerate() {
+        let Some(new_input) = new_inputs.get(index) else {
+            report.add(
+                AbiChangeSeverity::Breaking,
+                item,
+                format!("argument #{index} ({}) removed", old_input.arg_name),
+            );
+            continue;
+        };
+
+        if old_input.type_name != new_input.type_name {
+            report.add(
+                AbiChangeSeverity::Breaking,
+                item,
+                format!(
+                    "argument #{index} ({}) type changed from {} to {}",
+                    old_input.arg_name, old_input.type_name, new_input.type_name
+                ),
+            );
+        } else if old_input.arg_name != new_input.arg_name {
+            report.add(
+                AbiChangeSeverity::NonBreaking,
+                item,
+                format!(
+                    "argument #{index} renamed from {} to {}",
+                    old_input.arg_name, new_input.arg_name
+                ),
+            );
+        }
+    }
+
+    for (index, new_input) in new_inputs.iter().enumerate().skip(old_inputs.len()) {
+        let severity = if can_be_omitted(&new_input.type_name) {
+            AbiChangeSeverity::NonBreaking
+        } else {
+            AbiChangeSeverity::Breaking
+        };
+        report.add(
+            severity,
+            item,
+            format!(
+                "argument #{index} ({}: {}) added",
+                new_input.arg_name, new_input.type_name
+            ),
+        );
+    }
+}
+
+fn compare_outputs(
+    report: &mut AbiDiffReport,
+    item: &str,
+    old_outputs: &[OutputAbiJson],
+    new_outputs: &[OutputAbiJson],
+) {
+    let old_types: Vec<&str> = old_outputs
+        .iter()
+        .map(|output| output.type_name.as_str())
+        .collect();
+    let new_types: Vec<&str> = new_outputs
+        .iter()
+        .map(|output| output.type_name.as_str())
+        .collect();
+    if old_types.len() != new_types.len() {
+        report.add(
+            AbiChangeSeverity::Breaking,
+            item,
+            format!(
+                "results changed from ({}) to ({})",
+                old_types.join(", "),
+                new_types.join(", ")
+            ),
+        );
+        return;
+    }
+
+    for (index, (old_type, new_type)) in old_types.iter().zip(new_types.iter()).enumerate() {
+        if old_type != new_type {
+            report.add(
+                AbiChangeSeverity::Breaking,
+                item,
+                format!("result #{index} type changed from {old_type} to {new_type}"),
+            );
+        }
+    }
+}
+
+fn is_readonly(mutability: &EndpointMutabilityAbiJson) -> bool {
+    !matches!(mutability, EndpointMutabilityAbiJson::Mutable)
+}
+
+/// Views can still be called in transactions, but mutable endpoints can no longer be queried.
+fn compare_mutability(
+    report: &mut AbiDiffReport,
+    item: &str,
+    old_mutability: &EndpointMutabilityAbiJson,
+    new_mutability: &EndpointMutabilityAbiJson,
+) {
+    match (is_readonly(old_mutability), is_readonly(new_mutability)) {
+        (true, false) => report.add(
+            AbiChangeSeverity::Breaking,
+            item,
+            "changed from readonly to mutable".to_string(),
+        ),
+        (false, true) => report.add(
+            AbiChangeSeverity::NonBreaking,
+            item,
+            "changed from mutable to readonly".to_string(),
+        ),
+        _ => {},
+    }
+}
+
+fn token_list(tokens: &[String]) -> String {
+    if tokens.is_empty() {
+        "not payable".to_string()
+    } else {
+        format!("payable in {}", tokens.join(", "))
+    }
+}
+
+/// Payments that used to be accepted need to still be accepted.
+fn compare_payable(
+    report: &mut AbiDiffReport,
+    item: &str,
+    old_tokens: &[String],
+    new_tokens: &[String],
+) {
+    let old_set: BTreeSet<&String> = old_tokens.iter().collect();
+    let new_set: BTreeSet<&String> = new_tokens.iter().collect();
+    if old_set == new_set {
+        return;
+    }
+
+    let accepts_all_old_tokens = new_tokens.iter().any(|token| token == "*")
+        || old_tokens.iter().all(|token| new_set.contains(token));
+    let severity = if accepts_all_old_tokens {
+        AbiChangeSeverity::NonBreaking
+    } else {
+        AbiChangeSeverity::Breaking
+    };
+    report.add(
+        severity,
+        item,
+        format!(
+            "changed from {} to {}",
+            token_list(old_tokens),
+            token_list(new_tokens)
+        ),
+    );
+}
+
+fn compare_access(
+    report: &mut AbiDiffReport,
+    item: &str,
+    old_endpoint: &EndpointAbiJson,
+    new_endpoint: &EndpointAbiJson,
+) {
+    let restrictions = [
+        (
+            "the owner",
+            old_endpoint.only_owner.unwrap_or(false),
+            new_endpoint.only_owner.unwrap_or(false),
+        ),
+        (
+            "admins",
+            old_endpoint.only_admin.unwrap_or(false),
+            new_endpoint.only_admin.unwrap_or(false),
+        ),
+        (
+            "calls made from callbacks",
+            old_endpoint.only_callback_context.unwrap_or(false),
+            new_endpoint.only_callback_context.unwrap_or(false),
+        ),
+        (
+            "user accounts",
+            old_endpoint.not_from_contract.unwrap_or(false),
+            new_endpoint.not_from_contract.unwrap_or(false),
+        ),
+        (
+            "non-reentrant calls",
+            old_endpoint.non_reentrant.unwrap_or(false),
+            new_endpoint.non_reentrant.unwrap_or(false),
+        ),
+    ];
+    for (callers, old_restricted, new_restricted) in restrictions {
+        match (old_restricted, new_restricted) {
+            (false, true) => report.add(
+                AbiChangeSeverity::Breaking,
+                item,
+                format!("now restricted to {callers}"),
+            ),
+            (true, false) => report.add(
+                AbiChangeSeverity::NonBreaking,
+                item,
+                format!("no longer restricted to {callers}"),
+            ),
+            _ => {},
+        }
+    }
+
+    match (&old_endpoint.only_role, &new_endpoint.only_role) {
+        (old_role, Some(new_role)) if old_role.as_ref() != Some(new_role) => report.add(
+            AbiChangeSeverity::Breaking,
+            item,
+            format!("now restricted to role {new_role}"),
+        ),
+        (Some(old_role), None) => report.add(
+            AbiChangeSeverity::NonBreaking,
+            item,
+            format!("no longer restricted to role {old_role}"),
+        ),
+        _ => {},
+    }
+}
+
+fn events_by_identifier(abi: &ContractAbiJson) -> BTreeMap<&str, &EventAbiJson> {
+    abi.events
+        .iter()
+        .map(|event| (event.identifier.as_str(), event))
+        .collect()
+}
+
+/// Indexers decode events by their identifier and their topics, in order.
+fn compare_events(
+    report: &mut AbiDiffReport,
+    old_abi: &ContractAbiJson,
+    new_abi: &ContractAbiJson,
+) {
+    let old_events = events_by_identifier(old_abi);
+    let new_events = events_by_identifier(new_abi);
+
+    for (identifier, old_event) in &old_events {
+        let item = format!("event \"{identifier}\"");
+        let Some(new_event) = new_events.get(identifier) else {
+            report.add(AbiChangeSeverity::Breaking, &item, "removed".to_string());
+            continue;
+        };
+
+        if old_event.inputs.len() != new_event.inputs.len() {
+            report.add(
+                AbiChangeSeverity::Breaking,
+                &item,
+                format!(
+                    "had {} arguments, now has {}",
+                    old_event.inputs.len(),
+                    new_event.inputs.len()
+                ),
+            );
+        }
+
+        for (index, (old_input, new_input)) in old_event
+            .inputs
+            .iter()
+            .zip(new_event.inputs.iter())
+            .enumerate()
+        {
+            if old_input.type_name != new_input.type_name {
+                report.add(
+                    AbiChangeSeverity::Breaking,
+                    &item,
+                    format!(
+                        "argument #{index} ({}) type changed from {} to {}",
+                        old_input.arg_name, old_input.type_name, new_input.type_name
+                    ),
+                );
+            }
+            if old_input.indexed.unwrap_or(false) != new_input.indexed.unwrap_or(false) {
+                report.add(
+                    AbiChangeSeverity::Breaking,
+                    &item,
+                    format!(
+                        "argument #{index} ({}) moved between the topics and the data",
+                        old_input.arg_name
+                    ),
+                );
+            }
+            if old_input.arg_name != new_input.arg_name {
+                report.add(
+                    AbiChangeSeverity::NonBreaking,
+                    &item,
+                    format!(
+                        "argument #{index} renamed from {} to {}",
+                        old_input.arg_name, new_input.arg_name
+                    ),
+                );
+            }
+        }
+    }
+
+    for identifier in new_events.keys() {
+        if !old_events.contains_key(identifier) {
+            report.add(
+                AbiChangeSeverity::NonBreaking,
+                &format!("event \"{identifier}\""),
+                "added".to_string(),
+            );
+        }
+    }
+}
+
+fn errors_by_code(abi: &ContractAbiJson) -> BTreeMap<u32, &ErrorAbiJson> {
+    abi.errors.iter().map(|error| (error.code, error)).collect()
+}
+
+/// Off-chain tools decode errors by their code and message template.
+fn compare_errors(
+    report: &mut AbiDiffReport,
+    old_abi: &ContractAbiJson,
+    new_abi: &ContractAbiJson,
+) {
+    let old_errors = errors_by_code(old_abi);
+    let new_errors = errors_by_code(new_abi);
+
+    for (code, old_error) in &old_errors {
+        let item = format!("error {code} \"{}\"", old_error.name);
+        let Some(new_error) = new_errors.get(code) else {
+            report.add(AbiChangeSeverity::Breaking, &item, "removed".to_string());
+            continue;
+        };
+
+        if old_error.message != new_error.message {
+            report.add(
+                AbiChangeSeverity::Breaking,
+                &item,
+                format!(
+                    "message changed from \"{}\" to \"{}\"",
+                    old_error.message, new_error.message
+                ),
+            );
+        }
+        for old_field in &old_error.fields {
+            if let Some(new_field) = new_error
+                .fields
+                .iter()
+                .find(|new_field| new_field.name == old_field.name)
+            {
+                if old_field.type_name != new_field.type_name {
+                    report.add(
+                        AbiChangeSeverity::Breaking,
+                        &item,
+                        format!(
+                            "field {} type changed from {} to {}",
+                            old_field.name, old_field.type_name, new_field.type_name
+                        ),
+                    );
+                }
+            }
+        }
+        if old_error.name != new_error.name {
+            report.add(
+                AbiChangeSeverity::NonBreaking,
+                &item,
+                format!("renamed to {}", new_error.name),
+            );
+        }
+    }
+
+    for (code, new_error) in &new_errors {
+        if !old_errors.contains_key(code) {
+            report.add(
+                AbiChangeSeverity::NonBreaking,
+                &format!("error {code} \"{}\"", new_error.name),
+                "added".to_string(),
+            );
+        }
+    }
+}
+
+/// Each type is compared on its own, the types of its fields by name only,
+/// since they are compared separately.
+/// Types that are no longer described were removed from the interface,
+/// which only breaks it if an endpoint, event or storage entry used them.
+fn compare_types(report: &mut AbiDiffReport, old_abi: &ContractAbiJson, new_abi: &ContractAbiJson) {
+    let type_comparator = TypeComparator {
+        old_types: &old_abi.types,
+        new_types: &new_abi.types,
+    };
+    let mut visited: BTreeSet<String> = old_abi.types.keys().cloned().collect();
+    let referenced = referenced_type_names(old_abi);
+
+    for (type_name, old_description) in &old_abi.types {
+        let item = format!("type \"{type_name}\"");
+        let Some(new_description) = new_abi.types.get(type_name) else {
+            let severity = if referenced.contains(type_name.as_str()) {
+                AbiChangeSeverity::Breaking
+            } else {
+                AbiChangeSeverity::NonBreaking
+            };
+            report.add(severity, &item, "removed".to_string());
+            continue;
+        };
+        for (severity, message) in type_comparator.compare_descriptions(
+            type_name,
+            old_description,
+            new_description,
+            &mut visited,
+        ) {
+            let severity = match severity {
+                StorageChangeSeverity::Incompatible => AbiChangeSeverity::Breaking,
+                _ => AbiChangeSeverity::NonBreaking,
+            };
+            report.add(severity, &item, message);
+        }
+    }
+}
+
+/// All type names used by the endpoints, events and storage entries,
+/// including the arguments of generic types and the field types of the described types.
+fn referenced_type_names(abi: &ContractAbiJson) -> BTreeSet<&str> {
+    let constructor_inputs = abi
+        .constructor
+        .iter()
+        .chain(abi.upgrade_constructor.iter())
+        .flat_map(|constructor| constructor.inputs.iter())
+        .map(|input| input.type_name.as_str());
+    let endpoint_types = abi.endpoints.iter().flat_map(|endpoint| {
+        endpoint
+            .inputs
+            .iter()
+            .map(|input| input.type_name.as_str())
+            .chain(
+                endpoint
+                    .outputs
+                    .iter()
+                    .map(|output| output.type_name.as_str()),
+            )
+    });
+    let event_types = abi
+        .events
+        .iter()
+        .flat_map(|event| event.inputs.iter().map(|input| input.type_name.as_str()));
+    let storage_types = abi.storage.iter().flat_map(|storage| {
+        storage
+            .key_args
+            .iter()
+            .map(|key_arg| key_arg.type_name.as_str())
+            .chain(storage.value_types.iter().map(String::as_str))
+    });
+
+    let mut referenced = BTreeSet::new();
+    for type_name in constructor_inputs
+        .chain(endpoint_types)
+        .chain(event_types)
+        .chain(storage_types)
+    {
+        collect_referenced_type_names(abi, type_name, &mut referenced);
+    }
+    referenced
+}
+
+fn collect_referenced_type_names<'a>(
+    abi: &'a ContractAbiJson,
+    type_name: &'a str,
+    referenced: &mut BTreeSet<&'a str>,
+) {
+    if !referenced.insert(type_name) {
+        return;
+    }
+    let (_, args) = split_type_name(type_name);
+    for arg in args {
+        collect_referenced_type_names(abi, arg, referenced);
+    }
+    let Some(description) = abi.types.get(type_name) else {
+        return;
+    };
+    let fields = description.fields.iter().chain(
+        description
+            .variants
+            .iter()
+            .flat_map(|variant| variant.fields.iter()),
+    );
+    for field in fields {
+        collect_referenced_type_names(abi, &field.field_type, referenced);
+    }
+}
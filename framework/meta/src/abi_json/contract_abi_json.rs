@@ -7,13 +7,23 @@ use std::collections::BTreeMap;
 #[serde(rename_all = "camelCase")]
 pub struct ContractAbiJson {
     pub build_info: BuildInfoAbiJson,
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub docs: Vec<String>,
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub constructor: Option<ConstructorAbiJson>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upgrade_constructor: Option<ConstructorAbiJson>,
     pub endpoints: Vec<EndpointAbiJson>,
     pub events: Vec<EventAbiJson>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub storage: Vec<StorageAbiJson>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<ErrorAbiJson>,
     pub has_callback: bool,
     pub types: BTreeMap<String, TypeDescriptionJson>,
 }
@@ -25,8 +35,14 @@ impl From<&ContractAbi> for ContractAbiJson {
             docs: abi.docs.iter().map(|d| d.to_string()).collect(),
             name: abi.name.to_string(),
             constructor: abi.constructors.get(0).map(ConstructorAbiJson::from),
+            upgrade_constructor: abi
+                .upgrade_constructors
+                .first()
+                .map(ConstructorAbiJson::from),
             endpoints: abi.endpoints.iter().map(EndpointAbiJson::from).collect(),
             events: abi.events.iter().map(EventAbiJson::from).collect(),
+            storage: abi.storage.iter().map(StorageAbiJson::from).collect(),
+            errors: abi.errors.iter().map(ErrorAbiJson::from).collect(),
             has_callback: abi.has_callback,
             types: BTreeMap::new(),
         };
@@ -25,6 +25,7 @@ impl From<&InputAbi> for InputAbiJson {
 #[derive(Serialize, Deserialize)]
 pub struct OutputAbiJson {
     #[serde(rename = "name")]
+    #[serde(default)]
     #[serde(skip_serializing_if = "String::is_empty")]
     pub output_name: String,
     #[serde(rename = "type")]
@@ -55,9 +56,11 @@ pub enum EndpointMutabilityAbiJson {
 /// Same as EndpointAbiJson but ignores the name
 #[derive(Serialize, Deserialize)]
 pub struct ConstructorAbiJson {
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub docs: Vec<String>,
     #[serde(rename = "payableInTokens")]
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub payable_in_tokens: Vec<String>,
     pub inputs: Vec<InputAbiJson>,
@@ -81,6 +84,7 @@ impl From<&EndpointAbi> for ConstructorAbiJson {
 
 #[derive(Serialize, Deserialize)]
 pub struct EndpointAbiJson {
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub docs: Vec<String>,
     pub name: String,
@@ -90,12 +94,32 @@ pub struct EndpointAbiJson {
     #[serde(rename = "onlyAdmin")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub only_admin: Option<bool>,
+    #[serde(rename = "onlyRole")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub only_role: Option<String>,
+    #[serde(rename = "onlyCallbackContext")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub only_callback_context: Option<bool>,
+    #[serde(rename = "notFromContract")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub not_from_contract: Option<bool>,
+    #[serde(rename = "nonReentrant")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub non_reentrant: Option<bool>,
+    #[serde(rename = "maxGas")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_gas: Option<u64>,
+    #[serde(rename = "maxReturnItems")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_return_items: Option<usize>,
     pub mutability: EndpointMutabilityAbiJson,
     #[serde(rename = "payableInTokens")]
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub payable_in_tokens: Vec<String>,
     pub inputs: Vec<InputAbiJson>,
     pub outputs: Vec<OutputAbiJson>,
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub labels: Vec<String>,
 }
@@ -107,6 +131,20 @@ impl From<&EndpointAbi> for EndpointAbiJson {
             name: abi.name.to_string(),
             only_owner: if abi.only_owner { Some(true) } else { None },
             only_admin: if abi.only_admin { Some(true) } else { None },
+            only_role: abi.only_role.map(|role| role.to_string()),
+            only_callback_context: if abi.only_callback_context {
+                Some(true)
+            } else {
+                None
+            },
+            not_from_contract: if abi.not_from_contract {
+                Some(true)
+            } else {
+                None
+            },
+            non_reentrant: if abi.non_reentrant { Some(true) } else { None },
+            max_gas: abi.max_gas,
+            max_return_items: abi.max_return_items,
             mutability: match abi.mutability {
                 EndpointMutabilityAbi::Mutable => EndpointMutabilityAbiJson::Mutable,
                 EndpointMutabilityAbi::Readonly => EndpointMutabilityAbiJson::Readonly,
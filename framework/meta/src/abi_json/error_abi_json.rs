@@ -0,0 +1,45 @@
+use multiversx_sc::abi::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct ErrorFieldAbiJson {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_name: String,
+}
+
+impl From<&ErrorFieldAbi> for ErrorFieldAbiJson {
+    fn from(abi: &ErrorFieldAbi) -> Self {
+        ErrorFieldAbiJson {
+            name: abi.name.to_string(),
+            type_name: abi.type_name.clone(),
+        }
+    }
+}
+
+/// The signalled message is `E<code>: ` followed by the message template,
+/// with the fields filled in.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ErrorAbiJson {
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
+    pub name: String,
+    pub code: u32,
+    pub message: String,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<ErrorFieldAbiJson>,
+}
+
+impl From<&ErrorAbi> for ErrorAbiJson {
+    fn from(abi: &ErrorAbi) -> Self {
+        ErrorAbiJson {
+            docs: abi.docs.iter().map(|d| d.to_string()).collect(),
+            name: abi.name.to_string(),
+            code: abi.code,
+            message: abi.message.to_string(),
+            fields: abi.fields.iter().map(ErrorFieldAbiJson::from).collect(),
+        }
+    }
+}
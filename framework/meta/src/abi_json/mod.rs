@@ -1,16 +1,29 @@
 mod build_info_abi_json;
 mod contract_abi_json;
 mod endpoint_abi_json;
+mod error_abi_json;
 mod event_abi_json;
+mod storage_abi_json;
 mod type_abi_json;
 
-pub use build_info_abi_json::{BuildInfoAbiJson, RustcAbiJson};
+pub use build_info_abi_json::{
+    BuildInfoAbiJson, ContractCrateBuildAbiJson, FrameworkBuildAbiJson, RustcAbiJson,
+};
 pub use contract_abi_json::*;
 pub use endpoint_abi_json::*;
+pub use error_abi_json::*;
 pub use event_abi_json::*;
 use multiversx_sc::{abi::ContractAbi, contract_base::ContractAbiProvider};
+pub use storage_abi_json::*;
 pub use type_abi_json::*;
 
+/// Loads an ABI JSON file, e.g. one produced by a previous build.
+pub fn read_abi_json(path: &str) -> ContractAbiJson {
+    let json_str =
+        std::fs::read_to_string(path).unwrap_or_else(|err| panic!("failed to read {path}: {err}"));
+    serde_json::from_str(&json_str).unwrap_or_else(|err| panic!("failed to parse {path}: {err}"))
+}
+
 /// Function provided for convenience.
 /// Yields the ABI JSON of a contract as string.
 pub fn contract_abi<AbiObj: ContractAbiProvider>() -> String {
@@ -40,3 +53,33 @@ pub fn abi_to_json_dummy_environment(contract_abi: &ContractAbi) -> String {
     };
     serialize_abi_to_json(&abi_json)
 }
+
+/// Splits an ABI type name into its base name and its generic arguments,
+/// e.g. `("Option", ["List<u32>"])` for `Option<List<u32>>`.
+pub fn split_type_name(type_name: &str) -> (&str, Vec<&str>) {
+    let type_name = type_name.trim();
+    let Some(open) = type_name.find('<') else {
+        return (type_name, Vec::new());
+    };
+    if !type_name.ends_with('>') {
+        return (type_name, Vec::new());
+    }
+
+    let inner = &type_name[open + 1..type_name.len() - 1];
+    let mut args = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (index, c) in inner.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth -= 1,
+            ',' if depth == 0 => {
+                args.push(inner[start..index].trim());
+                start = index + 1;
+            },
+            _ => {},
+        }
+    }
+    args.push(inner[start..].trim());
+    (&type_name[..open], args)
+}
@@ -6,26 +6,46 @@ pub struct TypeDescriptionJson {
     #[serde(rename = "type")]
     pub content_type: String,
 
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub docs: Vec<String>,
 
+    /// Only present for enums with `u16` discriminants, the others use `u8`.
+    #[serde(rename = "discriminantType")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discriminant_type: Option<String>,
+
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub variants: Vec<EnumVariantDescriptionJson>,
 
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub fields: Vec<StructFieldDescriptionJson>,
 }
 
+impl TypeDescriptionJson {
+    /// Size of the encoded enum discriminant, in bytes.
+    pub fn discriminant_len(&self) -> usize {
+        match self.discriminant_type.as_deref() {
+            Some("u16") => 2,
+            _ => 1,
+        }
+    }
+}
+
 impl From<&TypeDescription> for TypeDescriptionJson {
     fn from(abi: &TypeDescription) -> Self {
         let content_type = match &abi.contents {
             TypeContents::NotSpecified => "not_specified",
-            TypeContents::Enum(_) => "enum",
+            TypeContents::Enum { .. } => "enum",
             TypeContents::Struct(_) => "struct",
         };
         let mut type_desc_json = TypeDescriptionJson {
             content_type: content_type.to_string(),
             docs: abi.docs.iter().map(|line| line.to_string()).collect(),
+            discriminant_type: None,
             variants: Vec::new(),
             fields: Vec::new(),
         };
@@ -37,7 +57,14 @@ impl From<&TypeDescription> for TypeDescriptionJson {
                         .push(StructFieldDescriptionJson::from(field));
                 }
             },
-            TypeContents::Enum(variants) => {
+            TypeContents::Enum {
+                discriminant_type,
+                variants,
+            } => {
+                if *discriminant_type != EnumDiscriminantType::U8 {
+                    type_desc_json.discriminant_type =
+                        Some(discriminant_type.type_name().to_string());
+                }
                 for variant in variants {
                     type_desc_json
                         .variants
@@ -53,6 +80,7 @@ impl From<&TypeDescription> for TypeDescriptionJson {
 
 #[derive(Serialize, Deserialize)]
 pub struct StructFieldDescriptionJson {
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub docs: Vec<String>,
     pub name: String,
@@ -72,10 +100,12 @@ impl From<&StructFieldDescription> for StructFieldDescriptionJson {
 
 #[derive(Serialize, Deserialize)]
 pub struct EnumVariantDescriptionJson {
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub docs: Vec<String>,
     pub name: String,
     pub discriminant: usize,
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub fields: Vec<StructFieldDescriptionJson>,
 }
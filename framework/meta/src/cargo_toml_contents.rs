@@ -43,6 +43,11 @@ impl CargoTomlContents {
             .expect("failed to write Cargo.toml contents to file");
     }
 
+    pub fn package_name(&self) -> Option<String> {
+        let name = self.toml_value.get("package")?.get("name")?;
+        name.as_str().map(str::to_string)
+    }
+
     /// Assumes that a package section already exists.
     pub fn change_package_name(&mut self, new_package_name: String) {
         let package = self
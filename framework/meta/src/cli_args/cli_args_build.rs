@@ -64,6 +64,14 @@ pub struct BuildArgs {
     #[arg(long = "twiggy-dominators", verbatim_doc_comment)]
     pub twiggy_dominators: bool,
 
+    /// Builds with `cargo +<toolchain>`. Only set by reproducible builds.
+    #[arg(skip)]
+    pub toolchain: Option<String>,
+
+    /// Local path prefixes replaced in the compiled code. Only set by reproducible builds.
+    #[arg(skip)]
+    pub remap_path_prefixes: Vec<(String, String)>,
+
     /// Backwards compatibility with mxpy, delete when github actions are fixed.
     #[deprecated]
     #[arg(long = "target", verbatim_doc_comment)]
@@ -97,6 +105,8 @@ impl Default for BuildArgs {
             twiggy_paths: false,
             twiggy_monos: false,
             twiggy_dominators: false,
+            toolchain: None,
+            remap_path_prefixes: Vec::new(),
             target: None,
             release: false,
             out_dir: None,
@@ -254,3 +264,61 @@ impl CliArgsToRaw for TwiggyArgs {
         raw
     }
 }
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct ReproducibleBuildArgs {
+    /// The toolchain to build with, e.g. `nightly-2022-12-08`.
+    /// Will be read from the closest `rust-toolchain.toml` or `rust-toolchain` file if not specified.
+    #[arg(long, verbatim_doc_comment)]
+    pub toolchain: Option<String>,
+
+    /// Allows specifying the target directory where the Rust compiler will build the intermediary files.
+    #[arg(long = "target-dir", verbatim_doc_comment)]
+    pub target_dir: Option<String>,
+}
+
+impl CliArgsToRaw for ReproducibleBuildArgs {
+    fn to_raw(&self) -> Vec<String> {
+        let mut raw = Vec::new();
+        if let Some(toolchain) = &self.toolchain {
+            raw.push("--toolchain".to_string());
+            raw.push(toolchain.clone());
+        }
+        if let Some(target_dir) = &self.target_dir {
+            raw.push("--target-dir".to_string());
+            raw.push(target_dir.clone());
+        }
+        raw
+    }
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct VerifyBuildArgs {
+    /// The contract bytecode to verify, e.g. as downloaded from the network.
+    #[arg(verbatim_doc_comment)]
+    pub wasm: String,
+
+    /// The build manifest produced by `reproducible-build`.
+    /// Will be the one in the output folder if not specified.
+    #[arg(long, verbatim_doc_comment)]
+    pub manifest: Option<String>,
+
+    /// Allows specifying the target directory where the Rust compiler will build the intermediary files.
+    #[arg(long = "target-dir", verbatim_doc_comment)]
+    pub target_dir: Option<String>,
+}
+
+impl CliArgsToRaw for VerifyBuildArgs {
+    fn to_raw(&self) -> Vec<String> {
+        let mut raw = vec![self.wasm.clone()];
+        if let Some(manifest) = &self.manifest {
+            raw.push("--manifest".to_string());
+            raw.push(manifest.clone());
+        }
+        if let Some(target_dir) = &self.target_dir {
+            raw.push("--target-dir".to_string());
+            raw.push(target_dir.clone());
+        }
+        raw
+    }
+}
@@ -1,6 +1,8 @@
 use clap::{ArgAction, Args, Parser, Subcommand};
 
-use super::{BuildArgs, BuildDbgArgs, CliArgsToRaw, TwiggyArgs};
+use super::{
+    BuildArgs, BuildDbgArgs, CliArgsToRaw, ReproducibleBuildArgs, TwiggyArgs, VerifyBuildArgs,
+};
 
 /// Parsed arguments of the meta crate CLI.
 #[derive(Default, PartialEq, Eq, Debug, Parser)]
@@ -55,6 +57,18 @@ pub enum ContractCliAction {
     )]
     Twiggy(TwiggyArgs),
 
+    #[command(
+        name = "reproducible-build",
+        about = "Builds contract(s) deterministically, then writes their code hashes and a build manifest."
+    )]
+    ReproducibleBuild(ReproducibleBuildArgs),
+
+    #[command(
+        name = "verify",
+        about = "Rebuilds the contract(s) from a build manifest and checks that a given wasm matches the result."
+    )]
+    Verify(VerifyBuildArgs),
+
     #[command(about = "Clean the Rust project and the output folder.")]
     Clean,
 
@@ -87,6 +101,14 @@ impl CliArgsToRaw for ContractCliAction {
                 raw.push("twiggy".to_string());
                 raw.append(&mut args.to_raw());
             },
+            ContractCliAction::ReproducibleBuild(args) => {
+                raw.push("reproducible-build".to_string());
+                raw.append(&mut args.to_raw());
+            },
+            ContractCliAction::Verify(args) => {
+                raw.push("verify".to_string());
+                raw.append(&mut args.to_raw());
+            },
             ContractCliAction::Clean => {
                 raw.push("clean".to_string());
             },
@@ -48,6 +48,44 @@ pub enum StandaloneCliAction {
         about = "Generates a report on the local depedencies of contract crates. Will explore indirect depdencies too."
     )]
     LocalDeps(LocalDepsArgs),
+
+    #[command(
+        name = "storage-diff",
+        about = "Compares the storage layouts of two versions of a contract ABI and reports incompatible changes."
+    )]
+    StorageDiff(StorageDiffArgs),
+
+    #[command(
+        name = "decode-events",
+        about = "Decodes the event logs of a transaction, based on the contract ABI."
+    )]
+    DecodeEvents(DecodeEventsArgs),
+
+    #[command(
+        name = "generate-proxy",
+        about = "Generates a crate with the types and the proxy of a contract, based on its ABI."
+    )]
+    GenerateProxy(GenerateProxyArgs),
+
+    #[command(
+        name = "abi-diff",
+        about = "Compares two versions of a contract ABI and classifies the changes as breaking or non-breaking."
+    )]
+    AbiDiff(AbiDiffArgs),
+
+    #[command(
+        about = "Creates a new contract crate, with its meta, wasm and interactor crates, starting from a template."
+    )]
+    New(NewArgs),
+
+    #[command(
+        name = "test-gen",
+        about = "Generates or refreshes the Rust test files that run the scenarios of each contract."
+    )]
+    TestGen(TestGenArgs),
+
+    #[command(about = "Formats and checks the scenario JSON files.")]
+    Scen(ScenArgs),
 }
 
 #[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
@@ -128,3 +166,153 @@ pub struct LocalDepsArgs {
     #[clap(global = true, default_value = "target")]
     pub ignore: Vec<String>,
 }
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct StorageDiffArgs {
+    /// ABI JSON of the contract version currently deployed.
+    #[arg(verbatim_doc_comment)]
+    pub old: String,
+
+    /// ABI JSON of the contract version to upgrade to.
+    #[arg(verbatim_doc_comment)]
+    pub new: String,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct DecodeEventsArgs {
+    /// ABI JSON of the contract that emitted the events.
+    #[arg(verbatim_doc_comment)]
+    pub abi: String,
+
+    /// JSON of the transaction, as returned by the API, with its logs and smart contract results.
+    #[arg(verbatim_doc_comment)]
+    pub tx: String,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct GenerateProxyArgs {
+    /// ABI JSON of the contract.
+    #[arg(verbatim_doc_comment)]
+    pub abi: String,
+
+    /// Directory of the generated crate.
+    /// Will be the crate name if not specified.
+    #[arg(long, verbatim_doc_comment)]
+    pub output: Option<String>,
+
+    /// Name of the generated crate.
+    /// Will be the contract crate name followed by "-proxy" if not specified.
+    #[arg(long = "name", verbatim_doc_comment)]
+    pub crate_name: Option<String>,
+
+    /// Local path to the framework base crate, to be used instead of the published version.
+    #[arg(long = "framework-path", verbatim_doc_comment)]
+    pub framework_path: Option<String>,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct AbiDiffArgs {
+    /// ABI JSON of the previous contract version.
+    #[arg(verbatim_doc_comment)]
+    pub old: String,
+
+    /// ABI JSON of the new contract version.
+    #[arg(verbatim_doc_comment)]
+    pub new: String,
+
+    /// Exit with an error code if any breaking change is found.
+    #[arg(long = "fail-on-breaking", verbatim_doc_comment)]
+    pub fail_on_breaking: bool,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct NewArgs {
+    /// The contract template to start from: adder, crowdfunding or ping-pong.
+    #[arg(long, verbatim_doc_comment)]
+    pub template: String,
+
+    /// Name of the new contract crate.
+    /// Will be the template name if not specified.
+    #[arg(long, verbatim_doc_comment)]
+    pub name: Option<String>,
+
+    /// Target directory where to create the new contract crate.
+    /// Will be current directory if not specified.
+    #[arg(long, verbatim_doc_comment)]
+    pub path: Option<String>,
+
+    /// Local checkout of the framework repository, where the templates are taken from.
+    /// The templates embedded in this tool are used if not specified.
+    #[arg(long = "repo-path", verbatim_doc_comment)]
+    pub repo_path: Option<String>,
+
+    /// Keep the framework dependencies pointing to the local repository, instead of the published version.
+    #[arg(long = "local-framework", verbatim_doc_comment)]
+    pub local_framework: bool,
+
+    /// Skips generating the interactor crate, which requires building the contract.
+    #[arg(long = "no-interactor", verbatim_doc_comment)]
+    pub no_interactor: bool,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct TestGenArgs {
+    /// Target directory where to generate contract tests.
+    /// Will be current directory if not specified.
+    #[arg(long, verbatim_doc_comment)]
+    pub path: Option<String>,
+
+    /// Ignore all directories with these names.
+    #[arg(long, verbatim_doc_comment)]
+    #[clap(global = true, default_value = "target")]
+    pub ignore: Vec<String>,
+
+    /// Also adds tests for the scenarios that have none.
+    /// By default these are only reported.
+    #[arg(long = "add-missing", verbatim_doc_comment)]
+    pub add_missing: bool,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct ScenArgs {
+    #[command(subcommand)]
+    pub command: ScenCliAction,
+
+    /// Target directory or scenario file.
+    /// Will be current directory if not specified.
+    #[arg(long, verbatim_doc_comment)]
+    #[clap(global = true)]
+    pub path: Option<String>,
+
+    /// Ignore all directories with these names.
+    #[arg(long, verbatim_doc_comment)]
+    #[clap(global = true, default_value = "target")]
+    pub ignore: Vec<String>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Subcommand)]
+pub enum ScenCliAction {
+    #[command(
+        about = "Formats the scenario files the canonical way, converting the legacy mandos naming."
+    )]
+    Fmt(ScenFmtArgs),
+
+    #[command(
+        about = "Flags unknown fields, undefined addresses, unused accounts and missing external steps."
+    )]
+    Lint(ScenLintArgs),
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct ScenFmtArgs {
+    /// Only checks the formatting, fails if any file would change.
+    #[arg(long, verbatim_doc_comment)]
+    pub check: bool,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct ScenLintArgs {
+    /// Fails if any issue is found.
+    #[arg(long, verbatim_doc_comment)]
+    pub check: bool,
+}
@@ -0,0 +1,214 @@
+/// VM hooks available since the first VM 1.x release.
+pub const EI_1_0_NAMES: &[&str] = &[
+    "addEC",
+    "asyncCall",
+    "bigFloatAbs",
+    "bigFloatAdd",
+    "bigFloatCeil",
+    "bigFloatClone",
+    "bigFloatCmp",
+    "bigFloatDiv",
+    "bigFloatFloor",
+    "bigFloatGetConstE",
+    "bigFloatGetConstPi",
+    "bigFloatIsInt",
+    "bigFloatMul",
+    "bigFloatNeg",
+    "bigFloatNewFromFrac",
+    "bigFloatNewFromParts",
+    "bigFloatNewFromSci",
+    "bigFloatPow",
+    "bigFloatSetBigInt",
+    "bigFloatSetInt64",
+    "bigFloatSign",
+    "bigFloatSqrt",
+    "bigFloatSub",
+    "bigFloatTruncate",
+    "bigIntAbs",
+    "bigIntAdd",
+    "bigIntAnd",
+    "bigIntCmp",
+    "bigIntFinishSigned",
+    "bigIntFinishUnsigned",
+    "bigIntGetCallValue",
+    "bigIntGetESDTCallValue",
+    "bigIntGetESDTCallValueByIndex",
+    "bigIntGetESDTExternalBalance",
+    "bigIntGetExternalBalance",
+    "bigIntGetInt64",
+    "bigIntGetSignedArgument",
+    "bigIntGetSignedBytes",
+    "bigIntGetUnsignedArgument",
+    "bigIntGetUnsignedBytes",
+    "bigIntIsInt64",
+    "bigIntLog2",
+    "bigIntMul",
+    "bigIntNeg",
+    "bigIntNew",
+    "bigIntOr",
+    "bigIntPow",
+    "bigIntSetInt64",
+    "bigIntSetSignedBytes",
+    "bigIntSetUnsignedBytes",
+    "bigIntShl",
+    "bigIntShr",
+    "bigIntSign",
+    "bigIntSignedByteLength",
+    "bigIntSqrt",
+    "bigIntStorageLoadUnsigned",
+    "bigIntStorageStoreUnsigned",
+    "bigIntSub",
+    "bigIntTDiv",
+    "bigIntTMod",
+    "bigIntToString",
+    "bigIntUnsignedByteLength",
+    "bigIntXor",
+    "blockHash",
+    "checkNoPayment",
+    "cleanReturnData",
+    "createContract",
+    "createEC",
+    "deleteFromReturnData",
+    "deployFromSourceContract",
+    "doubleEC",
+    "ellipticCurveGetValues",
+    "encodeSecp256k1DerSignature",
+    "executeOnDestContext",
+    "executeOnSameContext",
+    "executeReadOnly",
+    "finish",
+    "generateKeyEC",
+    "getArgument",
+    "getArgumentLength",
+    "getBlockEpoch",
+    "getBlockNonce",
+    "getBlockRandomSeed",
+    "getBlockRound",
+    "getBlockTimestamp",
+    "getCaller",
+    "getCurrentESDTNFTNonce",
+    "getCurveLengthEC",
+    "getESDTLocalRoles",
+    "getESDTNFTAttributeLength",
+    "getESDTNFTNameLength",
+    "getESDTNFTURILength",
+    "getESDTTokenData",
+    "getESDTTokenName",
+    "getESDTTokenNameByIndex",
+    "getESDTTokenNonce",
+    "getESDTTokenNonceByIndex",
+    "getESDTTokenType",
+    "getESDTTokenTypeByIndex",
+    "getFunction",
+    "getGasLeft",
+    "getNumArguments",
+    "getNumESDTTransfers",
+    "getNumReturnData",
+    "getOriginalTxHash",
+    "getOwnerAddress",
+    "getPrevBlockEpoch",
+    "getPrevBlockNonce",
+    "getPrevBlockRandomSeed",
+    "getPrevBlockRound",
+    "getPrevBlockTimestamp",
+    "getPrivKeyByteLengthEC",
+    "getReturnData",
+    "getReturnDataSize",
+    "getSCAddress",
+    "getShardOfAddress",
+    "getStateRootHash",
+    "isOnCurveEC",
+    "isSmartContract",
+    "keccak256",
+    "mBufferAppend",
+    "mBufferAppendBytes",
+    "mBufferCopyByteSlice",
+    "mBufferEq",
+    "mBufferFinish",
+    "mBufferFromBigFloat",
+    "mBufferFromBigIntSigned",
+    "mBufferFromBigIntUnsigned",
+    "mBufferGetArgument",
+    "mBufferGetByteSlice",
+    "mBufferGetBytes",
+    "mBufferGetLength",
+    "mBufferNew",
+    "mBufferNewFromBytes",
+    "mBufferSetByteSlice",
+    "mBufferSetBytes",
+    "mBufferSetRandom",
+    "mBufferStorageLoad",
+    "mBufferStorageLoadFromAddress",
+    "mBufferStorageStore",
+    "mBufferToBigFloat",
+    "mBufferToBigIntSigned",
+    "mBufferToBigIntUnsigned",
+    "managedAsyncCall",
+    "managedCaller",
+    "managedCreateContract",
+    "managedCreateEC",
+    "managedDeployFromSourceContract",
+    "managedEncodeSecp256k1DerSignature",
+    "managedExecuteOnDestContext",
+    "managedExecuteOnSameContext",
+    "managedExecuteReadOnly",
+    "managedGenerateKeyEC",
+    "managedGetBlockRandomSeed",
+    "managedGetESDTTokenData",
+    "managedGetMultiESDTCallValue",
+    "managedGetOriginalTxHash",
+    "managedGetPrevBlockRandomSeed",
+    "managedGetReturnData",
+    "managedGetStateRootHash",
+    "managedKeccak256",
+    "managedMarshalCompressedEC",
+    "managedMarshalEC",
+    "managedMultiTransferESDTNFTExecute",
+    "managedOwnerAddress",
+    "managedRipemd160",
+    "managedSCAddress",
+    "managedScalarBaseMultEC",
+    "managedScalarMultEC",
+    "managedSha256",
+    "managedSignalError",
+    "managedTransferValueExecute",
+    "managedUnmarshalCompressedEC",
+    "managedUnmarshalEC",
+    "managedUpgradeContract",
+    "managedUpgradeFromSourceContract",
+    "managedVerifyBLS",
+    "managedVerifyCustomSecp256k1",
+    "managedVerifyEd25519",
+    "managedVerifySecp256k1",
+    "managedWriteLog",
+    "marshalCompressedEC",
+    "marshalEC",
+    "multiTransferESDTNFTExecute",
+    "ripemd160",
+    "scalarBaseMultEC",
+    "scalarMultEC",
+    "sha256",
+    "signalError",
+    "smallIntFinishSigned",
+    "smallIntFinishUnsigned",
+    "smallIntGetSignedArgument",
+    "smallIntGetUnsignedArgument",
+    "storageLoad",
+    "storageLoadLength",
+    "storageStore",
+    "transferESDTExecute",
+    "transferESDTNFTExecute",
+    "transferValue",
+    "transferValueExecute",
+    "unmarshalCompressedEC",
+    "unmarshalEC",
+    "upgradeContract",
+    "upgradeFromSourceContract",
+    "validateTokenIdentifier",
+    "verifyBLS",
+    "verifyCustomSecp256k1",
+    "verifyEd25519",
+    "verifySecp256k1",
+    "writeEventLog",
+    "writeLog",
+];
@@ -0,0 +1,7 @@
+/// VM hooks added in VM 1.1, on top of those in VM 1.0.
+pub const EI_1_1_ADDED_NAMES: &[&str] = &[
+    "managedBufferToHex",
+    "managedIsESDTFrozen",
+    "managedIsESDTLimitedTransfer",
+    "managedIsESDTPaused",
+];
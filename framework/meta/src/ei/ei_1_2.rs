@@ -0,0 +1,2 @@
+/// VM hooks added in VM 1.2, on top of those in VM 1.1.
+pub const EI_1_2_ADDED_NAMES: &[&str] = &["managedCreateAsyncCall", "managedGetCallbackClosure"];
@@ -0,0 +1,60 @@
+use super::*;
+
+/// The VM releases whose executor interface (EI) is known to the framework.
+///
+/// Each release only adds new VM hooks, so contracts built for an older release
+/// also run on all newer ones.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum EIVersion {
+    V1_0,
+    V1_1,
+    V1_2,
+}
+
+impl EIVersion {
+    pub const ALL: &'static [EIVersion] = &[EIVersion::V1_0, EIVersion::V1_1, EIVersion::V1_2];
+
+    /// The version, as written in `multicontract.toml`, e.g. `ei = "1.2"`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .find(|version| version.name() == name)
+            .copied()
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            EIVersion::V1_0 => "1.0",
+            EIVersion::V1_1 => "1.1",
+            EIVersion::V1_2 => "1.2",
+        }
+    }
+
+    fn added_names(&self) -> &'static [&'static str] {
+        match self {
+            EIVersion::V1_0 => EI_1_0_NAMES,
+            EIVersion::V1_1 => EI_1_1_ADDED_NAMES,
+            EIVersion::V1_2 => EI_1_2_ADDED_NAMES,
+        }
+    }
+
+    /// All VM hooks available in this release, including those of the previous releases.
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        Self::ALL
+            .iter()
+            .filter(move |version| *version <= self)
+            .flat_map(|version| version.added_names().iter().copied())
+    }
+
+    pub fn contains(&self, import_name: &str) -> bool {
+        self.names().any(|name| name == import_name)
+    }
+
+    /// The first release that provides the VM hook, if any.
+    pub fn introduced_in(import_name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .find(|version| version.added_names().contains(&import_name))
+            .copied()
+    }
+}
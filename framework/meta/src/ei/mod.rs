@@ -0,0 +1,9 @@
+mod ei_1_0;
+mod ei_1_1;
+mod ei_1_2;
+mod ei_version;
+
+pub use ei_1_0::EI_1_0_NAMES;
+pub use ei_1_1::EI_1_1_ADDED_NAMES;
+pub use ei_1_2::EI_1_2_ADDED_NAMES;
+pub use ei_version::EIVersion;
@@ -0,0 +1,297 @@
+use crate::abi_json::{split_type_name, StructFieldDescriptionJson, TypeDescriptionJson};
+use multiversx_sc::codec::num_bigint::{BigInt, BigUint};
+use std::collections::BTreeMap;
+
+use super::{AbiField, AbiValue};
+
+/// Size in bytes of the fixed-size integer types.
+fn int_size(base: &str) -> Option<(usize, bool)> {
+    match base {
+        "u8" => Some((1, false)),
+        "u16" => Some((2, false)),
+        "u32" | "usize" | "NonZeroUsize" => Some((4, false)),
+        "u64" => Some((8, false)),
+        "i8" => Some((1, true)),
+        "i16" => Some((2, true)),
+        "i32" | "isize" => Some((4, true)),
+        "i64" => Some((8, true)),
+        _ => None,
+    }
+}
+
+/// Types that are nested-encoded with a length prefix and top-encoded as raw bytes.
+fn is_length_prefixed(base: &str) -> bool {
+    matches!(
+        base,
+        "BigUint"
+            | "BigInt"
+            | "bytes"
+            | "utf-8 string"
+            | "TokenIdentifier"
+            | "EgldOrEsdtTokenIdentifier"
+    )
+}
+
+fn array_len(base: &str) -> Option<usize> {
+    base.strip_prefix("array")
+        .and_then(|len| len.parse::<usize>().ok())
+}
+
+struct NestedInput<'b> {
+    bytes: &'b [u8],
+}
+
+impl<'b> NestedInput<'b> {
+    fn read(&mut self, len: usize) -> Result<&'b [u8], String> {
+        if self.bytes.len() < len {
+            return Err("input too short".to_string());
+        }
+        let (chunk, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(chunk)
+    }
+
+    fn read_len(&mut self) -> Result<usize, String> {
+        let raw = self.read(4)?;
+        Ok(u32::from_be_bytes(raw.try_into().unwrap()) as usize)
+    }
+}
+
+/// Decodes values based on their ABI type names,
+/// looking up structs and enums in the type descriptions of the ABI.
+pub(crate) struct AbiTypeDecoder<'a> {
+    pub types: &'a BTreeMap<String, TypeDescriptionJson>,
+}
+
+impl<'a> AbiTypeDecoder<'a> {
+    /// Decodes a value that takes up the entire buffer, e.g. an event topic or the event data.
+    pub fn top_decode(&self, type_name: &str, bytes: &[u8]) -> Result<AbiValue, String> {
+        let (base, args) = split_type_name(type_name);
+
+        if let Some((size, signed)) = int_size(base) {
+            if bytes.len() > size {
+                return Err(format!("{type_name} cannot be more than {size} bytes long"));
+            }
+            return Ok(decode_int(bytes, signed));
+        }
+
+        match base {
+            "bool" => match bytes {
+                [] => Ok(AbiValue::Bool(false)),
+                [1] => Ok(AbiValue::Bool(true)),
+                _ => Err("invalid bool".to_string()),
+            },
+            "BigUint" => Ok(AbiValue::Unsigned(BigUint::from_bytes_be(bytes))),
+            "BigInt" => Ok(AbiValue::Signed(BigInt::from_signed_bytes_be(bytes))),
+            "bytes" | "H256" => Ok(AbiValue::Bytes(bytes.to_vec())),
+            "utf-8 string" | "TokenIdentifier" | "EgldOrEsdtTokenIdentifier" => {
+                decode_string(type_name, bytes)
+            },
+            "Address" => decode_address(bytes),
+            "Option" if args.len() == 1 => match bytes.split_first() {
+                None => Ok(AbiValue::Option(None)),
+                Some((1, rest)) => {
+                    let value = self.nested_decode_all(args[0], rest)?;
+                    Ok(AbiValue::Option(Some(Box::new(value))))
+                },
+                Some(_) => Err("invalid Option".to_string()),
+            },
+            "List" | "vec" | "Queue" if args.len() == 1 => {
+                let mut input = NestedInput { bytes };
+                let mut items = Vec::new();
+                while !input.bytes.is_empty() {
+                    items.push(self.nested_decode(args[0], &mut input)?);
+                }
+                Ok(AbiValue::List(items))
+            },
+            _ => match self.types.get(type_name) {
+                Some(description) if description.content_type == "enum" => {
+                    self.top_decode_enum(type_name, description, bytes)
+                },
+                _ => self.nested_decode_all(type_name, bytes),
+            },
+        }
+    }
+
+    /// Fieldless enums are top-encoded like their discriminant, the others like nested values,
+    /// except for the variant with discriminant 0, which can also be encoded as an empty buffer.
+    fn top_decode_enum(
+        &self,
+        type_name: &str,
+        description: &TypeDescriptionJson,
+        bytes: &[u8],
+    ) -> Result<AbiValue, String> {
+        let fieldless = description
+            .variants
+            .iter()
+            .all(|variant| variant.fields.is_empty());
+        if bytes.is_empty() || (fieldless && bytes.len() <= description.discriminant_len()) {
+            // top-encoded integers skip their leading zeros
+            self.enum_variant(
+                type_name,
+                description,
+                decode_discriminant(bytes),
+                &mut NestedInput { bytes: &[] },
+            )
+        } else {
+            self.nested_decode_all(type_name, bytes)
+        }
+    }
+
+    fn nested_decode_all(&self, type_name: &str, bytes: &[u8]) -> Result<AbiValue, String> {
+        let mut input = NestedInput { bytes };
+        let value = self.nested_decode(type_name, &mut input)?;
+        if !input.bytes.is_empty() {
+            return Err(format!(
+                "{} unexpected trailing bytes after {type_name}",
+                input.bytes.len()
+            ));
+        }
+        Ok(value)
+    }
+
+    fn nested_decode(&self, type_name: &str, input: &mut NestedInput) -> Result<AbiValue, String> {
+        let (base, args) = split_type_name(type_name);
+
+        if let Some((size, signed)) = int_size(base) {
+            return Ok(decode_int(input.read(size)?, signed));
+        }
+        if is_length_prefixed(base) {
+            let len = input.read_len()?;
+            let bytes = input.read(len)?;
+            return self.top_decode(type_name, bytes);
+        }
+        if let Some(len) = array_len(base) {
+            if args.len() == 1 {
+                let items = (0..len)
+                    .map(|_| self.nested_decode(args[0], input))
+                    .collect::<Result<Vec<_>, _>>()?;
+                return Ok(AbiValue::List(items));
+            }
+        }
+
+        match base {
+            "bool" => match input.read(1)? {
+                [0] => Ok(AbiValue::Bool(false)),
+                [1] => Ok(AbiValue::Bool(true)),
+                _ => Err("invalid bool".to_string()),
+            },
+            "Address" => decode_address(input.read(32)?),
+            "H256" => Ok(AbiValue::Bytes(input.read(32)?.to_vec())),
+            "CodeMetadata" => Ok(decode_int(input.read(2)?, false)),
+            "Option" if args.len() == 1 => match input.read(1)? {
+                [0] => Ok(AbiValue::Option(None)),
+                [1] => {
+                    let value = self.nested_decode(args[0], input)?;
+                    Ok(AbiValue::Option(Some(Box::new(value))))
+                },
+                _ => Err("invalid Option".to_string()),
+            },
+            "List" | "vec" | "Queue" if args.len() == 1 => {
+                let len = input.read_len()?;
+                let items = (0..len)
+                    .map(|_| self.nested_decode(args[0], input))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(AbiValue::List(items))
+            },
+            "tuple" => {
+                let items = args
+                    .iter()
+                    .map(|arg| self.nested_decode(arg, input))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(AbiValue::Tuple(items))
+            },
+            _ => self.nested_decode_described(type_name, input),
+        }
+    }
+
+    fn nested_decode_described(
+        &self,
+        type_name: &str,
+        input: &mut NestedInput,
+    ) -> Result<AbiValue, String> {
+        let description = self
+            .types
+            .get(type_name)
+            .ok_or_else(|| format!("type {type_name} not found in ABI"))?;
+        match description.content_type.as_str() {
+            "struct" => Ok(AbiValue::Struct {
+                type_name: type_name.to_string(),
+                fields: self.nested_decode_fields(&description.fields, input)?,
+            }),
+            "enum" => {
+                let discriminant = decode_discriminant(input.read(description.discriminant_len())?);
+                self.enum_variant(type_name, description, discriminant, input)
+            },
+            other => Err(format!("type {type_name} has unknown kind {other}")),
+        }
+    }
+
+    fn enum_variant(
+        &self,
+        type_name: &str,
+        description: &TypeDescriptionJson,
+        discriminant: usize,
+        input: &mut NestedInput,
+    ) -> Result<AbiValue, String> {
+        let variant = description
+            .variants
+            .iter()
+            .find(|variant| variant.discriminant == discriminant)
+            .ok_or_else(|| {
+                format!("{type_name} has no variant with discriminant {discriminant}")
+            })?;
+        Ok(AbiValue::Enum {
+            type_name: type_name.to_string(),
+            variant: variant.name.clone(),
+            discriminant,
+            fields: self.nested_decode_fields(&variant.fields, input)?,
+        })
+    }
+
+    fn nested_decode_fields(
+        &self,
+        fields: &[StructFieldDescriptionJson],
+        input: &mut NestedInput,
+    ) -> Result<Vec<AbiField>, String> {
+        fields
+            .iter()
+            .map(|field| {
+                self.nested_decode(&field.field_type, input)
+                    .map(|value| AbiField {
+                        name: field.name.clone(),
+                        value,
+                    })
+                    .map_err(|err| format!("field {}: {err}", field.name))
+            })
+            .collect()
+    }
+}
+
+fn decode_int(bytes: &[u8], signed: bool) -> AbiValue {
+    if signed {
+        AbiValue::Signed(BigInt::from_signed_bytes_be(bytes))
+    } else {
+        AbiValue::Unsigned(BigUint::from_bytes_be(bytes))
+    }
+}
+
+/// Enum discriminants are `u8` or `u16`, big-endian.
+fn decode_discriminant(bytes: &[u8]) -> usize {
+    bytes
+        .iter()
+        .fold(0, |value, byte| (value << 8) | *byte as usize)
+}
+
+fn decode_string(type_name: &str, bytes: &[u8]) -> Result<AbiValue, String> {
+    String::from_utf8(bytes.to_vec())
+        .map(AbiValue::String)
+        .map_err(|_| format!("invalid UTF-8 in {type_name}"))
+}
+
+fn decode_address(bytes: &[u8]) -> Result<AbiValue, String> {
+    bytes
+        .try_into()
+        .map(AbiValue::Address)
+        .map_err(|_| format!("address must be 32 bytes long, got {}", bytes.len()))
+}
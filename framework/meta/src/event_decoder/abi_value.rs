@@ -0,0 +1,192 @@
+use multiversx_sc::codec::num_bigint::{BigInt, BigUint};
+use std::fmt;
+
+/// A value decoded based on its ABI type, without a Rust type to decode it into.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum AbiValue {
+    Bool(bool),
+
+    /// All unsigned integer types, from `u8` to `BigUint`.
+    Unsigned(BigUint),
+
+    /// All signed integer types, from `i8` to `BigInt`.
+    Signed(BigInt),
+
+    Bytes(Vec<u8>),
+
+    /// UTF-8 strings and token identifiers.
+    String(String),
+
+    Address([u8; 32]),
+
+    Option(Option<Box<AbiValue>>),
+
+    /// Lists, arrays and variadic arguments.
+    List(Vec<AbiValue>),
+
+    /// Tuples and multi-values.
+    Tuple(Vec<AbiValue>),
+
+    Struct {
+        type_name: String,
+        fields: Vec<AbiField>,
+    },
+
+    Enum {
+        type_name: String,
+        variant: String,
+        discriminant: usize,
+        fields: Vec<AbiField>,
+    },
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct AbiField {
+    pub name: String,
+    pub value: AbiValue,
+}
+
+impl AbiValue {
+    pub fn as_biguint(&self) -> Option<&BigUint> {
+        match self {
+            AbiValue::Unsigned(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_u64(&self) -> Option<u64> {
+        self.as_biguint()
+            .and_then(|value| u64::try_from(value).ok())
+    }
+
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            AbiValue::Bytes(bytes) => Some(bytes.as_slice()),
+            AbiValue::String(s) => Some(s.as_bytes()),
+            AbiValue::Address(address) => Some(&address[..]),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            AbiValue::String(s) => Some(s.as_str()),
+            _ => None,
+        }
+    }
+
+    /// The value of a struct or enum variant field.
+    pub fn field(&self, name: &str) -> Option<&AbiValue> {
+        match self {
+            AbiValue::Struct { fields, .. } | AbiValue::Enum { fields, .. } => fields
+                .iter()
+                .find(|field| field.name == name)
+                .map(|field| &field.value),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! abi_value_from_unsigned {
+    ($ty:ty) => {
+        impl From<$ty> for AbiValue {
+            fn from(value: $ty) -> Self {
+                AbiValue::Unsigned(BigUint::from(value))
+            }
+        }
+    };
+}
+
+abi_value_from_unsigned!(u8);
+abi_value_from_unsigned!(u16);
+abi_value_from_unsigned!(u32);
+abi_value_from_unsigned!(u64);
+
+impl From<BigUint> for AbiValue {
+    fn from(value: BigUint) -> Self {
+        AbiValue::Unsigned(value)
+    }
+}
+
+impl From<bool> for AbiValue {
+    fn from(value: bool) -> Self {
+        AbiValue::Bool(value)
+    }
+}
+
+impl From<&str> for AbiValue {
+    fn from(value: &str) -> Self {
+        AbiValue::String(value.to_string())
+    }
+}
+
+fn fmt_hex(f: &mut fmt::Formatter<'_>, bytes: &[u8]) -> fmt::Result {
+    write!(f, "0x")?;
+    for byte in bytes {
+        write!(f, "{byte:02x}")?;
+    }
+    Ok(())
+}
+
+fn fmt_list(f: &mut fmt::Formatter<'_>, items: &[AbiValue]) -> fmt::Result {
+    for (index, item) in items.iter().enumerate() {
+        if index > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{item}")?;
+    }
+    Ok(())
+}
+
+fn fmt_fields(f: &mut fmt::Formatter<'_>, fields: &[AbiField]) -> fmt::Result {
+    write!(f, " {{ ")?;
+    for (index, field) in fields.iter().enumerate() {
+        if index > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{}: {}", field.name, field.value)?;
+    }
+    write!(f, " }}")
+}
+
+impl fmt::Display for AbiValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AbiValue::Bool(value) => write!(f, "{value}"),
+            AbiValue::Unsigned(value) => write!(f, "{value}"),
+            AbiValue::Signed(value) => write!(f, "{value}"),
+            AbiValue::Bytes(bytes) => fmt_hex(f, bytes),
+            AbiValue::String(s) => write!(f, "{s:?}"),
+            AbiValue::Address(address) => fmt_hex(f, &address[..]),
+            AbiValue::Option(None) => write!(f, "None"),
+            AbiValue::Option(Some(value)) => write!(f, "Some({value})"),
+            AbiValue::List(items) => {
+                write!(f, "[")?;
+                fmt_list(f, items)?;
+                write!(f, "]")
+            },
+            AbiValue::Tuple(items) => {
+                write!(f, "(")?;
+                fmt_list(f, items)?;
+                write!(f, ")")
+            },
+            AbiValue::Struct { type_name, fields } => {
+                write!(f, "{type_name}")?;
+                fmt_fields(f, fields)
+            },
+            AbiValue::Enum {
+                type_name,
+                variant,
+                fields,
+                ..
+            } => {
+                write!(f, "{type_name}::{variant}")?;
+                if fields.is_empty() {
+                    Ok(())
+                } else {
+                    fmt_fields(f, fields)
+                }
+            },
+        }
+    }
+}
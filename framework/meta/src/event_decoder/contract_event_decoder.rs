@@ -0,0 +1,191 @@
+use crate::abi_json::{split_type_name, ContractAbiJson, EndpointAbiJson, EventAbiJson};
+use multiversx_sc::{abi::ContractAbi, contract_base::ContractAbiProvider};
+use std::{iter::Peekable, slice::Iter};
+
+use super::{
+    abi_type_decoder::AbiTypeDecoder, AbiValue, DecodedEvent, DecodedEventField, EventDecodeError,
+};
+
+/// Decodes event logs into named events with typed fields, based on the events declared in a contract ABI.
+///
+/// Logs are matched to events by their first topic, which is the event identifier.
+/// The indexed event arguments are each encoded in the following topics,
+/// in order, and the only non-indexed argument, if any, in the log data.
+///
+/// Endpoint results are multi-encoded the same way as the indexed arguments, so it can decode them too.
+pub struct EventDecoder {
+    abi: ContractAbiJson,
+}
+
+type Topics<'t> = Peekable<Iter<'t, Vec<u8>>>;
+
+impl EventDecoder {
+    pub fn new(abi: ContractAbiJson) -> Self {
+        EventDecoder { abi }
+    }
+
+    pub fn from_abi(abi: &ContractAbi) -> Self {
+        EventDecoder::new(ContractAbiJson::from(abi))
+    }
+
+    /// Uses the event types of a contract crate, e.g. `EventDecoder::from_contract::<adder::AbiProvider>()`.
+    pub fn from_contract<Abi: ContractAbiProvider>() -> Self {
+        EventDecoder::from_abi(&Abi::abi())
+    }
+
+    /// Loads a `*.abi.json` file.
+    pub fn from_abi_file(path: &str) -> Self {
+        EventDecoder::new(crate::abi_json::read_abi_json(path))
+    }
+
+    pub fn find_event_abi(&self, identifier: &str) -> Option<&EventAbiJson> {
+        self.abi
+            .events
+            .iter()
+            .find(|event| event.identifier == identifier)
+    }
+
+    pub fn find_endpoint_abi(&self, name: &str) -> Option<&EndpointAbiJson> {
+        self.abi
+            .endpoints
+            .iter()
+            .find(|endpoint| endpoint.name == name)
+    }
+
+    /// Decodes multi-encoded values, e.g. the results of an endpoint, one value for each of the given types.
+    ///
+    /// Multi-value types can take up several of the raw values, or none at all.
+    pub fn decode_multi_values(
+        &self,
+        type_names: &[&str],
+        values: &[Vec<u8>],
+    ) -> Result<Vec<AbiValue>, String> {
+        let type_decoder = AbiTypeDecoder {
+            types: &self.abi.types,
+        };
+        let mut values_iter = values.iter().peekable();
+        let decoded = type_names
+            .iter()
+            .map(|type_name| decode_topics(&type_decoder, type_name, &mut values_iter))
+            .collect::<Result<Vec<_>, _>>()?;
+        let remaining_values = values_iter.count();
+        if remaining_values > 0 {
+            return Err(format!("{remaining_values} values left over"));
+        }
+        Ok(decoded)
+    }
+
+    /// Decodes a log, given its raw topics (including the event identifier) and data.
+    pub fn decode(
+        &self,
+        topics: &[Vec<u8>],
+        data: &[u8],
+    ) -> Result<DecodedEvent, EventDecodeError> {
+        let (identifier_topic, topics) = topics
+            .split_first()
+            .ok_or(EventDecodeError::MissingIdentifier)?;
+        let identifier = String::from_utf8_lossy(identifier_topic).to_string();
+        let event_abi = self
+            .find_event_abi(&identifier)
+            .ok_or_else(|| EventDecodeError::UnknownEvent(identifier.clone()))?;
+
+        let type_decoder = AbiTypeDecoder {
+            types: &self.abi.types,
+        };
+        let mut topics_iter = topics.iter().peekable();
+        let mut fields = Vec::with_capacity(event_abi.inputs.len());
+        for input in &event_abi.inputs {
+            let indexed = input.indexed.unwrap_or(false);
+            let decode_result = if indexed {
+                decode_topics(&type_decoder, &input.type_name, &mut topics_iter)
+            } else {
+                type_decoder.top_decode(&input.type_name, data)
+            };
+            let value = decode_result.map_err(|message| EventDecodeError::InvalidEncoding {
+                identifier: identifier.clone(),
+                field: input.arg_name.clone(),
+                message,
+            })?;
+            fields.push(DecodedEventField {
+                name: input.arg_name.clone(),
+                type_name: input.type_name.clone(),
+                indexed,
+                value,
+            });
+        }
+
+        let remaining_topics = topics_iter.count();
+        if remaining_topics > 0 {
+            return Err(EventDecodeError::TooManyTopics {
+                identifier,
+                expected: topics.len() - remaining_topics + 1,
+                actual: topics.len() + 1,
+            });
+        }
+
+        Ok(DecodedEvent { identifier, fields })
+    }
+
+    /// Same as `decode`, but logs of events missing from the ABI yield `None`,
+    /// so that logs of built-in functions or of other contracts can be skipped.
+    pub fn decode_if_known(
+        &self,
+        topics: &[Vec<u8>],
+        data: &[u8],
+    ) -> Result<Option<DecodedEvent>, EventDecodeError> {
+        match self.decode(topics, data) {
+            Ok(event) => Ok(Some(event)),
+            Err(EventDecodeError::UnknownEvent(_)) | Err(EventDecodeError::MissingIdentifier) => {
+                Ok(None)
+            },
+            Err(err) => Err(err),
+        }
+    }
+}
+
+/// Indexed arguments are multi-encoded, so multi-value types can take up several topics,
+/// or none at all.
+fn decode_topics(
+    type_decoder: &AbiTypeDecoder,
+    type_name: &str,
+    topics: &mut Topics,
+) -> Result<AbiValue, String> {
+    let (base, args) = split_type_name(type_name);
+    match base {
+        "variadic" if args.len() == 1 => {
+            let mut items = Vec::new();
+            while topics.peek().is_some() {
+                items.push(decode_topics(type_decoder, args[0], topics)?);
+            }
+            Ok(AbiValue::List(items))
+        },
+        "counted-variadic" if args.len() == 1 => {
+            let count = decode_topics(type_decoder, "u32", topics)?
+                .as_u64()
+                .unwrap_or_default();
+            let items = (0..count)
+                .map(|_| decode_topics(type_decoder, args[0], topics))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(AbiValue::List(items))
+        },
+        "optional" if args.len() == 1 => {
+            if topics.peek().is_some() {
+                let value = decode_topics(type_decoder, args[0], topics)?;
+                Ok(AbiValue::Option(Some(Box::new(value))))
+            } else {
+                Ok(AbiValue::Option(None))
+            }
+        },
+        "multi" => {
+            let items = args
+                .iter()
+                .map(|arg| decode_topics(type_decoder, arg, topics))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(AbiValue::Tuple(items))
+        },
+        _ => {
+            let topic = topics.next().ok_or_else(|| "missing topic".to_string())?;
+            type_decoder.top_decode(type_name, topic)
+        },
+    }
+}
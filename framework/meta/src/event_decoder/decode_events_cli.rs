@@ -0,0 +1,88 @@
+use crate::cli_args::DecodeEventsArgs;
+use serde_json::Value;
+use std::fs;
+
+use super::EventDecoder;
+
+/// A log as it appears in the transaction JSON returned by the API, with base64 topics and data.
+pub struct TxJsonLog {
+    pub endpoint: String,
+    pub topics: Vec<Vec<u8>>,
+    pub data: Vec<u8>,
+}
+
+pub fn decode_events(args: &DecodeEventsArgs) {
+    let decoder = EventDecoder::from_abi_file(&args.abi);
+    let tx_path = &args.tx;
+    let tx_json_str =
+        fs::read_to_string(tx_path).unwrap_or_else(|err| panic!("failed to read {tx_path}: {err}"));
+    let tx_json: Value = serde_json::from_str(&tx_json_str)
+        .unwrap_or_else(|err| panic!("failed to parse {tx_path}: {err}"));
+
+    let logs = collect_tx_json_logs(&tx_json);
+    if logs.is_empty() {
+        println!("No logs found.");
+        return;
+    }
+
+    for log in &logs {
+        match decoder.decode(&log.topics, &log.data) {
+            Ok(event) => println!("[{}] {event}", log.endpoint),
+            Err(err) => println!("[{}] could not decode: {err}", log.endpoint),
+        }
+    }
+}
+
+/// Collects the logs of the transaction and of all its smart contract results,
+/// from either the full API response or just the transaction object.
+pub fn collect_tx_json_logs(tx_json: &Value) -> Vec<TxJsonLog> {
+    let mut logs = Vec::new();
+    collect_logs_recursive(tx_json, &mut logs);
+    logs
+}
+
+fn collect_logs_recursive(json: &Value, logs: &mut Vec<TxJsonLog>) {
+    match json {
+        Value::Object(map) => {
+            if let Some(Value::Array(events)) = map.get("events") {
+                logs.extend(events.iter().filter_map(parse_tx_json_log));
+            }
+            for (key, value) in map {
+                if key != "events" {
+                    collect_logs_recursive(value, logs);
+                }
+            }
+        },
+        Value::Array(items) => {
+            for item in items {
+                collect_logs_recursive(item, logs);
+            }
+        },
+        _ => {},
+    }
+}
+
+fn parse_tx_json_log(event_json: &Value) -> Option<TxJsonLog> {
+    let endpoint = event_json.get("identifier")?.as_str()?.to_string();
+    let topics = match event_json.get("topics") {
+        Some(Value::Array(topics)) => topics
+            .iter()
+            .map(|topic| decode_base64(topic.as_str().unwrap_or_default()))
+            .collect(),
+        _ => Vec::new(),
+    };
+    let data = event_json
+        .get("data")
+        .and_then(Value::as_str)
+        .map(decode_base64)
+        .unwrap_or_default();
+    Some(TxJsonLog {
+        endpoint,
+        topics,
+        data,
+    })
+}
+
+fn decode_base64(encoded: &str) -> Vec<u8> {
+    base64::decode(encoded).unwrap_or_else(|err| panic!("invalid base64 {encoded:?}: {err}"))
+}
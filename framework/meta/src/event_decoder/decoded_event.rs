@@ -0,0 +1,85 @@
+use std::fmt;
+
+use super::AbiValue;
+
+/// An event log, with its topics and data decoded according to the contract ABI.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DecodedEvent {
+    pub identifier: String,
+    pub fields: Vec<DecodedEventField>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DecodedEventField {
+    pub name: String,
+    pub type_name: String,
+    pub indexed: bool,
+    pub value: AbiValue,
+}
+
+impl DecodedEvent {
+    pub fn field(&self, name: &str) -> Option<&AbiValue> {
+        self.fields
+            .iter()
+            .find(|field| field.name == name)
+            .map(|field| &field.value)
+    }
+}
+
+impl fmt::Display for DecodedEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}(", self.identifier)?;
+        for (index, field) in self.fields.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}: {}", field.name, field.value)?;
+        }
+        write!(f, ")")
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum EventDecodeError {
+    /// Logs without topics have no event identifier.
+    MissingIdentifier,
+
+    /// The event identifier is not declared in the ABI, e.g. logs of built-in functions.
+    UnknownEvent(String),
+
+    InvalidEncoding {
+        identifier: String,
+        field: String,
+        message: String,
+    },
+
+    TooManyTopics {
+        identifier: String,
+        expected: usize,
+        actual: usize,
+    },
+}
+
+impl fmt::Display for EventDecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EventDecodeError::MissingIdentifier => write!(f, "log has no topics"),
+            EventDecodeError::UnknownEvent(identifier) => {
+                write!(f, "event {identifier:?} not found in ABI")
+            },
+            EventDecodeError::InvalidEncoding {
+                identifier,
+                field,
+                message,
+            } => write!(f, "event {identifier:?}, field {field}: {message}"),
+            EventDecodeError::TooManyTopics {
+                identifier,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "event {identifier:?} expects {expected} topics, got {actual}"
+            ),
+        }
+    }
+}
@@ -0,0 +1,10 @@
+mod abi_type_decoder;
+mod abi_value;
+mod contract_event_decoder;
+mod decode_events_cli;
+mod decoded_event;
+
+pub use abi_value::{AbiField, AbiValue};
+pub use contract_event_decoder::EventDecoder;
+pub use decode_events_cli::{collect_tx_json_logs, decode_events, TxJsonLog};
+pub use decoded_event::{DecodedEvent, DecodedEventField, EventDecodeError};
@@ -14,6 +14,7 @@ pub const FRAMEWORK_CRATE_NAMES: &[&str] = &[
     "multiversx-sc-scenario",
     "multiversx-sc-wasm-adapter",
     "multiversx-sc-modules",
+    "multiversx-sc-snippets",
     "elrond-wasm",
     "elrond-wasm-debug",
     "elrond-wasm-modules",
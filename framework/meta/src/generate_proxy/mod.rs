@@ -0,0 +1,174 @@
+mod proxy_trait_gen;
+mod proxy_type_gen;
+mod proxy_type_map;
+
+use crate::{
+    abi_json::{read_abi_json, ContractAbiJson},
+    cli_args::GenerateProxyArgs,
+};
+use std::{fmt::Write, fs, process::Command};
+
+pub use proxy_trait_gen::rust_method_name;
+pub use proxy_type_map::ProxyTypeMap;
+
+const FRAMEWORK_VERSION: &str = env!("CARGO_PKG_VERSION");
+const RUSTFMT_NAME: &str = "rustfmt";
+
+pub(crate) fn write_docs(code: &mut String, docs: &[String], indent: &str) {
+    for line in docs {
+        if line.is_empty() {
+            writeln!(code, "{indent}///").unwrap();
+        } else {
+            writeln!(code, "{indent}/// {line}").unwrap();
+        }
+    }
+}
+
+/// Name of the generated crate, unless specified otherwise, e.g. `adder-proxy`.
+pub fn default_proxy_crate_name(abi: &ContractAbiJson) -> String {
+    format!("{}-proxy", abi.build_info.contract_crate.name)
+}
+
+/// The source of the proxy crate: the type definitions and the proxy trait.
+///
+/// Types that already exist in the framework, such as `EsdtTokenPayment`, are not generated again.
+pub fn generate_proxy_lib_rs(abi: &ContractAbiJson) -> String {
+    let endpoint_type_names: Vec<&str> = abi
+        .constructor
+        .iter()
+        .chain(abi.upgrade_constructor.iter())
+        .flat_map(|constructor| {
+            let inputs = constructor
+                .inputs
+                .iter()
+                .map(|input| input.type_name.as_str());
+            let outputs = constructor
+                .outputs
+                .iter()
+                .map(|output| output.type_name.as_str());
+            inputs.chain(outputs)
+        })
+        .chain(abi.endpoints.iter().flat_map(|endpoint| {
+            let inputs = endpoint.inputs.iter().map(|input| input.type_name.as_str());
+            let outputs = endpoint
+                .outputs
+                .iter()
+                .map(|output| output.type_name.as_str());
+            inputs.chain(outputs)
+        }))
+        .collect();
+    let type_map = ProxyTypeMap::new(&abi.types, &endpoint_type_names);
+
+    let mut body = String::new();
+    for (type_name, description) in type_map.generated_types() {
+        writeln!(body).unwrap();
+        if description.content_type == "enum" {
+            proxy_type_gen::write_enum(&mut body, &type_map, type_name, description);
+        } else {
+            proxy_type_gen::write_struct(&mut body, &type_map, type_name, description);
+        }
+    }
+    writeln!(body).unwrap();
+    proxy_trait_gen::write_proxy_trait(&mut body, abi, &type_map);
+
+    let mut code = String::new();
+    writeln!(
+        code,
+        "// Code generated by the multiversx-sc proxy generator. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Contract:                             {}
+// Types:                                {}
+// Endpoints:                            {}
+
+#![no_std]
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();",
+        abi.name,
+        type_map.generated_types().count(),
+        abi.endpoints.len(),
+    )
+    .unwrap();
+
+    // heap types are not part of the prelude unless the "alloc" feature is on
+    let heap_types = type_map.used_heap_types();
+    match heap_types.as_slice() {
+        [] => {},
+        [heap_type] => writeln!(code, "\nuse multiversx_sc::types::heap::{heap_type};").unwrap(),
+        _ => writeln!(
+            code,
+            "\nuse multiversx_sc::types::heap::{{{}}};",
+            heap_types.join(", ")
+        )
+        .unwrap(),
+    }
+
+    code.push_str(&body);
+    code
+}
+
+/// The `Cargo.toml` of the proxy crate.
+///
+/// The framework dependency can point to a local path, e.g. when generating inside the framework repository.
+pub fn generate_proxy_cargo_toml(
+    abi: &ContractAbiJson,
+    crate_name: &str,
+    framework_path: Option<&str>,
+) -> String {
+    let mut cargo_toml = format!(
+        r#"[package]
+name = "{crate_name}"
+version = "{}"
+edition = "2021"
+publish = false
+
+[lib]
+path = "src/lib.rs"
+
+[dependencies.multiversx-sc]
+version = "{FRAMEWORK_VERSION}"
+"#,
+        abi.build_info.contract_crate.version,
+    );
+    if let Some(framework_path) = framework_path {
+        writeln!(cargo_toml, "path = \"{framework_path}\"").unwrap();
+    }
+    cargo_toml
+}
+
+pub fn generate_proxy(args: &GenerateProxyArgs) {
+    let abi = read_abi_json(&args.abi);
+    let crate_name = args
+        .crate_name
+        .clone()
+        .unwrap_or_else(|| default_proxy_crate_name(&abi));
+    let output_path = args.output.clone().unwrap_or_else(|| crate_name.clone());
+
+    fs::create_dir_all(format!("{output_path}/src"))
+        .unwrap_or_else(|err| panic!("failed to create {output_path}: {err}"));
+    fs::write(
+        format!("{output_path}/Cargo.toml"),
+        generate_proxy_cargo_toml(&abi, &crate_name, args.framework_path.as_deref()),
+    )
+    .unwrap();
+    let lib_rs_path = format!("{output_path}/src/lib.rs");
+    fs::write(&lib_rs_path, generate_proxy_lib_rs(&abi)).unwrap();
+    format_rust_file(&lib_rs_path);
+
+    println!("Generated proxy crate {crate_name} in {output_path}");
+}
+
+/// The generated code is not formatted, long signatures are left on a single line.
+fn format_rust_file(path: &str) {
+    let status = Command::new(RUSTFMT_NAME)
+        .args(["--edition", "2021", path])
+        .status();
+    let formatted = matches!(status, Ok(status) if status.success());
+    if !formatted {
+        println!("Warning: {RUSTFMT_NAME} not installed, {path} was left unformatted");
+    }
+}
@@ -0,0 +1,139 @@
+use crate::abi_json::{ContractAbiJson, InputAbiJson, OutputAbiJson};
+use std::fmt::Write;
+
+use super::{proxy_type_map::ProxyTypeMap, write_docs};
+
+const PROXY_API: &str = "Self::Api";
+
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "static", "struct", "super", "trait", "true", "type", "unsafe", "use",
+    "where", "while",
+];
+
+/// Endpoint names are usually camelCase in the ABI, e.g. `getSum` becomes `get_sum`.
+pub fn rust_method_name(endpoint_name: &str) -> String {
+    let chars: Vec<char> = endpoint_name.chars().collect();
+    let mut name = String::new();
+    for (index, c) in chars.iter().enumerate() {
+        if c.is_ascii_uppercase() && index > 0 {
+            let prev = chars[index - 1];
+            let next_is_lower =
+                matches!(chars.get(index + 1), Some(next) if next.is_ascii_lowercase());
+            if prev.is_ascii_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_ascii_uppercase() && next_is_lower)
+            {
+                name.push('_');
+            }
+        }
+        name.push(c.to_ascii_lowercase());
+    }
+    rust_ident(&name)
+}
+
+fn rust_ident(name: &str) -> String {
+    if RUST_KEYWORDS.contains(&name) {
+        format!("r#{name}")
+    } else {
+        name.to_string()
+    }
+}
+
+fn write_payable(code: &mut String, payable_in_tokens: &[String]) {
+    match payable_in_tokens {
+        [] => {},
+        [token] => writeln!(code, "    #[payable(\"{token}\")]").unwrap(),
+        _ => writeln!(code, "    #[payable(\"*\")]").unwrap(),
+    }
+}
+
+fn write_method(
+    code: &mut String,
+    type_map: &ProxyTypeMap,
+    method_name: &str,
+    inputs: &[InputAbiJson],
+    outputs: &[OutputAbiJson],
+) {
+    write!(code, "    fn {method_name}(&self").unwrap();
+    for input in inputs {
+        write!(
+            code,
+            ", {}: {}",
+            rust_ident(&input.arg_name),
+            type_map.rust_type(&input.type_name, PROXY_API)
+        )
+        .unwrap();
+    }
+    write!(code, ")").unwrap();
+
+    let output_types: Vec<String> = outputs
+        .iter()
+        .map(|output| type_map.rust_type(&output.type_name, PROXY_API))
+        .collect();
+    match output_types.len() {
+        0 => {},
+        1 => write!(code, " -> {}", output_types[0]).unwrap(),
+        len => write!(code, " -> MultiValue{len}<{}>", output_types.join(", ")).unwrap(),
+    }
+    writeln!(code, ";").unwrap();
+}
+
+pub(crate) fn write_proxy_trait(code: &mut String, abi: &ContractAbiJson, type_map: &ProxyTypeMap) {
+    write_docs(code, &abi.docs, "");
+    writeln!(code, "#[multiversx_sc::proxy]").unwrap();
+    writeln!(code, "pub trait {} {{", abi.name).unwrap();
+
+    let mut first = true;
+    if let Some(constructor) = &abi.constructor {
+        write_docs(code, &constructor.docs, "    ");
+        writeln!(code, "    #[init]").unwrap();
+        write_payable(code, &constructor.payable_in_tokens);
+        write_method(
+            code,
+            type_map,
+            "init",
+            &constructor.inputs,
+            &constructor.outputs,
+        );
+        first = false;
+    }
+
+    if let Some(upgrade_constructor) = &abi.upgrade_constructor {
+        if !first {
+            writeln!(code).unwrap();
+        }
+        first = false;
+
+        write_docs(code, &upgrade_constructor.docs, "    ");
+        writeln!(code, "    #[upgrade]").unwrap();
+        write_payable(code, &upgrade_constructor.payable_in_tokens);
+        write_method(
+            code,
+            type_map,
+            "upgrade",
+            &upgrade_constructor.inputs,
+            &upgrade_constructor.outputs,
+        );
+    }
+
+    for endpoint in &abi.endpoints {
+        if !first {
+            writeln!(code).unwrap();
+        }
+        first = false;
+
+        write_docs(code, &endpoint.docs, "    ");
+        write_payable(code, &endpoint.payable_in_tokens);
+        writeln!(code, "    #[endpoint({})]", endpoint.name).unwrap();
+        write_method(
+            code,
+            type_map,
+            &rust_method_name(&endpoint.name),
+            &endpoint.inputs,
+            &endpoint.outputs,
+        );
+    }
+    writeln!(code, "}}").unwrap();
+}
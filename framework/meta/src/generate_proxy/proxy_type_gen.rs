@@ -0,0 +1,130 @@
+use crate::abi_json::{StructFieldDescriptionJson, TypeDescriptionJson};
+use std::fmt::Write;
+
+use super::{proxy_type_map::ProxyTypeMap, write_docs};
+
+const TYPE_API: &str = "M";
+
+/// Tuple structs and tuple variants have their fields named by index in the ABI.
+fn has_tuple_fields(fields: &[StructFieldDescriptionJson]) -> bool {
+    !fields.is_empty()
+        && fields
+            .iter()
+            .all(|field| field.name.chars().all(|c| c.is_ascii_digit()))
+}
+
+fn write_derives(code: &mut String, type_map: &ProxyTypeMap, type_name: &str) {
+    let mut derives = vec![
+        "TopEncode",
+        "TopDecode",
+        "NestedEncode",
+        "NestedDecode",
+        "TypeAbi",
+    ];
+    if type_map.is_vec_item(type_name) {
+        derives.push("ManagedVecItem");
+    }
+    derives.extend(["Clone", "PartialEq", "Debug"]);
+    writeln!(code, "#[derive({})]", derives.join(", ")).unwrap();
+}
+
+fn type_header(type_map: &ProxyTypeMap, type_name: &str) -> String {
+    if type_map.is_generic(type_name) {
+        format!("{type_name}<{TYPE_API}: ManagedTypeApi>")
+    } else {
+        type_name.to_string()
+    }
+}
+
+fn write_fields(
+    code: &mut String,
+    type_map: &ProxyTypeMap,
+    defined_type: &str,
+    fields: &[StructFieldDescriptionJson],
+    indent: &str,
+    visibility: &str,
+) {
+    let field_type = |field: &StructFieldDescriptionJson| {
+        type_map.rust_type_in_definition(&field.field_type, TYPE_API, Some(defined_type))
+    };
+
+    if has_tuple_fields(fields) {
+        let field_types: Vec<String> = fields
+            .iter()
+            .map(|field| format!("{visibility}{}", field_type(field)))
+            .collect();
+        write!(code, "({})", field_types.join(", ")).unwrap();
+        return;
+    }
+
+    if fields.is_empty() {
+        write!(code, " {{}}").unwrap();
+        return;
+    }
+
+    writeln!(code, " {{").unwrap();
+    for field in fields {
+        write_docs(code, &field.docs, &format!("{indent}    "));
+        writeln!(
+            code,
+            "{indent}    {visibility}{}: {},",
+            field.name,
+            field_type(field)
+        )
+        .unwrap();
+    }
+    write!(code, "{indent}}}").unwrap();
+}
+
+pub(crate) fn write_struct(
+    code: &mut String,
+    type_map: &ProxyTypeMap,
+    type_name: &str,
+    description: &TypeDescriptionJson,
+) {
+    write_docs(code, &description.docs, "");
+    let tuple = has_tuple_fields(&description.fields);
+    write_derives(code, type_map, type_name);
+    write!(code, "pub struct {}", type_header(type_map, type_name)).unwrap();
+    write_fields(code, type_map, type_name, &description.fields, "", "pub ");
+    if tuple {
+        write!(code, ";").unwrap();
+    }
+    writeln!(code).unwrap();
+}
+
+/// Discriminants that do not follow the previous variant are pinned explicitly.
+pub(crate) fn write_enum(
+    code: &mut String,
+    type_map: &ProxyTypeMap,
+    type_name: &str,
+    description: &TypeDescriptionJson,
+) {
+    write_docs(code, &description.docs, "");
+    write_derives(code, type_map, type_name);
+    if description.discriminant_len() == 2 {
+        writeln!(code, "#[codec(discriminant_type = \"u16\")]").unwrap();
+    }
+    writeln!(code, "pub enum {} {{", type_header(type_map, type_name)).unwrap();
+
+    let mut expected_discriminant = 0;
+    for variant in &description.variants {
+        write_docs(code, &variant.docs, "    ");
+        if variant.discriminant != expected_discriminant {
+            writeln!(
+                code,
+                "    #[codec(discriminant = {})]",
+                variant.discriminant
+            )
+            .unwrap();
+        }
+        expected_discriminant = variant.discriminant + 1;
+
+        write!(code, "    {}", variant.name).unwrap();
+        if !variant.fields.is_empty() {
+            write_fields(code, type_map, type_name, &variant.fields, "    ", "");
+        }
+        writeln!(code, ",").unwrap();
+    }
+    writeln!(code, "}}").unwrap();
+}
@@ -0,0 +1,343 @@
+use crate::abi_json::{split_type_name, TypeDescriptionJson};
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, BTreeSet},
+};
+
+/// Types described in the ABI that already exist in the framework, so they are not generated again.
+const FRAMEWORK_DESCRIBED_TYPES: &[&str] = &[
+    "EsdtTokenPayment",
+    "EgldOrEsdtTokenPayment",
+    "EsdtTokenData",
+    "EsdtTokenType",
+    "EsdtLocalRole",
+];
+
+/// Framework types that are generic over the managed type API.
+fn framework_managed_type(base: &str) -> Option<&'static str> {
+    match base {
+        "BigUint" => Some("BigUint"),
+        "BigInt" => Some("BigInt"),
+        "bytes" | "utf-8 string" => Some("ManagedBuffer"),
+        "Address" => Some("ManagedAddress"),
+        "TokenIdentifier" => Some("TokenIdentifier"),
+        "EgldOrEsdtTokenIdentifier" => Some("EgldOrEsdtTokenIdentifier"),
+        "EsdtTokenPayment" => Some("EsdtTokenPayment"),
+        "EgldOrEsdtTokenPayment" => Some("EgldOrEsdtTokenPayment"),
+        "EsdtTokenData" => Some("EsdtTokenData"),
+        _ => None,
+    }
+}
+
+/// Framework types that can be stored in managed vectors.
+fn framework_vec_item_type(base: &str) -> bool {
+    matches!(
+        base,
+        "u8" | "u16"
+            | "u32"
+            | "u64"
+            | "i32"
+            | "i64"
+            | "bool"
+            | "BigUint"
+            | "BigInt"
+            | "bytes"
+            | "utf-8 string"
+            | "Address"
+            | "TokenIdentifier"
+            | "H256"
+            | "EsdtTokenPayment"
+            | "EsdtLocalRole"
+    )
+}
+
+/// Framework types that are used as they are.
+fn framework_plain_type(base: &str) -> Option<&'static str> {
+    match base {
+        "u8" => Some("u8"),
+        "u16" => Some("u16"),
+        "u32" => Some("u32"),
+        "u64" => Some("u64"),
+        "i8" => Some("i8"),
+        "i16" => Some("i16"),
+        "i32" => Some("i32"),
+        "i64" => Some("i64"),
+        "bool" => Some("bool"),
+        "()" => Some("()"),
+        "NonZeroUsize" => Some("NonZeroUsize"),
+        "CodeMetadata" => Some("CodeMetadata"),
+        "EsdtTokenType" => Some("EsdtTokenType"),
+        "EsdtLocalRole" => Some("EsdtLocalRole"),
+        "OperationCompletionStatus" => Some("OperationCompletionStatus"),
+        "ignore" => Some("IgnoreValue"),
+        _ => None,
+    }
+}
+
+/// Maps ABI type names to Rust types, for the types generated from the ABI type descriptions.
+pub struct ProxyTypeMap<'a> {
+    pub types: &'a BTreeMap<String, TypeDescriptionJson>,
+
+    /// Described types that contain managed types, so they need to be generic over the API.
+    managed_types: BTreeSet<String>,
+
+    /// Described types that can be stored in managed vectors.
+    vec_item_capable_types: BTreeSet<String>,
+
+    /// Described types that appear as list items, so they need to be stored in managed vectors.
+    vec_item_types: BTreeSet<String>,
+
+    /// Heap types used so far, which need to be imported explicitly.
+    heap_types: RefCell<BTreeSet<&'static str>>,
+}
+
+impl<'a> ProxyTypeMap<'a> {
+    pub fn new(types: &'a BTreeMap<String, TypeDescriptionJson>, used_type_names: &[&str]) -> Self {
+        let mut type_map = ProxyTypeMap {
+            types,
+            managed_types: BTreeSet::new(),
+            vec_item_capable_types: BTreeSet::new(),
+            vec_item_types: BTreeSet::new(),
+            heap_types: RefCell::new(BTreeSet::new()),
+        };
+
+        // only structs with named fields and fieldless enums can derive `ManagedVecItem`,
+        // recursive types never qualify, since they start out as not capable
+        loop {
+            let newly_capable: Vec<String> = type_map
+                .generated_types()
+                .filter(|(name, _)| !type_map.vec_item_capable_types.contains(*name))
+                .filter(|(_, description)| type_map.can_derive_vec_item(description))
+                .map(|(name, _)| name.clone())
+                .collect();
+            if newly_capable.is_empty() {
+                break;
+            }
+            type_map.vec_item_capable_types.extend(newly_capable);
+        }
+
+        // a type is managed if any of its fields is, this is propagated until nothing changes
+        loop {
+            let newly_managed: Vec<String> = type_map
+                .generated_types()
+                .filter(|(name, _)| !type_map.managed_types.contains(*name))
+                .filter(|(_, description)| {
+                    all_fields(description).any(|field_type| type_map.is_managed(field_type))
+                })
+                .map(|(name, _)| name.clone())
+                .collect();
+            if newly_managed.is_empty() {
+                break;
+            }
+            type_map.managed_types.extend(newly_managed);
+        }
+
+        let mut vec_item_types = BTreeSet::new();
+        let field_types = types.values().flat_map(all_fields);
+        for type_name in used_type_names.iter().copied().chain(field_types) {
+            collect_vec_items(type_name, &mut vec_item_types);
+        }
+        vec_item_types.retain(|type_name| type_map.vec_item_capable_types.contains(type_name));
+        type_map.vec_item_types = vec_item_types;
+
+        type_map
+    }
+
+    /// The described types for which Rust definitions need to be generated.
+    pub fn generated_types(&self) -> impl Iterator<Item = (&'a String, &'a TypeDescriptionJson)> {
+        self.types
+            .iter()
+            .filter(|(name, _)| !FRAMEWORK_DESCRIBED_TYPES.contains(&name.as_str()))
+    }
+
+    pub fn is_generic(&self, type_name: &str) -> bool {
+        self.managed_types.contains(type_name)
+    }
+
+    pub fn is_vec_item(&self, type_name: &str) -> bool {
+        self.vec_item_types.contains(type_name)
+    }
+
+    /// Heap types used by the types returned so far, e.g. `Box` for recursive types.
+    pub fn used_heap_types(&self) -> Vec<&'static str> {
+        self.heap_types.borrow().iter().copied().collect()
+    }
+
+    fn can_derive_vec_item(&self, description: &TypeDescriptionJson) -> bool {
+        if description.content_type == "enum" {
+            !description.variants.is_empty()
+                && description
+                    .variants
+                    .iter()
+                    .all(|variant| variant.fields.is_empty())
+        } else {
+            !description.fields.is_empty()
+                && description.fields.iter().all(|field| {
+                    !field.name.chars().all(|c| c.is_ascii_digit())
+                        && self.is_managed_vec_item(&field.field_type)
+                })
+        }
+    }
+
+    /// Lists of these types become managed vectors, lists of other types heap vectors.
+    fn is_managed_vec_item(&self, type_name: &str) -> bool {
+        let (base, args) = split_type_name(type_name);
+        if framework_vec_item_type(base) || self.vec_item_capable_types.contains(base) {
+            return true;
+        }
+        match base {
+            "Option" | "List" | "vec" | "Queue" => {
+                args.len() == 1 && self.is_managed_vec_item(args[0])
+            },
+            _ => base.starts_with("array") && args == ["u8"],
+        }
+    }
+
+    fn use_heap_type(&self, heap_type: &'static str) -> &'static str {
+        self.heap_types.borrow_mut().insert(heap_type);
+        heap_type
+    }
+
+    fn is_managed(&self, type_name: &str) -> bool {
+        let (base, args) = split_type_name(type_name);
+        if framework_managed_type(base).is_some() || self.managed_types.contains(base) {
+            return true;
+        }
+        match base {
+            "List" | "vec" | "Queue" if args.len() == 1 => {
+                self.is_managed_vec_item(args[0]) || self.is_managed(args[0])
+            },
+            "H256" | "variadic" | "counted-variadic" | "AsyncCallResult" => true,
+            _ => args.iter().any(|arg| self.is_managed(arg)),
+        }
+    }
+
+    /// Whether a field of this type refers back to the type being defined, without any indirection.
+    /// Such fields need to be boxed, otherwise the type would have an infinite size.
+    fn is_recursive(
+        &self,
+        type_name: &str,
+        defined_type: &str,
+        visited: &mut BTreeSet<String>,
+    ) -> bool {
+        let (base, args) = split_type_name(type_name);
+        if base == defined_type {
+            return true;
+        }
+        match base {
+            "List" | "vec" | "Queue" => false,
+            _ => {
+                if args
+                    .iter()
+                    .any(|arg| self.is_recursive(arg, defined_type, visited))
+                {
+                    return true;
+                }
+                match self.types.get(base) {
+                    Some(description) if visited.insert(base.to_string()) => {
+                        all_fields(description)
+                            .any(|field_type| self.is_recursive(field_type, defined_type, visited))
+                    },
+                    _ => false,
+                }
+            },
+        }
+    }
+
+    /// The Rust type for an ABI type, with `api` as the managed type API,
+    /// e.g. `M` in type definitions or `Self::Api` in the proxy trait.
+    pub fn rust_type(&self, type_name: &str, api: &str) -> String {
+        self.rust_type_in_definition(type_name, api, None)
+    }
+
+    /// Same as `rust_type`, but boxes references to the type being defined.
+    pub fn rust_type_in_definition(
+        &self,
+        type_name: &str,
+        api: &str,
+        defined_type: Option<&str>,
+    ) -> String {
+        let (base, args) = split_type_name(type_name);
+        let arg_type = |index: usize| self.rust_type_in_definition(args[index], api, defined_type);
+        let arg_types = || {
+            (0..args.len())
+                .map(arg_type)
+                .collect::<Vec<String>>()
+                .join(", ")
+        };
+
+        if let Some(managed) = framework_managed_type(base) {
+            return format!("{managed}<{api}>");
+        }
+        if let Some(plain) = framework_plain_type(base) {
+            return plain.to_string();
+        }
+        if let Some(len) = base.strip_prefix("array") {
+            if args.len() == 1 {
+                return format!("[{}; {len}]", arg_type(0));
+            }
+        }
+
+        match base {
+            "H256" => format!("ManagedByteArray<{api}, 32>"),
+            "Option" => format!("Option<{}>", arg_type(0)),
+            "List" | "vec" | "Queue" if self.is_managed_vec_item(args[0]) => {
+                // the items of a managed vector are stored separately, so no boxing is needed
+                format!(
+                    "ManagedVec<{api}, {}>",
+                    self.rust_type_in_definition(args[0], api, None)
+                )
+            },
+            "List" | "vec" | "Queue" => format!(
+                "{}<{}>",
+                self.use_heap_type("Vec"),
+                self.rust_type_in_definition(args[0], api, None)
+            ),
+            "tuple" if args.len() == 1 => format!("({},)", arg_type(0)),
+            "tuple" => format!("({})", arg_types()),
+            "variadic" => format!("MultiValueEncoded<{api}, {}>", arg_type(0)),
+            "counted-variadic" => format!("MultiValueManagedVecCounted<{api}, {}>", arg_type(0)),
+            "optional" => format!("OptionalValue<{}>", arg_type(0)),
+            "multi" => format!("MultiValue{}<{}>", args.len(), arg_types()),
+            "AsyncCallResult" => format!("ManagedAsyncCallResult<{api}, {}>", arg_type(0)),
+            _ => {
+                let rust_type = if self.is_generic(base) {
+                    format!("{base}<{api}>")
+                } else {
+                    base.to_string()
+                };
+                match defined_type {
+                    Some(defined_type)
+                        if self.is_recursive(base, defined_type, &mut BTreeSet::new()) =>
+                    {
+                        format!("{}<{rust_type}>", self.use_heap_type("Box"))
+                    },
+                    _ => rust_type,
+                }
+            },
+        }
+    }
+}
+
+fn all_fields(description: &TypeDescriptionJson) -> impl Iterator<Item = &str> {
+    description
+        .fields
+        .iter()
+        .chain(
+            description
+                .variants
+                .iter()
+                .flat_map(|variant| variant.fields.iter()),
+        )
+        .map(|field| field.field_type.as_str())
+}
+
+fn collect_vec_items(type_name: &str, vec_item_types: &mut BTreeSet<String>) {
+    let (base, args) = split_type_name(type_name);
+    if matches!(base, "List" | "vec" | "Queue") && args.len() == 1 {
+        vec_item_types.insert(split_type_name(args[0]).0.to_string());
+    }
+    for arg in args {
+        collect_vec_items(arg, vec_item_types);
+    }
+}
@@ -4,6 +4,7 @@ use std::{
 };
 
 static SNIPPETS_SOURCE_FILE_NAME: &str = "interact.rs";
+const FRAMEWORK_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 pub(crate) fn create_snippets_folder(snippets_folder_path: &str) {
     // returns error if folder already exists, so we ignore the result
@@ -69,10 +70,10 @@ path = "src/{SNIPPETS_SOURCE_FILE_NAME}"
 path = ".."
 
 [dependencies.multiversx-sc-snippets]
-version = "0.39.2"
+version = "{FRAMEWORK_VERSION}"
 
 [dependencies.multiversx-sc-scenario]
-version = "0.39.2"
+version = "{FRAMEWORK_VERSION}"
 
 [dependencies.multiversx-chain-vm]
 version = "0.1.2"
@@ -32,6 +32,9 @@ pub(crate) fn write_state_struct_impl(
     .unwrap();
 
     write_deploy_method_impl(file, &abi.constructors[0], wasm_output_file_path_expr);
+    if let Some(upgrade_abi) = abi.upgrade_constructors.first() {
+        write_upgrade_method_impl(file, upgrade_abi, wasm_output_file_path_expr);
+    }
 
     for endpoint_abi in &abi.endpoints {
         write_endpoint_impl(file, endpoint_abi);
@@ -84,6 +87,42 @@ fn write_deploy_method_impl(
     write_newline(file);
 }
 
+/// Upgrades go through the `upgradeContract` built-in function, which then calls the `#[upgrade]` endpoint.
+fn write_upgrade_method_impl(
+    file: &mut File,
+    upgrade_abi: &EndpointAbi,
+    wasm_output_file_path_expr: &str,
+) {
+    write_method_declaration(file, "upgrade");
+    write_endpoint_args_declaration(file, &upgrade_abi.inputs);
+
+    writeln!(
+        file,
+        r#"        let tx_hash = self
+            .interactor
+            .sc_call(
+                ScCallStep::new()
+                    .upgrade_contract(
+                        self.contract.{}({}),
+                        {},
+                        &InterpreterContext::default(),
+                        CodeMetadata::all(),
+                    )
+                    .from(&self.wallet_address)
+                    .gas_limit(DEFAULT_GAS_LIMIT),
+            )
+            .await;
+
+        println!("upgrade tx hash: {{}}", tx_hash);
+    }}
+"#,
+        upgrade_abi.rust_method_name,
+        endpoint_args_when_called(upgrade_abi.inputs.as_slice()),
+        wasm_output_file_path_expr
+    )
+    .unwrap();
+}
+
 fn write_endpoint_impl(file: &mut File, endpoint_abi: &EndpointAbi) {
     write_method_declaration(file, endpoint_abi.rust_method_name);
     write_payments_declaration(file, endpoint_abi.payable_in_tokens);
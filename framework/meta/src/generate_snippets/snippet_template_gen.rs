@@ -72,6 +72,9 @@ async fn main() {{
 
     // all contracts have a deploy snippet
     writeln!(file, r#"        "deploy" => state.deploy().await,"#).unwrap();
+    if !abi.upgrade_constructors.is_empty() {
+        writeln!(file, r#"        "upgrade" => state.upgrade().await,"#).unwrap();
+    }
 
     for endpoint in &abi.endpoints {
         writeln!(
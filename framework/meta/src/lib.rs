@@ -1,7 +1,11 @@
+pub mod abi_diff;
 pub mod abi_json;
 mod cargo_toml_contents;
 pub mod cli_args;
+pub mod ei;
+pub mod event_decoder;
 mod folder_structure;
+pub mod generate_proxy;
 mod generate_snippets;
 mod local_deps;
 mod meta_abi;
@@ -9,10 +13,15 @@ mod meta_all;
 mod meta_cli;
 mod meta_config;
 mod meta_info;
-mod meta_validate_abi;
+pub mod meta_validate_abi;
 mod meta_wasm_tools;
 pub mod output_contract;
+pub mod reproducible_build;
 mod sc_upgrade;
+pub mod scenario_fmt;
+pub mod storage_diff;
+pub mod template;
+pub mod test_gen;
 
 pub use cargo_toml_contents::CargoTomlContents;
 pub use meta_cli::{cli_main, cli_main_standalone, multi_contract_config};
@@ -1,15 +1,24 @@
 use super::{
-    meta_config::MetaConfig, meta_validate_abi::validate_abi, output_contract::OutputContractConfig,
+    meta_config::MetaConfig,
+    meta_validate_abi::{check_abi_rules, validate_abi},
+    output_contract::OutputContractConfig,
 };
 use crate::{
+    abi_diff::abi_diff,
     cli_args::{ContractCliAction, ContractCliArgs, StandaloneCliAction, StandaloneCliArgs},
+    event_decoder::decode_events,
+    generate_proxy::generate_proxy,
     local_deps::local_deps,
     meta_all::call_all_meta,
     meta_info::call_info,
     sc_upgrade::upgrade_sc,
+    scenario_fmt::scen,
+    storage_diff::storage_diff,
+    template::create_contract,
+    test_gen::test_gen,
 };
 use clap::Parser;
-use multiversx_sc::contract_base::ContractAbiProvider;
+use multiversx_sc::{abi::ContractAbi, contract_base::ContractAbiProvider};
 
 /// Entry point in the program when calling it as a standalone tool.
 pub fn cli_main_standalone() {
@@ -23,6 +32,13 @@ pub fn cli_main_standalone() {
         Some(StandaloneCliAction::LocalDeps(args)) => {
             local_deps(args);
         },
+        Some(StandaloneCliAction::StorageDiff(args)) => storage_diff(args),
+        Some(StandaloneCliAction::DecodeEvents(args)) => decode_events(args),
+        Some(StandaloneCliAction::GenerateProxy(args)) => generate_proxy(args),
+        Some(StandaloneCliAction::AbiDiff(args)) => abi_diff(args),
+        Some(StandaloneCliAction::New(args)) => create_contract(args),
+        Some(StandaloneCliAction::TestGen(args)) => test_gen(args),
+        Some(StandaloneCliAction::Scen(args)) => scen(args),
         None => {},
     }
 }
@@ -33,13 +49,26 @@ pub fn cli_main<AbiObj: ContractAbiProvider>() {
     let mut meta_config_opt = process_abi::<AbiObj>(&cli_args);
     match cli_args.command {
         ContractCliAction::Abi => {},
-        ContractCliAction::Build(build_args) => meta_config_opt.build(build_args),
+        ContractCliAction::Build(build_args) => {
+            check_abi_rules_on_build(&meta_config_opt.original_contract_abi);
+            meta_config_opt.build(build_args)
+        },
         ContractCliAction::BuildDbg(build_args) => {
+            check_abi_rules_on_build(&meta_config_opt.original_contract_abi);
             meta_config_opt.build(build_args.into_build_args())
         },
         ContractCliAction::Twiggy(build_args) => {
+            check_abi_rules_on_build(&meta_config_opt.original_contract_abi);
             meta_config_opt.build(build_args.into_build_args())
         },
+        ContractCliAction::ReproducibleBuild(args) => {
+            check_abi_rules_on_build(&meta_config_opt.original_contract_abi);
+            meta_config_opt.reproducible_build(&args);
+        },
+        ContractCliAction::Verify(args) => {
+            check_abi_rules_on_build(&meta_config_opt.original_contract_abi);
+            meta_config_opt.verify_build(&args)
+        },
         ContractCliAction::Clean => meta_config_opt.clean(),
         ContractCliAction::Update => meta_config_opt.update(),
         ContractCliAction::GenerateSnippets(gs_args) => {
@@ -48,6 +77,15 @@ pub fn cli_main<AbiObj: ContractAbiProvider>() {
     }
 }
 
+fn check_abi_rules_on_build(abi: &ContractAbi) {
+    if let Err(errors) = check_abi_rules(abi) {
+        for error in &errors {
+            eprintln!("error: {error}");
+        }
+        panic!("Invalid contract ABI, {} problem(s) found.", errors.len());
+    }
+}
+
 fn process_abi<AbiObj: ContractAbiProvider>(cli_args: &ContractCliArgs) -> MetaConfig {
     let input_abi = <AbiObj as ContractAbiProvider>::abi();
     validate_abi(&input_abi).expect("Invalid contract structure");
@@ -1,4 +1,28 @@
-use multiversx_sc::abi::ContractAbi;
+use crate::abi_json::split_type_name;
+use multiversx_sc::{
+    abi::{
+        ContractAbi, EndpointAbi, EndpointMutabilityAbi, SourceLocationAbi, StructFieldDescription,
+        TypeContents,
+    },
+    api::BUILTIN_FUNCTION_NAMES,
+    storage::protected_keys::FRAMEWORK_RESERVED_KEY_PREFIX,
+};
+use std::{collections::BTreeMap, fmt};
+
+/// Primitive types and byte slices do not provide type descriptions, their ABI is implicit.
+const IMPLICIT_TYPE_NAMES: &[&str] = &[
+    "u8",
+    "u16",
+    "u32",
+    "u64",
+    "i8",
+    "i16",
+    "i32",
+    "i64",
+    "NonZeroUsize",
+    "bool",
+    "bytes",
+];
 
 fn validate_abi_constructor(abi: &ContractAbi) -> Result<(), &'static str> {
     match abi.constructors.len() {
@@ -12,3 +36,295 @@ pub fn validate_abi(abi: &ContractAbi) -> Result<(), &'static str> {
     validate_abi_constructor(abi)?;
     Ok(())
 }
+
+/// A problem found in the contract ABI, pointing to the method that caused it, when known.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct AbiValidationError {
+    pub location: Option<SourceLocationAbi>,
+    pub message: String,
+}
+
+impl fmt::Display for AbiValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "{}:{}: {}", location.file, location.line, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+fn error(location: Option<SourceLocationAbi>, message: String) -> AbiValidationError {
+    AbiValidationError { location, message }
+}
+
+/// Checks the contract ABI for mistakes that compile fine, but lead to broken or unusable contracts.
+pub fn validate_abi_rules(abi: &ContractAbi) -> Vec<AbiValidationError> {
+    let mut errors = Vec::new();
+    check_duplicate_endpoints(abi, &mut errors);
+    for endpoint in all_endpoints(abi) {
+        check_builtin_function_name(endpoint, &mut errors);
+        check_multi_value_arguments(endpoint, &mut errors);
+        check_described_types(abi, endpoint, &mut errors);
+    }
+    check_duplicate_events(abi, &mut errors);
+    check_described_event_types(abi, &mut errors);
+    check_reserved_storage_keys(abi, &mut errors);
+    errors
+}
+
+/// Checks the contract ABI for suspicious, but still usable, declarations.
+pub fn validate_abi_warnings(abi: &ContractAbi) -> Vec<AbiValidationError> {
+    let mut warnings = Vec::new();
+    for endpoint in all_endpoints(abi) {
+        check_payable_view(endpoint, &mut warnings);
+    }
+    warnings
+}
+
+fn all_endpoints(abi: &ContractAbi) -> impl Iterator<Item = &EndpointAbi> {
+    abi.constructors
+        .iter()
+        .chain(abi.upgrade_constructors.iter())
+        .chain(abi.endpoints.iter())
+}
+
+/// Called on build. Prints the warnings and returns the errors, which should stop the build.
+pub(crate) fn check_abi_rules(abi: &ContractAbi) -> Result<(), Vec<AbiValidationError>> {
+    for warning in validate_abi_warnings(abi) {
+        eprintln!("warning: {warning}");
+    }
+
+    let errors = validate_abi_rules(abi);
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Endpoints can come from several modules, so the same name can be declared in different places.
+fn check_duplicate_endpoints(abi: &ContractAbi, errors: &mut Vec<AbiValidationError>) {
+    let mut endpoints_by_name: BTreeMap<&str, Vec<&EndpointAbi>> = BTreeMap::new();
+    for endpoint in &abi.endpoints {
+        endpoints_by_name
+            .entry(endpoint.name)
+            .or_default()
+            .push(endpoint);
+    }
+
+    for (name, endpoints) in endpoints_by_name {
+        let Some((first, duplicates)) = endpoints.split_first() else {
+            continue;
+        };
+        for duplicate in duplicates {
+            errors.push(error(
+                duplicate.location,
+                format!(
+                    "endpoint `{name}` ({}) is already declared by `{}`{}",
+                    duplicate.rust_method_name,
+                    first.rust_method_name,
+                    location_suffix(first.location)
+                ),
+            ));
+        }
+    }
+}
+
+fn location_suffix(location: Option<SourceLocationAbi>) -> String {
+    match location {
+        Some(location) => format!(" at {}:{}", location.file, location.line),
+        None => String::new(),
+    }
+}
+
+fn check_builtin_function_name(endpoint: &EndpointAbi, errors: &mut Vec<AbiValidationError>) {
+    if BUILTIN_FUNCTION_NAMES.contains(&endpoint.name) {
+        errors.push(error(
+            endpoint.location,
+            format!(
+                "endpoint `{}` has the same name as a built-in function, it can never be called",
+                endpoint.name
+            ),
+        ));
+    }
+}
+
+/// Payable views are allowed, since they can be called in transactions, but a query can never pay them.
+fn check_payable_view(endpoint: &EndpointAbi, warnings: &mut Vec<AbiValidationError>) {
+    let readonly = matches!(
+        endpoint.mutability,
+        EndpointMutabilityAbi::Readonly | EndpointMutabilityAbi::Pure
+    );
+    if readonly && !endpoint.payable_in_tokens.is_empty() {
+        warnings.push(error(
+            endpoint.location,
+            format!(
+                "view `{}` is payable, queries cannot send payments",
+                endpoint.name
+            ),
+        ));
+    }
+}
+
+/// Variadic arguments take all the remaining arguments, optional arguments can only be omitted at the end.
+/// Ignored arguments are allowed after both, since they discard whatever is left.
+fn check_multi_value_arguments(endpoint: &EndpointAbi, errors: &mut Vec<AbiValidationError>) {
+    for (index, input) in endpoint.inputs.iter().enumerate() {
+        let (base, _) = split_type_name(&input.type_name);
+        let following = &endpoint.inputs[index + 1..];
+        let misplaced = match base {
+            "variadic" => following
+                .iter()
+                .any(|next_input| next_input.type_name != "ignore"),
+            "optional" => following.iter().any(|next_input| {
+                !matches!(
+                    split_type_name(&next_input.type_name).0,
+                    "optional" | "variadic" | "ignore"
+                )
+            }),
+            _ => false,
+        };
+        if misplaced {
+            errors.push(error(
+                endpoint.location,
+                format!(
+                    "argument `{}` of endpoint `{}` is {}, so it must be the last argument",
+                    input.arg_name, endpoint.name, input.type_name
+                ),
+            ));
+        }
+    }
+}
+
+/// Every type used in an endpoint needs to describe itself, otherwise it is missing from the ABI JSON.
+///
+/// The generated ABI code provides the descriptions of all argument and result types,
+/// so what can actually go missing are the field types of structs and enums with hand-written descriptions.
+fn check_described_types<'a>(
+    abi: &'a ContractAbi,
+    endpoint: &'a EndpointAbi,
+    errors: &mut Vec<AbiValidationError>,
+) {
+    let type_names = endpoint
+        .inputs
+        .iter()
+        .map(|input| input.type_name.as_str())
+        .chain(
+            endpoint
+                .outputs
+                .iter()
+                .map(|output| output.type_name.as_str()),
+        );
+    for missing_type in undescribed_types(abi, type_names) {
+        errors.push(error(
+            endpoint.location,
+            format!(
+                "type `{missing_type}` used in endpoint `{}` is missing from the type descriptions",
+                endpoint.name
+            ),
+        ));
+    }
+}
+
+fn check_described_event_types(abi: &ContractAbi, errors: &mut Vec<AbiValidationError>) {
+    for event in &abi.events {
+        let type_names = event.inputs.iter().map(|input| input.type_name.as_str());
+        for missing_type in undescribed_types(abi, type_names) {
+            errors.push(error(
+                event.location,
+                format!(
+                    "type `{missing_type}` used in event `{}` is missing from the type descriptions",
+                    event.identifier
+                ),
+            ));
+        }
+    }
+}
+
+fn undescribed_types<'a>(
+    abi: &'a ContractAbi,
+    type_names: impl Iterator<Item = &'a str>,
+) -> Vec<&'a str> {
+    let mut visited = Vec::new();
+    let mut missing = Vec::new();
+    for type_name in type_names {
+        collect_undescribed_types(abi, type_name, &mut visited, &mut missing);
+    }
+    missing
+}
+
+/// Generic types such as `List<T>` describe their arguments, not themselves.
+/// Described structs and enums are followed into their fields.
+fn collect_undescribed_types<'a>(
+    abi: &'a ContractAbi,
+    type_name: &'a str,
+    visited: &mut Vec<&'a str>,
+    missing: &mut Vec<&'a str>,
+) {
+    if visited.contains(&type_name) {
+        return;
+    }
+    visited.push(type_name);
+
+    let (_, args) = split_type_name(type_name);
+    if !args.is_empty() {
+        for arg in args {
+            collect_undescribed_types(abi, arg, visited, missing);
+        }
+        return;
+    }
+    if IMPLICIT_TYPE_NAMES.contains(&type_name) {
+        return;
+    }
+
+    let Some(description) = abi.type_descriptions.0.get(type_name) else {
+        missing.push(type_name);
+        return;
+    };
+    let fields: Vec<&StructFieldDescription> = match &description.contents {
+        TypeContents::Struct(fields) => fields.iter().collect(),
+        TypeContents::Enum { variants, .. } => variants
+            .iter()
+            .flat_map(|variant| variant.fields.iter())
+            .collect(),
+        TypeContents::NotSpecified => Vec::new(),
+    };
+    for field in fields {
+        collect_undescribed_types(abi, &field.field_type, visited, missing);
+    }
+}
+
+fn check_duplicate_events(abi: &ContractAbi, errors: &mut Vec<AbiValidationError>) {
+    for (index, event) in abi.events.iter().enumerate() {
+        if let Some(first) = abi.events[..index]
+            .iter()
+            .find(|previous| previous.identifier == event.identifier)
+        {
+            errors.push(error(
+                event.location,
+                format!(
+                    "event `{}` is already declared{}",
+                    event.identifier,
+                    location_suffix(first.location)
+                ),
+            ));
+        }
+    }
+}
+
+/// Keys under the reserved prefix belong to the framework (schema version, reentrancy lock),
+/// the contract would fail as soon as it accessed such a mapper.
+fn check_reserved_storage_keys(abi: &ContractAbi, errors: &mut Vec<AbiValidationError>) {
+    for storage in &abi.storage {
+        if storage
+            .key
+            .as_bytes()
+            .starts_with(FRAMEWORK_RESERVED_KEY_PREFIX)
+        {
+            errors.push(error(
+                None,
+                format!("storage key `{}` is reserved by the framework", storage.key),
+            ));
+        }
+    }
+}
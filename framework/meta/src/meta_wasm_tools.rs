@@ -43,7 +43,7 @@ fn is_wasm2wat_installed() -> bool {
         .is_ok()
 }
 
-fn is_wasm_objdump_installed() -> bool {
+pub(crate) fn is_wasm_objdump_installed() -> bool {
     Command::new(WASM_OBJDUMP_NAME)
         .args(["--version"])
         .output()
@@ -57,6 +57,14 @@ fn is_twiggy_installed() -> bool {
         .is_ok()
 }
 
+pub(crate) fn wasm_opt_version() -> Option<String> {
+    let output = Command::new(WASM_OPT_NAME)
+        .args(["--version"])
+        .output()
+        .ok()?;
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
 pub(crate) fn run_wasm_opt(output_wasm_path: &str) {
     let exit_status = Command::new(WASM_OPT_NAME)
         .args([output_wasm_path, "-Oz", "--output", output_wasm_path])
@@ -0,0 +1,87 @@
+use std::fs;
+
+use multiversx_sc::abi::ContractAbi;
+
+use super::OutputContract;
+use crate::cli_args::BuildArgs;
+
+const NO_LIMIT: &str = "-";
+
+/// Lists the limits declared with `#[max_gas]` and `#[max_return_items]`,
+/// one line per endpoint that declares any.
+///
+/// Returns `None` if no endpoint declares limits.
+pub fn format_budget_report(abi: &ContractAbi, wasm_size: Option<u64>) -> Option<String> {
+    let rows: Vec<[String; 3]> = abi
+        .endpoints
+        .iter()
+        .filter(|endpoint| endpoint.max_gas.is_some() || endpoint.max_return_items.is_some())
+        .map(|endpoint| {
+            [
+                endpoint.name.to_string(),
+                endpoint
+                    .max_gas
+                    .map_or_else(|| NO_LIMIT.to_string(), |max_gas| max_gas.to_string()),
+                endpoint.max_return_items.map_or_else(
+                    || NO_LIMIT.to_string(),
+                    |max_return_items| max_return_items.to_string(),
+                ),
+            ]
+        })
+        .collect();
+    if rows.is_empty() {
+        return None;
+    }
+
+    let header = [
+        "endpoint".to_string(),
+        "max gas".to_string(),
+        "max return items".to_string(),
+    ];
+    let mut widths = [0usize; 3];
+    for row in core::iter::once(&header).chain(rows.iter()) {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    let mut report = String::new();
+    if let Some(wasm_size) = wasm_size {
+        report.push_str(&format!("wasm size: {wasm_size} bytes\n\n"));
+    }
+    for row in core::iter::once(&header).chain(rows.iter()) {
+        let line = format!(
+            "{:<w0$}  {:>w1$}  {:>w2$}",
+            row[0],
+            row[1],
+            row[2],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+        );
+        report.push_str(line.trim_end());
+        report.push('\n');
+    }
+    Some(report)
+}
+
+impl OutputContract {
+    pub fn budget_report_name(&self, build_args: &BuildArgs) -> String {
+        format!("budget-{}.txt", self.output_name_base(build_args))
+    }
+
+    /// Written next to the twiggy reports, so that reviewers see size and declared limits together.
+    pub(super) fn write_budget_report(&self, build_args: &BuildArgs, output_path: &str) {
+        let output_wasm_path = format!("{output_path}/{}", self.wasm_output_name(build_args));
+        let wasm_size = fs::metadata(output_wasm_path)
+            .ok()
+            .map(|metadata| metadata.len());
+        let Some(report) = format_budget_report(&self.abi, wasm_size) else {
+            return;
+        };
+
+        let output_budget_path = format!("{output_path}/{}", self.budget_report_name(build_args));
+        fs::write(&output_budget_path, &report).expect("failed to write budget report");
+        println!("Endpoint budgets of {}:\n{report}", self.contract_name);
+    }
+}
@@ -0,0 +1,135 @@
+use std::fs;
+
+use super::{BuildConstraintsSerde, OutputContract};
+use crate::{cli_args::BuildArgs, ei::EIVersion, meta_wasm_tools};
+
+/// Size and import limits of a contract, configured in `multicontract.toml`.
+#[derive(Default, Clone, PartialEq, Eq, Debug)]
+pub struct BuildConstraints {
+    pub max_wasm_size: Option<usize>,
+    pub ei_version: Option<EIVersion>,
+    pub allowed_imports: Option<Vec<String>>,
+    pub forbidden_imports: Vec<String>,
+}
+
+fn parse_ei_version(name: &str) -> EIVersion {
+    EIVersion::from_name(name).unwrap_or_else(|| {
+        let known_versions: Vec<&str> = EIVersion::ALL.iter().map(EIVersion::name).collect();
+        panic!(
+            "unknown EI version \"{name}\" in multicontract.toml, known versions are: {}",
+            known_versions.join(", ")
+        )
+    })
+}
+
+impl BuildConstraints {
+    /// Each contract setting overrides the corresponding general setting.
+    pub fn from_serde(
+        general: &BuildConstraintsSerde,
+        contract: Option<&BuildConstraintsSerde>,
+    ) -> Self {
+        let empty = BuildConstraintsSerde::default();
+        let contract = contract.unwrap_or(&empty);
+        BuildConstraints {
+            max_wasm_size: contract.max_wasm_size.or(general.max_wasm_size),
+            ei_version: contract
+                .ei
+                .as_ref()
+                .or(general.ei.as_ref())
+                .map(|name| parse_ei_version(name)),
+            allowed_imports: contract
+                .allowed_imports
+                .clone()
+                .or_else(|| general.allowed_imports.clone()),
+            forbidden_imports: contract
+                .forbidden_imports
+                .clone()
+                .or_else(|| general.forbidden_imports.clone())
+                .unwrap_or_default(),
+        }
+    }
+
+    pub fn has_import_rules(&self) -> bool {
+        self.ei_version.is_some()
+            || self.allowed_imports.is_some()
+            || !self.forbidden_imports.is_empty()
+    }
+
+    pub fn check_wasm_size(&self, wasm_size: usize) -> Vec<String> {
+        match self.max_wasm_size {
+            Some(max_wasm_size) if wasm_size > max_wasm_size => vec![format!(
+                "wasm size is {wasm_size} bytes, max-wasm-size is {max_wasm_size} bytes"
+            )],
+            _ => Vec::new(),
+        }
+    }
+
+    pub fn check_imports(&self, import_names: &[String]) -> Vec<String> {
+        let mut violations = Vec::new();
+        for import_name in import_names {
+            if self.forbidden_imports.contains(import_name) {
+                violations.push(format!("import `{import_name}` is in forbidden-imports"));
+            }
+            if let Some(allowed_imports) = &self.allowed_imports {
+                if !allowed_imports.contains(import_name) {
+                    violations.push(format!("import `{import_name}` is not in allowed-imports"));
+                }
+            }
+            if let Some(ei_version) = self.ei_version {
+                if !ei_version.contains(import_name) {
+                    let reason = match EIVersion::introduced_in(import_name) {
+                        Some(introduced_in) => format!("requires EI {}", introduced_in.name()),
+                        None => "is not a known VM hook".to_string(),
+                    };
+                    violations.push(format!(
+                        "import `{import_name}` {reason}, the contract targets EI {}",
+                        ei_version.name()
+                    ));
+                }
+            }
+        }
+        violations
+    }
+}
+
+pub fn format_build_constraints_report(contract_name: &str, violations: &[String]) -> String {
+    let mut report =
+        format!("Contract {contract_name} violates the constraints in multicontract.toml:");
+    for violation in violations {
+        report.push_str("\n  - ");
+        report.push_str(violation);
+    }
+    report
+}
+
+impl OutputContract {
+    /// Fails the build if the output does not fit the constraints in `multicontract.toml`.
+    pub(super) fn check_build_constraints(&self, build_args: &BuildArgs, output_path: &str) {
+        let constraints = &self.settings.build_constraints;
+        let output_wasm_path = format!("{output_path}/{}", self.wasm_output_name(build_args));
+
+        let mut violations = Vec::new();
+        if constraints.max_wasm_size.is_some() {
+            let wasm_size = fs::metadata(&output_wasm_path)
+                .expect("failed to read contract size")
+                .len() as usize;
+            violations.extend(constraints.check_wasm_size(wasm_size));
+        }
+        if constraints.has_import_rules() {
+            assert!(
+                meta_wasm_tools::is_wasm_objdump_installed(),
+                "wasm-objdump is required to check the imports of contract {}",
+                self.contract_name
+            );
+            let result = meta_wasm_tools::run_wasm_objdump(output_wasm_path.as_str());
+            let import_names = meta_wasm_tools::parse_imports(result.as_str());
+            violations.extend(constraints.check_imports(import_names.as_slice()));
+        }
+
+        assert!(
+            violations.is_empty(),
+            "{}",
+            format_build_constraints_report(&self.contract_name, &violations)
+        );
+    }
+}
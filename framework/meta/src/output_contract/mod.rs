@@ -1,3 +1,5 @@
+mod budget_report;
+mod build_constraints;
 mod multi_contract_serde;
 mod output_contract_builder;
 mod output_contract_model;
@@ -7,6 +9,8 @@ mod wasm_clean;
 mod wasm_crate_gen;
 mod wasm_update;
 
+pub use budget_report::format_budget_report;
+pub use build_constraints::*;
 pub use multi_contract_serde::*;
 pub use output_contract_builder::*;
 pub use output_contract_model::*;
@@ -35,9 +35,37 @@ pub struct OutputContractSerde {
     #[serde(default)]
     #[serde(rename = "panic-message")]
     pub panic_message: Option<bool>,
+
+    #[serde(flatten)]
+    pub build_constraints: BuildConstraintsSerde,
 }
 
 #[derive(Deserialize, Default, Debug)]
 pub struct MultiContractGeneralSettingsSerde {
     pub main: Option<String>,
+
+    /// Defaults for all contracts, each contract can override them.
+    #[serde(flatten)]
+    pub build_constraints: BuildConstraintsSerde,
+}
+
+/// Limits checked after building, the build fails if they are not met.
+#[derive(Deserialize, Default, Debug, Clone)]
+pub struct BuildConstraintsSerde {
+    /// In bytes.
+    #[serde(default)]
+    #[serde(rename = "max-wasm-size")]
+    pub max_wasm_size: Option<usize>,
+
+    /// The VM release the contract targets, only VM hooks known to it can be imported.
+    #[serde(default)]
+    pub ei: Option<String>,
+
+    #[serde(default)]
+    #[serde(rename = "allowed-imports")]
+    pub allowed_imports: Option<Vec<String>>,
+
+    #[serde(default)]
+    #[serde(rename = "forbidden-imports")]
+    pub forbidden_imports: Option<Vec<String>>,
 }
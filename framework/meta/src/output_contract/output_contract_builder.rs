@@ -6,8 +6,8 @@ use std::{
 };
 
 use super::{
-    MultiContractConfigSerde, OutputContract, OutputContractConfig, OutputContractSerde,
-    OutputContractSettings,
+    BuildConstraints, MultiContractConfigSerde, MultiContractGeneralSettingsSerde, OutputContract,
+    OutputContractConfig, OutputContractSerde, OutputContractSettings,
 };
 
 /// Temporary structure, to help create instances of `OutputContract`. Not publicly exposed.
@@ -19,21 +19,32 @@ struct OutputContractBuilder {
     pub add_labels: BTreeSet<String>,
     pub add_endpoints: BTreeSet<String>,
     pub constructors: Vec<EndpointAbi>,
+    pub upgrade_constructors: Vec<EndpointAbi>,
     pub endpoint_names: HashSet<String>,
     pub endpoints: Vec<EndpointAbi>,
     pub settings: OutputContractSettings,
 }
 
 impl OutputContractBuilder {
-    fn new(id: String) -> Self {
+    fn new(id: String, general_settings: &MultiContractGeneralSettingsSerde) -> Self {
         OutputContractBuilder {
             contract_id: id.clone(),
             explicit_name: id,
+            settings: OutputContractSettings {
+                build_constraints: BuildConstraints::from_serde(
+                    &general_settings.build_constraints,
+                    None,
+                ),
+                ..Default::default()
+            },
             ..Default::default()
         }
     }
 
-    fn map_from_config(kvp: (&String, &OutputContractSerde)) -> (String, OutputContractBuilder) {
+    fn map_from_config(
+        kvp: (&String, &OutputContractSerde),
+        general_settings: &MultiContractGeneralSettingsSerde,
+    ) -> (String, OutputContractBuilder) {
         let (contract_id, cms) = kvp;
         let external_view = cms.external_view.unwrap_or_default();
         let mut constructors = Vec::new();
@@ -54,6 +65,10 @@ impl OutputContractBuilder {
                 settings: OutputContractSettings {
                     external_view: cms.external_view.unwrap_or_default(),
                     panic_message: cms.panic_message.unwrap_or_default(),
+                    build_constraints: BuildConstraints::from_serde(
+                        &general_settings.build_constraints,
+                        Some(&cms.build_constraints),
+                    ),
                 },
                 ..Default::default()
             },
@@ -78,6 +93,19 @@ impl OutputContractBuilder {
         self.constructors.push(constructor_abi.clone());
     }
 
+    /// External view contracts cannot be upgraded into anything else, so they get no upgrade endpoint.
+    fn add_upgrade_constructor(&mut self, upgrade_abi: &EndpointAbi) {
+        if self.settings.external_view {
+            return;
+        }
+        assert!(
+            self.upgrade_constructors.is_empty(),
+            "More than one upgrade constructor specified for contract {}",
+            self.explicit_name
+        );
+        self.upgrade_constructors.push(upgrade_abi.clone());
+    }
+
     /// Keeps endpoints unique, while retaining their order.
     fn add_endpoint(&mut self, endpoint_abi: &EndpointAbi) {
         if !self.endpoint_names.contains(endpoint_abi.name) {
@@ -90,12 +118,13 @@ impl OutputContractBuilder {
 fn process_labels_for_contracts(
     contract_builders: &mut HashMap<String, OutputContractBuilder>,
     labels_for_contracts: &HashMap<String, Vec<String>>,
+    general_settings: &MultiContractGeneralSettingsSerde,
 ) {
     for (label, targets) in labels_for_contracts {
         for target in targets {
             contract_builders
                 .entry(target.clone())
-                .or_insert_with(|| OutputContractBuilder::new(target.clone()))
+                .or_insert_with(|| OutputContractBuilder::new(target.clone(), general_settings))
                 .add_labels
                 .insert(label.clone());
         }
@@ -124,6 +153,11 @@ fn collect_unlabelled_endpoints(
                     builder.add_constructor(constructor_abi);
                 }
             }
+            for upgrade_abi in &original_abi.upgrade_constructors {
+                if endpoint_unlabelled(upgrade_abi) {
+                    builder.add_upgrade_constructor(upgrade_abi);
+                }
+            }
             for endpoint_abi in &original_abi.endpoints {
                 if endpoint_unlabelled(endpoint_abi) {
                     builder.add_endpoint(endpoint_abi);
@@ -143,6 +177,11 @@ fn collect_labelled_endpoints(
                 builder.add_constructor(constructor_abi);
             }
         }
+        for upgrade_abi in &original_abi.upgrade_constructors {
+            if endpoint_matches_labels(upgrade_abi, &builder.add_labels) {
+                builder.add_upgrade_constructor(upgrade_abi);
+            }
+        }
         for endpoint_abi in &original_abi.endpoints {
             if endpoint_matches_labels(endpoint_abi, &builder.add_labels) {
                 builder.add_endpoint(endpoint_abi);
@@ -161,6 +200,11 @@ fn collect_add_endpoints(
                 builder.add_constructor(constructor_abi);
             }
         }
+        for upgrade_abi in &original_abi.upgrade_constructors {
+            if builder.add_endpoints.contains(upgrade_abi.name) {
+                builder.add_upgrade_constructor(upgrade_abi);
+            }
+        }
         for endpoint_abi in &original_abi.endpoints {
             if builder.add_endpoints.contains(endpoint_abi.name) {
                 builder.add_endpoint(endpoint_abi);
@@ -175,9 +219,12 @@ fn build_contract_abi(builder: OutputContractBuilder, original_abi: &ContractAbi
         docs: original_abi.docs,
         name: original_abi.name,
         constructors: builder.constructors,
+        upgrade_constructors: builder.upgrade_constructors,
         endpoints: builder.endpoints,
         promise_callbacks: original_abi.promise_callbacks.clone(),
         events: original_abi.events.clone(),
+        storage: original_abi.storage.clone(),
+        errors: original_abi.errors.clone(),
         has_callback: !builder.settings.external_view && original_abi.has_callback,
         type_descriptions: original_abi.type_descriptions.clone(),
     }
@@ -227,12 +274,16 @@ impl OutputContractConfig {
         let mut contract_builders: HashMap<String, OutputContractBuilder> = config
             .contracts
             .iter()
-            .map(OutputContractBuilder::map_from_config)
+            .map(|kvp| OutputContractBuilder::map_from_config(kvp, &config.settings))
             .collect();
         collect_unlabelled_endpoints(&mut contract_builders, original_abi);
         collect_labelled_endpoints(&mut contract_builders, original_abi);
         collect_add_endpoints(&mut contract_builders, original_abi);
-        process_labels_for_contracts(&mut contract_builders, &config.labels_for_contracts);
+        process_labels_for_contracts(
+            &mut contract_builders,
+            &config.labels_for_contracts,
+            &config.settings,
+        );
         let mut contracts: Vec<OutputContract> = contract_builders
             .into_values()
             .map(|builder| build_contract(builder, original_abi))
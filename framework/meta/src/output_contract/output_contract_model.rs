@@ -1,5 +1,6 @@
 use multiversx_sc::abi::ContractAbi;
 
+use super::BuildConstraints;
 use crate::cli_args::BuildArgs;
 
 pub const DEFAULT_LABEL: &str = "default";
@@ -62,6 +63,9 @@ pub struct OutputContractSettings {
     /// Panic messages add a lot of bloat to the final bytecode,
     /// so they should only be used for debugging purposes.
     pub panic_message: bool,
+
+    /// Checked after each build, the build fails if they are not met.
+    pub build_constraints: BuildConstraints,
 }
 
 /// Represents a contract created by the framework when building.
@@ -140,7 +144,7 @@ impl OutputContract {
         format!("{}.abi.json", &self.contract_name)
     }
 
-    fn output_name_base(&self, build_args: &BuildArgs) -> String {
+    pub(super) fn output_name_base(&self, build_args: &BuildArgs) -> String {
         if let Some(wasm_name_override) = &build_args.wasm_name_override {
             wasm_name_override.clone()
         } else if let Some(suffix) = &build_args.wasm_name_suffix {
@@ -189,11 +193,14 @@ impl OutputContract {
             .collect()
     }
 
-    /// Yields "init" + all endpoint names + "callBack" (if it exists).
+    /// Yields "init" + "upgrade" (if it exists) + all endpoint names + "callBack" (if it exists).
     ///
     /// Should correspond to all wasm exported functions.
     pub fn all_exported_function_names(&self) -> Vec<String> {
         let mut result = vec!["init".to_string()];
+        for upgrade_abi in &self.abi.upgrade_constructors {
+            result.push(upgrade_abi.name.to_string());
+        }
         result.append(&mut self.endpoint_names());
         if self.abi.has_callback {
             result.push("callBack".to_string());
@@ -24,6 +24,9 @@ impl OutputContract {
 
     fn compose_build_command(&self, build_args: &BuildArgs) -> Command {
         let mut command = Command::new("cargo");
+        if let Some(toolchain) = &build_args.toolchain {
+            command.arg(format!("+{toolchain}"));
+        }
         command
             .args(["build", "--target=wasm32-unknown-unknown", "--release"])
             .current_dir(self.wasm_crate_path());
@@ -52,6 +55,16 @@ fn compose_rustflags(build_args: &BuildArgs) -> String {
         }
         rustflags.push_str("--emit=mir");
     }
+    for (from, to) in &build_args.remap_path_prefixes {
+        assert!(
+            !from.contains(char::is_whitespace),
+            "cannot remap path containing whitespace: {from}"
+        );
+        if !rustflags.is_empty() {
+            rustflags.push(' ');
+        }
+        rustflags.push_str(&format!("--remap-path-prefix={from}={to}"));
+    }
     rustflags
 }
 
@@ -62,6 +75,8 @@ impl OutputContract {
         self.run_wasm2wat(build_args, output_path);
         self.extract_imports(build_args, output_path);
         self.run_twiggy(build_args, output_path);
+        self.write_budget_report(build_args, output_path);
+        self.check_build_constraints(build_args, output_path);
     }
 
     fn copy_contracts_to_output(&self, build_args: &BuildArgs, output_path: &str) {
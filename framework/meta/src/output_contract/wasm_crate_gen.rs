@@ -16,6 +16,7 @@ const PREFIX_AUTO_GENERATED: &str =
 ";
 
 const NUM_INIT: usize = 1;
+const NUM_UPGRADE: usize = 1;
 const NUM_ASYNC_CB: usize = 1;
 
 const PREFIX_NO_STD: &str = "
@@ -56,6 +57,11 @@ impl OutputContract {
 
     fn write_wasm_src_lib_contents(&self, wasm_lib_file: &mut File) {
         let explicit_endpoint_names = self.endpoint_names();
+        let upgrade_names = self
+            .abi
+            .upgrade_constructors
+            .iter()
+            .map(|upgrade_abi| upgrade_abi.name.to_string());
         wasm_lib_file
             .write_all(PREFIX_AUTO_GENERATED.as_bytes())
             .unwrap();
@@ -66,7 +72,8 @@ impl OutputContract {
             .write_all(self.panic_handler_macro_invocation().as_bytes())
             .unwrap();
 
-        let mut all_endpoint_names = explicit_endpoint_names;
+        let mut all_endpoint_names: Vec<String> = upgrade_names.collect();
+        all_endpoint_names.extend(explicit_endpoint_names);
         if self.abi.has_callback {
             all_endpoint_names.push("callBack".to_string());
         }
@@ -96,6 +103,9 @@ impl OutputContract {
     /// Writing some nicely formatted comments breaking down all exported functions.
     fn write_stat_comments(&self, wasm_lib_file: &mut File) {
         write_stat_comment(wasm_lib_file, "Init:", NUM_INIT);
+        if !self.abi.upgrade_constructors.is_empty() {
+            write_stat_comment(wasm_lib_file, "Upgrade:", NUM_UPGRADE);
+        }
         write_stat_comment(wasm_lib_file, "Endpoints:", self.abi.endpoints.len());
         if self.abi.has_callback {
             write_stat_comment(wasm_lib_file, "Async Callback:", NUM_ASYNC_CB);
@@ -109,8 +119,11 @@ impl OutputContract {
                 self.abi.promise_callbacks.len(),
             );
         }
-        let total =
-            self.abi.endpoints.len() + NUM_INIT + NUM_ASYNC_CB + self.abi.promise_callbacks.len();
+        let total = self.abi.endpoints.len()
+            + NUM_INIT
+            + self.abi.upgrade_constructors.len()
+            + NUM_ASYNC_CB
+            + self.abi.promise_callbacks.len();
 
         write_stat_comment(wasm_lib_file, "Total number of exported functions:", total);
     }
@@ -0,0 +1,17 @@
+//! BLAKE2b with a 32 byte digest, as used by the protocol to compute the code hash of deployed contracts.
+
+use blake2::{digest::consts::U32, Blake2b, Digest};
+
+type Blake2b256 = Blake2b<U32>;
+
+pub fn blake2b_256(data: &[u8]) -> [u8; 32] {
+    Blake2b256::digest(data).into()
+}
+
+/// The hash as lowercase hex, as found in `*.codehash.txt` and in the build manifest.
+pub fn blake2b_256_hex(data: &[u8]) -> String {
+    blake2b_256(data)
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fs};
+
+use crate::abi_json::BuildInfoAbiJson;
+
+/// Written next to the contracts by `reproducible-build`,
+/// contains everything needed to rebuild them and check the result.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BuildManifestJson {
+    pub toolchain: String,
+    pub build_info: BuildInfoAbiJson,
+
+    /// The output of `wasm-opt --version`, if it was applied.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wasm_opt: Option<String>,
+
+    /// The prefixes local paths were replaced with, in the compiled code.
+    pub path_remaps: Vec<String>,
+
+    pub contracts: Vec<BuildManifestContractJson>,
+
+    /// BLAKE2b-256 hashes of the lock files and of the build configuration, by path relative to the contract crate.
+    pub files: BTreeMap<String, String>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BuildManifestContractJson {
+    pub name: String,
+    pub wasm: String,
+    pub size: usize,
+    pub code_hash: String,
+}
+
+impl BuildManifestJson {
+    pub fn find_contract_by_wasm(
+        &self,
+        wasm_file_name: &str,
+    ) -> Option<&BuildManifestContractJson> {
+        self.contracts
+            .iter()
+            .find(|contract| contract.wasm == wasm_file_name)
+    }
+}
+
+pub fn read_build_manifest(path: &str) -> BuildManifestJson {
+    let json_str =
+        fs::read_to_string(path).unwrap_or_else(|err| panic!("failed to read {path}: {err}"));
+    serde_json::from_str(&json_str).unwrap_or_else(|err| panic!("failed to parse {path}: {err}"))
+}
+
+pub fn write_build_manifest(path: &str, manifest: &BuildManifestJson) {
+    let mut json = serde_json::to_string_pretty(manifest).unwrap();
+    json.push('\n');
+    fs::write(path, json).unwrap_or_else(|err| panic!("failed to write {path}: {err}"));
+}
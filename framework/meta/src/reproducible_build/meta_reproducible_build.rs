@@ -0,0 +1,200 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use super::{
+    blake2b_256_hex, find_pinned_toolchain, read_build_manifest,
+    toolchain::{path_remaps, project_root, rustc_version_of_toolchain},
+    write_build_manifest, BuildManifestContractJson, BuildManifestJson,
+};
+use crate::{
+    abi_json::BuildInfoAbiJson,
+    cli_args::{BuildArgs, ReproducibleBuildArgs, VerifyBuildArgs},
+    meta_config::MetaConfig,
+    meta_wasm_tools,
+};
+
+const CONTRACT_DIR: &str = "..";
+const CONTRACT_CONFIG_FILES: &[&str] = &["Cargo.lock", "multicontract.toml"];
+
+fn read_file(path: &str) -> Vec<u8> {
+    fs::read(path).unwrap_or_else(|err| panic!("failed to read {path}: {err}"))
+}
+
+impl MetaConfig {
+    fn build_manifest_path(&self) -> String {
+        format!(
+            "{}/{}.build-manifest.json",
+            self.output_dir,
+            self.output_contracts.main_contract().contract_name
+        )
+    }
+
+    /// Builds with a pinned toolchain, locked dependencies and no local paths in the output,
+    /// so that anyone can obtain the same code hash from the same sources.
+    pub fn reproducible_build(&mut self, args: &ReproducibleBuildArgs) -> BuildManifestJson {
+        let toolchain = args
+            .toolchain
+            .clone()
+            .or_else(|| find_pinned_toolchain(Path::new(CONTRACT_DIR)))
+            .expect("reproducible builds need a pinned toolchain: add a rust-toolchain.toml file or specify --toolchain");
+
+        let mut files = BTreeMap::new();
+        for output_contract in &self.output_contracts.contracts {
+            let lock_path = format!("{}/Cargo.lock", output_contract.wasm_crate_path());
+            assert!(
+                Path::new(&lock_path).exists(),
+                "reproducible builds need pinned dependencies, but {lock_path} is missing; run `cargo run update` to create it"
+            );
+            files.insert(
+                format!("{}/Cargo.lock", output_contract.wasm_crate_dir_name()),
+                blake2b_256_hex(&read_file(&lock_path)),
+            );
+        }
+        for file_name in CONTRACT_CONFIG_FILES {
+            let path = format!("{CONTRACT_DIR}/{file_name}");
+            if Path::new(&path).exists() {
+                files.insert(file_name.to_string(), blake2b_256_hex(&read_file(&path)));
+            }
+        }
+
+        let remaps = path_remaps(&project_root(Path::new(CONTRACT_DIR)));
+        let build_args = BuildArgs {
+            locked: true,
+            toolchain: Some(toolchain.clone()),
+            remap_path_prefixes: remaps.clone(),
+            target_dir: args.target_dir.clone(),
+            ..BuildArgs::default()
+        };
+        let wasm_opt = meta_wasm_tools::wasm_opt_version();
+        self.build(build_args.clone());
+
+        let mut contracts = Vec::new();
+        for output_contract in &self.output_contracts.contracts {
+            let wasm_name = output_contract.wasm_output_name(&build_args);
+            let wasm_bytes = read_file(&format!("{}/{wasm_name}", self.output_dir));
+            let code_hash = blake2b_256_hex(&wasm_bytes);
+            let code_hash_path = format!(
+                "{}/{}.codehash.txt",
+                self.output_dir, output_contract.contract_name
+            );
+            fs::write(&code_hash_path, &code_hash)
+                .unwrap_or_else(|err| panic!("failed to write {code_hash_path}: {err}"));
+            println!("Code hash of {wasm_name}: {code_hash}");
+            contracts.push(BuildManifestContractJson {
+                name: output_contract.contract_name.clone(),
+                wasm: wasm_name,
+                size: wasm_bytes.len(),
+                code_hash,
+            });
+        }
+
+        let mut build_info = BuildInfoAbiJson::from(&self.original_contract_abi.build_info);
+        build_info.rustc = rustc_version_of_toolchain(&toolchain);
+        let manifest = BuildManifestJson {
+            toolchain,
+            build_info,
+            wasm_opt,
+            path_remaps: remaps.into_iter().map(|(_, to)| to).collect(),
+            contracts,
+            files,
+        };
+        write_build_manifest(&self.build_manifest_path(), &manifest);
+        manifest
+    }
+
+    /// Rebuilds the contracts as described in the build manifest,
+    /// then checks that the given wasm is identical to the result.
+    ///
+    /// The rebuild goes to a temporary directory,
+    /// so the manifest, wasm and code hash files in the output directory are left untouched.
+    pub fn verify_build(&mut self, args: &VerifyBuildArgs) {
+        let manifest_path = args
+            .manifest
+            .clone()
+            .unwrap_or_else(|| self.build_manifest_path());
+        let expected_manifest = read_build_manifest(&manifest_path);
+        let wasm_hash = blake2b_256_hex(&read_file(&args.wasm));
+        let wasm_file_name = Path::new(&args.wasm)
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or_default()
+            .to_string();
+        let expected_contract = expected_manifest
+            .find_contract_by_wasm(&wasm_file_name)
+            .unwrap_or_else(|| {
+                panic!("{wasm_file_name} is not one of the contracts in {manifest_path}")
+            });
+
+        let rebuild_dir =
+            tempfile::tempdir().expect("failed to create a temporary directory for the rebuild");
+        let output_dir = std::mem::replace(
+            &mut self.output_dir,
+            rebuild_dir.path().to_string_lossy().to_string(),
+        );
+        let rebuilt_manifest = self.reproducible_build(&ReproducibleBuildArgs {
+            toolchain: Some(expected_manifest.toolchain.clone()),
+            target_dir: args.target_dir.clone(),
+        });
+        self.output_dir = output_dir;
+        let rebuilt_contract = rebuilt_manifest
+            .find_contract_by_wasm(&wasm_file_name)
+            .expect("contract missing from rebuild");
+
+        let report = verification_report(
+            &expected_manifest,
+            &rebuilt_manifest,
+            &expected_contract.code_hash,
+            &wasm_hash,
+        );
+        for line in &report {
+            println!("{line}");
+        }
+        assert!(
+            rebuilt_contract.code_hash == wasm_hash,
+            "verification failed: {} has code hash {wasm_hash}, the rebuild produced {}",
+            args.wasm,
+            rebuilt_contract.code_hash
+        );
+        println!("Verified: {} matches the sources.", args.wasm);
+    }
+}
+
+/// Lists the differences between the original build and the rebuild, which explain a hash mismatch.
+pub fn verification_report(
+    expected: &BuildManifestJson,
+    rebuilt: &BuildManifestJson,
+    manifest_code_hash: &str,
+    wasm_hash: &str,
+) -> Vec<String> {
+    let mut report = Vec::new();
+    if manifest_code_hash != wasm_hash {
+        report.push(format!(
+            "the wasm code hash {wasm_hash} differs from the one in the manifest, {manifest_code_hash}"
+        ));
+    }
+    if expected.build_info.rustc.short != rebuilt.build_info.rustc.short {
+        report.push(format!(
+            "compiler differs: \"{}\" in the manifest, \"{}\" now",
+            expected.build_info.rustc.short, rebuilt.build_info.rustc.short
+        ));
+    }
+    if expected.build_info.framework.version != rebuilt.build_info.framework.version {
+        report.push(format!(
+            "framework version differs: {} in the manifest, {} now",
+            expected.build_info.framework.version, rebuilt.build_info.framework.version
+        ));
+    }
+    if expected.wasm_opt != rebuilt.wasm_opt {
+        report.push(format!(
+            "wasm-opt differs: {:?} in the manifest, {:?} now",
+            expected.wasm_opt, rebuilt.wasm_opt
+        ));
+    }
+    for (path, hash) in &expected.files {
+        match rebuilt.files.get(path) {
+            Some(rebuilt_hash) if rebuilt_hash == hash => {},
+            Some(_) => report.push(format!("{path} differs from the one in the manifest")),
+            None => report.push(format!("{path} is missing")),
+        }
+    }
+    report
+}
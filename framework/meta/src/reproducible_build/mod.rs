@@ -0,0 +1,9 @@
+mod blake2b;
+mod build_manifest;
+mod meta_reproducible_build;
+mod toolchain;
+
+pub use blake2b::{blake2b_256, blake2b_256_hex};
+pub use build_manifest::*;
+pub use meta_reproducible_build::verification_report;
+pub use toolchain::{find_pinned_toolchain, parse_rustc_verbose_version, parse_toolchain_file};
@@ -0,0 +1,124 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use crate::abi_json::RustcAbiJson;
+
+const TOOLCHAIN_FILE_NAMES: &[&str] = &["rust-toolchain.toml", "rust-toolchain"];
+
+/// Reads the toolchain from a `rust-toolchain.toml` or legacy `rust-toolchain` file.
+pub fn parse_toolchain_file(contents: &str) -> Option<String> {
+    if let Ok(value) = contents.parse::<toml::Value>() {
+        return value
+            .get("toolchain")
+            .and_then(|toolchain| toolchain.get("channel"))
+            .and_then(toml::Value::as_str)
+            .map(str::to_string);
+    }
+
+    let trimmed = contents.trim();
+    if trimmed.is_empty() || trimmed.contains(char::is_whitespace) {
+        None
+    } else {
+        Some(trimmed.to_string())
+    }
+}
+
+/// The toolchain pinned in the closest toolchain file, looking from the given directory upwards.
+pub fn find_pinned_toolchain(start_dir: &Path) -> Option<String> {
+    let start_dir = fs::canonicalize(start_dir).ok()?;
+    for dir in start_dir.ancestors() {
+        for file_name in TOOLCHAIN_FILE_NAMES {
+            if let Ok(contents) = fs::read_to_string(dir.join(file_name)) {
+                return parse_toolchain_file(&contents);
+            }
+        }
+    }
+    None
+}
+
+/// Parses the output of `rustc -vV`.
+pub fn parse_rustc_verbose_version(output: &str) -> RustcAbiJson {
+    let mut rustc = RustcAbiJson {
+        version: String::new(),
+        commit_hash: String::new(),
+        commit_date: String::new(),
+        channel: String::new(),
+        short: String::new(),
+    };
+    for line in output.lines() {
+        if line.starts_with("rustc ") {
+            rustc.short = line.to_string();
+        } else if let Some(value) = line.strip_prefix("commit-hash: ") {
+            rustc.commit_hash = value.to_string();
+        } else if let Some(value) = line.strip_prefix("commit-date: ") {
+            rustc.commit_date = value.to_string();
+        } else if let Some(value) = line.strip_prefix("release: ") {
+            rustc.version = value.to_string();
+        }
+    }
+    rustc.channel = if rustc.version.contains("nightly") {
+        "Nightly"
+    } else if rustc.version.contains("beta") {
+        "Beta"
+    } else if rustc.version.contains("dev") {
+        "Dev"
+    } else {
+        "Stable"
+    }
+    .to_string();
+    rustc
+}
+
+/// The compiler version of the given toolchain, which is not necessarily the one running the meta crate.
+pub fn rustc_version_of_toolchain(toolchain: &str) -> RustcAbiJson {
+    let output = Command::new("rustc")
+        .arg(format!("+{toolchain}"))
+        .arg("-vV")
+        .output()
+        .expect("failed to run rustc");
+    assert!(
+        output.status.success(),
+        "toolchain {toolchain} is not installed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    parse_rustc_verbose_version(&String::from_utf8_lossy(&output.stdout))
+}
+
+fn home_subdir(env_var: &str, default_subdir: &str) -> Option<PathBuf> {
+    env::var_os(env_var)
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(default_subdir)))
+}
+
+/// Local paths that end up in the compiled code, e.g. via panic locations,
+/// paired with the machine-independent prefix they get replaced with.
+///
+/// The project root comes last, since the last matching remap wins.
+pub fn path_remaps(project_root: &Path) -> Vec<(String, String)> {
+    let mut remaps = Vec::new();
+    if let Some(cargo_home) = home_subdir("CARGO_HOME", ".cargo") {
+        remaps.push((cargo_home.display().to_string(), "/cargo".to_string()));
+    }
+    if let Some(rustup_home) = home_subdir("RUSTUP_HOME", ".rustup") {
+        remaps.push((rustup_home.display().to_string(), "/rustup".to_string()));
+    }
+    remaps.push((project_root.display().to_string(), "/project".to_string()));
+    remaps
+}
+
+/// The root of the git repository containing the contract, or the contract crate itself.
+pub fn project_root(contract_dir: &Path) -> PathBuf {
+    let git_root = Command::new("git")
+        .args(["rev-parse", "--show-toplevel"])
+        .current_dir(contract_dir)
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| PathBuf::from(String::from_utf8_lossy(&output.stdout).trim()));
+    git_root.unwrap_or_else(|| {
+        fs::canonicalize(contract_dir).expect("failed to resolve contract directory")
+    })
+}
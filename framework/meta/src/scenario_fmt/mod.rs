@@ -0,0 +1,116 @@
+mod scenario_files;
+mod scenario_formatter;
+mod scenario_lint;
+
+pub use scenario_files::{
+    find_legacy_scenario_dirs, find_scenario_files, is_scenario_file, upgrade_legacy_path,
+    SCENARIO_FILE_SUFFIXES,
+};
+pub use scenario_formatter::{
+    find_unknown_fields, format_scenario_json, parse_scenario_raw, upgrade_legacy_naming,
+};
+pub use scenario_lint::{lint_scenario_file, ScenarioLintIssue, ScenarioLintKind};
+
+use std::{fs, path::Path};
+
+use colored::Colorize;
+
+use crate::cli_args::{ScenArgs, ScenCliAction};
+
+pub fn scen(args: &ScenArgs) {
+    let path = if let Some(some_path) = &args.path {
+        some_path.as_str()
+    } else {
+        "./"
+    };
+
+    match &args.command {
+        ScenCliAction::Fmt(fmt_args) => scen_fmt(Path::new(path), &args.ignore, fmt_args.check),
+        ScenCliAction::Lint(lint_args) => scen_lint(Path::new(path), &args.ignore, lint_args.check),
+    }
+}
+
+/// Formats all scenario files under the path and renames the legacy `mandos` folders.
+///
+/// With `check`, nothing gets written, instead it fails if anything would change.
+pub fn scen_fmt(path: &Path, ignore: &[String], check: bool) {
+    let mut unformatted_count = 0;
+    let mut error_count = 0;
+
+    for legacy_dir in find_legacy_scenario_dirs(path, ignore) {
+        let new_dir = legacy_dir.with_file_name(scenario_files::SCENARIOS_DIR_NAME);
+        if check {
+            println!("{} {}", "Legacy folder:".yellow(), legacy_dir.display());
+            unformatted_count += 1;
+        } else if new_dir.exists() {
+            println!(
+                "{} {} not renamed, {} already exists",
+                "Error:".red(),
+                legacy_dir.display(),
+                new_dir.display()
+            );
+            error_count += 1;
+        } else {
+            fs::rename(&legacy_dir, &new_dir).expect("failed to rename scenarios folder");
+            println!("{} {}", "Renamed".green(), new_dir.display());
+        }
+    }
+
+    for file_path in find_scenario_files(path, ignore) {
+        let json = fs::read_to_string(&file_path).expect("failed to read scenario file");
+        let formatted = match format_scenario_json(&json) {
+            Ok(formatted) => formatted,
+            Err(err) => {
+                println!("{} {}: {err}", "Error:".red(), file_path.display());
+                error_count += 1;
+                continue;
+            },
+        };
+        if formatted == json {
+            continue;
+        }
+
+        unformatted_count += 1;
+        if check {
+            println!("{} {}", "Not formatted:".yellow(), file_path.display());
+        } else {
+            fs::write(&file_path, formatted).expect("failed to write scenario file");
+            println!("{} {}", "Formatted".green(), file_path.display());
+        }
+    }
+
+    if error_count > 0 || (check && unformatted_count > 0) {
+        println!("\n{unformatted_count} scenario files not formatted, {error_count} errors.");
+        std::process::exit(1);
+    }
+}
+
+/// Reports the problems in all scenario files under the path.
+///
+/// With `check`, it fails if any were found.
+pub fn scen_lint(path: &Path, ignore: &[String], check: bool) {
+    let mut issue_count = 0;
+    for file_path in find_scenario_files(path, ignore) {
+        let issues = lint_scenario_file(&file_path);
+        if issues.is_empty() {
+            continue;
+        }
+
+        println!("{}", file_path.display());
+        for issue in &issues {
+            if issue.kind.is_error() {
+                println!("    {} {issue}", "error:".red());
+            } else {
+                println!("    {} {issue}", "warning:".yellow());
+            }
+        }
+        issue_count += issues.len();
+    }
+
+    if issue_count > 0 {
+        println!("\n{issue_count} scenario issues found.");
+        if check {
+            std::process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The scenario JSON files: the scenarios themselves and the steps they include.
+pub const SCENARIO_FILE_SUFFIXES: &[&str] = &[".scen.json", ".steps.json", ".step.json"];
+
+/// Scenario folders used to be called `mandos`.
+pub const LEGACY_SCENARIOS_DIR_NAME: &str = "mandos";
+pub const SCENARIOS_DIR_NAME: &str = "scenarios";
+
+pub fn is_scenario_file(path: &Path) -> bool {
+    matches!(
+        path.file_name().and_then(|name| name.to_str()),
+        Some(name) if SCENARIO_FILE_SUFFIXES.iter().any(|suffix| name.ends_with(suffix))
+    )
+}
+
+/// Only the `.scen.json` files are run on their own, the others are only ever included.
+pub fn is_main_scenario_file(path: &Path) -> bool {
+    matches!(
+        path.file_name().and_then(|name| name.to_str()),
+        Some(name) if name.ends_with(SCENARIO_FILE_SUFFIXES[0])
+    )
+}
+
+fn is_ignored(path: &Path, ignore: &[String]) -> bool {
+    matches!(
+        path.file_name().and_then(|name| name.to_str()),
+        Some(name) if ignore.iter().any(|ignored| ignored == name)
+    )
+}
+
+fn populate_scenario_files(path: &Path, ignore: &[String], result: &mut Vec<PathBuf>) {
+    if is_ignored(path, ignore) {
+        return;
+    }
+
+    if path.is_file() {
+        if is_scenario_file(path) {
+            result.push(path.to_path_buf());
+        }
+        return;
+    }
+
+    let Ok(read_dir) = fs::read_dir(path) else {
+        return;
+    };
+    for entry in read_dir {
+        populate_scenario_files(&entry.unwrap().path(), ignore, result);
+    }
+}
+
+/// All scenario files under a path, in alphabetical order. The path can also be a single file.
+pub fn find_scenario_files(path: &Path, ignore: &[String]) -> Vec<PathBuf> {
+    let mut result = Vec::new();
+    populate_scenario_files(path, ignore, &mut result);
+    result.sort();
+    result
+}
+
+fn populate_legacy_dirs(path: &Path, ignore: &[String], result: &mut Vec<PathBuf>) {
+    if !path.is_dir() || is_ignored(path, ignore) {
+        return;
+    }
+
+    if path.file_name().and_then(|name| name.to_str()) == Some(LEGACY_SCENARIOS_DIR_NAME) {
+        result.push(path.to_path_buf());
+        return;
+    }
+
+    for entry in fs::read_dir(path).unwrap() {
+        populate_legacy_dirs(&entry.unwrap().path(), ignore, result);
+    }
+}
+
+/// The `mandos` folders under a path, which should be renamed to `scenarios`.
+pub fn find_legacy_scenario_dirs(path: &Path, ignore: &[String]) -> Vec<PathBuf> {
+    let mut result = Vec::new();
+    populate_legacy_dirs(path, ignore, &mut result);
+    result.sort();
+    result
+}
+
+/// Replaces the `mandos` folder in relative paths, such as the ones of the `externalSteps`.
+pub fn upgrade_legacy_path(path: &str) -> String {
+    path.split('/')
+        .map(|segment| {
+            if segment == LEGACY_SCENARIOS_DIR_NAME {
+                SCENARIOS_DIR_NAME
+            } else {
+                segment
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
@@ -0,0 +1,162 @@
+use multiversx_chain_scenario_format::serde_raw::{ScenarioRaw, StepRaw, ValueSubTree};
+use serde::Serialize;
+use serde_json::Value;
+
+use super::scenario_files::upgrade_legacy_path;
+
+pub fn parse_scenario_raw(json: &str) -> Result<ScenarioRaw, String> {
+    serde_json::from_str(json).map_err(|err| format!("invalid scenario JSON: {err}"))
+}
+
+fn upgrade_legacy_value(value: &mut Option<ValueSubTree>, egld_value: &mut Option<ValueSubTree>) {
+    if egld_value.is_none() {
+        *egld_value = value.take();
+    }
+}
+
+/// Converts the fields and paths still named after the old `mandos` format, e.g. `value` becomes `egldValue`.
+pub fn upgrade_legacy_naming(scenario: &mut ScenarioRaw) {
+    for step in &mut scenario.steps {
+        match step {
+            StepRaw::ExternalSteps { path, .. } => *path = upgrade_legacy_path(path),
+            StepRaw::ScCall { tx, .. } => upgrade_legacy_value(&mut tx.value, &mut tx.egld_value),
+            StepRaw::ScDeploy { tx, .. } => upgrade_legacy_value(&mut tx.value, &mut tx.egld_value),
+            StepRaw::Transfer { tx, .. } => upgrade_legacy_value(&mut tx.value, &mut tx.egld_value),
+            StepRaw::ValidatorReward { tx, .. } => {
+                upgrade_legacy_value(&mut tx.value, &mut tx.egld_value)
+            },
+            _ => {},
+        }
+    }
+}
+
+fn is_empty_json(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::String(s) => s.is_empty(),
+        Value::Array(list) => list.is_empty(),
+        Value::Object(map) => map.is_empty(),
+        _ => false,
+    }
+}
+
+fn populate_unknown_fields(path: &str, original: &Value, known: &Value, result: &mut Vec<String>) {
+    match (original, known) {
+        (Value::Object(original_map), Value::Object(known_map)) => {
+            for (key, original_value) in original_map {
+                let field_path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{path}.{key}")
+                };
+                match known_map.get(key) {
+                    Some(known_value) => {
+                        populate_unknown_fields(&field_path, original_value, known_value, result)
+                    },
+                    // empty fields are the defaults, dropping them changes nothing
+                    None if !is_empty_json(original_value) => result.push(field_path),
+                    None => {},
+                }
+            }
+        },
+        (Value::Array(original_list), Value::Array(known_list)) => {
+            for (index, (original_item, known_item)) in
+                original_list.iter().zip(known_list.iter()).enumerate()
+            {
+                populate_unknown_fields(
+                    &format!("{path}[{index}]"),
+                    original_item,
+                    known_item,
+                    result,
+                );
+            }
+        },
+        _ => {},
+    }
+}
+
+/// The fields that the scenario model does not know about, and would be lost when writing the scenario back.
+pub fn find_unknown_fields(json: &str, scenario: &ScenarioRaw) -> Vec<String> {
+    let Ok(original) = serde_json::from_str::<Value>(json) else {
+        return Vec::new();
+    };
+    let known = serde_json::to_value(scenario).unwrap();
+    let mut result = Vec::new();
+    populate_unknown_fields("", &original, &known, &mut result);
+    result
+}
+
+/// Fields renamed by `upgrade_legacy_naming`, they take the place of the old ones.
+const LEGACY_RENAMED_FIELDS: &[(&str, &str)] = &[("value", "egldValue")];
+
+fn renamed_field(key: &str) -> &str {
+    LEGACY_RENAMED_FIELDS
+        .iter()
+        .find(|(old_name, _)| *old_name == key)
+        .map_or(key, |(_, new_name)| new_name)
+}
+
+/// Keeps the fields in the order of the original file,
+/// and leaves out the empty fields that the original did not spell out.
+fn follow_original_layout(formatted: Value, original: &Value) -> Value {
+    match (formatted, original) {
+        (Value::Object(mut formatted_map), Value::Object(original_map)) => {
+            let mut result = serde_json::Map::new();
+            for (key, original_value) in original_map {
+                let new_key = renamed_field(key);
+                if let Some(formatted_value) = formatted_map.remove(new_key) {
+                    result.insert(
+                        new_key.to_string(),
+                        follow_original_layout(formatted_value, original_value),
+                    );
+                }
+            }
+            for (key, formatted_value) in formatted_map {
+                if !is_empty_json(&formatted_value) {
+                    result.insert(key, formatted_value);
+                }
+            }
+            Value::Object(result)
+        },
+        (Value::Array(formatted_list), Value::Array(original_list)) => Value::Array(
+            formatted_list
+                .into_iter()
+                .enumerate()
+                .map(|(index, formatted_item)| match original_list.get(index) {
+                    Some(original_item) => follow_original_layout(formatted_item, original_item),
+                    None => formatted_item,
+                })
+                .collect(),
+        ),
+        (formatted, _) => formatted,
+    }
+}
+
+fn to_pretty_json(value: &Value) -> String {
+    let formatter = serde_json::ser::PrettyFormatter::with_indent(b"    ");
+    let mut ser = serde_json::Serializer::with_formatter(Vec::new(), formatter);
+    value.serialize(&mut ser).unwrap();
+    let mut serialized = String::from_utf8(ser.into_inner()).unwrap();
+    serialized.push('\n');
+    serialized
+}
+
+/// Formats a scenario file the canonical way, the same way the scenario generators write it,
+/// except that the fields keep their order from the original file.
+///
+/// Files with unknown fields are not formatted, since the fields would be lost.
+pub fn format_scenario_json(json: &str) -> Result<String, String> {
+    let mut scenario = parse_scenario_raw(json)?;
+    let unknown_fields = find_unknown_fields(json, &scenario);
+    if !unknown_fields.is_empty() {
+        return Err(format!("unknown fields: {}", unknown_fields.join(", ")));
+    }
+
+    upgrade_legacy_naming(&mut scenario);
+    let original: Value =
+        serde_json::from_str(json).map_err(|err| format!("invalid scenario JSON: {err}"))?;
+    let formatted: Value = serde_json::from_str(&scenario.to_json_string()).unwrap();
+    Ok(to_pretty_json(&follow_original_layout(
+        formatted, &original,
+    )))
+}
@@ -0,0 +1,351 @@
+use std::{
+    collections::{BTreeSet, HashSet},
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+
+use multiversx_chain_scenario_format::serde_raw::{StepRaw, ValueSubTree};
+use serde_json::Value;
+
+use super::{
+    scenario_files::{is_main_scenario_file, upgrade_legacy_path},
+    scenario_formatter::{find_unknown_fields, parse_scenario_raw},
+};
+
+/// Accounts that can be targeted without being set up first: the system smart contracts.
+const SYSTEM_SC_ADDRESS_PREFIX: &str =
+    "0x000000000000000000010000000000000000000000000000000000000";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScenarioLintKind {
+    InvalidJson,
+    UnknownField,
+    LegacyNaming,
+    UnreachableExternalSteps,
+    UndefinedAddress,
+    UnusedAccount,
+}
+
+impl ScenarioLintKind {
+    pub fn is_error(&self) -> bool {
+        matches!(
+            self,
+            ScenarioLintKind::InvalidJson | ScenarioLintKind::UnreachableExternalSteps
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScenarioLintIssue {
+    pub kind: ScenarioLintKind,
+
+    /// Where in the file, e.g. `steps[2].tx.from`.
+    /// Issues coming from included steps are prefixed by the path of the steps file.
+    pub location: String,
+
+    pub detail: String,
+}
+
+impl ScenarioLintIssue {
+    fn new(kind: ScenarioLintKind, location: impl Into<String>, detail: impl Into<String>) -> Self {
+        ScenarioLintIssue {
+            kind,
+            location: location.into(),
+            detail: detail.into(),
+        }
+    }
+}
+
+impl fmt::Display for ScenarioLintIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let location = &self.location;
+        let detail = &self.detail;
+        match self.kind {
+            ScenarioLintKind::InvalidJson => write!(f, "{detail}"),
+            ScenarioLintKind::UnknownField => write!(f, "{location}: unknown field"),
+            ScenarioLintKind::LegacyNaming => write!(f, "{location}: legacy naming, {detail}"),
+            ScenarioLintKind::UnreachableExternalSteps => {
+                write!(f, "{location}: external steps file {detail} not found")
+            },
+            ScenarioLintKind::UndefinedAddress => {
+                write!(f, "{location}: account {detail} was not set up")
+            },
+            ScenarioLintKind::UnusedAccount => {
+                write!(f, "{location}: account {detail} is never used")
+            },
+        }
+    }
+}
+
+/// One scenario file, together with the steps it includes, as one sequence of steps.
+struct LoadedStep {
+    location: String,
+    in_main_file: bool,
+    step: StepRaw,
+    json: Value,
+}
+
+fn load_steps(
+    path: &Path,
+    location_prefix: &str,
+    in_main_file: bool,
+    visited: &mut HashSet<PathBuf>,
+    result: &mut Vec<LoadedStep>,
+) {
+    let Ok(canonical_path) = path.canonicalize() else {
+        return;
+    };
+    if !visited.insert(canonical_path) {
+        return;
+    }
+    let Ok(json) = fs::read_to_string(path) else {
+        return;
+    };
+    let (Ok(scenario), Ok(Value::Object(mut json))) = (
+        parse_scenario_raw(&json),
+        serde_json::from_str::<Value>(&json),
+    ) else {
+        return;
+    };
+    let Some(Value::Array(step_jsons)) = json.remove("steps") else {
+        return;
+    };
+
+    for (index, (step, step_json)) in scenario.steps.into_iter().zip(step_jsons).enumerate() {
+        let location = format!("{location_prefix}steps[{index}]");
+        if let StepRaw::ExternalSteps {
+            path: external_path,
+            ..
+        } = &step
+        {
+            let external_path = path.parent().unwrap().join(external_path);
+            let prefix = format!("{location} {}: ", external_path.display());
+            load_steps(&external_path, &prefix, false, visited, result);
+        }
+        result.push(LoadedStep {
+            location,
+            in_main_file,
+            step,
+            json: step_json,
+        });
+    }
+}
+
+fn address_expr(value: &ValueSubTree) -> Option<&str> {
+    match value {
+        ValueSubTree::Str(s) if !s.is_empty() && !s.starts_with(SYSTEM_SC_ADDRESS_PREFIX) => {
+            Some(s.as_str())
+        },
+        _ => None,
+    }
+}
+
+/// The addresses a step relies on, with their location relative to the step.
+fn referenced_addresses(step: &StepRaw) -> Vec<(&'static str, &str)> {
+    let mut result = Vec::new();
+    match step {
+        StepRaw::ScCall { tx, .. } => {
+            result.extend(address_expr(&tx.from).map(|address| ("tx.from", address)));
+            result.extend(address_expr(&tx.to).map(|address| ("tx.to", address)));
+        },
+        StepRaw::ScQuery { tx, .. } => {
+            result.extend(address_expr(&tx.to).map(|address| ("tx.to", address)));
+        },
+        StepRaw::ScDeploy { tx, .. } => {
+            result.extend(address_expr(&tx.from).map(|address| ("tx.from", address)));
+        },
+        StepRaw::Transfer { tx, .. } => {
+            result.extend(address_expr(&tx.from).map(|address| ("tx.from", address)));
+        },
+        StepRaw::ValidatorReward { tx, .. } => {
+            result.extend(address_expr(&tx.to).map(|address| ("tx.to", address)));
+        },
+        StepRaw::CheckState { accounts, .. } => {
+            result.extend(
+                accounts
+                    .accounts
+                    .keys()
+                    .filter(|address| !address.starts_with(SYSTEM_SC_ADDRESS_PREFIX))
+                    .map(|address| ("accounts", address.as_str())),
+            );
+        },
+        _ => {},
+    }
+    result
+}
+
+/// `address:owner` is used in `address:owner|str:x`, but not in `address:owner2`.
+fn contains_address(s: &str, address: &str) -> bool {
+    s.match_indices(address).any(|(index, _)| {
+        let next = s[index + address.len()..].chars().next();
+        !matches!(next, Some(c) if c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    })
+}
+
+fn collect_strings<'a>(json: &'a Value, with_keys: bool, result: &mut Vec<&'a str>) {
+    match json {
+        Value::String(s) => result.push(s),
+        Value::Array(list) => list
+            .iter()
+            .for_each(|item| collect_strings(item, true, result)),
+        Value::Object(map) => {
+            for (key, value) in map {
+                if with_keys {
+                    result.push(key);
+                }
+                collect_strings(value, true, result);
+            }
+        },
+        _ => {},
+    }
+}
+
+/// All the strings in the steps, except for the account keys in `setState`, which define the accounts.
+fn collect_usage_strings(steps: &[LoadedStep]) -> Vec<&str> {
+    let mut result = Vec::new();
+    for loaded_step in steps {
+        let Value::Object(map) = &loaded_step.json else {
+            continue;
+        };
+        for (key, value) in map {
+            let is_definition =
+                key == "accounts" && matches!(loaded_step.step, StepRaw::SetState { .. });
+            collect_strings(value, !is_definition, &mut result);
+        }
+    }
+    result
+}
+
+fn lint_accounts(steps: &[LoadedStep], issues: &mut Vec<ScenarioLintIssue>) {
+    let mut defined = BTreeSet::new();
+    let mut defined_in_main_file = Vec::new();
+    for loaded_step in steps {
+        if let StepRaw::SetState {
+            accounts,
+            new_addresses,
+            ..
+        } = &loaded_step.step
+        {
+            for address in accounts.keys() {
+                // only the first time an account is set up is reported
+                let newly_defined = defined.insert(address.as_str());
+                if newly_defined && loaded_step.in_main_file {
+                    defined_in_main_file
+                        .push((format!("{}.accounts", loaded_step.location), address));
+                }
+            }
+            defined.extend(
+                new_addresses
+                    .iter()
+                    .filter_map(|new_address| address_expr(&new_address.new_address)),
+            );
+        }
+
+        for (field, address) in referenced_addresses(&loaded_step.step) {
+            if !defined.contains(address) {
+                issues.push(ScenarioLintIssue::new(
+                    ScenarioLintKind::UndefinedAddress,
+                    format!("{}.{field}", loaded_step.location),
+                    address,
+                ));
+            }
+        }
+    }
+
+    let usage_strings = collect_usage_strings(steps);
+    for (location, address) in defined_in_main_file {
+        if !usage_strings.iter().any(|s| contains_address(s, address)) {
+            issues.push(ScenarioLintIssue::new(
+                ScenarioLintKind::UnusedAccount,
+                location,
+                address.as_str(),
+            ));
+        }
+    }
+}
+
+fn lint_legacy_value(
+    location: &str,
+    value: &Option<ValueSubTree>,
+    issues: &mut Vec<ScenarioLintIssue>,
+) {
+    if value.is_some() {
+        issues.push(ScenarioLintIssue::new(
+            ScenarioLintKind::LegacyNaming,
+            format!("{location}.tx.value"),
+            "use egldValue instead",
+        ));
+    }
+}
+
+/// Checks a scenario file, without running it.
+///
+/// Undefined addresses and unused accounts only make sense for whole scenarios,
+/// so these are not checked for the files that only hold steps.
+pub fn lint_scenario_file(path: &Path) -> Vec<ScenarioLintIssue> {
+    let json = match fs::read_to_string(path) {
+        Ok(json) => json,
+        Err(err) => {
+            return vec![ScenarioLintIssue::new(
+                ScenarioLintKind::InvalidJson,
+                "",
+                format!("cannot read file: {err}"),
+            )]
+        },
+    };
+    let scenario = match parse_scenario_raw(&json) {
+        Ok(scenario) => scenario,
+        Err(err) => {
+            return vec![ScenarioLintIssue::new(
+                ScenarioLintKind::InvalidJson,
+                "",
+                err,
+            )]
+        },
+    };
+
+    let mut issues: Vec<ScenarioLintIssue> = find_unknown_fields(&json, &scenario)
+        .into_iter()
+        .map(|location| ScenarioLintIssue::new(ScenarioLintKind::UnknownField, location, ""))
+        .collect();
+
+    for (index, step) in scenario.steps.iter().enumerate() {
+        let location = format!("steps[{index}]");
+        match step {
+            StepRaw::ExternalSteps {
+                path: external_path,
+                ..
+            } => {
+                if upgrade_legacy_path(external_path) != *external_path {
+                    issues.push(ScenarioLintIssue::new(
+                        ScenarioLintKind::LegacyNaming,
+                        format!("{location}.path"),
+                        "the mandos folder is now called scenarios",
+                    ));
+                }
+                if !path.parent().unwrap().join(external_path).is_file() {
+                    issues.push(ScenarioLintIssue::new(
+                        ScenarioLintKind::UnreachableExternalSteps,
+                        format!("{location}.path"),
+                        external_path.as_str(),
+                    ));
+                }
+            },
+            StepRaw::ScCall { tx, .. } => lint_legacy_value(&location, &tx.value, &mut issues),
+            StepRaw::ScDeploy { tx, .. } => lint_legacy_value(&location, &tx.value, &mut issues),
+            StepRaw::Transfer { tx, .. } => lint_legacy_value(&location, &tx.value, &mut issues),
+            StepRaw::ValidatorReward { tx, .. } => {
+                lint_legacy_value(&location, &tx.value, &mut issues)
+            },
+            _ => {},
+        }
+    }
+
+    if is_main_scenario_file(path) {
+        let mut steps = Vec::new();
+        load_steps(path, "", true, &mut HashSet::new(), &mut steps);
+        lint_accounts(&steps, &mut issues);
+    }
+
+    issues
+}
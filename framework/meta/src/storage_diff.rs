@@ -0,0 +1,492 @@
+use crate::{
+    abi_json::{
+        read_abi_json, ContractAbiJson, StorageAbiJson, StructFieldDescriptionJson,
+        TypeDescriptionJson,
+    },
+    cli_args::StorageDiffArgs,
+};
+use multiversx_sc::abi::STORAGE_TYPE_NAME_UNKNOWN;
+use std::collections::{BTreeMap, BTreeSet};
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum StorageChangeSeverity {
+    /// Changes that do not affect the data already in storage.
+    Compatible,
+
+    /// Changes that cannot be checked, or that leave unused data in storage.
+    Warning,
+
+    /// Changes that make the data already in storage undecodable or meaningless.
+    Incompatible,
+}
+
+impl StorageChangeSeverity {
+    fn label(&self) -> &'static str {
+        match self {
+            StorageChangeSeverity::Compatible => "compatible",
+            StorageChangeSeverity::Warning => "warning",
+            StorageChangeSeverity::Incompatible => "INCOMPATIBLE",
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct StorageChange {
+    pub severity: StorageChangeSeverity,
+    pub key: String,
+    pub message: String,
+}
+
+#[derive(Default, Debug)]
+pub struct StorageDiffReport {
+    pub changes: Vec<StorageChange>,
+}
+
+impl StorageDiffReport {
+    pub fn has_incompatible_changes(&self) -> bool {
+        self.changes
+            .iter()
+            .any(|change| change.severity == StorageChangeSeverity::Incompatible)
+    }
+
+    pub fn print(&self) {
+        if self.changes.is_empty() {
+            println!("No storage layout changes.");
+            return;
+        }
+
+        for change in &self.changes {
+            println!(
+                "[{}] storage key \"{}\": {}",
+                change.severity.label(),
+                change.key,
+                change.message
+            );
+        }
+    }
+
+    fn add(&mut self, severity: StorageChangeSeverity, key: &str, message: String) {
+        self.changes.push(StorageChange {
+            severity,
+            key: key.to_string(),
+            message,
+        });
+    }
+}
+
+pub fn storage_diff(args: &StorageDiffArgs) {
+    let old_abi = read_abi_json(&args.old);
+    let new_abi = read_abi_json(&args.new);
+
+    let report = compare_storage_layout(&old_abi, &new_abi);
+    report.print();
+
+    if report.has_incompatible_changes() {
+        std::process::exit(1);
+    }
+}
+
+/// Several storage entries can share a key, e.g. a mapper and a getter with extra key arguments,
+/// so they are grouped by key, in the order in which they appear in the ABI.
+fn storage_by_key(abi: &ContractAbiJson) -> BTreeMap<&str, Vec<&StorageAbiJson>> {
+    let mut storage_by_key: BTreeMap<&str, Vec<&StorageAbiJson>> = BTreeMap::new();
+    for storage in &abi.storage {
+        storage_by_key
+            .entry(storage.key.as_str())
+            .or_default()
+            .push(storage);
+    }
+    storage_by_key
+}
+
+/// Compares the storage layouts declared in two versions of a contract ABI.
+///
+/// The value types are compared structurally, using the type descriptions in each ABI,
+/// so that changes in the fields of stored structs and enums are also detected.
+pub fn compare_storage_layout(
+    old_abi: &ContractAbiJson,
+    new_abi: &ContractAbiJson,
+) -> StorageDiffReport {
+    let mut report = StorageDiffReport::default();
+    let type_comparator = TypeComparator {
+        old_types: &old_abi.types,
+        new_types: &new_abi.types,
+    };
+    let old_storage = storage_by_key(old_abi);
+    let new_storage = storage_by_key(new_abi);
+
+    let keys: BTreeSet<&str> = old_storage
+        .keys()
+        .chain(new_storage.keys())
+        .copied()
+        .collect();
+    for key in keys {
+        let old_entries = old_storage.get(key).cloned().unwrap_or_default();
+        let mut new_entries = new_storage.get(key).cloned().unwrap_or_default();
+
+        // entries with the same key are matched by mapper first,
+        // whatever is left over on both sides is matched in order, as a mapper change
+        let mut unmatched_old_entries = Vec::new();
+        for old_entry in old_entries {
+            match new_entries
+                .iter()
+                .position(|new_entry| new_entry.mapper == old_entry.mapper)
+            {
+                Some(index) => {
+                    let new_entry = new_entries.remove(index);
+                    compare_storage_entry(&mut report, &type_comparator, old_entry, new_entry);
+                },
+                None => unmatched_old_entries.push(old_entry),
+            }
+        }
+
+        let mut new_entries = new_entries.into_iter();
+        for old_entry in unmatched_old_entries {
+            match new_entries.next() {
+                Some(new_entry) => {
+                    compare_storage_entry(&mut report, &type_comparator, old_entry, new_entry)
+                },
+                None => report.add(
+                    StorageChangeSeverity::Warning,
+                    key,
+                    format!(
+                        "{} removed, existing data will be left in storage",
+                        old_entry.mapper
+                    ),
+                ),
+            }
+        }
+        for new_entry in new_entries {
+            report.add(
+                StorageChangeSeverity::Compatible,
+                key,
+                format!("{} added", new_entry.mapper),
+            );
+        }
+    }
+
+    report
+}
+
+fn compare_storage_entry(
+    report: &mut StorageDiffReport,
+    type_comparator: &TypeComparator,
+    old_entry: &StorageAbiJson,
+    new_entry: &StorageAbiJson,
+) {
+    let key = old_entry.key.as_str();
+    if old_entry.mapper != new_entry.mapper {
+        report.add(
+            StorageChangeSeverity::Incompatible,
+            key,
+            format!(
+                "mapper changed from {} to {}",
+                old_entry.mapper, new_entry.mapper
+            ),
+        );
+        return;
+    }
+
+    let old_key_arg_types: Vec<&str> = old_entry
+        .key_args
+        .iter()
+        .map(|arg| arg.type_name.as_str())
+        .collect();
+    let new_key_arg_types: Vec<&str> = new_entry
+        .key_args
+        .iter()
+        .map(|arg| arg.type_name.as_str())
+        .collect();
+    compare_type_lists(
+        report,
+        type_comparator,
+        key,
+        "key argument",
+        &old_key_arg_types,
+        &new_key_arg_types,
+    );
+
+    let old_value_types: Vec<&str> = old_entry.value_types.iter().map(String::as_str).collect();
+    let new_value_types: Vec<&str> = new_entry.value_types.iter().map(String::as_str).collect();
+    compare_type_lists(
+        report,
+        type_comparator,
+        key,
+        "value type",
+        &old_value_types,
+        &new_value_types,
+    );
+}
+
+fn compare_type_lists(
+    report: &mut StorageDiffReport,
+    type_comparator: &TypeComparator,
+    key: &str,
+    what: &str,
+    old_type_names: &[&str],
+    new_type_names: &[&str],
+) {
+    if old_type_names.len() != new_type_names.len() {
+        report.add(
+            StorageChangeSeverity::Incompatible,
+            key,
+            format!(
+                "{what}s changed from ({}) to ({})",
+                old_type_names.join(", "),
+                new_type_names.join(", ")
+            ),
+        );
+        return;
+    }
+
+    for (index, (old_type_name, new_type_name)) in
+        old_type_names.iter().zip(new_type_names.iter()).enumerate()
+    {
+        if *old_type_name == STORAGE_TYPE_NAME_UNKNOWN
+            || *new_type_name == STORAGE_TYPE_NAME_UNKNOWN
+        {
+            report.add(
+                StorageChangeSeverity::Warning,
+                key,
+                format!("{what} #{index} has no ABI description and cannot be checked"),
+            );
+            continue;
+        }
+
+        let mut visited = BTreeSet::new();
+        for (severity, message) in
+            type_comparator.compare_types(old_type_name, new_type_name, &mut visited)
+        {
+            report.add(severity, key, format!("{what} #{index}: {message}"));
+        }
+    }
+}
+
+pub(crate) struct TypeComparator<'a> {
+    pub old_types: &'a BTreeMap<String, TypeDescriptionJson>,
+    pub new_types: &'a BTreeMap<String, TypeDescriptionJson>,
+}
+
+pub(crate) type TypeChange = (StorageChangeSeverity, String);
+
+impl<'a> TypeComparator<'a> {
+    /// Type names are compared as they appear in the ABI, e.g. `List<Option<MyStruct>>`,
+    /// then each type they refer to that has a description is compared field by field.
+    ///
+    /// Only the layout gets encoded, so a described type that got renamed, e.g. to `List<Option<NewStruct>>`,
+    /// is compatible as long as its description still matches.
+    pub(crate) fn compare_types(
+        &self,
+        old_type_name: &str,
+        new_type_name: &str,
+        visited: &mut BTreeSet<String>,
+    ) -> Vec<TypeChange> {
+        let type_changed = || {
+            vec![(
+                StorageChangeSeverity::Incompatible,
+                format!("type changed from {old_type_name} to {new_type_name}"),
+            )]
+        };
+        let old_tokens = type_name_tokens(old_type_name);
+        let new_tokens = type_name_tokens(new_type_name);
+        if old_tokens.len() != new_tokens.len() {
+            return type_changed();
+        }
+
+        let mut changes = Vec::new();
+        for (old_token, new_token) in old_tokens.into_iter().zip(new_tokens) {
+            if old_token == new_token {
+                changes.extend(self.compare_same_type(old_token, visited));
+                continue;
+            }
+
+            let (Some(old_description), Some(new_description)) =
+                (self.old_types.get(old_token), self.new_types.get(new_token))
+            else {
+                return type_changed();
+            };
+            changes.push((
+                StorageChangeSeverity::Compatible,
+                format!("type {old_token} renamed to {new_token}"),
+            ));
+            if visited.insert(old_token.to_string()) {
+                changes.extend(self.compare_descriptions(
+                    old_token,
+                    old_description,
+                    new_description,
+                    visited,
+                ));
+            }
+        }
+        changes
+    }
+
+    fn compare_same_type(
+        &self,
+        type_name: &str,
+        visited: &mut BTreeSet<String>,
+    ) -> Vec<TypeChange> {
+        let Some(old_description) = self.old_types.get(type_name) else {
+            return Vec::new();
+        };
+        if !visited.insert(type_name.to_string()) {
+            return Vec::new();
+        }
+
+        match self.new_types.get(type_name) {
+            Some(new_description) => {
+                self.compare_descriptions(type_name, old_description, new_description, visited)
+            },
+            None => vec![(
+                StorageChangeSeverity::Incompatible,
+                format!("type {type_name} no longer described in the new ABI"),
+            )],
+        }
+    }
+
+    pub(crate) fn compare_descriptions(
+        &self,
+        type_name: &str,
+        old_description: &TypeDescriptionJson,
+        new_description: &TypeDescriptionJson,
+        visited: &mut BTreeSet<String>,
+    ) -> Vec<TypeChange> {
+        if old_description.content_type != new_description.content_type {
+            return vec![(
+                StorageChangeSeverity::Incompatible,
+                format!(
+                    "type {type_name} changed from {} to {}",
+                    old_description.content_type, new_description.content_type
+                ),
+            )];
+        }
+
+        if old_description.discriminant_len() != new_description.discriminant_len() {
+            return vec![(
+                StorageChangeSeverity::Incompatible,
+                format!(
+                    "type {type_name} discriminants changed from {} to {} bytes",
+                    old_description.discriminant_len(),
+                    new_description.discriminant_len()
+                ),
+            )];
+        }
+
+        let mut changes = self.compare_fields(
+            type_name,
+            &old_description.fields,
+            &new_description.fields,
+            visited,
+        );
+
+        for old_variant in &old_description.variants {
+            let context = format!("{type_name}::{}", old_variant.name);
+            match new_description
+                .variants
+                .iter()
+                .find(|new_variant| new_variant.discriminant == old_variant.discriminant)
+            {
+                Some(new_variant) => {
+                    if new_variant.name != old_variant.name {
+                        changes.push((
+                            StorageChangeSeverity::Compatible,
+                            format!("variant {context} renamed to {}", new_variant.name),
+                        ));
+                    }
+                    changes.extend(self.compare_fields(
+                        &context,
+                        &old_variant.fields,
+                        &new_variant.fields,
+                        visited,
+                    ));
+                },
+                None => changes.push((
+                    StorageChangeSeverity::Incompatible,
+                    format!(
+                        "variant {context} (discriminant {}) removed",
+                        old_variant.discriminant
+                    ),
+                )),
+            }
+        }
+
+        changes
+    }
+
+    /// Fields are encoded in order and without names, so only their position and type matter.
+    fn compare_fields(
+        &self,
+        context: &str,
+        old_fields: &[StructFieldDescriptionJson],
+        new_fields: &[StructFieldDescriptionJson],
+        visited: &mut BTreeSet<String>,
+    ) -> Vec<TypeChange> {
+        let mut changes = Vec::new();
+        if old_fields.len() != new_fields.len() {
+            changes.push((
+                StorageChangeSeverity::Incompatible,
+                format!(
+                    "{context} had {} fields, now has {}",
+                    old_fields.len(),
+                    new_fields.len()
+                ),
+            ));
+        }
+
+        for (old_field, new_field) in old_fields.iter().zip(new_fields.iter()) {
+            if old_field.name != new_field.name {
+                if old_fields.iter().any(|field| field.name == new_field.name) {
+                    changes.push((
+                        StorageChangeSeverity::Incompatible,
+                        format!(
+                            "{context} fields reordered, {} is now where {} used to be",
+                            new_field.name, old_field.name
+                        ),
+                    ));
+                } else {
+                    changes.push((
+                        StorageChangeSeverity::Compatible,
+                        format!(
+                            "{context} field {} renamed to {}",
+                            old_field.name, new_field.name
+                        ),
+                    ));
+                }
+            }
+
+            for (severity, message) in
+                self.compare_types(&old_field.field_type, &new_field.field_type, visited)
+            {
+                changes.push((
+                    severity,
+                    format!("{context} field {}: {message}", old_field.name),
+                ));
+            }
+        }
+
+        changes
+    }
+}
+
+/// Splits an ABI type name into identifiers and punctuation,
+/// e.g. `List`, `<`, `Option`, `<`, `MyStruct`, `>` and `>` for `List<Option<MyStruct>>`.
+fn type_name_tokens(type_name: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut identifier_start = None;
+    for (index, c) in type_name.char_indices() {
+        if c.is_alphanumeric() || c == '_' {
+            identifier_start.get_or_insert(index);
+            continue;
+        }
+        if let Some(start) = identifier_start.take() {
+            tokens.push(&type_name[start..index]);
+        }
+        if !c.is_whitespace() {
+            tokens.push(&type_name[index..index + c.len_utf8()]);
+        }
+    }
+    if let Some(start) = identifier_start {
+        tokens.push(&type_name[start..]);
+    }
+    tokens
+}
@@ -0,0 +1,69 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{folder_structure::CARGO_TOML_FILE_NAME, CargoTomlContents};
+
+/// A contract that `sc-meta new` can start from.
+///
+/// Templates are regular contracts from the framework repository, they are kept working by the repository's own tests.
+/// `sc-meta` embeds their files when it is built.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ContractTemplate {
+    pub name: &'static str,
+
+    /// Relative to the root of the framework repository.
+    pub path: &'static str,
+}
+
+include!("template_list.rs");
+
+mod embedded {
+    include!(concat!(env!("OUT_DIR"), "/embedded_templates.rs"));
+}
+
+/// Writes the template files embedded at build time to `repo_path`, laid out as in the framework repository.
+///
+/// Returns `false` if there are none, which is the case when `sc-meta` is built from the published crate.
+pub fn extract_embedded_templates(repo_path: &Path) -> bool {
+    if embedded::EMBEDDED_TEMPLATE_FILES.is_empty() {
+        return false;
+    }
+    for (relative_path, contents) in embedded::EMBEDDED_TEMPLATE_FILES {
+        let path = repo_path.join(relative_path);
+        fs::create_dir_all(path.parent().unwrap())
+            .unwrap_or_else(|err| panic!("failed to create {}: {err}", path.display()));
+        fs::write(&path, contents)
+            .unwrap_or_else(|err| panic!("failed to write {}: {err}", path.display()));
+    }
+    true
+}
+
+pub fn template_names() -> Vec<&'static str> {
+    CONTRACT_TEMPLATES
+        .iter()
+        .map(|template| template.name)
+        .collect()
+}
+
+impl ContractTemplate {
+    pub fn find(name: &str) -> Option<&'static ContractTemplate> {
+        CONTRACT_TEMPLATES
+            .iter()
+            .find(|template| template.name == name)
+    }
+
+    pub fn source_dir(&self, repo_path: &Path) -> PathBuf {
+        repo_path.join(self.path)
+    }
+
+    /// The package name in the template's `Cargo.toml`, e.g. `crowdfunding-esdt`.
+    pub fn crate_name(&self, repo_path: &Path) -> String {
+        let cargo_toml_path = self.source_dir(repo_path).join(CARGO_TOML_FILE_NAME);
+        let cargo_toml_contents = CargoTomlContents::load_from_file(cargo_toml_path);
+        cargo_toml_contents
+            .package_name()
+            .expect("template Cargo.toml has no package name")
+    }
+}
@@ -0,0 +1,74 @@
+mod contract_template;
+mod template_copy;
+mod template_instance;
+
+pub use contract_template::{
+    extract_embedded_templates, template_names, ContractTemplate, CONTRACT_TEMPLATES,
+};
+pub use template_copy::{
+    apply_replacements, copy_template_dir, TemplateAdjustments, TemplateReplacement,
+};
+pub use template_instance::{validate_crate_name, TemplateInstance};
+
+use std::path::{Path, PathBuf};
+
+use crate::{cli_args::NewArgs, meta_all::call_contract_meta};
+
+/// Creates a new contract crate from a template, then lets its meta crate generate the interactor.
+pub fn create_contract(args: &NewArgs) {
+    let template = ContractTemplate::find(&args.template).unwrap_or_else(|| {
+        panic!(
+            "unknown template `{}`, available templates: {}",
+            args.template,
+            template_names().join(", ")
+        )
+    });
+    if let Some(name) = &args.name {
+        validate_crate_name(name).unwrap_or_else(|err| panic!("{err}"));
+    }
+
+    // the embedded templates are extracted to a temporary directory, which is removed in the end
+    let embedded_templates_dir;
+    let repo_path = match &args.repo_path {
+        Some(repo_path) => PathBuf::from(repo_path),
+        None => {
+            assert!(
+                !args.local_framework,
+                "--local-framework needs a checkout of the framework repository, use --repo-path to point to it"
+            );
+            embedded_templates_dir = tempfile::tempdir()
+                .expect("failed to create a temporary directory for the templates");
+            assert!(
+                extract_embedded_templates(embedded_templates_dir.path()),
+                "this build of sc-meta has no embedded templates, use --repo-path to point to a checkout of the framework repository"
+            );
+            embedded_templates_dir.path().to_path_buf()
+        },
+    };
+    assert!(
+        template.source_dir(&repo_path).is_dir(),
+        "template `{}` not found in {}",
+        template.name,
+        repo_path.display()
+    );
+
+    let parent_dir = Path::new(args.path.as_deref().unwrap_or("./"));
+    let instance = TemplateInstance::new(
+        *template,
+        &repo_path,
+        args.name.as_deref(),
+        parent_dir,
+        args.local_framework,
+    );
+    instance.create();
+    println!(
+        "Created contract {} from template {} in {}",
+        instance.crate_name,
+        template.name,
+        instance.target_dir.display()
+    );
+
+    if !args.no_interactor {
+        call_contract_meta(&instance.target_dir, &["snippets".to_string()]);
+    }
+}
@@ -0,0 +1,136 @@
+use std::{fs, path::Path};
+
+use crate::test_gen::SCENARIO_FILE_SUFFIX;
+
+/// Build artifacts, generated files and the legacy `mxpy` interaction scripts are not part of a new contract.
+const IGNORED_NAMES: &[&str] = &[
+    "target",
+    "output",
+    "interaction",
+    "interact-rs",
+    "scenarios-gen",
+    "Cargo.lock",
+];
+
+/// Scenario traces and scenarios written by the Rust tests.
+const IGNORED_SCENARIO_PREFIXES: &[&str] = &["trace", "_generated"];
+
+/// A text replacement, applied when copying the template files.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TemplateReplacement {
+    pub from: String,
+    pub to: String,
+}
+
+impl TemplateReplacement {
+    pub fn new(from: impl Into<String>, to: impl Into<String>) -> Self {
+        TemplateReplacement {
+            from: from.into(),
+            to: to.into(),
+        }
+    }
+}
+
+/// How the template files are adjusted to the new contract name.
+///
+/// Only Rust, TOML and JSON files are adjusted, all others are copied as they are.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TemplateAdjustments {
+    pub rust: Vec<TemplateReplacement>,
+    pub toml: Vec<TemplateReplacement>,
+    pub json: Vec<TemplateReplacement>,
+    pub rust_file_names: Vec<TemplateReplacement>,
+}
+
+impl TemplateAdjustments {
+    fn content_replacements(&self, path: &Path) -> Option<&[TemplateReplacement]> {
+        match path.extension()?.to_str()? {
+            "rs" => Some(&self.rust),
+            "toml" => Some(&self.toml),
+            "json" => Some(&self.json),
+            _ => None,
+        }
+    }
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+/// Applies all the replacements in a single pass, so a replaced text is never replaced again.
+///
+/// At each position the first matching replacement wins.
+/// A match must not continue an identifier, so replacing `adder` leaves `ladder` alone.
+pub fn apply_replacements(text: &str, replacements: &[TemplateReplacement]) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    let mut previous_char: Option<char> = None;
+    'outer: while let Some(c) = rest.chars().next() {
+        let at_boundary = !matches!(previous_char, Some(previous) if is_identifier_char(previous));
+        for replacement in replacements {
+            let continues_identifier =
+                replacement.from.starts_with(is_identifier_char) && !at_boundary;
+            if !continues_identifier && rest.starts_with(replacement.from.as_str()) {
+                result.push_str(&replacement.to);
+                rest = &rest[replacement.from.len()..];
+                previous_char = replacement.from.chars().last();
+                continue 'outer;
+            }
+        }
+        result.push(c);
+        rest = &rest[c.len_utf8()..];
+        previous_char = Some(c);
+    }
+    result
+}
+
+fn is_ignored(file_name: &str) -> bool {
+    if IGNORED_NAMES.contains(&file_name) {
+        return true;
+    }
+    file_name.ends_with(SCENARIO_FILE_SUFFIX)
+        && IGNORED_SCENARIO_PREFIXES
+            .iter()
+            .any(|prefix| file_name.starts_with(prefix))
+}
+
+/// Copies the template recursively, adjusting file contents and Rust file names along the way.
+pub fn copy_template_dir(source: &Path, target: &Path, adjustments: &TemplateAdjustments) {
+    fs::create_dir_all(target)
+        .unwrap_or_else(|err| panic!("failed to create {}: {err}", target.display()));
+
+    let read_dir = fs::read_dir(source).expect("error reading template directory");
+    for child_result in read_dir {
+        let child_path = child_result.unwrap().path();
+        let file_name = child_path
+            .file_name()
+            .unwrap()
+            .to_str()
+            .expect("non-utf8 file name in template");
+        if is_ignored(file_name) {
+            continue;
+        }
+
+        if child_path.is_dir() {
+            copy_template_dir(&child_path, &target.join(file_name), adjustments);
+            continue;
+        }
+
+        let Some(content_replacements) = adjustments.content_replacements(&child_path) else {
+            fs::copy(&child_path, target.join(file_name)).expect("failed to copy template file");
+            continue;
+        };
+
+        let target_file_name = if child_path.extension().unwrap() == "rs" {
+            apply_replacements(file_name, &adjustments.rust_file_names)
+        } else {
+            file_name.to_string()
+        };
+        let contents = fs::read_to_string(&child_path).expect("failed to read template file");
+        fs::write(
+            target.join(target_file_name),
+            apply_replacements(&contents, content_replacements),
+        )
+        .expect("failed to write contract file");
+    }
+}
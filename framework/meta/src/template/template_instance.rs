@@ -0,0 +1,265 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use toml::{value::Table, Value};
+
+use crate::{
+    cargo_toml_contents::{CARGO_TOML_DEPENDENCIES, CARGO_TOML_DEV_DEPENDENCIES},
+    folder_structure::{CARGO_TOML_FILE_NAME, FRAMEWORK_CRATE_NAMES},
+    test_gen::{
+        scenario_file_path, scenario_go_test_code, scenario_names, scenario_rs_test_code,
+        scenario_rs_world_code, ScenarioTestEntry, ScenarioTestFile, ScenarioTestKind,
+        SCENARIOS_DIR_NAME,
+    },
+    CargoTomlContents,
+};
+
+use super::{copy_template_dir, ContractTemplate, TemplateAdjustments, TemplateReplacement};
+
+const FRAMEWORK_VERSION: &str = env!("CARGO_PKG_VERSION");
+const MULTI_CONTRACT_CONFIG_FILE_NAME: &str = "multicontract.toml";
+const CONTRACT_SUB_CRATES: &[&str] = &["meta", "wasm"];
+
+/// A new contract crate, created from a template.
+#[derive(Clone, Debug)]
+pub struct TemplateInstance {
+    pub template: ContractTemplate,
+    pub repo_path: PathBuf,
+    pub template_crate_name: String,
+    pub crate_name: String,
+    pub target_dir: PathBuf,
+
+    /// Framework dependencies keep pointing to the repository the template comes from.
+    pub local_framework: bool,
+}
+
+/// Crate names are kebab-case by convention, but snake_case also works.
+pub fn validate_crate_name(crate_name: &str) -> Result<(), String> {
+    let starts_with_letter = matches!(crate_name.chars().next(), Some(c) if c.is_ascii_lowercase());
+    let valid_chars = crate_name
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_');
+    if starts_with_letter && valid_chars {
+        Ok(())
+    } else {
+        Err(format!(
+            "invalid contract crate name `{crate_name}`, only lowercase letters, digits, '-' and '_' are allowed, starting with a letter"
+        ))
+    }
+}
+
+fn snake_case(crate_name: &str) -> String {
+    crate_name.replace('-', "_")
+}
+
+impl TemplateInstance {
+    pub fn new(
+        template: ContractTemplate,
+        repo_path: &Path,
+        crate_name: Option<&str>,
+        parent_dir: &Path,
+        local_framework: bool,
+    ) -> Self {
+        let template_crate_name = template.crate_name(repo_path);
+        let crate_name = crate_name.unwrap_or(template.name).to_string();
+        let target_dir = parent_dir.join(&crate_name);
+        TemplateInstance {
+            template,
+            repo_path: repo_path.to_path_buf(),
+            template_crate_name,
+            crate_name,
+            target_dir,
+            local_framework,
+        }
+    }
+
+    pub fn source_dir(&self) -> PathBuf {
+        self.template.source_dir(&self.repo_path)
+    }
+
+    /// How the contract name appears in the code, in the scenarios and in the `Cargo.toml` files.
+    pub fn adjustments(&self) -> TemplateAdjustments {
+        let old_kebab = &self.template_crate_name;
+        let new_kebab = &self.crate_name;
+        let old_snake = snake_case(old_kebab);
+        let new_snake = snake_case(new_kebab);
+        let wasm_replacement =
+            TemplateReplacement::new(format!("{old_kebab}.wasm"), format!("{new_kebab}.wasm"));
+
+        TemplateAdjustments {
+            rust: vec![
+                TemplateReplacement::new(
+                    format!("set_current_dir_from_workspace(\"{}\")", self.template.path),
+                    "set_current_dir_from_workspace(\"\")",
+                ),
+                wasm_replacement.clone(),
+                TemplateReplacement::new(format!("{old_snake}::"), format!("{new_snake}::")),
+                TemplateReplacement::new(
+                    format!("endpoints! {{\n    {old_snake}\n"),
+                    format!("endpoints! {{\n    {new_snake}\n"),
+                ),
+            ],
+            toml: vec![
+                TemplateReplacement::new(
+                    format!("src/{old_snake}.rs"),
+                    format!("src/{new_snake}.rs"),
+                ),
+                TemplateReplacement::new(old_kebab, new_kebab),
+            ],
+            json: vec![wasm_replacement],
+            rust_file_names: vec![
+                TemplateReplacement::new(format!("{old_snake}_"), format!("{new_snake}_")),
+                TemplateReplacement::new(format!("{old_snake}.rs"), format!("{new_snake}.rs")),
+            ],
+        }
+    }
+
+    /// Creates the contract crate, together with its meta and wasm crates, multi-contract config and scenario tests.
+    ///
+    /// Does not call cargo, the interactor is generated separately, by the meta crate.
+    pub fn create(&self) {
+        assert!(
+            !self.target_dir.exists(),
+            "target directory {} already exists",
+            self.target_dir.display()
+        );
+
+        copy_template_dir(&self.source_dir(), &self.target_dir, &self.adjustments());
+        self.update_cargo_toml("", true);
+        for sub_crate in CONTRACT_SUB_CRATES {
+            self.update_cargo_toml(sub_crate, false);
+        }
+        self.write_multi_contract_config();
+        self.write_scenario_tests();
+    }
+
+    fn update_cargo_toml(&self, crate_dir: &str, is_contract_crate: bool) {
+        let cargo_toml_path = self.target_dir.join(crate_dir).join(CARGO_TOML_FILE_NAME);
+        if !cargo_toml_path.is_file() {
+            return;
+        }
+
+        let mut cargo_toml_contents = CargoTomlContents::load_from_file(&cargo_toml_path);
+        let template_crate_dir = self.source_dir().join(crate_dir);
+        for deps_name in [CARGO_TOML_DEPENDENCIES, CARGO_TOML_DEV_DEPENDENCIES] {
+            if let Some(Value::Table(deps)) = cargo_toml_contents.toml_value.get_mut(deps_name) {
+                self.update_framework_dependencies(deps, &template_crate_dir);
+            }
+        }
+
+        // the contract and its meta crate form a workspace, the wasm crate is already a workspace of its own
+        if is_contract_crate {
+            let mut workspace = Table::new();
+            workspace.insert(
+                "members".to_string(),
+                Value::Array(vec![
+                    Value::String(".".to_string()),
+                    Value::String("meta".to_string()),
+                ]),
+            );
+            cargo_toml_contents
+                .toml_value
+                .as_table_mut()
+                .expect("malformed contract Cargo.toml")
+                .insert("workspace".to_string(), Value::Table(workspace));
+        }
+
+        cargo_toml_contents.save_to_file(&cargo_toml_path);
+    }
+
+    fn update_framework_dependencies(&self, deps: &mut Table, template_crate_dir: &Path) {
+        for &framework_crate_name in FRAMEWORK_CRATE_NAMES {
+            match deps.get_mut(framework_crate_name) {
+                Some(Value::Table(dep)) => {
+                    dep.insert(
+                        "version".to_string(),
+                        Value::String(FRAMEWORK_VERSION.to_string()),
+                    );
+                    let Some(Value::String(path)) = dep.remove("path") else {
+                        continue;
+                    };
+                    if self.local_framework {
+                        let local_path = template_crate_dir
+                            .join(path)
+                            .canonicalize()
+                            .expect("framework crate not found in the template repository");
+                        dep.insert(
+                            "path".to_string(),
+                            Value::String(local_path.to_string_lossy().to_string()),
+                        );
+                    }
+                },
+                Some(version) => {
+                    *version = Value::String(FRAMEWORK_VERSION.to_string());
+                },
+                None => {},
+            }
+        }
+    }
+
+    fn write_multi_contract_config(&self) {
+        let config_path = self.target_dir.join(MULTI_CONTRACT_CONFIG_FILE_NAME);
+        if config_path.exists() {
+            return;
+        }
+
+        fs::write(
+            config_path,
+            format!(
+                "[settings]
+main = \"main\"
+
+[contracts.main]
+name = \"{}\"
+add-unlabelled = true
+",
+                self.crate_name
+            ),
+        )
+        .expect("failed to write multicontract.toml");
+    }
+
+    /// Scenarios not covered by the template's own tests are added, but ignored.
+    fn write_scenario_tests(&self) {
+        let names = scenario_names(&self.target_dir.join(SCENARIOS_DIR_NAME));
+        if names.is_empty() {
+            return;
+        }
+
+        let template_snake = snake_case(&self.template_crate_name);
+        let crate_snake = snake_case(&self.crate_name);
+        for kind in [ScenarioTestKind::Rs, ScenarioTestKind::Go] {
+            let template_test_path = self
+                .source_dir()
+                .join("tests")
+                .join(kind.test_file_name(&template_snake));
+            let template_tests = fs::read_to_string(template_test_path)
+                .ok()
+                .map(|code| ScenarioTestFile::parse(&code));
+            let entries: Vec<ScenarioTestEntry> = names
+                .iter()
+                .map(|name| match &template_tests {
+                    None => ScenarioTestEntry::new(kind, name),
+                    Some(template_tests) => template_tests
+                        .find_entry(&scenario_file_path(name))
+                        .cloned()
+                        .unwrap_or_else(|| ScenarioTestEntry::ignored(kind, name)),
+                })
+                .collect();
+
+            let code = match kind {
+                ScenarioTestKind::Rs => scenario_rs_test_code(
+                    &scenario_rs_world_code("", &self.crate_name, &crate_snake),
+                    &entries,
+                ),
+                ScenarioTestKind::Go => scenario_go_test_code(&entries),
+            };
+            let tests_dir = self.target_dir.join("tests");
+            fs::create_dir_all(&tests_dir).expect("failed to create tests directory");
+            fs::write(tests_dir.join(kind.test_file_name(&crate_snake)), code)
+                .expect("failed to write scenario test file");
+        }
+    }
+}
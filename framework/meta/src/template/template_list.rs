@@ -0,0 +1,16 @@
+// Also included by the build script of the meta crate, which embeds the files of these templates.
+
+pub const CONTRACT_TEMPLATES: &[ContractTemplate] = &[
+    ContractTemplate {
+        name: "adder",
+        path: "contracts/examples/adder",
+    },
+    ContractTemplate {
+        name: "crowdfunding",
+        path: "contracts/examples/crowdfunding-esdt",
+    },
+    ContractTemplate {
+        name: "ping-pong",
+        path: "contracts/examples/ping-pong-egld",
+    },
+];
@@ -0,0 +1,73 @@
+mod scenario_list;
+mod scenario_test_code;
+mod scenario_test_file;
+mod test_gen_contract;
+
+pub use scenario_list::{
+    scenario_file_path, scenario_names, scenario_test_fn_name, SCENARIOS_DIR_NAME,
+    SCENARIO_FILE_SUFFIX,
+};
+pub use scenario_test_code::{scenario_rs_world_code, ScenarioTestEntry, ScenarioTestKind};
+pub use scenario_test_file::{
+    insert_scenario_tests, parse_scenario_test_entries, scenario_go_test_code,
+    scenario_rs_test_code, ScenarioTestFile,
+};
+pub use test_gen_contract::{test_gen_file, workspace_relative_path, TestGenFileReport};
+
+use colored::Colorize;
+
+use crate::{
+    cli_args::TestGenArgs,
+    folder_structure::{dir_pretty_print, RelevantDirectories},
+};
+
+/// Generates or refreshes the scenario test harnesses of all contracts under the given path.
+pub fn test_gen(args: &TestGenArgs) {
+    let path = if let Some(some_path) = &args.path {
+        some_path.as_str()
+    } else {
+        "./"
+    };
+
+    let dirs = RelevantDirectories::find_all(path, args.ignore.as_slice());
+    dir_pretty_print(dirs.iter_contract_crates(), "", &|_| {});
+
+    let mut missing_count = 0;
+    for contract_crate in dirs.iter_contract_crates() {
+        for kind in [ScenarioTestKind::Rs, ScenarioTestKind::Go] {
+            let Some(report) = test_gen_file(&contract_crate.path, kind, args.add_missing) else {
+                continue;
+            };
+            print_test_gen_report(&report);
+            missing_count += report.missing.len();
+        }
+    }
+
+    if missing_count > 0 {
+        println!(
+            "\n{}",
+            format!(
+                "{missing_count} scenario test entries missing, use --add-missing to add them."
+            )
+            .yellow()
+        );
+    }
+}
+
+fn print_test_gen_report(report: &TestGenFileReport) {
+    let path = report.path.display();
+    if report.created {
+        println!("{} {path}", "Created".green());
+    } else if report.changed {
+        println!("{} {path}", "Updated".green());
+    }
+    for name in &report.added {
+        println!("    added {name}");
+    }
+    for scenario_path in &report.stale {
+        println!("    {} {scenario_path} not found", "stale:".yellow());
+    }
+    for name in &report.missing {
+        println!("    {} {name} has no test in {path}", "missing:".yellow());
+    }
+}
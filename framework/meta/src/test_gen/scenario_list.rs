@@ -0,0 +1,49 @@
+use std::{fs, path::Path};
+
+pub const SCENARIO_FILE_SUFFIX: &str = ".scen.json";
+pub const SCENARIOS_DIR_NAME: &str = "scenarios";
+
+/// The names of all the scenarios in a folder, without the `.scen.json` suffix, in alphabetical order.
+///
+/// Only looks at the top level, the `.steps.json` files are not scenarios in their own right.
+pub fn scenario_names(scenarios_dir: &Path) -> Vec<String> {
+    let Ok(read_dir) = fs::read_dir(scenarios_dir) else {
+        return Vec::new();
+    };
+
+    let mut names: Vec<String> = read_dir
+        .filter_map(|entry| {
+            let path = entry.unwrap().path();
+            if !path.is_file() {
+                return None;
+            }
+            let file_name = path.file_name()?.to_str()?;
+            file_name
+                .strip_suffix(SCENARIO_FILE_SUFFIX)
+                .map(str::to_string)
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+/// The name of the test function that runs a scenario, without the `_rs`/`_go` suffix.
+///
+/// E.g. `ping-pong-call-ping` becomes `ping_pong_call_ping`.
+pub fn scenario_test_fn_name(scenario_name: &str) -> String {
+    scenario_name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Path of a scenario relative to the contract crate, as it appears in the tests.
+pub fn scenario_file_path(scenario_name: &str) -> String {
+    format!("{SCENARIOS_DIR_NAME}/{scenario_name}{SCENARIO_FILE_SUFFIX}")
+}
@@ -0,0 +1,154 @@
+use std::fmt::Write;
+
+use super::{scenario_file_path, scenario_test_fn_name};
+
+/// Same limits as the `rustfmt` defaults, so that the generated files need no formatting.
+const MAX_WIDTH: usize = 100;
+const FN_CALL_WIDTH: usize = 60;
+const INDENT: &str = "    ";
+pub(super) const TEST_ATTRIBUTE: &str = "#[test]";
+const IGNORE_ATTRIBUTE: &str = "#[ignore]";
+
+/// One test, running one scenario.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScenarioTestEntry {
+    /// Relative to the contract crate, e.g. `scenarios/adder.scen.json`.
+    pub scenario_path: String,
+
+    pub fn_name: String,
+
+    /// Attributes and comments above the test function, as they are, e.g. `#[test]` and `#[ignore]`.
+    pub attributes: Vec<String>,
+}
+
+impl ScenarioTestEntry {
+    pub fn new(kind: ScenarioTestKind, scenario_name: &str) -> Self {
+        ScenarioTestEntry {
+            scenario_path: scenario_file_path(scenario_name),
+            fn_name: kind.test_fn_name(scenario_name),
+            attributes: vec![TEST_ATTRIBUTE.to_string()],
+        }
+    }
+
+    pub fn ignored(kind: ScenarioTestKind, scenario_name: &str) -> Self {
+        let mut entry = Self::new(kind, scenario_name);
+        entry.attributes.push(IGNORE_ATTRIBUTE.to_string());
+        entry
+    }
+
+    pub fn is_ignored(&self) -> bool {
+        self.attributes
+            .iter()
+            .any(|attribute| attribute.trim().starts_with("#[ignore"))
+    }
+}
+
+/// The two scenario test harnesses of a contract: on the Rust VM and on the Go VM.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScenarioTestKind {
+    Rs,
+    Go,
+}
+
+impl ScenarioTestKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ScenarioTestKind::Rs => "rs",
+            ScenarioTestKind::Go => "go",
+        }
+    }
+
+    /// Suffix of the harness file name, e.g. `scenario_rs_test.rs`.
+    pub fn test_file_suffix(&self) -> String {
+        format!("scenario_{}_test.rs", self.name())
+    }
+
+    /// E.g. `adder_scenario_rs_test.rs`.
+    pub fn test_file_name(&self, contract_crate_name_snake_case: &str) -> String {
+        format!(
+            "{contract_crate_name_snake_case}_{}",
+            self.test_file_suffix()
+        )
+    }
+
+    pub fn test_fn_name(&self, scenario_name: &str) -> String {
+        format!("{}_{}", scenario_test_fn_name(scenario_name), self.name())
+    }
+}
+
+/// Formats a function call the way `rustfmt` would: on one line if it fits, one argument per line otherwise.
+///
+/// A single argument is allowed to take up the whole line.
+fn write_fn_call(code: &mut String, indent: &str, callee: &str, args: &[String], suffix: &str) {
+    let args_single_line = args.join(", ");
+    let single_line = format!("{indent}{callee}({args_single_line}){suffix}");
+    let args_fit = args.len() == 1 || args_single_line.len() <= FN_CALL_WIDTH;
+    if args_fit && single_line.len() <= MAX_WIDTH {
+        writeln!(code, "{single_line}").unwrap();
+        return;
+    }
+
+    writeln!(code, "{indent}{callee}(").unwrap();
+    for arg in args {
+        writeln!(code, "{indent}{INDENT}{arg},").unwrap();
+    }
+    writeln!(code, "{indent}){suffix}").unwrap();
+}
+
+/// The imports and the `world()` function of a new Rust VM test harness.
+///
+/// The workspace path is relative to the Cargo workspace root, it is empty when the contract is its own workspace.
+pub fn scenario_rs_world_code(
+    workspace_path: &str,
+    wasm_output_name: &str,
+    contract_crate_name_snake_case: &str,
+) -> String {
+    let mut code = String::new();
+    writeln!(
+        code,
+        "use multiversx_sc_scenario::*;
+
+fn world() -> ScenarioWorld {{
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace(\"{workspace_path}\");
+"
+    )
+    .unwrap();
+    write_fn_call(
+        &mut code,
+        INDENT,
+        "blockchain.register_contract",
+        &[
+            format!("\"file:output/{wasm_output_name}.wasm\""),
+            format!("{contract_crate_name_snake_case}::ContractBuilder"),
+        ],
+        ";",
+    );
+    writeln!(code, "{INDENT}blockchain\n}}").unwrap();
+    code
+}
+
+pub(super) fn write_test_fn(code: &mut String, kind: ScenarioTestKind, entry: &ScenarioTestEntry) {
+    for attribute in &entry.attributes {
+        writeln!(code, "{attribute}").unwrap();
+    }
+    writeln!(code, "fn {}() {{", entry.fn_name).unwrap();
+    let scenario_path = format!("\"{}\"", entry.scenario_path);
+    match kind {
+        ScenarioTestKind::Rs => write_fn_call(
+            code,
+            INDENT,
+            "multiversx_sc_scenario::run_rs",
+            &[scenario_path, "world()".to_string()],
+            ";",
+        ),
+        ScenarioTestKind::Go => write_fn_call(
+            code,
+            INDENT,
+            "multiversx_sc_scenario::run_go",
+            &[scenario_path],
+            ";",
+        ),
+    }
+    writeln!(code, "}}").unwrap();
+}
@@ -0,0 +1,223 @@
+use std::ops::Range;
+
+use super::{
+    scenario_test_code::{write_test_fn, TEST_ATTRIBUTE},
+    ScenarioTestEntry, ScenarioTestKind, SCENARIO_FILE_SUFFIX,
+};
+
+/// In-memory representation of a scenario test harness file.
+///
+/// Everything before the first test, such as the imports and the `world()` function, is kept as it is.
+/// Tests that do not run a scenario are also kept as they are, after the generated ones.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ScenarioTestFile {
+    pub header: String,
+    pub entries: Vec<ScenarioTestEntry>,
+    pub other_tests: Vec<String>,
+
+    /// Where each of the entries was found in the parsed code, attributes and comments included.
+    entry_ranges: Vec<Range<usize>>,
+}
+
+/// E.g. `"scenarios/adder.scen.json"` yields `scenarios/adder.scen.json`.
+fn scenario_path_in_line(line: &str) -> Option<&str> {
+    let suffix_end = line.find(&format!("{SCENARIO_FILE_SUFFIX}\""))? + SCENARIO_FILE_SUFFIX.len();
+    let path_start = line[..suffix_end].rfind('"')? + 1;
+    Some(&line[path_start..suffix_end])
+}
+
+fn is_attribute_or_comment(line: &str) -> bool {
+    line.starts_with("#[") || line.starts_with("//")
+}
+
+struct TestInProgress {
+    start: usize,
+    fn_name: String,
+    attributes: Vec<String>,
+    scenario_path: Option<String>,
+    raw: String,
+}
+
+impl ScenarioTestFile {
+    pub fn new(header: &str, entries: Vec<ScenarioTestEntry>) -> Self {
+        ScenarioTestFile {
+            header: header.to_string(),
+            entries,
+            other_tests: Vec::new(),
+            entry_ranges: Vec::new(),
+        }
+    }
+
+    pub fn parse(code: &str) -> Self {
+        let mut file = ScenarioTestFile::default();
+        let mut header_end = None;
+        let mut pending_start = 0;
+        let mut pending: Vec<&str> = Vec::new();
+        let mut pending_has_test_attribute = false;
+        let mut in_block_comment = false;
+        let mut current: Option<TestInProgress> = None;
+        let mut offset = 0;
+
+        for raw_line in code.split_inclusive('\n') {
+            let line_start = offset;
+            offset += raw_line.len();
+            let line = raw_line.trim();
+
+            if let Some(test) = &mut current {
+                test.raw.push_str(raw_line);
+                if test.scenario_path.is_none() {
+                    test.scenario_path = scenario_path_in_line(line).map(str::to_string);
+                }
+                if raw_line.starts_with('}') {
+                    file.finish_test(current.take().unwrap(), offset);
+                }
+                continue;
+            }
+
+            // commented out code is kept together with the test that follows it
+            let is_comment = in_block_comment || line.starts_with("/*");
+            if is_comment {
+                in_block_comment = !line.ends_with("*/");
+            }
+            if line.is_empty() && pending.is_empty() {
+                continue;
+            }
+            if is_comment || line.is_empty() || is_attribute_or_comment(line) {
+                if pending.is_empty() {
+                    pending_start = line_start;
+                }
+                pending.push(raw_line.trim_end());
+                pending_has_test_attribute |= !is_comment && line == TEST_ATTRIBUTE;
+                continue;
+            }
+
+            if let Some(signature) = line.strip_prefix("fn ") {
+                if pending_has_test_attribute {
+                    header_end.get_or_insert(pending_start);
+                    let test = TestInProgress {
+                        start: pending_start,
+                        fn_name: signature.split('(').next().unwrap().trim().to_string(),
+                        attributes: pending.iter().map(|line| line.to_string()).collect(),
+                        scenario_path: None,
+                        raw: code[pending_start..offset].to_string(),
+                    };
+                    if line.ends_with('}') {
+                        file.finish_test(test, offset);
+                    } else {
+                        current = Some(test);
+                    }
+                }
+            }
+            pending.clear();
+            pending_has_test_attribute = false;
+        }
+
+        file.header = code[..header_end.unwrap_or(code.len())].to_string();
+        file
+    }
+
+    fn finish_test(&mut self, test: TestInProgress, end: usize) {
+        match test.scenario_path {
+            Some(scenario_path) => {
+                self.entries.push(ScenarioTestEntry {
+                    scenario_path,
+                    fn_name: test.fn_name,
+                    attributes: test.attributes,
+                });
+                self.entry_ranges.push(test.start..end);
+            },
+            None => self.other_tests.push(test.raw),
+        }
+    }
+
+    pub fn find_entry(&self, scenario_path: &str) -> Option<&ScenarioTestEntry> {
+        self.entries
+            .iter()
+            .find(|entry| entry.scenario_path == scenario_path)
+    }
+
+    /// The header, followed by one test per scenario, then the other tests.
+    pub fn to_code(&self, kind: ScenarioTestKind) -> String {
+        let mut code = self.header.trim_end().to_string();
+        if !code.is_empty() {
+            code.push('\n');
+        }
+        for entry in &self.entries {
+            if !code.is_empty() {
+                code.push('\n');
+            }
+            write_test_fn(&mut code, kind, entry);
+        }
+        for other_test in &self.other_tests {
+            if !code.is_empty() {
+                code.push('\n');
+            }
+            code.push_str(other_test.trim_end());
+            code.push('\n');
+        }
+        code
+    }
+}
+
+fn sort_key(scenario_path: &str) -> &str {
+    scenario_path
+        .strip_suffix(SCENARIO_FILE_SUFFIX)
+        .unwrap_or(scenario_path)
+}
+
+/// Adds tests to an existing harness, leaving the rest of the code untouched.
+///
+/// Each new test goes before the first existing one that sorts after it, or after the last one.
+pub fn insert_scenario_tests(
+    code: &str,
+    kind: ScenarioTestKind,
+    new_entries: &[ScenarioTestEntry],
+) -> String {
+    let file = ScenarioTestFile::parse(code);
+    let mut insertions: Vec<(usize, String)> = Vec::new();
+    for new_entry in new_entries {
+        let mut test_code = String::new();
+        write_test_fn(&mut test_code, kind, new_entry);
+        let next_index = file
+            .entries
+            .iter()
+            .position(|entry| sort_key(&entry.scenario_path) > sort_key(&new_entry.scenario_path));
+        match (next_index, file.entry_ranges.last()) {
+            (Some(index), _) => {
+                insertions.push((file.entry_ranges[index].start, format!("{test_code}\n")))
+            },
+            (None, Some(last_range)) => insertions.push((last_range.end, format!("\n{test_code}"))),
+            (None, None) => insertions.push((code.len(), format!("\n{test_code}"))),
+        }
+    }
+    // stable, so tests inserted at the same position keep their order
+    insertions.sort_by_key(|(position, _)| *position);
+
+    let mut result = String::new();
+    let mut copied = 0;
+    for (position, test_code) in insertions {
+        result.push_str(&code[copied..position]);
+        if position == code.len() && !result.is_empty() && !result.ends_with('\n') {
+            result.push('\n');
+        }
+        result.push_str(&test_code);
+        copied = position;
+    }
+    result.push_str(&code[copied..]);
+    result
+}
+
+/// Reads back the tests of an existing harness, in file order, together with their attributes.
+pub fn parse_scenario_test_entries(code: &str) -> Vec<ScenarioTestEntry> {
+    ScenarioTestFile::parse(code).entries
+}
+
+/// The full Rust VM test harness: the `world()` code, followed by one test per scenario.
+pub fn scenario_rs_test_code(world_code: &str, entries: &[ScenarioTestEntry]) -> String {
+    ScenarioTestFile::new(world_code, entries.to_vec()).to_code(ScenarioTestKind::Rs)
+}
+
+/// The full Go VM test harness, one test per scenario.
+pub fn scenario_go_test_code(entries: &[ScenarioTestEntry]) -> String {
+    ScenarioTestFile::new("", entries.to_vec()).to_code(ScenarioTestKind::Go)
+}
@@ -0,0 +1,174 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{folder_structure::CARGO_TOML_FILE_NAME, CargoTomlContents};
+
+use super::{
+    insert_scenario_tests, scenario_file_path, scenario_names, scenario_rs_world_code,
+    ScenarioTestEntry, ScenarioTestFile, ScenarioTestKind, SCENARIOS_DIR_NAME,
+};
+
+const TESTS_DIR_NAME: &str = "tests";
+
+/// What happened to one test harness file of a contract.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TestGenFileReport {
+    pub path: PathBuf,
+    pub created: bool,
+    pub changed: bool,
+
+    /// Scenarios in the `scenarios` folder that the harness does not run.
+    pub missing: Vec<String>,
+
+    /// Scenarios that were added to the harness, only with `--add-missing`.
+    pub added: Vec<String>,
+
+    /// Tests whose scenario file no longer exists, unless they are ignored.
+    /// They are left in place, to be removed by hand.
+    pub stale: Vec<String>,
+}
+
+/// Harnesses are recognized by what they run, since their file names vary,
+/// e.g. `adder_scenario_rs_test.rs`, `esdt_system_sc_mock_rs_test.rs` or the legacy `mandos_rs_test.rs`.
+fn is_test_harness(code: &str, kind: ScenarioTestKind) -> bool {
+    match kind {
+        ScenarioTestKind::Rs => {
+            code.contains("world()") && (code.contains("run_rs(") || code.contains("mandos_rs("))
+        },
+        ScenarioTestKind::Go => code.contains("run_go(") || code.contains("mandos_go("),
+    }
+}
+
+/// If there are several harnesses, the one with the standard file name wins.
+fn find_test_file(tests_dir: &Path, kind: ScenarioTestKind) -> Option<(PathBuf, String)> {
+    let suffix = kind.test_file_suffix();
+    let mut found: Vec<(bool, PathBuf, String)> = fs::read_dir(tests_dir)
+        .ok()?
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().and_then(|ext| ext.to_str()) == Some("rs"))
+        .filter_map(|path| {
+            let code = fs::read_to_string(&path).ok()?;
+            if !is_test_harness(&code, kind) {
+                return None;
+            }
+            let standard_name = matches!(
+                path.file_name().and_then(|name| name.to_str()),
+                Some(name) if name.ends_with(&suffix)
+            );
+            Some((!standard_name, path, code))
+        })
+        .collect();
+    found.sort();
+    found.into_iter().next().map(|(_, path, code)| (path, code))
+}
+
+/// Path of the contract relative to the root of its Cargo workspace, as needed by `set_current_dir_from_workspace`.
+pub fn workspace_relative_path(contract_dir: &Path) -> String {
+    let contract_dir = contract_dir
+        .canonicalize()
+        .expect("failed to canonicalize contract path");
+    for ancestor in contract_dir.ancestors() {
+        let cargo_toml_path = ancestor.join(CARGO_TOML_FILE_NAME);
+        if !cargo_toml_path.is_file() {
+            continue;
+        }
+        let cargo_toml_contents = CargoTomlContents::load_from_file(&cargo_toml_path);
+        if cargo_toml_contents.toml_value.get("workspace").is_some() {
+            let relative = contract_dir.strip_prefix(ancestor).unwrap();
+            return relative.to_string_lossy().replace('\\', "/");
+        }
+    }
+    String::new()
+}
+
+fn contract_crate_name(contract_dir: &Path) -> String {
+    CargoTomlContents::load_from_file(contract_dir.join(CARGO_TOML_FILE_NAME))
+        .package_name()
+        .expect("contract Cargo.toml has no package name")
+}
+
+fn new_harness_code(
+    contract_dir: &Path,
+    kind: ScenarioTestKind,
+    entries: Vec<ScenarioTestEntry>,
+) -> String {
+    let header = match kind {
+        ScenarioTestKind::Rs => {
+            let crate_name = contract_crate_name(contract_dir);
+            scenario_rs_world_code(
+                &workspace_relative_path(contract_dir),
+                &crate_name,
+                &crate_name.replace('-', "_"),
+            )
+        },
+        ScenarioTestKind::Go => String::new(),
+    };
+    ScenarioTestFile::new(&header, entries).to_code(kind)
+}
+
+/// Generates or refreshes one of the scenario test harnesses of a contract.
+///
+/// Only the missing tests are added to an existing harness, the rest of the file is left as it is,
+/// so hand-written tests and `#[ignore]` markers survive.
+/// Both adding tests and creating new harnesses require `add_missing`.
+pub fn test_gen_file(
+    contract_dir: &Path,
+    kind: ScenarioTestKind,
+    add_missing: bool,
+) -> Option<TestGenFileReport> {
+    let names = scenario_names(&contract_dir.join(SCENARIOS_DIR_NAME));
+    if names.is_empty() {
+        return None;
+    }
+
+    let tests_dir = contract_dir.join(TESTS_DIR_NAME);
+    let mut report = TestGenFileReport::default();
+    let existing = find_test_file(&tests_dir, kind);
+    let file = match &existing {
+        Some((path, old_code)) => {
+            report.path = path.clone();
+            ScenarioTestFile::parse(old_code)
+        },
+        None => {
+            let crate_name_snake_case = contract_crate_name(contract_dir).replace('-', "_");
+            report.path = tests_dir.join(kind.test_file_name(&crate_name_snake_case));
+            ScenarioTestFile::default()
+        },
+    };
+
+    for entry in &file.entries {
+        if !entry.is_ignored() && !contract_dir.join(&entry.scenario_path).is_file() {
+            report.stale.push(entry.scenario_path.clone());
+        }
+    }
+
+    let mut new_entries = Vec::new();
+    for name in &names {
+        if file.find_entry(&scenario_file_path(name)).is_some() {
+            continue;
+        }
+        if add_missing {
+            new_entries.push(ScenarioTestEntry::new(kind, name));
+            report.added.push(name.clone());
+        } else {
+            report.missing.push(name.clone());
+        }
+    }
+    if new_entries.is_empty() {
+        return Some(report);
+    }
+
+    let new_code = match &existing {
+        Some((_, old_code)) => insert_scenario_tests(old_code, kind, &new_entries),
+        None => {
+            report.created = true;
+            new_harness_code(contract_dir, kind, new_entries)
+        },
+    };
+    report.changed = true;
+    fs::create_dir_all(&tests_dir).expect("failed to create tests directory");
+    fs::write(&report.path, new_code).expect("failed to write test file");
+    Some(report)
+}
@@ -0,0 +1,490 @@
+use multiversx_sc_meta::{
+    abi_diff::{compare_abi, AbiChangeSeverity, AbiDiffReport},
+    abi_json::{ConstructorAbiJson, ContractAbiJson, ErrorAbiJson},
+};
+
+fn abi_with(endpoints_json: &str, events_json: &str, types_json: &str) -> ContractAbiJson {
+    let abi_json = format!(
+        r#"{{
+            "buildInfo": {{
+                "rustc": {{
+                    "version": "x.x.x-nightly",
+                    "commitHash": "",
+                    "commitDate": "",
+                    "channel": "Nightly",
+                    "short": ""
+                }},
+                "contractCrate": {{ "name": "test", "version": "0.0.0", "gitVersion": "" }},
+                "framework": {{ "name": "multiversx-sc", "version": "0.39.5" }}
+            }},
+            "name": "Test",
+            "constructor": {{
+                "inputs": [{{ "name": "initial_value", "type": "BigUint" }}],
+                "outputs": []
+            }},
+            "endpoints": {endpoints_json},
+            "events": {events_json},
+            "hasCallback": false,
+            "types": {types_json}
+        }}"#
+    );
+    serde_json::from_str(&abi_json).unwrap()
+}
+
+const ENDPOINTS_JSON: &str = r#"[
+    {
+        "name": "getSum",
+        "mutability": "readonly",
+        "inputs": [],
+        "outputs": [{ "type": "BigUint" }]
+    },
+    {
+        "name": "add",
+        "mutability": "mutable",
+        "payableInTokens": ["EGLD"],
+        "inputs": [{ "name": "value", "type": "BigUint" }],
+        "outputs": []
+    },
+    {
+        "name": "schedule",
+        "mutability": "mutable",
+        "inputs": [{ "name": "schedule", "type": "Schedule" }],
+        "outputs": []
+    }
+]"#;
+
+const EVENTS_JSON: &str = r#"[
+    {
+        "identifier": "added",
+        "inputs": [
+            { "name": "caller", "type": "Address", "indexed": true },
+            { "name": "value", "type": "BigUint" }
+        ]
+    }
+]"#;
+
+const TYPES_JSON: &str = r#"{
+    "Schedule": {
+        "type": "struct",
+        "fields": [
+            { "name": "amount", "type": "BigUint" },
+            { "name": "start", "type": "u64" }
+        ]
+    }
+}"#;
+
+fn changes(report: &AbiDiffReport) -> Vec<(AbiChangeSeverity, String)> {
+    report
+        .changes
+        .iter()
+        .map(|change| {
+            (
+                change.severity,
+                format!("{}: {}", change.item, change.message),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn abi_diff_unchanged_test() {
+    let old_abi = abi_with(ENDPOINTS_JSON, EVENTS_JSON, TYPES_JSON);
+    let new_abi = abi_with(ENDPOINTS_JSON, EVENTS_JSON, TYPES_JSON);
+
+    let report = compare_abi(&old_abi, &new_abi);
+    assert!(report.changes.is_empty());
+}
+
+#[test]
+fn abi_diff_endpoint_renamed_test() {
+    let old_abi = abi_with(ENDPOINTS_JSON, EVENTS_JSON, TYPES_JSON);
+    let new_endpoints = ENDPOINTS_JSON.replace(r#""name": "getSum""#, r#""name": "getTotal""#);
+    let new_abi = abi_with(&new_endpoints, EVENTS_JSON, TYPES_JSON);
+
+    let report = compare_abi(&old_abi, &new_abi);
+    assert!(report.has_breaking_changes());
+    assert_eq!(
+        changes(&report),
+        vec![
+            (
+                AbiChangeSeverity::Breaking,
+                r#"endpoint "getSum": removed, possibly renamed to "getTotal""#.to_string()
+            ),
+            (
+                AbiChangeSeverity::NonBreaking,
+                r#"endpoint "getTotal": added"#.to_string()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn abi_diff_signature_changes_test() {
+    let old_abi = abi_with(ENDPOINTS_JSON, EVENTS_JSON, TYPES_JSON);
+    let new_endpoints = ENDPOINTS_JSON
+        .replace(r#""outputs": [{ "type": "BigUint" }]"#, r#""outputs": [{ "type": "u64" }]"#)
+        .replace(
+            r#""inputs": [{ "name": "value", "type": "BigUint" }]"#,
+            r#""inputs": [
+                { "name": "amount", "type": "BigUint" },
+                { "name": "memo", "type": "optional<bytes>", "multi_arg": true }
+            ]"#,
+        )
+        .replace(
+            r#""inputs": [{ "name": "schedule", "type": "Schedule" }]"#,
+            r#""inputs": [{ "name": "schedule", "type": "Schedule" }, { "name": "delay", "type": "u64" }]"#,
+        );
+    let new_abi = abi_with(&new_endpoints, EVENTS_JSON, TYPES_JSON);
+
+    let report = compare_abi(&old_abi, &new_abi);
+    assert_eq!(
+        changes(&report),
+        vec![
+            (
+                AbiChangeSeverity::NonBreaking,
+                r#"endpoint "add": argument #0 renamed from value to amount"#.to_string()
+            ),
+            (
+                AbiChangeSeverity::NonBreaking,
+                r#"endpoint "add": argument #1 (memo: optional<bytes>) added"#.to_string()
+            ),
+            (
+                AbiChangeSeverity::Breaking,
+                r#"endpoint "getSum": result #0 type changed from BigUint to u64"#.to_string()
+            ),
+            (
+                AbiChangeSeverity::Breaking,
+                r#"endpoint "schedule": argument #1 (delay: u64) added"#.to_string()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn abi_diff_mutability_and_payable_test() {
+    let old_abi = abi_with(ENDPOINTS_JSON, EVENTS_JSON, TYPES_JSON);
+
+    let widened_endpoints = ENDPOINTS_JSON
+        .replace(
+            r#""payableInTokens": ["EGLD"]"#,
+            r#""payableInTokens": ["*"]"#,
+        )
+        .replace(
+            r#""name": "schedule",
+        "mutability": "mutable""#,
+            r#""name": "schedule",
+        "mutability": "readonly""#,
+        );
+    let widened_abi = abi_with(&widened_endpoints, EVENTS_JSON, TYPES_JSON);
+    let report = compare_abi(&old_abi, &widened_abi);
+    assert!(!report.has_breaking_changes());
+    assert_eq!(
+        changes(&report),
+        vec![
+            (
+                AbiChangeSeverity::NonBreaking,
+                r#"endpoint "add": changed from payable in EGLD to payable in *"#.to_string()
+            ),
+            (
+                AbiChangeSeverity::NonBreaking,
+                r#"endpoint "schedule": changed from mutable to readonly"#.to_string()
+            ),
+        ]
+    );
+
+    let narrowed_endpoints = ENDPOINTS_JSON
+        .replace(r#""payableInTokens": ["EGLD"],"#, r#""onlyOwner": true,"#)
+        .replace(r#""mutability": "readonly""#, r#""mutability": "mutable""#);
+    let narrowed_abi = abi_with(&narrowed_endpoints, EVENTS_JSON, TYPES_JSON);
+    let report = compare_abi(&old_abi, &narrowed_abi);
+    assert_eq!(
+        changes(&report),
+        vec![
+            (
+                AbiChangeSeverity::Breaking,
+                r#"endpoint "add": changed from payable in EGLD to not payable"#.to_string()
+            ),
+            (
+                AbiChangeSeverity::Breaking,
+                r#"endpoint "add": now restricted to the owner"#.to_string()
+            ),
+            (
+                AbiChangeSeverity::Breaking,
+                r#"endpoint "getSum": changed from readonly to mutable"#.to_string()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn abi_diff_call_guards_test() {
+    let old_abi = abi_with(ENDPOINTS_JSON, EVENTS_JSON, TYPES_JSON);
+
+    let guarded_endpoints = ENDPOINTS_JSON
+        .replace(
+            r#""payableInTokens": ["EGLD"],"#,
+            r#""payableInTokens": ["EGLD"],
+        "nonReentrant": true,"#,
+        )
+        .replace(
+            r#""name": "schedule","#,
+            r#""name": "schedule",
+        "onlyCallbackContext": true,"#,
+        );
+    let guarded_abi = abi_with(&guarded_endpoints, EVENTS_JSON, TYPES_JSON);
+    let report = compare_abi(&old_abi, &guarded_abi);
+    assert_eq!(
+        changes(&report),
+        vec![
+            (
+                AbiChangeSeverity::Breaking,
+                r#"endpoint "add": now restricted to non-reentrant calls"#.to_string()
+            ),
+            (
+                AbiChangeSeverity::Breaking,
+                r#"endpoint "schedule": now restricted to calls made from callbacks"#.to_string()
+            ),
+        ]
+    );
+
+    let report = compare_abi(&guarded_abi, &old_abi);
+    assert!(!report.has_breaking_changes());
+    assert_eq!(report.changes.len(), 2);
+}
+
+#[test]
+fn abi_diff_event_changes_test() {
+    let old_abi = abi_with(ENDPOINTS_JSON, EVENTS_JSON, TYPES_JSON);
+    let new_events = EVENTS_JSON
+        .replace(
+            r#"{ "name": "value", "type": "BigUint" }"#,
+            r#"{ "name": "value", "type": "BigUint", "indexed": true }"#,
+        )
+        .replace(r#""name": "caller""#, r#""name": "from""#);
+    let new_abi = abi_with(ENDPOINTS_JSON, &new_events, TYPES_JSON);
+
+    let report = compare_abi(&old_abi, &new_abi);
+    assert_eq!(
+        changes(&report),
+        vec![
+            (
+                AbiChangeSeverity::NonBreaking,
+                r#"event "added": argument #0 renamed from caller to from"#.to_string()
+            ),
+            (
+                AbiChangeSeverity::Breaking,
+                r#"event "added": argument #1 (value) moved between the topics and the data"#
+                    .to_string()
+            ),
+        ]
+    );
+
+    let removed_abi = abi_with(ENDPOINTS_JSON, "[]", TYPES_JSON);
+    assert_eq!(
+        changes(&compare_abi(&old_abi, &removed_abi)),
+        vec![(
+            AbiChangeSeverity::Breaking,
+            r#"event "added": removed"#.to_string()
+        )]
+    );
+}
+
+#[test]
+fn abi_diff_type_changes_test() {
+    let old_abi = abi_with(ENDPOINTS_JSON, EVENTS_JSON, TYPES_JSON);
+    let new_types = TYPES_JSON
+        .replace(r#""name": "amount""#, r#""name": "total""#)
+        .replace(
+            r#"{ "name": "start", "type": "u64" }"#,
+            r#"{ "name": "start", "type": "u32" }"#,
+        );
+    let new_abi = abi_with(ENDPOINTS_JSON, EVENTS_JSON, &new_types);
+
+    let report = compare_abi(&old_abi, &new_abi);
+    assert_eq!(
+        changes(&report),
+        vec![
+            (
+                AbiChangeSeverity::NonBreaking,
+                r#"type "Schedule": Schedule field amount renamed to total"#.to_string()
+            ),
+            (
+                AbiChangeSeverity::Breaking,
+                r#"type "Schedule": Schedule field start: type changed from u64 to u32"#
+                    .to_string()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn abi_diff_type_removed_test() {
+    let old_abi = abi_with(ENDPOINTS_JSON, EVENTS_JSON, TYPES_JSON);
+    let new_abi = abi_with(ENDPOINTS_JSON, EVENTS_JSON, "{}");
+
+    let report = compare_abi(&old_abi, &new_abi);
+    assert!(report.has_breaking_changes());
+    assert_eq!(
+        changes(&report),
+        vec![(
+            AbiChangeSeverity::Breaking,
+            r#"type "Schedule": removed"#.to_string()
+        )]
+    );
+}
+
+#[test]
+fn abi_diff_unused_type_removed_test() {
+    const TYPES_WITH_UNUSED_JSON: &str = r#"{
+        "Schedule": {
+            "type": "struct",
+            "fields": [
+                { "name": "amount", "type": "BigUint" },
+                { "name": "start", "type": "u64" }
+            ]
+        },
+        "Period": {
+            "type": "struct",
+            "fields": [{ "name": "epochs", "type": "u64" }]
+        }
+    }"#;
+    let old_abi = abi_with(ENDPOINTS_JSON, EVENTS_JSON, TYPES_WITH_UNUSED_JSON);
+    let new_abi = abi_with(ENDPOINTS_JSON, EVENTS_JSON, TYPES_JSON);
+
+    let report = compare_abi(&old_abi, &new_abi);
+    assert!(!report.has_breaking_changes());
+    assert_eq!(
+        changes(&report),
+        vec![(
+            AbiChangeSeverity::NonBreaking,
+            r#"type "Period": removed"#.to_string()
+        )]
+    );
+}
+
+#[test]
+fn abi_diff_constructor_changes_test() {
+    let with_constructor = abi_with(ENDPOINTS_JSON, EVENTS_JSON, TYPES_JSON);
+    let mut without_constructor = abi_with(ENDPOINTS_JSON, EVENTS_JSON, TYPES_JSON);
+    without_constructor.constructor = None;
+
+    assert_eq!(
+        changes(&compare_abi(&with_constructor, &without_constructor)),
+        vec![(
+            AbiChangeSeverity::Breaking,
+            "constructor: removed".to_string()
+        )]
+    );
+    assert_eq!(
+        changes(&compare_abi(&without_constructor, &with_constructor)),
+        vec![(
+            AbiChangeSeverity::Breaking,
+            "constructor: added".to_string()
+        )]
+    );
+}
+
+fn constructor(inputs_json: &str) -> ConstructorAbiJson {
+    serde_json::from_str(&format!(r#"{{ "inputs": {inputs_json}, "outputs": [] }}"#)).unwrap()
+}
+
+#[test]
+fn abi_diff_upgrade_constructor_changes_test() {
+    let without_upgrade = abi_with(ENDPOINTS_JSON, EVENTS_JSON, TYPES_JSON);
+    let mut old_abi = abi_with(ENDPOINTS_JSON, EVENTS_JSON, TYPES_JSON);
+    old_abi.upgrade_constructor = Some(constructor("[]"));
+    let mut new_abi = abi_with(ENDPOINTS_JSON, EVENTS_JSON, TYPES_JSON);
+    new_abi.upgrade_constructor = Some(constructor(
+        r#"[{ "name": "new_value", "type": "BigUint" }]"#,
+    ));
+
+    assert_eq!(
+        changes(&compare_abi(&old_abi, &new_abi)),
+        vec![(
+            AbiChangeSeverity::Breaking,
+            "upgrade constructor: argument #0 (new_value: BigUint) added".to_string()
+        )]
+    );
+    assert_eq!(
+        changes(&compare_abi(&old_abi, &without_upgrade)),
+        vec![(
+            AbiChangeSeverity::Breaking,
+            "upgrade constructor: removed".to_string()
+        )]
+    );
+    let report = compare_abi(&without_upgrade, &old_abi);
+    assert!(!report.has_breaking_changes());
+    assert_eq!(
+        changes(&report),
+        vec![(
+            AbiChangeSeverity::NonBreaking,
+            "upgrade constructor: added".to_string()
+        )]
+    );
+}
+
+const ERRORS_JSON: &str = r#"[
+    {
+        "name": "PaymentTooLow",
+        "code": 1,
+        "message": "payment too low, needed {needed}",
+        "fields": [{ "name": "needed", "type": "BigUint" }]
+    },
+    {
+        "name": "Paused",
+        "code": 2,
+        "message": "contract is paused"
+    }
+]"#;
+
+fn abi_with_errors(errors_json: &str) -> ContractAbiJson {
+    let mut abi = abi_with(ENDPOINTS_JSON, EVENTS_JSON, TYPES_JSON);
+    abi.errors = serde_json::from_str::<Vec<ErrorAbiJson>>(errors_json).unwrap();
+    abi
+}
+
+#[test]
+fn abi_diff_error_changes_test() {
+    let old_abi = abi_with_errors(ERRORS_JSON);
+    let new_errors = ERRORS_JSON
+        .replace("needed {needed}", "minimum {needed}")
+        .replace(r#""type": "BigUint""#, r#""type": "u64""#)
+        .replace(r#""name": "Paused""#, r#""name": "ContractPaused""#);
+    let new_abi = abi_with_errors(&new_errors);
+
+    let report = compare_abi(&old_abi, &new_abi);
+    assert_eq!(
+        changes(&report),
+        vec![
+            (
+                AbiChangeSeverity::Breaking,
+                r#"error 1 "PaymentTooLow": message changed from "payment too low, needed {needed}" to "payment too low, minimum {needed}""#
+                    .to_string()
+            ),
+            (
+                AbiChangeSeverity::Breaking,
+                r#"error 1 "PaymentTooLow": field needed type changed from BigUint to u64"#
+                    .to_string()
+            ),
+            (
+                AbiChangeSeverity::NonBreaking,
+                r#"error 2 "Paused": renamed to ContractPaused"#.to_string()
+            ),
+        ]
+    );
+
+    let removed_abi = abi_with_errors("[]");
+    assert_eq!(
+        changes(&compare_abi(&old_abi, &removed_abi)),
+        vec![
+            (
+                AbiChangeSeverity::Breaking,
+                r#"error 1 "PaymentTooLow": removed"#.to_string()
+            ),
+            (
+                AbiChangeSeverity::Breaking,
+                r#"error 2 "Paused": removed"#.to_string()
+            ),
+        ]
+    );
+}
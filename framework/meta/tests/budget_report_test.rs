@@ -0,0 +1,40 @@
+use multiversx_sc::abi::{ContractAbi, EndpointAbi};
+use multiversx_sc_meta::output_contract::format_budget_report;
+
+fn endpoint(
+    name: &'static str,
+    max_gas: Option<u64>,
+    max_return_items: Option<usize>,
+) -> EndpointAbi {
+    EndpointAbi {
+        max_gas,
+        max_return_items,
+        ..EndpointAbi::generate_with_name_and_labels(name, &[])
+    }
+}
+
+#[test]
+fn budget_report_test() {
+    let abi = ContractAbi::generate_with_endpoints(vec![
+        endpoint("deposit", Some(10_000_000), None),
+        endpoint("noLimits", None, None),
+        endpoint("getAllItems", None, Some(100)),
+    ]);
+
+    assert_eq!(
+        format_budget_report(&abi, Some(1234)).unwrap(),
+        "\
+wasm size: 1234 bytes
+
+endpoint      max gas  max return items
+deposit      10000000                 -
+getAllItems         -               100
+"
+    );
+}
+
+#[test]
+fn budget_report_no_limits_test() {
+    let abi = ContractAbi::generate_with_endpoints(vec![endpoint("noLimits", None, None)]);
+    assert_eq!(format_budget_report(&abi, Some(1234)), None);
+}
@@ -0,0 +1,214 @@
+use std::{collections::BTreeSet, fs, path::Path};
+
+use multiversx_sc::abi::{ContractAbi, EndpointAbi};
+use multiversx_sc_meta::{
+    ei::EIVersion,
+    output_contract::{
+        format_build_constraints_report, BuildConstraints, MultiContractConfigSerde,
+        OutputContractConfig,
+    },
+};
+
+fn get_serialized_toml() -> MultiContractConfigSerde {
+    toml::from_str(
+        r#"
+        [settings]
+        main = "main-contract"
+        max-wasm-size = 65536
+        ei = "1.2"
+
+        [contracts.main-contract]
+        add-unlabelled = true
+
+        [contracts.small-contract]
+        add-labels = ["label1"]
+        max-wasm-size = 4096
+        ei = "1.1"
+        forbidden-imports = ["getGasLeft"]
+
+        [contracts.restricted-contract]
+        add-labels = ["label1"]
+        allowed-imports = ["finish", "signalError"]
+
+        [labels-for-contracts]
+        label1 = ["labelled-only-contract"]
+    "#,
+    )
+    .unwrap()
+}
+
+fn get_contract_abi() -> ContractAbi {
+    ContractAbi::generate_with_endpoints(vec![
+        EndpointAbi::generate_with_name_and_labels("endpoint1", &["label1"]),
+        EndpointAbi::generate_with_name_and_labels("endpoint2", &[]),
+    ])
+}
+
+fn constraints_of<'a>(config: &'a OutputContractConfig, contract_id: &str) -> &'a BuildConstraints {
+    &config
+        .get_contract_by_id(contract_id.to_string())
+        .unwrap()
+        .settings
+        .build_constraints
+}
+
+#[test]
+fn build_constraints_config_test() {
+    let config =
+        OutputContractConfig::load_from_config(&get_serialized_toml(), &get_contract_abi());
+
+    assert_eq!(
+        constraints_of(&config, "main-contract"),
+        &BuildConstraints {
+            max_wasm_size: Some(65536),
+            ei_version: Some(EIVersion::V1_2),
+            allowed_imports: None,
+            forbidden_imports: Vec::new(),
+        }
+    );
+    assert_eq!(
+        constraints_of(&config, "small-contract"),
+        &BuildConstraints {
+            max_wasm_size: Some(4096),
+            ei_version: Some(EIVersion::V1_1),
+            allowed_imports: None,
+            forbidden_imports: vec!["getGasLeft".to_string()],
+        }
+    );
+    assert_eq!(
+        constraints_of(&config, "restricted-contract").allowed_imports,
+        Some(vec!["finish".to_string(), "signalError".to_string()])
+    );
+    // contracts only mentioned in labels-for-contracts get the general settings
+    assert_eq!(
+        constraints_of(&config, "labelled-only-contract"),
+        constraints_of(&config, "main-contract")
+    );
+}
+
+#[test]
+#[should_panic(expected = "unknown EI version \"0.9\" in multicontract.toml")]
+fn build_constraints_unknown_ei_version_test() {
+    let serde: MultiContractConfigSerde = toml::from_str(
+        r#"
+        [settings]
+        ei = "0.9"
+
+        [contracts.main-contract]
+        add-unlabelled = true
+    "#,
+    )
+    .unwrap();
+    let _ = OutputContractConfig::load_from_config(&serde, &get_contract_abi());
+}
+
+#[test]
+fn build_constraints_check_test() {
+    let constraints = BuildConstraints {
+        max_wasm_size: Some(4096),
+        ei_version: Some(EIVersion::V1_1),
+        allowed_imports: None,
+        forbidden_imports: vec!["getGasLeft".to_string()],
+    };
+    assert!(constraints.check_wasm_size(4096).is_empty());
+    assert_eq!(
+        constraints.check_wasm_size(5000),
+        vec!["wasm size is 5000 bytes, max-wasm-size is 4096 bytes"]
+    );
+
+    let imports = [
+        "finish",
+        "getGasLeft",
+        "managedCreateAsyncCall",
+        "unknownHook",
+    ]
+    .map(String::from);
+    let violations = constraints.check_imports(&imports);
+    assert_eq!(
+        violations,
+        vec![
+            "import `getGasLeft` is in forbidden-imports",
+            "import `managedCreateAsyncCall` requires EI 1.2, the contract targets EI 1.1",
+            "import `unknownHook` is not a known VM hook, the contract targets EI 1.1",
+        ]
+    );
+    assert_eq!(
+        format_build_constraints_report("small", &violations[..1]),
+        "Contract small violates the constraints in multicontract.toml:\n  - import `getGasLeft` is in forbidden-imports"
+    );
+
+    let restricted = BuildConstraints {
+        allowed_imports: Some(vec!["finish".to_string()]),
+        ..Default::default()
+    };
+    assert_eq!(
+        restricted.check_imports(&["finish".to_string(), "signalError".to_string()]),
+        vec!["import `signalError` is not in allowed-imports"]
+    );
+}
+
+#[test]
+fn ei_versions_test() {
+    assert_eq!(EIVersion::from_name("1.1"), Some(EIVersion::V1_1));
+    assert_eq!(EIVersion::from_name("2.0"), None);
+    assert!(EIVersion::V1_0.contains("getGasLeft"));
+    assert!(!EIVersion::V1_0.contains("managedIsESDTPaused"));
+    assert!(EIVersion::V1_1.contains("managedIsESDTPaused"));
+    assert!(!EIVersion::V1_1.contains("managedCreateAsyncCall"));
+    assert!(EIVersion::V1_2.contains("managedCreateAsyncCall"));
+    assert_eq!(
+        EIVersion::introduced_in("managedCreateAsyncCall"),
+        Some(EIVersion::V1_2)
+    );
+
+    for version in EIVersion::ALL {
+        let names: Vec<&str> = version.names().collect();
+        let unique: BTreeSet<&str> = names.iter().copied().collect();
+        assert_eq!(
+            names.len(),
+            unique.len(),
+            "duplicate names in EI {}",
+            version.name()
+        );
+    }
+}
+
+fn collect_extern_fn_names(dir: &Path, names: &mut BTreeSet<String>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            collect_extern_fn_names(&path, names);
+            continue;
+        }
+        let source = fs::read_to_string(&path).unwrap();
+        let mut in_extern_block = false;
+        for line in source.lines().map(str::trim) {
+            if line.starts_with("extern \"C\"") {
+                in_extern_block = true;
+            } else if in_extern_block && line == "}" {
+                in_extern_block = false;
+            } else if in_extern_block {
+                if let Some(rest) = line.strip_prefix("fn ") {
+                    names.insert(rest.split('(').next().unwrap().trim().to_string());
+                }
+            }
+        }
+    }
+}
+
+/// The framework should only import VM hooks known to the latest EI version.
+#[test]
+fn ei_latest_version_covers_wasm_adapter_test() {
+    let wasm_adapter_src = Path::new(env!("CARGO_MANIFEST_DIR")).join("../wasm-adapter/src");
+    let mut names = BTreeSet::new();
+    collect_extern_fn_names(&wasm_adapter_src, &mut names);
+    assert!(!names.is_empty());
+
+    let latest = EIVersion::ALL.last().unwrap();
+    let unknown: Vec<&String> = names.iter().filter(|name| !latest.contains(name)).collect();
+    assert!(
+        unknown.is_empty(),
+        "VM hooks missing from EI {}: {unknown:?}",
+        latest.name()
+    );
+}
@@ -0,0 +1,258 @@
+use multiversx_sc_meta::{
+    abi_json::ContractAbiJson,
+    event_decoder::{collect_tx_json_logs, AbiValue, EventDecodeError, EventDecoder},
+};
+
+const ABI_JSON: &str = r#"{
+    "buildInfo": {
+        "rustc": {
+            "version": "x.x.x-nightly",
+            "commitHash": "",
+            "commitDate": "",
+            "channel": "Nightly",
+            "short": ""
+        },
+        "contractCrate": { "name": "test", "version": "0.0.0", "gitVersion": "" },
+        "framework": { "name": "multiversx-sc", "version": "0.39.5" }
+    },
+    "name": "Test",
+    "endpoints": [],
+    "events": [
+        {
+            "identifier": "deposit",
+            "inputs": [
+                { "name": "caller", "type": "Address", "indexed": true },
+                { "name": "amount", "type": "BigUint", "indexed": true },
+                { "name": "schedule", "type": "Schedule" }
+            ]
+        },
+        {
+            "identifier": "batch",
+            "inputs": [
+                { "name": "curve", "type": "Curve", "indexed": true },
+                { "name": "ids", "type": "variadic<u32>", "indexed": true },
+                { "name": "weights", "type": "Option<List<u16>>" }
+            ]
+        }
+    ],
+    "hasCallback": false,
+    "types": {
+        "Schedule": {
+            "type": "struct",
+            "fields": [
+                { "name": "amount", "type": "BigUint" },
+                { "name": "start", "type": "u64" },
+                { "name": "curve", "type": "Curve" }
+            ]
+        },
+        "Curve": {
+            "type": "enum",
+            "variants": [
+                { "name": "Linear", "discriminant": 0 },
+                { "name": "Step", "discriminant": 1, "fields": [{ "name": "step", "type": "u64" }] }
+            ]
+        }
+    }
+}"#;
+
+fn decoder() -> EventDecoder {
+    let abi: ContractAbiJson = serde_json::from_str(ABI_JSON).unwrap();
+    EventDecoder::new(abi)
+}
+
+fn deposit_topics() -> Vec<Vec<u8>> {
+    vec![b"deposit".to_vec(), vec![0x11; 32], vec![0x01, 0x00]]
+}
+
+fn deposit_data() -> Vec<u8> {
+    [
+        &[0, 0, 0, 2, 0x01, 0xf4][..],
+        &10u64.to_be_bytes()[..],
+        &[1][..],
+        &7u64.to_be_bytes()[..],
+    ]
+    .concat()
+}
+
+#[test]
+fn event_decoder_indexed_and_data_test() {
+    let event = decoder()
+        .decode(&deposit_topics(), &deposit_data())
+        .unwrap();
+
+    assert_eq!(event.identifier, "deposit");
+    assert_eq!(event.field("caller"), Some(&AbiValue::Address([0x11; 32])));
+    assert_eq!(event.field("amount"), Some(&AbiValue::from(256u32)));
+
+    let schedule = event.field("schedule").unwrap();
+    assert_eq!(schedule.field("amount").unwrap().as_u64(), Some(500));
+    assert_eq!(schedule.field("start").unwrap().as_u64(), Some(10));
+    let curve = schedule.field("curve").unwrap();
+    assert_eq!(curve.field("step"), Some(&AbiValue::from(7u64)));
+
+    assert_eq!(
+        event.to_string(),
+        format!(
+            "deposit(caller: 0x{}, amount: 256, schedule: Schedule {{ amount: 500, start: 10, curve: Curve::Step {{ step: 7 }} }})",
+            "11".repeat(32)
+        )
+    );
+}
+
+#[test]
+fn event_decoder_multi_value_topics_test() {
+    let topics = vec![b"batch".to_vec(), Vec::new(), vec![1], vec![2, 0]];
+    let data = [&[1][..], &[0, 0, 0, 2, 0, 1, 0, 2][..]].concat();
+    let event = decoder().decode(&topics, &data).unwrap();
+
+    assert_eq!(
+        event.to_string(),
+        "batch(curve: Curve::Linear, ids: [1, 512], weights: Some([1, 2]))"
+    );
+
+    // no variadic topics and no data
+    let event = decoder()
+        .decode(&[b"batch".to_vec(), vec![1, 0, 0, 0, 0, 0, 0, 0, 3]], &[])
+        .unwrap();
+    assert_eq!(
+        event.to_string(),
+        "batch(curve: Curve::Step { step: 3 }, ids: [], weights: None)"
+    );
+}
+
+#[test]
+fn event_decoder_u16_discriminant_test() {
+    let abi_json = ABI_JSON.replace(
+        r#""type": "enum","#,
+        r#""type": "enum",
+            "discriminantType": "u16","#,
+    );
+    let abi: ContractAbiJson = serde_json::from_str(&abi_json).unwrap();
+    let decoder = EventDecoder::new(abi);
+
+    let data = [
+        &[0, 0, 0, 2, 0x01, 0xf4][..],
+        &10u64.to_be_bytes()[..],
+        &[0, 1][..],
+        &7u64.to_be_bytes()[..],
+    ]
+    .concat();
+    let event = decoder.decode(&deposit_topics(), &data).unwrap();
+    let curve = event.field("schedule").unwrap().field("curve").unwrap();
+    assert_eq!(curve.field("step"), Some(&AbiValue::from(7u64)));
+
+    let event = decoder
+        .decode(
+            &[b"batch".to_vec(), vec![0, 1, 0, 0, 0, 0, 0, 0, 0, 3]],
+            &[],
+        )
+        .unwrap();
+    assert_eq!(
+        event.to_string(),
+        "batch(curve: Curve::Step { step: 3 }, ids: [], weights: None)"
+    );
+
+    // the same bytes, read with a 1-byte discriminant
+    assert!(self::decoder().decode(&deposit_topics(), &data).is_err());
+}
+
+#[test]
+fn event_decoder_errors_test() {
+    let decoder = decoder();
+
+    assert_eq!(
+        decoder.decode(&[b"withdraw".to_vec()], &[]),
+        Err(EventDecodeError::UnknownEvent("withdraw".to_string()))
+    );
+    assert_eq!(
+        decoder.decode_if_known(&[b"withdraw".to_vec()], &[]),
+        Ok(None)
+    );
+    assert_eq!(
+        decoder.decode(&[], &[]),
+        Err(EventDecodeError::MissingIdentifier)
+    );
+
+    let mut topics = deposit_topics();
+    topics.push(vec![1]);
+    assert_eq!(
+        decoder.decode(&topics, &deposit_data()),
+        Err(EventDecodeError::TooManyTopics {
+            identifier: "deposit".to_string(),
+            expected: 3,
+            actual: 4
+        })
+    );
+
+    let mut topics = deposit_topics();
+    topics[1].pop();
+    assert_eq!(
+        decoder
+            .decode(&topics, &deposit_data())
+            .unwrap_err()
+            .to_string(),
+        "event \"deposit\", field caller: address must be 32 bytes long, got 31"
+    );
+
+    let mut data = deposit_data();
+    data.push(0);
+    assert_eq!(
+        decoder
+            .decode(&deposit_topics(), &data)
+            .unwrap_err()
+            .to_string(),
+        "event \"deposit\", field schedule: 1 unexpected trailing bytes after Schedule"
+    );
+}
+
+#[test]
+fn event_decoder_tx_json_logs_test() {
+    let tx_json_str = format!(
+        r#"{{
+            "data": {{
+                "transaction": {{
+                    "logs": {{
+                        "address": "erd1qqqqqqqqqqqqqpgqfzydqmdw7m2vazsp6u5p95yxz76t2p9rd8ss0zp9ts",
+                        "events": [
+                            {{
+                                "address": "erd1qqqqqqqqqqqqqpgqfzydqmdw7m2vazsp6u5p95yxz76t2p9rd8ss0zp9ts",
+                                "identifier": "deposit",
+                                "topics": ["{}", "{}", "{}"],
+                                "data": "{}"
+                            }}
+                        ]
+                    }},
+                    "smartContractResults": [
+                        {{
+                            "logs": {{
+                                "events": [
+                                    {{ "identifier": "completedTxEvent", "topics": ["{}"], "data": null }}
+                                ]
+                            }}
+                        }}
+                    ]
+                }}
+            }}
+        }}"#,
+        base64::encode(b"deposit"),
+        base64::encode([0x11; 32]),
+        base64::encode([0x01, 0x00]),
+        base64::encode(deposit_data()),
+        base64::encode(b"hash"),
+    );
+    let tx_json: serde_json::Value = serde_json::from_str(&tx_json_str).unwrap();
+
+    let logs = collect_tx_json_logs(&tx_json);
+    assert_eq!(logs.len(), 2);
+    assert_eq!(logs[0].endpoint, "deposit");
+    assert_eq!(logs[0].topics, deposit_topics());
+    assert_eq!(logs[0].data, deposit_data());
+    assert_eq!(logs[1].endpoint, "completedTxEvent");
+
+    let decoder = decoder();
+    assert!(decoder.decode(&logs[0].topics, &logs[0].data).is_ok());
+    assert_eq!(
+        decoder.decode_if_known(&logs[1].topics, &logs[1].data),
+        Ok(None)
+    );
+}
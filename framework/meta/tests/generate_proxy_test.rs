@@ -0,0 +1,111 @@
+use multiversx_sc_meta::{
+    abi_json::read_abi_json,
+    cli_args::GenerateProxyArgs,
+    generate_proxy::{
+        generate_proxy, generate_proxy_cargo_toml, generate_proxy_lib_rs, rust_method_name,
+    },
+};
+
+const ABI_TESTER_ABI_PATH: &str =
+    "../../contracts/feature-tests/abi-tester/abi_tester_expected_main.abi.json";
+const ABI_TESTER_PROXY_LIB_PATH: &str = "../../contracts/feature-tests/abi-tester/proxy/src/lib.rs";
+
+#[test]
+fn test_rust_method_name() {
+    assert_eq!(rust_method_name("getSum"), "get_sum");
+    assert_eq!(rust_method_name("add"), "add");
+    assert_eq!(rust_method_name("multi_result_3"), "multi_result_3");
+    assert_eq!(rust_method_name("getNFTData"), "get_nft_data");
+    assert_eq!(rust_method_name("issueToken2"), "issue_token2");
+    assert_eq!(rust_method_name("move"), "r#move");
+}
+
+#[test]
+fn test_generate_proxy_types() {
+    let abi = read_abi_json(ABI_TESTER_ABI_PATH);
+    let lib_rs = generate_proxy_lib_rs(&abi);
+
+    // recursive types are boxed, heap types imported explicitly
+    assert!(lib_rs.contains("use multiversx_sc::types::heap::{Box, Vec};"));
+    assert!(lib_rs.contains("    pub next: Option<Box<AbiTestType>>,\n"));
+
+    // only types with managed fields are generic
+    assert!(lib_rs.contains("pub struct AbiManagedType<M: ManagedTypeApi> {\n"));
+    assert!(lib_rs.contains("    pub big_uint: BigUint<M>,\n"));
+    assert!(lib_rs.contains("pub struct OnlyShowsUpInStorage {\n"));
+
+    // explicit discriminants are pinned
+    assert!(lib_rs.contains("    #[codec(discriminant = 30)]\n    Something {\n"));
+
+    // the discriminant type comes from the ABI, even when all values fit in a byte
+    assert!(lib_rs.contains("#[codec(discriminant_type = \"u16\")]\npub enum WideDiscriminant {\n"));
+
+    // framework types are not generated again
+    assert!(!lib_rs.contains("pub struct EsdtTokenPayment"));
+    assert!(!lib_rs.contains("pub enum EsdtLocalRole"));
+}
+
+#[test]
+fn test_generate_proxy_vec_items() {
+    let abi = read_abi_json(ABI_TESTER_ABI_PATH);
+    let lib_rs = generate_proxy_lib_rs(&abi);
+
+    assert!(lib_rs.contains(
+        "TypeAbi, ManagedVecItem, Clone, PartialEq, Debug)]\npub struct AbiManagedVecItem {"
+    ));
+    assert!(lib_rs.contains("-> ManagedVec<Self::Api, AbiManagedVecItem>;"));
+
+    // empty structs cannot be managed vector items
+    assert!(lib_rs.contains("pub struct OnlyShowsUpAsNestedInVec {}"));
+    assert!(lib_rs.contains("-> Vec<OnlyShowsUpAsNestedInVec>;"));
+}
+
+#[test]
+fn test_generate_proxy_trait() {
+    let abi = read_abi_json(ABI_TESTER_ABI_PATH);
+    let lib_rs = generate_proxy_lib_rs(&abi);
+
+    assert!(lib_rs.contains("#[multiversx_sc::proxy]\npub trait AbiTester {\n"));
+    assert!(lib_rs.contains(
+        "    #[init]\n    #[payable(\"EGLD\")]\n    fn init(&self, _constructor_arg_1: i32, _constructor_arg_2: OnlyShowsUpInConstructor);\n"
+    ));
+    assert!(lib_rs.contains("    #[upgrade]\n    fn upgrade(&self, _upgrade_arg: u32);\n"));
+    assert!(lib_rs.contains(
+        "    #[payable(\"TOKEN-FOR-ABI\")]\n    #[endpoint(payable_some_token)]\n    fn payable_some_token(&self);\n"
+    ));
+    assert!(lib_rs.contains(
+        "fn multi_result_3(&self) -> MultiValue3<i32, [u8; 3], ManagedBuffer<Self::Api>>;"
+    ));
+    assert!(lib_rs.contains("h256: ManagedByteArray<Self::Api, 32>"));
+    assert!(lib_rs.contains("_opt_args: OptionalValue<OnlyShowsUpAsNested06>"));
+}
+
+/// The checked-in abi-tester proxy needs to be regenerated whenever the generator or the ABI changes.
+#[test]
+fn test_generate_proxy_up_to_date() {
+    let output = format!("{}/abi-tester-proxy", env!("CARGO_TARGET_TMPDIR"));
+    generate_proxy(&GenerateProxyArgs {
+        abi: ABI_TESTER_ABI_PATH.to_string(),
+        output: Some(output.clone()),
+        crate_name: Some("abi-tester-proxy".to_string()),
+        framework_path: None,
+    });
+
+    let generated = std::fs::read_to_string(format!("{output}/src/lib.rs")).unwrap();
+    let checked_in = std::fs::read_to_string(ABI_TESTER_PROXY_LIB_PATH).unwrap();
+    assert!(
+        generated == checked_in,
+        "{ABI_TESTER_PROXY_LIB_PATH} is out of date, regenerate it with `sc-meta generate-proxy`"
+    );
+}
+
+#[test]
+fn test_generate_proxy_cargo_toml() {
+    let abi = read_abi_json(ABI_TESTER_ABI_PATH);
+    let cargo_toml = generate_proxy_cargo_toml(&abi, "abi-tester-proxy", Some("../framework/base"));
+    assert!(cargo_toml.starts_with("[package]\nname = \"abi-tester-proxy\"\nversion = \"0.0.0\"\n"));
+    assert!(cargo_toml.ends_with(&format!(
+        "[dependencies.multiversx-sc]\nversion = \"{}\"\npath = \"../framework/base\"\n",
+        env!("CARGO_PKG_VERSION")
+    )));
+}
@@ -0,0 +1,133 @@
+use std::collections::BTreeMap;
+
+use multiversx_sc_meta::{
+    abi_json::{BuildInfoAbiJson, ContractCrateBuildAbiJson, FrameworkBuildAbiJson},
+    reproducible_build::{
+        blake2b_256_hex, parse_rustc_verbose_version, parse_toolchain_file, verification_report,
+        BuildManifestContractJson, BuildManifestJson,
+    },
+};
+
+#[test]
+fn blake2b_256_test() {
+    assert_eq!(
+        blake2b_256_hex(b""),
+        "0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8"
+    );
+    assert_eq!(
+        blake2b_256_hex(b"abc"),
+        "bddd813c634239723171ef3fee98579b94964e3bb1cb3e427262c8c068d52319"
+    );
+    // exactly one block
+    assert_eq!(
+        blake2b_256_hex(&[b'x'; 128]),
+        "164ffb7089bae6f5a62fb0795e751dc9e88eac92e1a5b2fafe93a25abf2d9c3b"
+    );
+    let long_input: Vec<u8> = (0..3).flat_map(|_| 0..=255u8).collect();
+    assert_eq!(
+        blake2b_256_hex(&long_input),
+        "b8007121274217790e2923e0ad7027986e5a99d5531ef6ae7d294140fc81615d"
+    );
+}
+
+#[test]
+fn toolchain_file_test() {
+    assert_eq!(
+        parse_toolchain_file(
+            r#"
+            [toolchain]
+            channel = "nightly-2022-12-08"
+            targets = ["wasm32-unknown-unknown"]
+            "#
+        ),
+        Some("nightly-2022-12-08".to_string())
+    );
+    assert_eq!(parse_toolchain_file("1.66.0\n"), Some("1.66.0".to_string()));
+    assert_eq!(parse_toolchain_file("[toolchain]\n"), None);
+}
+
+#[test]
+fn rustc_verbose_version_test() {
+    let rustc = parse_rustc_verbose_version(
+        "rustc 1.68.0-nightly (c5d82ed7a 2022-12-07)
+binary: rustc
+commit-hash: c5d82ed7a4ad94a538bb87e5016e7d5ce0bd434b
+commit-date: 2022-12-07
+host: x86_64-unknown-linux-gnu
+release: 1.68.0-nightly
+LLVM version: 15.0.6
+",
+    );
+    assert_eq!(rustc.version, "1.68.0-nightly");
+    assert_eq!(
+        rustc.commit_hash,
+        "c5d82ed7a4ad94a538bb87e5016e7d5ce0bd434b"
+    );
+    assert_eq!(rustc.commit_date, "2022-12-07");
+    assert_eq!(rustc.channel, "Nightly");
+    assert_eq!(rustc.short, "rustc 1.68.0-nightly (c5d82ed7a 2022-12-07)");
+}
+
+fn manifest(code_hash: &str, rustc_short: &str, lock_hash: &str) -> BuildManifestJson {
+    let mut rustc = parse_rustc_verbose_version("release: 1.68.0-nightly");
+    rustc.short = rustc_short.to_string();
+    BuildManifestJson {
+        toolchain: "nightly-2022-12-08".to_string(),
+        build_info: BuildInfoAbiJson {
+            rustc,
+            contract_crate: ContractCrateBuildAbiJson {
+                name: "adder".to_string(),
+                version: "0.0.0".to_string(),
+                git_version: String::new(),
+            },
+            framework: FrameworkBuildAbiJson {
+                name: "multiversx-sc".to_string(),
+                version: "0.39.5".to_string(),
+            },
+        },
+        wasm_opt: None,
+        path_remaps: vec!["/cargo".to_string(), "/project".to_string()],
+        contracts: vec![BuildManifestContractJson {
+            name: "adder".to_string(),
+            wasm: "adder.wasm".to_string(),
+            size: 1000,
+            code_hash: code_hash.to_string(),
+        }],
+        files: BTreeMap::from([("wasm/Cargo.lock".to_string(), lock_hash.to_string())]),
+    }
+}
+
+#[test]
+fn build_manifest_serde_test() {
+    let original = manifest("aa", "rustc 1.68.0-nightly", "bb");
+    let json = serde_json::to_string_pretty(&original).unwrap();
+    assert!(json.contains("\"codeHash\": \"aa\""));
+    assert!(json.contains("\"pathRemaps\""));
+    assert!(!json.contains("wasmOpt"));
+
+    let parsed: BuildManifestJson = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        parsed
+            .find_contract_by_wasm("adder.wasm")
+            .unwrap()
+            .code_hash,
+        "aa"
+    );
+    assert!(parsed.find_contract_by_wasm("other.wasm").is_none());
+}
+
+#[test]
+fn verification_report_test() {
+    let expected = manifest("aa", "rustc 1.68.0-nightly", "bb");
+    assert!(verification_report(&expected, &expected, "aa", "aa").is_empty());
+
+    let rebuilt = manifest("cc", "rustc 1.69.0-nightly", "dd");
+    assert_eq!(
+        verification_report(&expected, &rebuilt, "aa", "ee"),
+        vec![
+            "the wasm code hash ee differs from the one in the manifest, aa",
+            "compiler differs: \"rustc 1.68.0-nightly\" in the manifest, \"rustc 1.69.0-nightly\" now",
+            "wasm/Cargo.lock differs from the one in the manifest",
+        ]
+    );
+}
@@ -0,0 +1,282 @@
+use std::{fs, path::PathBuf};
+
+use multiversx_sc_meta::scenario_fmt::{
+    find_legacy_scenario_dirs, find_scenario_files, format_scenario_json, lint_scenario_file,
+    upgrade_legacy_path, ScenarioLintIssue, ScenarioLintKind,
+};
+
+const LEGACY_SCENARIO: &str = r#"{
+    "name": "legacy",
+    "traceGas": true,
+    "steps": [
+        {
+            "step": "externalSteps",
+            "path": "../mandos/init.steps.json"
+        },
+        {
+            "step": "scCall",
+            "tx": {
+                "from": "address:owner",
+                "to": "sc:adder",
+                "value": "5",
+                "function": "add",
+                "arguments": ["1"],
+                "gasLimit": "5,000,000",
+                "gasPrice": "0"
+            },
+            "expect": {
+                "out": [],
+                "status": "0"
+            }
+        }
+    ]
+}"#;
+
+const FORMATTED_SCENARIO: &str = r#"{
+    "name": "legacy",
+    "traceGas": true,
+    "steps": [
+        {
+            "step": "externalSteps",
+            "path": "../scenarios/init.steps.json"
+        },
+        {
+            "step": "scCall",
+            "tx": {
+                "from": "address:owner",
+                "to": "sc:adder",
+                "egldValue": "5",
+                "function": "add",
+                "arguments": [
+                    "1"
+                ],
+                "gasLimit": "5,000,000",
+                "gasPrice": "0"
+            },
+            "expect": {
+                "out": [],
+                "status": "0"
+            }
+        }
+    ]
+}
+"#;
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR"))
+        .join("scenario_fmt_test")
+        .join(name);
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn issue(kind: ScenarioLintKind, location: &str, detail: &str) -> ScenarioLintIssue {
+    ScenarioLintIssue {
+        kind,
+        location: location.to_string(),
+        detail: detail.to_string(),
+    }
+}
+
+#[test]
+fn scen_fmt_test() {
+    let formatted = format_scenario_json(LEGACY_SCENARIO).unwrap();
+    assert_eq!(formatted, FORMATTED_SCENARIO);
+    assert_eq!(format_scenario_json(&formatted).unwrap(), formatted);
+
+    assert_eq!(
+        upgrade_legacy_path("../mandos/init.steps.json"),
+        "../scenarios/init.steps.json"
+    );
+    assert_eq!(
+        upgrade_legacy_path("steps/mandos-init.steps.json"),
+        "steps/mandos-init.steps.json"
+    );
+}
+
+#[test]
+fn scen_fmt_keeps_layout_test() {
+    // not sorted, no step ids, Go-only fields
+    let json = r#"{
+    "steps": [
+        {
+            "step": "setState",
+            "accounts": {
+                "sc:other-shard": {
+                    "shard": "1",
+                    "code": "file:../output/adder.wasm"
+                },
+                "address:owner": {
+                    "nonce": "0",
+                    "balance": "0"
+                }
+            }
+        },
+        {
+            "step": "scQuery",
+            "tx": {
+                "to": "sc:other-shard",
+                "function": "getSum",
+                "arguments": []
+            },
+            "expect": {
+                "out": [
+                    "5"
+                ]
+            }
+        },
+        {
+            "step": "checkState",
+            "accounts": {
+                "sc:other-shard": {
+                    "code": "file:../output/adder.wasm"
+                },
+                "+": ""
+            }
+        }
+    ]
+}
+"#;
+    assert_eq!(format_scenario_json(json).unwrap(), json);
+}
+
+#[test]
+fn scen_fmt_keeps_unknown_fields_test() {
+    let json = r#"{
+        "steps": [
+            {
+                "step": "setState",
+                "accounts": {
+                    "address:owner": {
+                        "nonce": "0",
+                        "rating": "1",
+                        "storage": {}
+                    }
+                }
+            }
+        ]
+    }"#;
+    assert_eq!(
+        format_scenario_json(json),
+        Err("unknown fields: steps[0].accounts.address:owner.rating".to_string())
+    );
+    assert!(format_scenario_json("{\"steps\": 5}").is_err());
+}
+
+#[test]
+fn scen_lint_test() {
+    let dir = temp_dir("lint");
+    fs::create_dir_all(dir.join("mandos")).unwrap();
+    fs::write(
+        dir.join("mandos/init.steps.json"),
+        r#"{
+    "steps": [
+        {
+            "step": "setState",
+            "accounts": {
+                "address:owner": {},
+                "sc:adder": {}
+            }
+        }
+    ]
+}"#,
+    )
+    .unwrap();
+    fs::write(
+        dir.join("mandos/add.scen.json"),
+        r#"{
+    "steps": [
+        {
+            "step": "externalSteps",
+            "path": "init.steps.json"
+        },
+        {
+            "step": "externalSteps",
+            "path": "missing.steps.json"
+        },
+        {
+            "step": "setState",
+            "accounts": {
+                "address:user": {},
+                "address:user2": {},
+                "address:other": {
+                    "storage": {
+                        "str:friend": "address:user2"
+                    }
+                }
+            }
+        },
+        {
+            "step": "scCall",
+            "id": "1",
+            "tx": {
+                "from": "address:owner",
+                "to": "sc:adder",
+                "value": "0",
+                "function": "add",
+                "arguments": [],
+                "gasLimit": "5,000,000",
+                "gasPrice": "0"
+            }
+        },
+        {
+            "step": "scQuery",
+            "tx": {
+                "to": "sc:unknown",
+                "function": "getSum",
+                "arguments": []
+            }
+        },
+        {
+            "step": "checkState",
+            "comments": "misspelled",
+            "accounts": {
+                "address:other": {},
+                "+": ""
+            }
+        }
+    ]
+}"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        find_scenario_files(&dir, &[]),
+        [
+            dir.join("mandos/add.scen.json"),
+            dir.join("mandos/init.steps.json")
+        ]
+    );
+    assert_eq!(find_legacy_scenario_dirs(&dir, &[]), [dir.join("mandos")]);
+
+    assert_eq!(
+        lint_scenario_file(&dir.join("mandos/add.scen.json")),
+        [
+            issue(ScenarioLintKind::UnknownField, "steps[5].comments", ""),
+            issue(
+                ScenarioLintKind::UnreachableExternalSteps,
+                "steps[1].path",
+                "missing.steps.json"
+            ),
+            issue(
+                ScenarioLintKind::LegacyNaming,
+                "steps[3].tx.value",
+                "use egldValue instead"
+            ),
+            issue(
+                ScenarioLintKind::UndefinedAddress,
+                "steps[4].tx.to",
+                "sc:unknown"
+            ),
+            issue(
+                ScenarioLintKind::UnusedAccount,
+                "steps[2].accounts",
+                "address:user"
+            ),
+        ]
+    );
+
+    // accounts and addresses are only checked in whole scenarios
+    assert!(lint_scenario_file(&dir.join("mandos/init.steps.json")).is_empty());
+}
@@ -0,0 +1,264 @@
+use multiversx_sc_meta::{
+    abi_json::ContractAbiJson,
+    storage_diff::{compare_storage_layout, StorageChangeSeverity},
+};
+
+fn abi_with_storage(storage_json: &str, types_json: &str) -> ContractAbiJson {
+    let abi_json = format!(
+        r#"{{
+            "buildInfo": {{
+                "rustc": {{
+                    "version": "x.x.x-nightly",
+                    "commitHash": "",
+                    "commitDate": "",
+                    "channel": "Nightly",
+                    "short": ""
+                }},
+                "contractCrate": {{ "name": "test", "version": "0.0.0", "gitVersion": "" }},
+                "framework": {{ "name": "multiversx-sc", "version": "0.39.5" }}
+            }},
+            "name": "Test",
+            "endpoints": [],
+            "events": [],
+            "storage": {storage_json},
+            "hasCallback": false,
+            "types": {types_json}
+        }}"#
+    );
+    serde_json::from_str(&abi_json).unwrap()
+}
+
+const STORAGE_JSON: &str = r#"[
+    {
+        "key": "schedules",
+        "mapper": "MapMapper",
+        "valueTypes": ["u64", "Schedule"]
+    },
+    {
+        "key": "total",
+        "mapper": "SingleValueMapper",
+        "keyArgs": [{ "name": "user", "type": "Address" }],
+        "valueTypes": ["BigUint"]
+    }
+]"#;
+
+const TYPES_JSON: &str = r#"{
+    "Schedule": {
+        "type": "struct",
+        "fields": [
+            { "name": "amount", "type": "BigUint" },
+            { "name": "start", "type": "u64" },
+            { "name": "end", "type": "u64" },
+            { "name": "curve", "type": "Curve" }
+        ]
+    },
+    "Curve": {
+        "type": "enum",
+        "variants": [
+            { "name": "Linear", "discriminant": 0 },
+            { "name": "Step", "discriminant": 1, "fields": [{ "name": "step", "type": "u64" }] }
+        ]
+    }
+}"#;
+
+fn severities(old_abi: &ContractAbiJson, new_abi: &ContractAbiJson) -> Vec<StorageChangeSeverity> {
+    compare_storage_layout(old_abi, new_abi)
+        .changes
+        .iter()
+        .map(|change| change.severity)
+        .collect()
+}
+
+#[test]
+fn storage_diff_unchanged_test() {
+    let old_abi = abi_with_storage(STORAGE_JSON, TYPES_JSON);
+    let new_abi = abi_with_storage(STORAGE_JSON, TYPES_JSON);
+
+    let report = compare_storage_layout(&old_abi, &new_abi);
+    assert!(report.changes.is_empty());
+}
+
+#[test]
+fn storage_diff_field_reordered_test() {
+    let old_abi = abi_with_storage(STORAGE_JSON, TYPES_JSON);
+    let new_types = TYPES_JSON.replace(
+        r#"{ "name": "start", "type": "u64" },
+            { "name": "end", "type": "u64" },"#,
+        r#"{ "name": "end", "type": "u64" },
+            { "name": "start", "type": "u64" },"#,
+    );
+    let new_abi = abi_with_storage(STORAGE_JSON, &new_types);
+
+    let report = compare_storage_layout(&old_abi, &new_abi);
+    assert!(report.has_incompatible_changes());
+    assert_eq!(report.changes[0].key, "schedules");
+    assert_eq!(
+        report.changes[0].message,
+        "value type #1: Schedule fields reordered, end is now where start used to be"
+    );
+}
+
+#[test]
+fn storage_diff_nested_type_changed_test() {
+    let old_abi = abi_with_storage(STORAGE_JSON, TYPES_JSON);
+    let new_types = TYPES_JSON.replace(
+        r#"[{ "name": "step", "type": "u64" }]"#,
+        r#"[{ "name": "step", "type": "u32" }]"#,
+    );
+    let new_abi = abi_with_storage(STORAGE_JSON, &new_types);
+
+    let report = compare_storage_layout(&old_abi, &new_abi);
+    assert_eq!(
+        report.changes[0].message,
+        "value type #1: Schedule field curve: Curve::Step field step: type changed from u64 to u32"
+    );
+    assert!(report.has_incompatible_changes());
+}
+
+#[test]
+fn storage_diff_discriminant_type_changed_test() {
+    let old_abi = abi_with_storage(STORAGE_JSON, TYPES_JSON);
+    let new_types = TYPES_JSON.replace(
+        r#""type": "enum","#,
+        r#""type": "enum",
+        "discriminantType": "u16","#,
+    );
+    let new_abi = abi_with_storage(STORAGE_JSON, &new_types);
+
+    let report = compare_storage_layout(&old_abi, &new_abi);
+    assert!(report.has_incompatible_changes());
+    assert_eq!(
+        report.changes[0].message,
+        "value type #1: Schedule field curve: type Curve discriminants changed from 1 to 2 bytes"
+    );
+}
+
+#[test]
+fn storage_diff_compatible_changes_test() {
+    let old_abi = abi_with_storage(STORAGE_JSON, TYPES_JSON);
+    let new_storage = STORAGE_JSON.replace(
+        "\n]",
+        r#",
+    {
+        "key": "paused",
+        "mapper": "SingleValueMapper",
+        "valueTypes": ["bool"]
+    }
+]"#,
+    );
+    let new_types = TYPES_JSON
+        .replace(r#""name": "amount""#, r#""name": "total_amount""#)
+        .replace(
+            r#"{ "name": "Linear", "discriminant": 0 },"#,
+            r#"{ "name": "Linear", "discriminant": 0 },
+            { "name": "Exponential", "discriminant": 2 },"#,
+        );
+    let new_abi = abi_with_storage(&new_storage, &new_types);
+
+    assert_eq!(
+        severities(&old_abi, &new_abi),
+        vec![
+            StorageChangeSeverity::Compatible,
+            StorageChangeSeverity::Compatible
+        ]
+    );
+}
+
+#[test]
+fn storage_diff_mapper_and_key_changes_test() {
+    let old_abi = abi_with_storage(STORAGE_JSON, TYPES_JSON);
+    let new_storage = STORAGE_JSON
+        .replace("MapMapper", "BiDiMapper")
+        .replace(r#""type": "Address""#, r#""type": "u64""#);
+    let new_abi = abi_with_storage(&new_storage, TYPES_JSON);
+
+    let report = compare_storage_layout(&old_abi, &new_abi);
+    let messages: Vec<&str> = report
+        .changes
+        .iter()
+        .map(|change| change.message.as_str())
+        .collect();
+    assert_eq!(
+        messages,
+        vec![
+            "mapper changed from MapMapper to BiDiMapper",
+            "key argument #0: type changed from Address to u64",
+        ]
+    );
+
+    let removed_abi = abi_with_storage("[]", TYPES_JSON);
+    assert_eq!(
+        severities(&old_abi, &removed_abi),
+        vec![
+            StorageChangeSeverity::Warning,
+            StorageChangeSeverity::Warning
+        ]
+    );
+}
+
+#[test]
+fn storage_diff_shared_key_test() {
+    let storage_json = r#"[
+        {
+            "key": "users",
+            "mapper": "SingleValueMapper",
+            "keyArgs": [{ "name": "id", "type": "u64" }],
+            "valueTypes": ["Address"]
+        },
+        {
+            "key": "users",
+            "mapper": "UnorderedSetMapper",
+            "valueTypes": ["u64"]
+        }
+    ]"#;
+    let old_abi = abi_with_storage(storage_json, "{}");
+    assert!(compare_storage_layout(&old_abi, &old_abi)
+        .changes
+        .is_empty());
+
+    let changed_abi = abi_with_storage(&storage_json.replace(r#"["u64"]"#, r#"["u32"]"#), "{}");
+    let report = compare_storage_layout(&old_abi, &changed_abi);
+    assert_eq!(report.changes.len(), 1);
+    assert_eq!(
+        report.changes[0].message,
+        "value type #0: type changed from u64 to u32"
+    );
+
+    let set_only_json =
+        r#"[{ "key": "users", "mapper": "UnorderedSetMapper", "valueTypes": ["u64"] }]"#;
+    let removed_abi = abi_with_storage(set_only_json, "{}");
+    let report = compare_storage_layout(&old_abi, &removed_abi);
+    assert_eq!(report.changes.len(), 1);
+    assert_eq!(report.changes[0].severity, StorageChangeSeverity::Warning);
+    assert_eq!(
+        report.changes[0].message,
+        "SingleValueMapper removed, existing data will be left in storage"
+    );
+}
+
+#[test]
+fn storage_diff_type_renamed_test() {
+    let old_abi = abi_with_storage(STORAGE_JSON, TYPES_JSON);
+    let renamed_types = TYPES_JSON
+        .replace(r#""type": "Curve""#, r#""type": "VestingCurve""#)
+        .replace(r#""Curve": {"#, r#""VestingCurve": {"#);
+    let new_abi = abi_with_storage(STORAGE_JSON, &renamed_types);
+
+    let report = compare_storage_layout(&old_abi, &new_abi);
+    assert!(!report.has_incompatible_changes());
+    assert_eq!(report.changes.len(), 1);
+    assert_eq!(
+        report.changes[0].message,
+        "value type #1: Schedule field curve: type Curve renamed to VestingCurve"
+    );
+
+    let changed_types = renamed_types.replace(
+        r#"[{ "name": "step", "type": "u64" }]"#,
+        r#"[{ "name": "step", "type": "u32" }]"#,
+    );
+    let changed_abi = abi_with_storage(STORAGE_JSON, &changed_types);
+    assert!(compare_storage_layout(&old_abi, &changed_abi).has_incompatible_changes());
+
+    let undescribed_abi = abi_with_storage(&STORAGE_JSON.replace("Schedule", "u64"), TYPES_JSON);
+    assert!(compare_storage_layout(&old_abi, &undescribed_abi).has_incompatible_changes());
+}
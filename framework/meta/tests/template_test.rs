@@ -0,0 +1,268 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use multiversx_sc_meta::{
+    template::{
+        apply_replacements, extract_embedded_templates, validate_crate_name, ContractTemplate,
+        TemplateInstance, TemplateReplacement,
+    },
+    test_gen::{
+        parse_scenario_test_entries, scenario_go_test_code, scenario_names, scenario_rs_test_code,
+        scenario_rs_world_code, ScenarioTestEntry, ScenarioTestKind,
+    },
+};
+
+const REPO_PATH: &str = "../..";
+
+fn new_contract(template_name: &str, crate_name: &str) -> TemplateInstance {
+    let parent_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("template_test");
+    let _ = fs::remove_dir_all(parent_dir.join(crate_name));
+    let instance = TemplateInstance::new(
+        *ContractTemplate::find(template_name).unwrap(),
+        Path::new(REPO_PATH),
+        Some(crate_name),
+        &parent_dir,
+        false,
+    );
+    instance.create();
+    instance
+}
+
+fn read(dir: &Path, file: &str) -> String {
+    fs::read_to_string(dir.join(file)).unwrap_or_else(|_| panic!("{file} not found"))
+}
+
+fn example_tests_path(example: &str, kind: &str) -> PathBuf {
+    let crate_snake = example.replace('-', "_");
+    Path::new(REPO_PATH)
+        .join("contracts/examples")
+        .join(example)
+        .join(format!("tests/{crate_snake}_scenario_{kind}_test.rs"))
+}
+
+#[test]
+fn test_apply_replacements() {
+    let replacements = [
+        TemplateReplacement::new("adder.wasm", "my-adder.wasm"),
+        TemplateReplacement::new("adder::", "my_adder::"),
+    ];
+    assert_eq!(
+        apply_replacements(
+            "use adder::*; \"file:output/adder.wasm\", adder.init(); ladder::x",
+            &replacements
+        ),
+        "use my_adder::*; \"file:output/my-adder.wasm\", adder.init(); ladder::x"
+    );
+
+    // replaced text is not replaced again
+    let replacements = [
+        TemplateReplacement::new("a", "b"),
+        TemplateReplacement::new("b", "c"),
+    ];
+    assert_eq!(apply_replacements("a b", &replacements), "b c");
+}
+
+#[test]
+fn test_validate_crate_name() {
+    assert!(validate_crate_name("my-adder").is_ok());
+    assert!(validate_crate_name("my_adder2").is_ok());
+    assert!(validate_crate_name("").is_err());
+    assert!(validate_crate_name("2adder").is_err());
+    assert!(validate_crate_name("MyAdder").is_err());
+    assert!(validate_crate_name("my adder").is_err());
+}
+
+#[test]
+fn test_scenario_test_code_matches_examples() {
+    for example in ["adder", "crowdfunding-esdt", "ping-pong-egld"] {
+        let crate_snake = example.replace('-', "_");
+
+        let rs_code = fs::read_to_string(example_tests_path(example, "rs")).unwrap();
+        let world_code = scenario_rs_world_code(
+            &format!("contracts/examples/{example}"),
+            example,
+            &crate_snake,
+        );
+        let entries = parse_scenario_test_entries(&rs_code);
+        assert_eq!(scenario_rs_test_code(&world_code, &entries), rs_code);
+
+        let go_code = fs::read_to_string(example_tests_path(example, "go")).unwrap();
+        let entries = parse_scenario_test_entries(&go_code);
+        assert_eq!(scenario_go_test_code(&entries), go_code);
+    }
+}
+
+#[test]
+fn test_parse_scenario_test_entries_ignore() {
+    let code = r#"
+#[test]
+#[ignore = "needs the Go VM"]
+fn first_rs() {
+    multiversx_sc_scenario::run_rs("scenarios/first.scen.json", world());
+}
+
+#[test]
+fn second_rs() {
+    multiversx_sc_scenario::run_rs(
+        "scenarios/second.scen.json",
+        world(),
+    );
+}
+
+#[test]
+fn hand_written() {}
+"#;
+    assert_eq!(
+        parse_scenario_test_entries(code),
+        vec![
+            ScenarioTestEntry {
+                scenario_path: "scenarios/first.scen.json".to_string(),
+                fn_name: "first_rs".to_string(),
+                attributes: vec![
+                    "#[test]".to_string(),
+                    "#[ignore = \"needs the Go VM\"]".to_string()
+                ],
+            },
+            ScenarioTestEntry::new(ScenarioTestKind::Rs, "second"),
+        ]
+    );
+}
+
+#[test]
+fn test_new_contract_from_adder() {
+    let instance = new_contract("adder", "my-adder");
+    let dir = instance.target_dir.as_path();
+
+    let cargo_toml = read(dir, "Cargo.toml");
+    assert!(cargo_toml.contains("name = \"my-adder\""));
+    assert!(cargo_toml.contains("path = \"src/my_adder.rs\""));
+    assert!(cargo_toml.contains(&format!(
+        "[dependencies.multiversx-sc]\nversion = \"{}\"\n",
+        env!("CARGO_PKG_VERSION")
+    )));
+    assert!(!cargo_toml.contains("framework/"));
+    assert!(cargo_toml.contains("[workspace]\nmembers = [\".\", \"meta\"]"));
+
+    let meta_cargo_toml = read(dir, "meta/Cargo.toml");
+    assert!(meta_cargo_toml.contains("name = \"my-adder-meta\""));
+    assert!(meta_cargo_toml.contains("[dependencies.my-adder]\npath = \"..\""));
+    assert!(read(dir, "meta/src/main.rs").contains("my_adder::AbiProvider"));
+
+    let wasm_cargo_toml = read(dir, "wasm/Cargo.toml");
+    assert!(wasm_cargo_toml.contains("name = \"my-adder-wasm\""));
+    assert!(!wasm_cargo_toml.contains("framework/"));
+    assert!(read(dir, "wasm/src/lib.rs").contains("endpoints! {\n    my_adder\n"));
+
+    assert!(dir.join("src/my_adder.rs").is_file());
+    assert!(read(dir, "multicontract.toml").contains("name = \"my-adder\""));
+    assert!(read(dir, "scenarios/adder.scen.json").contains("file:../output/my-adder.wasm"));
+
+    let rs_test = read(dir, "tests/my_adder_scenario_rs_test.rs");
+    assert!(rs_test.contains("set_current_dir_from_workspace(\"\")"));
+    assert!(rs_test
+        .contains("register_contract(\"file:output/my-adder.wasm\", my_adder::ContractBuilder)"));
+    assert!(rs_test.contains("fn adder_rs() {"));
+    assert!(read(dir, "tests/my_adder_scenario_go_test.rs").contains("fn adder_go() {"));
+
+    let unit_test = read(dir, "tests/my_adder_test.rs");
+    assert!(unit_test.contains("use my_adder::*;"));
+    assert!(unit_test.contains("adder.init("));
+
+    for skipped in [
+        "interaction",
+        "output",
+        "trace1.scen.json",
+        "wasm/Cargo.lock",
+    ] {
+        assert!(
+            !dir.join(skipped).exists(),
+            "{skipped} should not be copied"
+        );
+    }
+    assert!(!dir.join("tests/adder_test.rs").exists());
+}
+
+#[test]
+fn test_new_contract_keeps_template_test_coverage() {
+    let instance = new_contract("ping-pong", "ping-pong");
+    let dir = instance.target_dir.as_path();
+
+    let names = scenario_names(&dir.join("scenarios"));
+    assert!(names.contains(&"ping-pong-call-pong-all-interrupted-1".to_string()));
+
+    // scenarios the template does not run are added, but ignored
+    let rs_entries = parse_scenario_test_entries(&read(dir, "tests/ping_pong_scenario_rs_test.rs"));
+    assert_eq!(rs_entries.len(), names.len());
+    let ignored: Vec<&str> = rs_entries
+        .iter()
+        .filter(|entry| entry.is_ignored())
+        .map(|entry| entry.scenario_path.as_str())
+        .collect();
+    assert_eq!(
+        ignored,
+        [
+            "scenarios/ping-pong-call-ping-before-beginning.scen.json",
+            "scenarios/ping-pong-call-pong-all-interrupted-1.scen.json",
+            "scenarios/ping-pong-call-pong-all-interrupted-2.scen.json",
+        ]
+    );
+    assert!(read(dir, "tests/ping_pong_scenario_rs_test.rs")
+        .contains("register_contract(\"file:output/ping-pong.wasm\", ping_pong::ContractBuilder)"));
+
+    let crowdfunding = new_contract("crowdfunding", "crowdfunding");
+    let crowdfunding_dir = crowdfunding.target_dir.as_path();
+    assert!(crowdfunding_dir.join("src/crowdfunding.rs").is_file());
+    assert!(!crowdfunding_dir
+        .join("scenarios/_generated_init.scen.json")
+        .exists());
+    assert!(!crowdfunding_dir.join("scenarios-gen").exists());
+}
+
+/// The new contract depends on the published framework, so it gets patched to use the one in this repository.
+const PATCHED_FRAMEWORK_CRATES: &[(&str, &str)] = &[
+    ("multiversx-sc", "framework/base"),
+    ("multiversx-sc-scenario", "framework/scenario"),
+    ("multiversx-sc-snippets", "framework/snippets"),
+    ("multiversx-sc-meta", "framework/meta"),
+];
+
+#[test]
+fn test_new_contract_from_embedded_template_compiles() {
+    let test_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("template_embedded_test");
+    let _ = fs::remove_dir_all(&test_dir);
+    let templates_dir = test_dir.join("templates");
+    assert!(extract_embedded_templates(&templates_dir));
+
+    let instance = TemplateInstance::new(
+        *ContractTemplate::find("adder").unwrap(),
+        &templates_dir,
+        Some("embedded-adder"),
+        &test_dir,
+        false,
+    );
+    instance.create();
+    let dir = instance.target_dir.as_path();
+
+    let repo_path = Path::new(REPO_PATH).canonicalize().unwrap();
+    let mut cargo_toml = read(dir, "Cargo.toml");
+    cargo_toml.push_str("\n[patch.crates-io]\n");
+    for (crate_name, crate_path) in PATCHED_FRAMEWORK_CRATES {
+        let path = repo_path.join(crate_path);
+        cargo_toml.push_str(&format!("{crate_name} = {{ path = {:?} }}\n", path));
+    }
+    fs::write(dir.join("Cargo.toml"), cargo_toml).unwrap();
+    // starting from the repository's lock file, if there is one, keeps the dependency versions known to work
+    let _ = fs::copy(repo_path.join("Cargo.lock"), dir.join("Cargo.lock"));
+
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let status = Command::new(cargo)
+        .args(["check", "--all-targets"])
+        .current_dir(dir)
+        .env("CARGO_TARGET_DIR", test_dir.join("target"))
+        .status()
+        .expect("failed to run cargo check");
+    assert!(status.success(), "the new contract does not compile");
+}
@@ -0,0 +1,205 @@
+use std::{fs, path::PathBuf};
+
+use multiversx_sc_meta::test_gen::{
+    test_gen_file, ScenarioTestFile, ScenarioTestKind, TestGenFileReport,
+};
+
+const EXISTING_RS_TEST: &str = r#"use multiversx_sc_scenario::*;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("");
+
+    blockchain.register_contract("file:output/my-contract.wasm", my_contract::ContractBuilder);
+    blockchain
+}
+
+#[test]
+#[ignore = "fails on the Rust VM"]
+fn first_rs() {
+    multiversx_sc_scenario::run_rs("scenarios/first.scen.json", world());
+}
+
+#[test]
+fn deleted_rs() {
+    multiversx_sc_scenario::run_rs("scenarios/deleted.scen.json", world());
+}
+
+// not there yet
+#[ignore]
+#[test]
+fn future_rs() {
+    multiversx_sc_scenario::run_rs("scenarios/future.scen.json", world());
+}
+
+fn two() -> u32 {
+    2
+}
+
+#[test]
+fn third_rs() {
+    multiversx_sc_scenario::run_rs("scenarios/third.scen.json", world());
+}
+
+#[test]
+fn unit_test() {
+    assert_eq!(1 + 1, 2);
+}
+"#;
+
+fn contract_dir(name: &str) -> PathBuf {
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR"))
+        .join("test_gen_test")
+        .join(name);
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("scenarios")).unwrap();
+    fs::write(
+        dir.join("Cargo.toml"),
+        "[package]\nname = \"my-contract\"\nversion = \"0.0.0\"\n\n[workspace]\nmembers = [\".\"]\n",
+    )
+    .unwrap();
+    for scenario in ["first", "second", "third"] {
+        fs::write(dir.join(format!("scenarios/{scenario}.scen.json")), "{}").unwrap();
+    }
+    dir
+}
+
+fn fn_names(code: &str) -> Vec<String> {
+    ScenarioTestFile::parse(code)
+        .entries
+        .into_iter()
+        .map(|entry| entry.fn_name)
+        .collect()
+}
+
+#[test]
+fn test_gen_refresh_test() {
+    let dir = contract_dir("refresh");
+    fs::create_dir_all(dir.join("tests")).unwrap();
+    let test_path = dir.join("tests/my_contract_scenario_rs_test.rs");
+    fs::write(&test_path, EXISTING_RS_TEST).unwrap();
+
+    let report = test_gen_file(&dir, ScenarioTestKind::Rs, false).unwrap();
+    assert_eq!(
+        report,
+        TestGenFileReport {
+            path: test_path.clone(),
+            created: false,
+            changed: false,
+            missing: vec!["second".to_string()],
+            added: Vec::new(),
+            stale: vec!["scenarios/deleted.scen.json".to_string()],
+        }
+    );
+    assert_eq!(fs::read_to_string(&test_path).unwrap(), EXISTING_RS_TEST);
+
+    let report = test_gen_file(&dir, ScenarioTestKind::Rs, true).unwrap();
+    assert!(report.changed);
+    assert_eq!(report.added, ["second"]);
+    assert!(report.missing.is_empty());
+    let code = fs::read_to_string(&test_path).unwrap();
+    assert_eq!(
+        code,
+        EXISTING_RS_TEST.replace(
+            "#[test]\nfn third_rs()",
+            "#[test]\nfn second_rs() {\n    multiversx_sc_scenario::run_rs(\"scenarios/second.scen.json\", world());\n}\n\n#[test]\nfn third_rs()",
+        )
+    );
+    assert_eq!(
+        fn_names(&code),
+        [
+            "first_rs",
+            "deleted_rs",
+            "future_rs",
+            "second_rs",
+            "third_rs"
+        ]
+    );
+
+    // nothing left to do
+    let report = test_gen_file(&dir, ScenarioTestKind::Rs, true).unwrap();
+    assert!(!report.changed);
+    assert_eq!(fs::read_to_string(&test_path).unwrap(), code);
+}
+
+#[test]
+fn test_gen_finds_harness_by_content_test() {
+    let dir = contract_dir("by_content");
+    fs::create_dir_all(dir.join("tests")).unwrap();
+    // not a harness, even if the name looks like one
+    fs::write(
+        dir.join("tests/my_contract_scenario_go_test.rs"),
+        "#[test]\nfn unit_test() {}\n",
+    )
+    .unwrap();
+    let go_harness = "#[test]\nfn first_go() {\n    multiversx_sc_scenario::run_go(\"scenarios/first.scen.json\");\n}\n";
+    fs::write(dir.join("tests/my_contract_go_test.rs"), go_harness).unwrap();
+    let rs_harness = EXISTING_RS_TEST.replace("run_rs", "mandos_rs");
+    fs::write(dir.join("tests/mandos_rs_test.rs"), &rs_harness).unwrap();
+
+    let report = test_gen_file(&dir, ScenarioTestKind::Go, false).unwrap();
+    assert_eq!(report.path, dir.join("tests/my_contract_go_test.rs"));
+    assert_eq!(report.missing, ["second", "third"]);
+
+    let report = test_gen_file(&dir, ScenarioTestKind::Rs, false).unwrap();
+    assert_eq!(report.path, dir.join("tests/mandos_rs_test.rs"));
+    assert_eq!(report.missing, ["second"]);
+
+    let report = test_gen_file(&dir, ScenarioTestKind::Go, true).unwrap();
+    assert!(!report.created);
+    let code = fs::read_to_string(dir.join("tests/my_contract_go_test.rs")).unwrap();
+    assert!(code.starts_with(go_harness));
+    assert_eq!(fn_names(&code), ["first_go", "second_go", "third_go"]);
+    assert!(!dir.join("tests/my_contract_scenario_rs_test.rs").exists());
+}
+
+#[test]
+fn test_gen_new_files_test() {
+    let dir = contract_dir("new_files");
+
+    let report = test_gen_file(&dir, ScenarioTestKind::Rs, false).unwrap();
+    assert!(!report.created);
+    assert_eq!(report.missing, ["first", "second", "third"]);
+    assert!(!dir.join("tests").exists());
+
+    let report = test_gen_file(&dir, ScenarioTestKind::Rs, true).unwrap();
+    assert!(report.created);
+    assert_eq!(report.added, ["first", "second", "third"]);
+    let code = fs::read_to_string(dir.join("tests/my_contract_scenario_rs_test.rs")).unwrap();
+    assert!(code.contains("blockchain.set_current_dir_from_workspace(\"\");"));
+    assert!(code.contains(
+        "blockchain.register_contract(\"file:output/my-contract.wasm\", my_contract::ContractBuilder);"
+    ));
+    assert_eq!(fn_names(&code), ["first_rs", "second_rs", "third_rs"]);
+
+    let report = test_gen_file(&dir, ScenarioTestKind::Go, true).unwrap();
+    assert!(report.created);
+    let code = fs::read_to_string(dir.join("tests/my_contract_scenario_go_test.rs")).unwrap();
+    assert!(code.starts_with(
+        "#[test]\nfn first_go() {\n    multiversx_sc_scenario::run_go(\"scenarios/first.scen.json\");\n}\n"
+    ));
+
+    fs::remove_dir_all(dir.join("scenarios")).unwrap();
+    assert_eq!(test_gen_file(&dir, ScenarioTestKind::Rs, false), None);
+}
+
+#[test]
+fn test_gen_keeps_repo_harnesses_test() {
+    for (path, kind) in [
+        (
+            "contracts/examples/multisig/tests/multisig_scenario_go_test.rs",
+            ScenarioTestKind::Go,
+        ),
+        (
+            "contracts/feature-tests/alloc-features/tests/alloc_features_scenario_go_test.rs",
+            ScenarioTestKind::Go,
+        ),
+        (
+            "contracts/feature-tests/composability/tests/composability_scenario_rs_test.rs",
+            ScenarioTestKind::Rs,
+        ),
+    ] {
+        let code = fs::read_to_string(format!("../../{path}")).unwrap();
+        assert_eq!(ScenarioTestFile::parse(&code).to_code(kind), code, "{path}");
+    }
+}
@@ -0,0 +1,230 @@
+use multiversx_sc::abi::{
+    ContractAbi, EndpointAbi, EndpointMutabilityAbi, EventAbi, InputAbi, SourceLocationAbi,
+    StorageAbi, StructFieldDescription, TypeAbi, TypeContents, TypeDescription,
+    TypeDescriptionContainer, TypeName,
+};
+use multiversx_sc_meta::meta_validate_abi::{validate_abi_rules, validate_abi_warnings};
+
+fn location(line: u32) -> Option<SourceLocationAbi> {
+    Some(SourceLocationAbi {
+        file: "src/lib.rs",
+        line,
+    })
+}
+
+fn endpoint(name: &'static str, line: u32) -> EndpointAbi {
+    EndpointAbi {
+        name,
+        rust_method_name: name,
+        location: location(line),
+        ..Default::default()
+    }
+}
+
+fn input(arg_name: &'static str, type_name: &str) -> InputAbi {
+    InputAbi {
+        arg_name,
+        type_name: type_name.into(),
+        multi_arg: false,
+    }
+}
+
+fn event(identifier: &'static str, line: u32) -> EventAbi {
+    EventAbi {
+        docs: &[],
+        identifier,
+        inputs: Vec::new(),
+        location: location(line),
+    }
+}
+
+fn error_messages(abi: &ContractAbi) -> Vec<String> {
+    validate_abi_rules(abi)
+        .iter()
+        .map(|error| error.to_string())
+        .collect()
+}
+
+#[test]
+fn validate_abi_ok_test() {
+    let mut get_sum = endpoint("getSum", 10);
+    get_sum.mutability = EndpointMutabilityAbi::Readonly;
+    let mut add = endpoint("add", 20);
+    add.payable_in_tokens = &["EGLD"];
+    add.add_input::<u64>("value");
+
+    let mut abi = ContractAbi::generate_with_endpoints(vec![get_sum, add]);
+    abi.add_type_descriptions::<u64>();
+    abi.events.push(event("added", 30));
+
+    assert!(validate_abi_rules(&abi).is_empty());
+    assert!(validate_abi_warnings(&abi).is_empty());
+}
+
+#[test]
+fn validate_abi_duplicate_endpoints_test() {
+    let mut duplicate = endpoint("getSum", 40);
+    duplicate.rust_method_name = "sum_view";
+    let abi = ContractAbi::generate_with_endpoints(vec![endpoint("getSum", 10), duplicate]);
+
+    assert_eq!(
+        error_messages(&abi),
+        vec!["src/lib.rs:40: endpoint `getSum` (sum_view) is already declared by `getSum` at src/lib.rs:10"]
+    );
+}
+
+#[test]
+fn validate_abi_builtin_function_name_test() {
+    let abi = ContractAbi::generate_with_endpoints(vec![endpoint("ESDTTransfer", 12)]);
+
+    assert_eq!(
+        error_messages(&abi),
+        vec!["src/lib.rs:12: endpoint `ESDTTransfer` has the same name as a built-in function, it can never be called"]
+    );
+}
+
+#[test]
+fn validate_abi_payable_view_test() {
+    let mut view = endpoint("getSum", 15);
+    view.mutability = EndpointMutabilityAbi::Readonly;
+    view.payable_in_tokens = &["*"];
+    let abi = ContractAbi::generate_with_endpoints(vec![view]);
+
+    assert!(validate_abi_rules(&abi).is_empty());
+    let warnings: Vec<String> = validate_abi_warnings(&abi)
+        .iter()
+        .map(|warning| warning.to_string())
+        .collect();
+    assert_eq!(
+        warnings,
+        vec!["src/lib.rs:15: view `getSum` is payable, queries cannot send payments"]
+    );
+}
+
+#[test]
+fn validate_abi_missing_type_test() {
+    let mut add = endpoint("add", 25);
+    add.inputs.push(input("items", "List<Item>"));
+    add.add_input::<u32>("count");
+    let mut abi = ContractAbi::generate_with_endpoints(vec![add]);
+    abi.add_type_descriptions::<u32>();
+
+    assert_eq!(
+        error_messages(&abi),
+        vec!["src/lib.rs:25: type `Item` used in endpoint `add` is missing from the type descriptions"]
+    );
+}
+
+/// Describes its fields, but not the type of its `item` field.
+struct Deposit;
+
+impl TypeAbi for Deposit {
+    fn type_name() -> TypeName {
+        "Deposit".into()
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        accumulator.insert(
+            Self::type_name(),
+            TypeDescription {
+                docs: &[],
+                name: Self::type_name(),
+                contents: TypeContents::Struct(vec![
+                    StructFieldDescription {
+                        docs: &[],
+                        name: "item",
+                        field_type: "Item".into(),
+                    },
+                    StructFieldDescription {
+                        docs: &[],
+                        name: "amount",
+                        field_type: "u64".into(),
+                    },
+                ]),
+            },
+        );
+    }
+}
+
+#[test]
+fn validate_abi_missing_field_type_test() {
+    let mut deposit = endpoint("deposit", 27);
+    deposit.add_input::<Deposit>("deposit");
+    let mut deposit_event = event("deposited", 36);
+    deposit_event.add_input::<Deposit>("deposit", false);
+
+    let mut abi = ContractAbi::generate_with_endpoints(vec![deposit]);
+    abi.events.push(deposit_event);
+    abi.add_type_descriptions::<Deposit>();
+
+    assert_eq!(
+        error_messages(&abi),
+        vec![
+            "src/lib.rs:27: type `Item` used in endpoint `deposit` is missing from the type descriptions",
+            "src/lib.rs:36: type `Item` used in event `deposited` is missing from the type descriptions",
+        ]
+    );
+}
+
+#[test]
+fn validate_abi_multi_value_position_test() {
+    let mut add = endpoint("add", 33);
+    add.inputs.push(input("values", "variadic<u32>"));
+    add.inputs.push(input("memo", "optional<bytes>"));
+    add.inputs.push(input("flag", "bool"));
+    let mut optionals = endpoint("setConfig", 44);
+    optionals.inputs.push(input("first", "optional<u32>"));
+    optionals.inputs.push(input("rest", "variadic<u32>"));
+
+    let mut abi = ContractAbi::generate_with_endpoints(vec![add, optionals]);
+    abi.add_type_descriptions::<u32>();
+    abi.add_type_descriptions::<bool>();
+
+    assert_eq!(
+        error_messages(&abi),
+        vec![
+            "src/lib.rs:33: argument `values` of endpoint `add` is variadic<u32>, so it must be the last argument",
+            "src/lib.rs:33: argument `memo` of endpoint `add` is optional<bytes>, so it must be the last argument",
+        ]
+    );
+}
+
+#[test]
+fn validate_abi_duplicate_events_test() {
+    let mut abi = ContractAbi::default();
+    abi.events.push(event("added", 50));
+    abi.events.push(event("removed", 55));
+    abi.events.push(event("added", 60));
+
+    assert_eq!(
+        error_messages(&abi),
+        vec!["src/lib.rs:60: event `added` is already declared at src/lib.rs:50"]
+    );
+}
+
+#[test]
+fn validate_abi_reserved_storage_keys_test() {
+    let mut abi = ContractAbi::default();
+    for key in [
+        "sum",
+        "__sc:schemaVersion",
+        "__sc:custom",
+        "__schemaVersion",
+    ] {
+        abi.storage.push(StorageAbi {
+            docs: &[],
+            key,
+            mapper: "SingleValueMapper",
+            key_args: Vec::new(),
+            value_types: Vec::new(),
+        });
+    }
+
+    assert_eq!(
+        error_messages(&abi),
+        vec![
+            "storage key `__sc:schemaVersion` is reserved by the framework",
+            "storage key `__sc:custom` is reserved by the framework",
+        ]
+    );
+}
@@ -59,6 +59,17 @@ impl ScenarioWorld {
             .register_contract(expression, contract_builder);
     }
 
+    /// Links a contract path in a test to a contract implementation,
+    /// checking the endpoint budgets declared in its ABI on every call and query.
+    pub fn register_contract_with_budgets<Abi, B>(&mut self, expression: &str, contract_builder: B)
+    where
+        Abi: ContractAbiProvider,
+        B: CallableContractBuilder,
+    {
+        self.blockchain_mock
+            .register_contract_with_budgets::<Abi, B>(expression, contract_builder);
+    }
+
     #[deprecated(
         since = "0.37.0",
         note = "Got renamed to `register_contract`, but not completely removed, in order to ease test migration. Please replace with `register_contract`."
@@ -1,7 +1,8 @@
 use crate::{
+    event_decoder::{DecodedEvent, EventDecoder},
     multiversx_sc::{
         codec::{CodecFrom, TopEncodeMulti},
-        types::Address,
+        types::{Address, ContractError},
     },
     ScenarioWorld,
 };
@@ -52,6 +53,24 @@ impl StepHandler for ScenarioWorld {
     }
 }
 
+impl ScenarioWorld {
+    /// Adds a SC call step, executes it and decodes the logs of the events declared in the ABI,
+    /// so that tests can check the typed event fields.
+    pub fn sc_call_get_events(
+        &mut self,
+        step: ScCallStep,
+        decoder: &EventDecoder,
+    ) -> Vec<DecodedEvent> {
+        self.blockchain_mock
+            .perform_sc_call_get_events(step, decoder)
+    }
+
+    /// Adds a SC call step, executes it and decodes the typed contract error it failed with.
+    pub fn sc_call_get_error<E: ContractError>(&mut self, step: ScCallStep) -> E {
+        self.blockchain_mock.perform_sc_call_get_error(step)
+    }
+}
+
 impl TypedScCallExecutor for ScenarioWorld {
     fn execute_typed_sc_call<OriginalResult, RequestedResult>(
         &mut self,
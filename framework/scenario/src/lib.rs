@@ -10,7 +10,7 @@ use std::path::Path;
 pub use whitebox as testing_framework;
 
 pub use multiversx_chain_vm::{
-    self, bech32, multiversx_sc, num_bigint, scenario_format, ContractInfo, DebugApi,
+    self, bech32, event_decoder, multiversx_sc, num_bigint, scenario_format, ContractInfo, DebugApi,
 };
 
 /// Exposing the scenario model. Might be moved in the future,
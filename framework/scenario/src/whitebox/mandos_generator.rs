@@ -18,6 +18,7 @@ impl MandosGenerator {
                 check_gas: None,
                 comment: None,
                 gas_schedule: None,
+                trace_gas: None,
                 name: None,
                 steps: Vec::new(),
             },
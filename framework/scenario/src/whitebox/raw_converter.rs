@@ -49,6 +49,7 @@ pub(crate) fn account_as_raw(acc: &AccountData) -> AccountRaw {
         code: code_raw,
         comment: None,
         esdt: all_esdt_raw,
+        shard: None,
         nonce: Some(u64_as_raw(acc.nonce)),
         owner: acc.contract_owner.as_ref().map(address_as_raw),
         storage: storage_raw,
@@ -6,12 +6,13 @@ use multiversx_sdk::{
 };
 use std::{collections::HashMap, time::Duration};
 
-use crate::Sender;
+use crate::{Sender, TxPollingPolicy};
 
 pub struct Interactor {
     pub proxy: CommunicationProxy,
     pub network_config: NetworkConfig,
     pub sender_map: HashMap<Address, Sender>,
+    pub polling_policy: TxPollingPolicy,
 
     pub(crate) waiting_time_ms: u64,
 }
@@ -24,20 +25,20 @@ impl Interactor {
             proxy,
             network_config,
             sender_map: HashMap::new(),
+            polling_policy: TxPollingPolicy::default(),
             waiting_time_ms: 0,
         }
     }
 
+    pub fn with_polling_policy(mut self, polling_policy: TxPollingPolicy) -> Self {
+        self.polling_policy = polling_policy;
+        self
+    }
+
     pub fn register_wallet(&mut self, wallet: Wallet) -> Address {
         let address = erdrs_address_to_h256(wallet.address());
-        self.sender_map.insert(
-            address.clone(),
-            Sender {
-                address: address.clone(),
-                wallet,
-                current_nonce: None,
-            },
-        );
+        self.sender_map
+            .insert(address.clone(), Sender::new(address.clone(), wallet));
         address
     }
 
@@ -0,0 +1,134 @@
+use crate::{erdrs_address_to_h256, Interactor};
+use log::info;
+use multiversx_sc_scenario::multiversx_sc::types::Address;
+use multiversx_sdk::data::transaction::Transaction;
+use std::fmt;
+
+/// Maximum number of transactions sent in one request to the gateway.
+const TX_BATCH_SIZE: usize = 100;
+
+/// How many times the transactions rejected by the network are signed and sent again.
+const TX_BATCH_RESEND_ATTEMPTS: usize = 3;
+
+/// Some transactions of a batch were still rejected after all the resend attempts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TxBatchError {
+    /// The hashes of the accepted transactions, in the same order as the transactions, `None` for the rejected ones.
+    pub tx_hashes: Vec<Option<String>>,
+
+    /// Indices of the rejected transactions.
+    pub failed_indices: Vec<usize>,
+
+    /// The released nonces of the senders of rejected transactions that could not be rolled back,
+    /// because transactions with higher nonces were accepted.
+    /// Those transactions are not executed until the gaps are filled.
+    pub nonce_gaps: Vec<(Address, Vec<u64>)>,
+}
+
+impl fmt::Display for TxBatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} transaction(s) still rejected after {TX_BATCH_RESEND_ATTEMPTS} attempts, at indices {:?}",
+            self.failed_indices.len(),
+            self.failed_indices
+        )?;
+        for (address, nonces) in &self.nonce_gaps {
+            write!(f, ", nonce gap {nonces:?} for sender {address:?}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for TxBatchError {}
+
+impl Interactor {
+    /// Signs all transactions using the locally tracked nonces of their senders,
+    /// then sends them in batches, without waiting for any of them to be executed.
+    ///
+    /// Rejected transactions release their nonces and are signed and sent again,
+    /// so the ones accepted after them do not get stuck behind a nonce gap.
+    ///
+    /// Returns the transaction hashes, in the same order as the transactions,
+    /// or the transactions that were still rejected after all attempts.
+    pub async fn send_transactions(
+        &mut self,
+        mut transactions: Vec<Transaction>,
+    ) -> Result<Vec<String>, TxBatchError> {
+        let mut tx_hashes: Vec<Option<String>> = vec![None; transactions.len()];
+        let mut pending: Vec<usize> = (0..transactions.len()).collect();
+
+        for _ in 0..=TX_BATCH_RESEND_ATTEMPTS {
+            for index in &pending {
+                let sender_address = erdrs_address_to_h256(transactions[*index].sender.clone());
+                self.set_nonce_and_sign_tx(&sender_address, &mut transactions[*index])
+                    .await;
+            }
+
+            for chunk in pending.chunks(TX_BATCH_SIZE) {
+                let chunk_txs: Vec<Transaction> = chunk
+                    .iter()
+                    .map(|index| transactions[*index].clone())
+                    .collect();
+                let mut sent = self
+                    .proxy
+                    .send_transactions_by_index(&chunk_txs)
+                    .await
+                    .expect("failed to send transaction batch");
+                for (position, index) in chunk.iter().enumerate() {
+                    tx_hashes[*index] = sent.remove(&position);
+                }
+            }
+
+            let rejected: Vec<usize> = pending
+                .into_iter()
+                .filter(|index| tx_hashes[*index].is_none())
+                .collect();
+            info!(
+                "sent {} transactions, {} rejected",
+                transactions.len(),
+                rejected.len()
+            );
+
+            // released from the last one, so the sender nonces can roll back
+            for index in rejected.iter().rev() {
+                let sender_address = erdrs_address_to_h256(transactions[*index].sender.clone());
+                self.release_nonce(&sender_address, transactions[*index].nonce);
+            }
+
+            pending = rejected;
+            if pending.is_empty() {
+                break;
+            }
+        }
+
+        if pending.is_empty() {
+            return Ok(tx_hashes.into_iter().map(Option::unwrap).collect());
+        }
+
+        let mut nonce_gaps: Vec<(Address, Vec<u64>)> = Vec::new();
+        for index in &pending {
+            let sender_address = erdrs_address_to_h256(transactions[*index].sender.clone());
+            if nonce_gaps
+                .iter()
+                .any(|(address, _)| *address == sender_address)
+            {
+                continue;
+            }
+            let sender_gaps: Vec<u64> = self
+                .sender_mut(&sender_address)
+                .nonce_gaps
+                .iter()
+                .copied()
+                .collect();
+            if !sender_gaps.is_empty() {
+                nonce_gaps.push((sender_address, sender_gaps));
+            }
+        }
+        Err(TxBatchError {
+            tx_hashes,
+            failed_indices: pending,
+            nonce_gaps,
+        })
+    }
+}
@@ -3,9 +3,10 @@ use std::marker::PhantomData;
 use log::info;
 use multiversx_sc_scenario::{
     bech32,
+    event_decoder::{DecodedEvent, EventDecoder},
     multiversx_sc::{
         codec::{PanicErrorHandler, TopDecodeMulti},
-        types::Address,
+        types::{Address, ContractError},
     },
 };
 use multiversx_sdk::data::transaction::{
@@ -13,6 +14,7 @@ use multiversx_sdk::data::transaction::{
 };
 
 const LOG_IDENTIFIER_SC_DEPLOY: &str = "SCDeploy";
+const LOG_IDENTIFIER_SIGNAL_ERROR: &str = "signalError";
 
 pub struct InteractorResult<T: TopDecodeMulti> {
     pub scrs: Vec<ApiSmartContractResult>,
@@ -49,6 +51,66 @@ impl<T: TopDecodeMulti> InteractorResult<T> {
         }
     }
 
+    /// Decodes the logs of all events declared in the ABI, in order.
+    /// Other logs, e.g. of built-in functions, are skipped.
+    ///
+    /// Fails if a log is not valid base64, or if the log of a known event cannot be decoded.
+    pub fn decode_events(&self, decoder: &EventDecoder) -> Result<Vec<DecodedEvent>, String> {
+        let Some(logs) = &self.logs else {
+            return Ok(Vec::new());
+        };
+        let mut decoded_events = Vec::new();
+        for event in &logs.events {
+            let topics = event
+                .topics
+                .iter()
+                .flatten()
+                .map(|topic| {
+                    base64::decode(topic).map_err(|err| format!("invalid base64 log topic: {err}"))
+                })
+                .collect::<Result<Vec<Vec<u8>>, String>>()?;
+            let data = match &event.data {
+                Some(data) => {
+                    base64::decode(data).map_err(|err| format!("invalid base64 log data: {err}"))?
+                },
+                None => Vec::new(),
+            };
+            let decoded_event = decoder
+                .decode_if_known(&topics, &data)
+                .map_err(|err| format!("failed to decode log: {err}"))?;
+            decoded_events.extend(decoded_event);
+        }
+        Ok(decoded_events)
+    }
+
+    /// The first event with the given identifier, decoded based on the ABI.
+    pub fn find_event(
+        &self,
+        decoder: &EventDecoder,
+        identifier: &str,
+    ) -> Result<Option<DecodedEvent>, String> {
+        Ok(self
+            .decode_events(decoder)?
+            .into_iter()
+            .find(|event| event.identifier == identifier))
+    }
+
+    /// The message of the error signalled by the contract, if the transaction failed.
+    ///
+    /// Returns `None` if the message is not valid base64.
+    pub fn error_message(&self) -> Option<Vec<u8>> {
+        let event = self.find_log(LOG_IDENTIFIER_SIGNAL_ERROR)?;
+        let message = event.topics.as_ref()?.get(1)?;
+        base64::decode(message).ok()
+    }
+
+    /// Decodes the error signalled by the contract into a typed contract error.
+    ///
+    /// Returns `None` if the transaction did not fail, or failed with some other error.
+    pub fn decode_error<E: ContractError>(&self) -> Option<E> {
+        E::decode_error_message(&self.error_message()?)
+    }
+
     pub fn new_deployed_address(&self) -> Address {
         let event = self
             .find_log(LOG_IDENTIFIER_SC_DEPLOY)
@@ -88,3 +150,61 @@ fn decode_scr_data_or_panic(data: &str) -> Vec<Vec<u8>> {
         .map(|encoded_arg| hex::decode(encoded_arg).expect("error hex-decoding result"))
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use multiversx_sc_scenario::multiversx_sc::abi::ContractAbi;
+    use multiversx_sdk::data::address::Address as ErdrsAddress;
+
+    fn result_with_log(
+        identifier: &str,
+        topics: &[&str],
+        data: Option<&str>,
+    ) -> InteractorResult<()> {
+        let address = ErdrsAddress::from_bytes([0u8; 32]);
+        InteractorResult {
+            scrs: Vec::new(),
+            logs: Some(ApiLogs {
+                address: address.clone(),
+                events: vec![Events {
+                    address,
+                    identifier: identifier.to_string(),
+                    topics: Some(topics.iter().map(|topic| topic.to_string()).collect()),
+                    data: data.map(str::to_string),
+                }],
+            }),
+            _phantom: PhantomData,
+        }
+    }
+
+    #[test]
+    fn error_message_test() {
+        let result = result_with_log(
+            LOG_IDENTIFIER_SIGNAL_ERROR,
+            &["", &base64::encode("insufficient funds")],
+            None,
+        );
+        assert_eq!(result.error_message(), Some(b"insufficient funds".to_vec()));
+
+        let result = result_with_log(LOG_IDENTIFIER_SIGNAL_ERROR, &["", "not base64!"], None);
+        assert_eq!(result.error_message(), None);
+    }
+
+    #[test]
+    fn decode_events_invalid_base64_test() {
+        let decoder = EventDecoder::from_abi(&ContractAbi::default());
+
+        let result = result_with_log("transfer", &["not base64!"], None);
+        let err = result.decode_events(&decoder).unwrap_err();
+        assert!(err.starts_with("invalid base64 log topic"), "{err}");
+
+        let result = result_with_log(
+            "transfer",
+            &[&base64::encode("transfer")],
+            Some("not base64!"),
+        );
+        let err = result.decode_events(&decoder).unwrap_err();
+        assert!(err.starts_with("invalid base64 log data"), "{err}");
+    }
+}
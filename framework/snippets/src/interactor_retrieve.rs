@@ -1,26 +1,127 @@
 use crate::Interactor;
 use log::info;
 use multiversx_sdk::data::transaction::TransactionOnNetwork;
-use std::time::Duration;
+use std::{collections::BTreeSet, time::Duration};
 
-const TX_GET_RESULTS_NUM_RETRIES: usize = 8;
+const TX_STATUS_SUCCESS: &str = "success";
+const TX_STATUS_EXECUTED: &str = "executed";
+const TX_STATUS_FAIL: &str = "fail";
+const TX_STATUS_INVALID: &str = "invalid";
+
+fn is_final_tx_status(status: &str) -> bool {
+    matches!(
+        status,
+        TX_STATUS_SUCCESS | TX_STATUS_EXECUTED | TX_STATUS_FAIL | TX_STATUS_INVALID
+    )
+}
+
+/// Configures how the interactor waits for transactions to be executed.
+#[derive(Clone, Debug)]
+pub struct TxPollingPolicy {
+    /// Waiting time before the first status check.
+    pub initial_delay: Duration,
+
+    /// Waiting time between status checks.
+    pub poll_interval: Duration,
+
+    /// Gives up if the transactions are not final after this long.
+    pub timeout: Duration,
+
+    /// How many times to retry fetching the results of a final transaction,
+    /// with exponential backoff starting at 1 second.
+    pub result_fetch_retries: usize,
+}
+
+impl Default for TxPollingPolicy {
+    fn default() -> Self {
+        TxPollingPolicy {
+            initial_delay: Duration::from_secs(6),
+            poll_interval: Duration::from_secs(3),
+            timeout: Duration::from_secs(300),
+            result_fetch_retries: 8,
+        }
+    }
+}
 
 impl Interactor {
     pub(crate) async fn retrieve_tx_on_network(&mut self, tx_hash: &str) -> TransactionOnNetwork {
+        self.retrieve_txs_on_network(&[tx_hash.to_string()])
+            .await
+            .pop()
+            .unwrap()
+    }
+
+    /// Polls the status of all the transactions until they are final, then fetches their results.
+    pub(crate) async fn retrieve_txs_on_network(
+        &mut self,
+        tx_hashes: &[String],
+    ) -> Vec<TransactionOnNetwork> {
         self.waiting_time_ms = 0;
-        self.sleep(Duration::from_secs(25)).await;
+        let policy = self.polling_policy.clone();
+        self.sleep(policy.initial_delay).await;
 
-        let mut retries = TX_GET_RESULTS_NUM_RETRIES;
+        let mut pending: BTreeSet<usize> = (0..tx_hashes.len()).collect();
+        loop {
+            let status_requests: Vec<_> = pending
+                .iter()
+                .map(|index| {
+                    let proxy = self.proxy.clone();
+                    let tx_hash = tx_hashes[*index].clone();
+                    (
+                        *index,
+                        tokio::spawn(async move { proxy.get_transaction_status(&tx_hash).await }),
+                    )
+                })
+                .collect();
+            for (index, status_request) in status_requests {
+                match status_request.await.unwrap() {
+                    Ok(status) if is_final_tx_status(&status) => {
+                        pending.remove(&index);
+                    },
+                    Ok(_) => {},
+                    Err(err) => info!(
+                        "tx status fetch error after {} ms: {}",
+                        self.waiting_time_ms, err
+                    ),
+                }
+            }
+
+            if pending.is_empty() {
+                break;
+            }
+            assert!(
+                self.waiting_time_ms < policy.timeout.as_millis() as u64,
+                "{} transaction(s) still not final after {} ms, first one: {}",
+                pending.len(),
+                self.waiting_time_ms,
+                tx_hashes[*pending.first().unwrap()]
+            );
+            self.sleep(policy.poll_interval).await;
+        }
+
+        let mut txs = Vec::with_capacity(tx_hashes.len());
+        for tx_hash in tx_hashes {
+            txs.push(
+                self.fetch_tx_with_results(tx_hash, policy.result_fetch_retries)
+                    .await,
+            );
+        }
+        txs
+    }
+
+    async fn fetch_tx_with_results(
+        &mut self,
+        tx_hash: &str,
+        num_retries: usize,
+    ) -> TransactionOnNetwork {
+        let mut retries = num_retries;
         let mut wait = 1000u64;
         let tx = loop {
             let tx_info_result = self.proxy.get_transaction_info_with_results(tx_hash).await;
             match tx_info_result {
                 Ok(tx) => break tx,
                 Err(err) => {
-                    assert!(
-                        retries > 0,
-                        "still no answer after {TX_GET_RESULTS_NUM_RETRIES} retries"
-                    );
+                    assert!(retries > 0, "still no answer after {num_retries} retries");
 
                     info!(
                         "tx result fetch error after {} ms: {}",
@@ -37,3 +138,89 @@ impl Interactor {
         tx
     }
 }
+
+#[cfg(all(test, feature = "mock-gateway"))]
+mod tests {
+    use super::*;
+    use crate::{mock_gateway_world::compute_tx_hash, MockGateway};
+    use multiversx_sc_scenario::{multiversx_sc::types::Address, scenario_model::*, ScenarioWorld};
+    use multiversx_sdk::{data::transaction::Transaction, wallet::Wallet};
+
+    fn polling_policy(timeout_ms: u64) -> TxPollingPolicy {
+        TxPollingPolicy {
+            initial_delay: Duration::ZERO,
+            poll_interval: Duration::from_millis(10),
+            timeout: Duration::from_millis(timeout_ms),
+            result_fetch_retries: 0,
+        }
+    }
+
+    fn funded_gateway(wallet: &Wallet) -> MockGateway {
+        let address: Address = wallet.address().to_bytes().into();
+        MockGateway::start(move || {
+            let mut world = ScenarioWorld::new();
+            world.set_state_step(SetStateStep::new().put_account(
+                &address,
+                Account::new().balance("1,000,000,000,000,000,000"),
+            ));
+            world
+        })
+    }
+
+    fn test_wallet() -> Wallet {
+        Wallet::from_private_key("413f42575f7f26fad3317a778771212fdb80245850981e48b58a4f25e344e8f9")
+            .unwrap()
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "transaction(s) still not final after")]
+    async fn test_retrieve_txs_timeout() {
+        let gateway = funded_gateway(&test_wallet());
+        let mut interactor = gateway
+            .interactor()
+            .await
+            .with_polling_policy(polling_policy(30));
+        interactor.retrieve_txs_on_network(&["00".repeat(32)]).await;
+    }
+
+    /// Status fetch errors, such as transactions not yet known by the gateway, are retried until the timeout.
+    #[tokio::test]
+    async fn test_retrieve_txs_waits_for_late_tx() {
+        let wallet = test_wallet();
+        let gateway = funded_gateway(&wallet);
+        let mut interactor = gateway
+            .interactor()
+            .await
+            .with_polling_policy(polling_policy(5_000));
+        let sender_address = interactor.register_wallet(wallet);
+
+        let mut transaction = Transaction {
+            nonce: 0,
+            value: "1".to_string(),
+            receiver: crate::address_h256_to_erdrs(&sender_address),
+            sender: crate::address_h256_to_erdrs(&sender_address),
+            gas_price: interactor.network_config.min_gas_price,
+            gas_limit: 50_000,
+            data: None,
+            signature: None,
+            chain_id: interactor.network_config.chain_id.clone(),
+            version: interactor.network_config.min_transaction_version,
+            options: 0,
+        };
+        interactor
+            .set_nonce_and_sign_tx(&sender_address, &mut transaction)
+            .await;
+        let tx_hashes = vec![hex::encode(compute_tx_hash(&transaction))];
+
+        let proxy = interactor.proxy.clone();
+        let (txs, sent_tx_hash) =
+            tokio::join!(interactor.retrieve_txs_on_network(&tx_hashes), async move {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                proxy.send_transaction(&transaction).await.unwrap()
+            });
+        assert_eq!(sent_tx_hash, tx_hashes[0]);
+        assert_eq!(txs.len(), 1);
+        assert_eq!(txs[0].status, TX_STATUS_SUCCESS);
+        assert!(interactor.waiting_time_ms >= 50);
+    }
+}
@@ -1,46 +1,69 @@
-use crate::{address_h256_to_erdrs, mandos_to_erdrs_address, Interactor, InteractorResult};
+use crate::{mandos_to_erdrs_address, Interactor, InteractorResult, TxBatchError};
 use log::info;
 use multiversx_sc_scenario::{
-    multiversx_sc::{
-        codec::{multi_types::IgnoreValue, CodecFrom, TopEncodeMulti},
-        types::ContractCallWithEgld,
-    },
-    scenario_model::{ScCallStep, TransferStep, TxCall, TypedScCall},
-    DebugApi,
+    multiversx_sc::codec::{multi_types::IgnoreValue, CodecFrom, TopEncodeMulti},
+    num_bigint::BigUint,
+    scenario_model::{ScCallStep, TransferStep, TxCall, TxESDT, TypedScCall},
+};
+use multiversx_sdk::{
+    data::transaction::Transaction,
+    token_transfer::{TokenPayment, TokenTransferBuilder},
 };
-use multiversx_sdk::data::transaction::Transaction;
 
-fn contract_call_to_tx_data(contract_call: &ContractCallWithEgld<DebugApi, ()>) -> String {
-    let mut result = String::from_utf8(
-        contract_call
-            .basic
-            .endpoint_name
-            .to_boxed_bytes()
-            .into_vec(),
+fn tx_esdt_to_token_payment(tx_esdt: &TxESDT) -> TokenPayment {
+    let token_identifier = std::str::from_utf8(tx_esdt.esdt_token_identifier.value.as_slice())
+        .expect("token identifier is not valid utf-8");
+    TokenPayment::new(
+        token_identifier,
+        tx_esdt.nonce.value,
+        tx_esdt.esdt_value.value.clone(),
     )
-    .unwrap();
-    for argument in contract_call.basic.arg_buffer.raw_arg_iter() {
-        result.push('@');
-        result.push_str(hex::encode(argument.to_boxed_bytes().as_slice()).as_str());
-    }
-    result
 }
 
 impl Interactor {
+    /// ESDT payments are converted to `ESDTTransfer`, `ESDTNFTTransfer` or `MultiESDTNFTTransfer` calls,
+    /// the last two being sent to the caller itself, with the real receiver in the data field.
     fn tx_call_to_blockchain_tx(&self, tx_call: &TxCall) -> Transaction {
-        let contract_call = tx_call.to_contract_call();
-        let contract_call_tx_data = contract_call_to_tx_data(&contract_call);
-        let data = if contract_call_tx_data.is_empty() {
+        assert!(
+            tx_call.esdt_value.is_empty() || tx_call.egld_value.value == BigUint::default(),
+            "EGLD and ESDT payments cannot be sent in the same transaction"
+        );
+
+        let mut transfer_builder = TokenTransferBuilder::new(
+            &mandos_to_erdrs_address(&tx_call.from),
+            &mandos_to_erdrs_address(&tx_call.to),
+        )
+        .with_payments(
+            tx_call
+                .esdt_value
+                .iter()
+                .map(tx_esdt_to_token_payment)
+                .collect(),
+        );
+        if !tx_call.function.is_empty() {
+            transfer_builder = transfer_builder.with_contract_call(
+                &tx_call.function,
+                tx_call
+                    .arguments
+                    .iter()
+                    .map(|argument| argument.value.clone())
+                    .collect(),
+                0,
+            );
+        }
+
+        let tx_data = transfer_builder.data();
+        let data = if tx_data.is_empty() {
             None
         } else {
-            Some(base64::encode(contract_call_tx_data))
+            Some(base64::encode(tx_data))
         };
 
         Transaction {
             nonce: 0,
-            value: contract_call.egld_payment.to_alloc().to_string(),
-            sender: mandos_to_erdrs_address(&tx_call.from),
-            receiver: address_h256_to_erdrs(&contract_call.basic.to.to_address()),
+            value: tx_call.egld_value.value.to_string(),
+            sender: transfer_builder.sender.clone(),
+            receiver: transfer_builder.tx_receiver(),
             gas_price: self.network_config.min_gas_price,
             gas_limit: tx_call.gas_limit.value,
             data,
@@ -88,18 +111,34 @@ impl Interactor {
         InteractorResult::new(tx)
     }
 
-    pub async fn multiple_sc_calls(&mut self, sc_call_steps: &[ScCallStep]) {
-        let sender_address = &sc_call_steps.get(0).unwrap().tx.from.value;
-        for sc_call_step in sc_call_steps {
-            assert_eq!(
-                &sc_call_step.tx.from.value, sender_address,
-                "all calls are expected to have the same sender"
-            );
-            let mut transaction = self.tx_call_to_blockchain_tx(&sc_call_step.tx);
-            self.set_nonce_and_sign_tx(sender_address, &mut transaction)
-                .await;
-            let _ = self.proxy.send_transaction(&transaction).await.unwrap();
-        }
+    /// Signs and sends all calls in batches, without waiting for them to be executed.
+    ///
+    /// The calls can come from different senders, each of them using its own locally tracked nonces.
+    pub async fn multiple_sc_calls(
+        &mut self,
+        sc_call_steps: &[ScCallStep],
+    ) -> Result<Vec<String>, TxBatchError> {
+        let transactions = sc_call_steps
+            .iter()
+            .map(|sc_call_step| self.tx_call_to_blockchain_tx(&sc_call_step.tx))
+            .collect();
+        let tx_hashes = self.send_transactions(transactions).await?;
+        info!("sc call tx hashes: {:?}", tx_hashes);
+        Ok(tx_hashes)
+    }
+
+    /// Signs and sends all calls in batches, then waits for all of them to be executed.
+    pub async fn multiple_sc_calls_get_raw_results(
+        &mut self,
+        sc_call_steps: &[ScCallStep],
+    ) -> Result<Vec<InteractorResult<IgnoreValue>>, TxBatchError> {
+        let tx_hashes = self.multiple_sc_calls(sc_call_steps).await?;
+        Ok(self
+            .retrieve_txs_on_network(&tx_hashes)
+            .await
+            .into_iter()
+            .map(InteractorResult::new)
+            .collect())
     }
 
     pub async fn transfer(&mut self, transfer_step: TransferStep) -> String {
@@ -4,19 +4,43 @@ use multiversx_sc_scenario::{
     multiversx_sc::codec::{CodecFrom, TopEncodeMulti},
     scenario_model::{ScDeployStep, TypedScDeploy},
 };
-use multiversx_sdk::data::{address::Address as ErdrsAddress, transaction::Transaction};
+use multiversx_sdk::{
+    data::{address::Address as ErdrsAddress, transaction::Transaction},
+    token_transfer::TokenTransferBuilder,
+};
 
 const DEPLOY_RECEIVER: [u8; 32] = [0u8; 32];
+const VM_TYPE: [u8; 2] = [5, 0];
+
 impl Interactor {
+    /// Built like the contract calls, the deploy data being the code, followed by the VM type,
+    /// the code metadata and the init arguments. Deploys can only be paid in EGLD.
     fn sc_deploy_to_tx(&self, sc_deploy_step: &ScDeployStep) -> Transaction {
+        let tx_deploy = &sc_deploy_step.tx;
+        let mut deploy_args = vec![
+            VM_TYPE.to_vec(),
+            tx_deploy.code_metadata.to_byte_array().to_vec(),
+        ];
+        deploy_args.extend(
+            tx_deploy
+                .arguments
+                .iter()
+                .map(|argument| argument.value.clone()),
+        );
+        let transfer_builder = TokenTransferBuilder::new(
+            &mandos_to_erdrs_address(&tx_deploy.from),
+            &ErdrsAddress::from_bytes(DEPLOY_RECEIVER),
+        )
+        .with_contract_call(&hex::encode(&tx_deploy.contract_code.value), deploy_args, 0);
+
         Transaction {
             nonce: 0,
-            value: sc_deploy_step.tx.egld_value.value.to_string(),
-            sender: mandos_to_erdrs_address(&sc_deploy_step.tx.from),
-            receiver: ErdrsAddress::from_bytes(DEPLOY_RECEIVER),
+            value: tx_deploy.egld_value.value.to_string(),
+            sender: transfer_builder.sender.clone(),
+            receiver: transfer_builder.tx_receiver(),
             gas_price: self.network_config.min_gas_price,
-            gas_limit: sc_deploy_step.tx.gas_limit.value,
-            data: Some(base64::encode(sc_deploy_step.tx.to_tx_data())),
+            gas_limit: tx_deploy.gas_limit.value,
+            data: Some(base64::encode(transfer_builder.data())),
             signature: None,
             chain_id: self.network_config.chain_id.clone(),
             version: self.network_config.min_transaction_version,
@@ -1,14 +1,78 @@
 use log::debug;
 use multiversx_sc_scenario::multiversx_sc::types::Address;
 use multiversx_sdk::{data::transaction::Transaction, wallet::Wallet};
+use std::collections::BTreeSet;
 
 use crate::{address_h256_to_erdrs, Interactor};
 
 /// A user account that can sign transactions (a pem is present).
+///
+/// Keeps track of its nonce locally, so that many transactions can be sent
+/// without waiting for the previous ones to be executed.
 pub struct Sender {
     pub address: Address,
     pub wallet: Wallet,
+
+    /// The last nonce used. `None` means the nonce needs to be recalled from the network.
     pub current_nonce: Option<u64>,
+
+    /// Nonces that were used in transactions rejected by the network.
+    /// They are reused first, otherwise all the subsequent transactions would get stuck.
+    pub nonce_gaps: BTreeSet<u64>,
+}
+
+impl Sender {
+    pub fn new(address: Address, wallet: Wallet) -> Self {
+        Sender {
+            address,
+            wallet,
+            current_nonce: None,
+            nonce_gaps: BTreeSet::new(),
+        }
+    }
+
+    /// The nonce to use for the next transaction, if known locally.
+    fn next_nonce(&mut self) -> Option<u64> {
+        if let Some(gap_nonce) = self.nonce_gaps.pop_first() {
+            return Some(gap_nonce);
+        }
+
+        let nonce = self.current_nonce? + 1;
+        self.current_nonce = Some(nonce);
+        Some(nonce)
+    }
+
+    fn set_network_nonce(&mut self, network_nonce: u64) {
+        self.current_nonce = Some(network_nonce);
+        self.nonce_gaps.clear();
+    }
+
+    /// Marks a nonce as unused, because the transaction that used it was rejected.
+    pub fn release_nonce(&mut self, nonce: u64) {
+        if self.current_nonce != Some(nonce) {
+            self.nonce_gaps.insert(nonce);
+            return;
+        }
+
+        // the last nonce was released, roll back over any gaps right before it
+        let mut current_nonce = nonce.checked_sub(1);
+        while let Some(nonce) = current_nonce {
+            if !self.nonce_gaps.remove(&nonce) {
+                break;
+            }
+            current_nonce = nonce.checked_sub(1);
+        }
+        match current_nonce {
+            Some(nonce) => self.current_nonce = Some(nonce),
+            None => self.reset_nonce(),
+        }
+    }
+
+    /// Forgets the local nonce, the next transaction will recall it from the network.
+    pub fn reset_nonce(&mut self) {
+        self.current_nonce = None;
+        self.nonce_gaps.clear();
+    }
 }
 
 impl Interactor {
@@ -22,12 +86,31 @@ impl Interactor {
         account.nonce
     }
 
-    async fn get_sender_nonce(&self, sender: &Sender) -> u64 {
-        if let Some(nonce) = sender.current_nonce {
-            nonce + 1
-        } else {
-            self.recall_nonce(&sender.address).await
+    pub(crate) fn sender_mut(&mut self, sender_address: &Address) -> &mut Sender {
+        self.sender_map
+            .get_mut(sender_address)
+            .expect("the wallet that was supposed to sign is not registered")
+    }
+
+    /// Releases the nonce of a transaction that the network rejected, so that it gets reused.
+    pub fn release_nonce(&mut self, sender_address: &Address, nonce: u64) {
+        self.sender_mut(sender_address).release_nonce(nonce);
+    }
+
+    /// Forgets the local nonce of a sender, e.g. after it sent transactions from somewhere else.
+    pub fn reset_nonce(&mut self, sender_address: &Address) {
+        self.sender_mut(sender_address).reset_nonce();
+    }
+
+    async fn next_sender_nonce(&mut self, sender_address: &Address) -> u64 {
+        if let Some(nonce) = self.sender_mut(sender_address).next_nonce() {
+            return nonce;
         }
+
+        let network_nonce = self.recall_nonce(sender_address).await;
+        self.sender_mut(sender_address)
+            .set_network_nonce(network_nonce);
+        network_nonce
     }
 
     pub(crate) async fn set_nonce_and_sign_tx(
@@ -35,28 +118,61 @@ impl Interactor {
         sender_address: &Address,
         transaction: &mut Transaction,
     ) {
-        // read
-        let sender = self
-            .sender_map
-            .get(sender_address)
-            .expect("the wallet that was supposed to sign is not registered");
-
-        // recall if necessary
-        let nonce = self.get_sender_nonce(sender).await;
-
-        // update
-        let sender = self
-            .sender_map
-            .get_mut(sender_address)
-            .expect("the wallet that was supposed to sign is not registered");
-        sender.current_nonce = Some(nonce);
-
         // set tx nonce
-        transaction.nonce = nonce;
+        transaction.nonce = self.next_sender_nonce(sender_address).await;
 
         // sign
+        let sender = self.sender_mut(sender_address);
         let signature = sender.wallet.sign_tx(transaction);
         transaction.signature = Some(hex::encode(signature));
         debug!("transaction {:#?}", transaction);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_sender() -> Sender {
+        let wallet = Wallet::from_private_key(
+            "413f42575f7f26fad3317a778771212fdb80245850981e48b58a4f25e344e8f9",
+        )
+        .unwrap();
+        Sender::new(Address::zero(), wallet)
+    }
+
+    #[test]
+    fn test_sender_nonce_gap_recovery() {
+        let mut sender = test_sender();
+        assert_eq!(sender.next_nonce(), None);
+
+        sender.set_network_nonce(5);
+        assert_eq!(sender.next_nonce(), Some(6));
+        assert_eq!(sender.next_nonce(), Some(7));
+        assert_eq!(sender.next_nonce(), Some(8));
+
+        // gaps are filled first, in order
+        sender.release_nonce(6);
+        sender.release_nonce(7);
+        assert_eq!(sender.next_nonce(), Some(6));
+        assert_eq!(sender.next_nonce(), Some(7));
+        assert_eq!(sender.next_nonce(), Some(9));
+
+        // releasing the last nonce also rolls back over the gaps before it
+        sender.release_nonce(8);
+        sender.release_nonce(9);
+        assert_eq!(sender.current_nonce, Some(7));
+        assert!(sender.nonce_gaps.is_empty());
+        assert_eq!(sender.next_nonce(), Some(8));
+    }
+
+    #[test]
+    fn test_sender_release_first_nonce() {
+        let mut sender = test_sender();
+        sender.set_network_nonce(0);
+        assert_eq!(sender.next_nonce(), Some(1));
+        sender.release_nonce(1);
+        sender.release_nonce(0);
+        assert_eq!(sender.next_nonce(), None);
+    }
+}
@@ -1,4 +1,5 @@
 mod interactor;
+mod interactor_batch;
 mod interactor_dns;
 mod interactor_result;
 mod interactor_retrieve;
@@ -6,14 +7,22 @@ mod interactor_sc_call;
 mod interactor_sc_deploy;
 mod interactor_sender;
 mod interactor_vm_query;
+#[cfg(feature = "mock-gateway")]
+mod mock_gateway;
+#[cfg(feature = "mock-gateway")]
+mod mock_gateway_world;
 
 pub use env_logger;
 pub use hex;
 pub use interactor::*;
+pub use interactor_batch::*;
 pub use interactor_dns::*;
 pub use interactor_result::*;
+pub use interactor_retrieve::*;
 pub use interactor_sender::*;
 pub use log;
+#[cfg(feature = "mock-gateway")]
+pub use mock_gateway::*;
 pub use multiversx_sc_scenario::{self, multiversx_sc};
 pub use multiversx_sdk as erdrs;
 pub use tokio;
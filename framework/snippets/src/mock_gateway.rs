@@ -0,0 +1,202 @@
+use std::{
+    convert::Infallible,
+    net::SocketAddr,
+    panic::{self, AssertUnwindSafe},
+    sync::mpsc as std_mpsc,
+    thread,
+    time::Duration,
+};
+
+use hyper::{
+    header::CONTENT_TYPE,
+    service::{make_service_fn, service_fn},
+    Body, Request, Response, Server,
+};
+use multiversx_sc_scenario::{scenario_format::interpret_trait::InterpreterContext, ScenarioWorld};
+use tokio::sync::{mpsc, oneshot};
+
+use crate::{
+    mock_gateway_world::{GatewayRequest, GatewayResponse, MockGatewayWorld},
+    Interactor, TxPollingPolicy,
+};
+
+pub use crate::mock_gateway_world::MOCK_GATEWAY_CHAIN_ID;
+
+type WorldClosure = Box<dyn FnOnce(&mut ScenarioWorld) + Send>;
+
+enum GatewayJob {
+    Request(GatewayRequest, oneshot::Sender<GatewayResponse>),
+    WithWorld(WorldClosure),
+}
+
+/// A local stand-in for the gateway, backed by the Rust VM,
+/// which allows running interactors offline, e.g. in `cargo test`.
+///
+/// It serves the network config, accounts, ESDT balances, transaction sending,
+/// transactions with results and VM queries.
+/// Signed transactions are checked and executed right away on the contracts registered in the world.
+///
+/// The world is not thread-safe, so it lives on a dedicated thread,
+/// to which the HTTP server forwards all requests.
+/// The server itself runs on the current tokio runtime.
+pub struct MockGateway {
+    url: String,
+    interpreter_context: InterpreterContext,
+    job_sender: mpsc::UnboundedSender<GatewayJob>,
+    shutdown_sender: Option<oneshot::Sender<()>>,
+}
+
+impl MockGateway {
+    /// Starts the server on a random local port.
+    ///
+    /// The world builder runs on the world thread. It should register the contracts
+    /// and set up the accounts of the wallets that will sign transactions, with enough EGLD to pay for gas.
+    /// New contract addresses can be set explicitly, otherwise they are derived like on the real network.
+    ///
+    /// Needs to be called from within a tokio runtime.
+    pub fn start<F>(world_builder: F) -> Self
+    where
+        F: FnOnce() -> ScenarioWorld + Send + 'static,
+    {
+        let (job_sender, job_receiver) = mpsc::unbounded_channel();
+        let (context_sender, context_receiver) = std_mpsc::channel();
+        thread::Builder::new()
+            .name("mock-gateway-world".to_string())
+            .spawn(move || run_world(world_builder, context_sender, job_receiver))
+            .expect("failed to start mock gateway world thread");
+        let interpreter_context = context_receiver
+            .recv()
+            .expect("mock gateway world builder panicked");
+
+        let server_job_sender = job_sender.clone();
+        let make_service = make_service_fn(move |_| {
+            let job_sender = server_job_sender.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request| {
+                    forward_request(request, job_sender.clone())
+                }))
+            }
+        });
+        let server = Server::try_bind(&SocketAddr::from(([127, 0, 0, 1], 0)))
+            .expect("failed to bind mock gateway")
+            .serve(make_service);
+        let url = format!("http://{}", server.local_addr());
+
+        let (shutdown_sender, shutdown_receiver) = oneshot::channel::<()>();
+        tokio::spawn(server.with_graceful_shutdown(async {
+            let _ = shutdown_receiver.await;
+        }));
+
+        MockGateway {
+            url,
+            interpreter_context,
+            job_sender,
+            shutdown_sender: Some(shutdown_sender),
+        }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Context of the world, contract code expressions in deploys need to be interpreted with it,
+    /// so that they resolve to the same contracts that were registered.
+    pub fn interpreter_context(&self) -> &InterpreterContext {
+        &self.interpreter_context
+    }
+
+    /// An interactor connected to the mock gateway, which does not wait for transactions,
+    /// since they are executed as soon as they are sent.
+    pub async fn interactor(&self) -> Interactor {
+        Interactor::new(&self.url)
+            .await
+            .with_polling_policy(TxPollingPolicy {
+                initial_delay: Duration::ZERO,
+                poll_interval: Duration::ZERO,
+                timeout: Duration::ZERO,
+                result_fetch_retries: 0,
+            })
+    }
+
+    /// Runs a closure on the world, e.g. to add accounts or to check the state after some transactions.
+    ///
+    /// Panics in the closure are propagated to the caller.
+    pub fn with_world<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&mut ScenarioWorld) -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let (result_sender, result_receiver) = std_mpsc::channel();
+        self.job_sender
+            .send(GatewayJob::WithWorld(Box::new(move |world| {
+                let result = panic::catch_unwind(AssertUnwindSafe(|| f(world)));
+                let _ = result_sender.send(result);
+            })))
+            .unwrap_or_else(|_| panic!("mock gateway world stopped"));
+
+        match result_receiver.recv().expect("mock gateway world stopped") {
+            Ok(result) => result,
+            Err(panic_payload) => panic::resume_unwind(panic_payload),
+        }
+    }
+}
+
+impl Drop for MockGateway {
+    fn drop(&mut self) {
+        if let Some(shutdown_sender) = self.shutdown_sender.take() {
+            let _ = shutdown_sender.send(());
+        }
+    }
+}
+
+/// The world thread stops once the server and the gateway handle are both gone.
+fn run_world<F>(
+    world_builder: F,
+    context_sender: std_mpsc::Sender<InterpreterContext>,
+    mut job_receiver: mpsc::UnboundedReceiver<GatewayJob>,
+) where
+    F: FnOnce() -> ScenarioWorld,
+{
+    let mut gateway_world = MockGatewayWorld::new(world_builder());
+    let _ = context_sender.send(gateway_world.world.interpreter_context());
+
+    while let Some(job) = job_receiver.blocking_recv() {
+        match job {
+            GatewayJob::Request(request, response_sender) => {
+                let _ = response_sender.send(gateway_world.handle_request(request));
+            },
+            GatewayJob::WithWorld(f) => f(&mut gateway_world.world),
+        }
+    }
+}
+
+async fn forward_request(
+    request: Request<Body>,
+    job_sender: mpsc::UnboundedSender<GatewayJob>,
+) -> Result<Response<Body>, Infallible> {
+    let method = request.method().clone();
+    let path = request.uri().path().to_string();
+    let body = hyper::body::to_bytes(request.into_body())
+        .await
+        .map(|bytes| bytes.to_vec())
+        .unwrap_or_default();
+
+    let (response_sender, response_receiver) = oneshot::channel();
+    let _ = job_sender.send(GatewayJob::Request(
+        GatewayRequest { method, path, body },
+        response_sender,
+    ));
+    let response = match response_receiver.await {
+        Ok(response) => Response::builder()
+            .status(response.status)
+            .body(Body::from(response.body)),
+        Err(_) => Response::builder()
+            .status(hyper::StatusCode::INTERNAL_SERVER_ERROR)
+            .body(Body::from("mock gateway world stopped")),
+    };
+    let mut response = response.unwrap();
+    response
+        .headers_mut()
+        .insert(CONTENT_TYPE, "application/json".parse().unwrap());
+    Ok(response)
+}
@@ -0,0 +1,638 @@
+use std::{collections::HashMap, str::FromStr};
+
+use hyper::{Method, StatusCode};
+use multiversx_sc_scenario::{
+    multiversx_chain_vm::{
+        tx_execution::{execute_sc_query, sc_call_with_async_and_callback, sc_create},
+        tx_mock::{TxFunctionName, TxInput, TxResult},
+    },
+    multiversx_sc::types::Address,
+    num_bigint::BigUint,
+    ScenarioWorld,
+};
+use multiversx_sdk::{
+    crypto::public_key::PublicKey,
+    data::{
+        account::Account,
+        address::Address as ErdrsAddress,
+        esdt::EsdtBalance,
+        network_config::NetworkConfig,
+        transaction::{ApiLogs, ApiSmartContractResult, Events, Transaction, TransactionOnNetwork},
+        vm::{CallType, VMOutputApi, VmValueRequest},
+    },
+};
+use serde::Serialize;
+use serde_json::json;
+use sha3::{Digest, Keccak256};
+
+use crate::{address_h256_to_erdrs, erdrs_address_to_h256};
+
+pub const MOCK_GATEWAY_CHAIN_ID: &str = "mock";
+
+const TX_STATUS_SUCCESS: &str = "success";
+const TX_STATUS_FAIL: &str = "fail";
+const LOG_IDENTIFIER_SC_DEPLOY: &str = "SCDeploy";
+const LOG_IDENTIFIER_SIGNAL_ERROR: &str = "signalError";
+const RESULT_CODE_OK: &str = "6f6b";
+const VM_TYPE_WASM: [u8; 2] = [5, 0];
+
+/// A request received by the mock gateway, already read from the connection.
+pub(crate) struct GatewayRequest {
+    pub method: Method,
+    pub path: String,
+    pub body: Vec<u8>,
+}
+
+/// JSON response, wrapped in the same `data`/`error`/`code` envelope the real gateway uses.
+pub(crate) struct GatewayResponse {
+    pub status: StatusCode,
+    pub body: String,
+}
+
+impl GatewayResponse {
+    fn ok<T: Serialize>(data: T) -> Self {
+        GatewayResponse {
+            status: StatusCode::OK,
+            body: json!({ "data": data, "error": "", "code": "successful" }).to_string(),
+        }
+    }
+
+    fn error(status: StatusCode, message: &str) -> Self {
+        let code = if status.is_client_error() {
+            "bad_request"
+        } else {
+            "internal_issue"
+        };
+        GatewayResponse {
+            status,
+            body: json!({ "data": null, "error": message, "code": code }).to_string(),
+        }
+    }
+
+    fn bad_request(message: &str) -> Self {
+        Self::error(StatusCode::BAD_REQUEST, message)
+    }
+}
+
+/// The blockchain behind the mock gateway.
+///
+/// Transactions are executed on the Rust VM as soon as they are received,
+/// so they are already final by the time their hashes are returned.
+pub(crate) struct MockGatewayWorld {
+    pub world: ScenarioWorld,
+    network_config: NetworkConfig,
+    transactions: HashMap<String, TransactionOnNetwork>,
+    block_nonce: u64,
+}
+
+impl MockGatewayWorld {
+    pub fn new(world: ScenarioWorld) -> Self {
+        MockGatewayWorld {
+            world,
+            network_config: mock_network_config(),
+            transactions: HashMap::new(),
+            block_nonce: 0,
+        }
+    }
+
+    pub fn handle_request(&mut self, request: GatewayRequest) -> GatewayResponse {
+        let path = request.path.trim_start_matches('/');
+        let segments: Vec<&str> = path.split('/').collect();
+        match (&request.method, segments.as_slice()) {
+            (&Method::GET, ["network", "config"]) => {
+                GatewayResponse::ok(json!({ "config": self.network_config }))
+            },
+            (&Method::GET, ["address", address]) => self.get_account(address),
+            (&Method::GET, ["address", address, "esdt"]) => self.get_account_esdt_tokens(address),
+            (&Method::POST, ["transaction", "send"]) => self.send_transaction(&request.body),
+            (&Method::POST, ["transaction", "send-multiple"]) => {
+                self.send_transactions(&request.body)
+            },
+            (&Method::GET, ["transaction", tx_hash]) => self.get_transaction(tx_hash),
+            (&Method::GET, ["transaction", tx_hash, "status"]) => {
+                self.get_transaction_status(tx_hash)
+            },
+            (&Method::POST, ["vm-values", "query"]) => self.vm_query(&request.body),
+            _ => GatewayResponse::error(
+                StatusCode::NOT_FOUND,
+                &format!("unsupported mock gateway route: {} /{path}", request.method),
+            ),
+        }
+    }
+
+    fn get_account(&self, bech32_address: &str) -> GatewayResponse {
+        let erdrs_address = match parse_bech32_address(bech32_address) {
+            Ok(erdrs_address) => erdrs_address,
+            Err(message) => return GatewayResponse::bad_request(&message),
+        };
+
+        // like on the real network, missing accounts are reported as empty
+        let account = match self
+            .world
+            .blockchain_mock
+            .accounts
+            .get(&erdrs_address_to_h256(erdrs_address.clone()))
+        {
+            Some(account_data) => Account {
+                address: erdrs_address,
+                nonce: account_data.nonce,
+                balance: account_data.egld_balance.to_string(),
+                code: account_data
+                    .contract_path
+                    .as_ref()
+                    .map(hex::encode)
+                    .unwrap_or_default(),
+                code_hash: None,
+                root_hash: None,
+            },
+            None => Account {
+                address: erdrs_address,
+                nonce: 0,
+                balance: "0".to_string(),
+                code: String::new(),
+                code_hash: None,
+                root_hash: None,
+            },
+        };
+        GatewayResponse::ok(json!({ "account": account }))
+    }
+
+    fn get_account_esdt_tokens(&self, bech32_address: &str) -> GatewayResponse {
+        let erdrs_address = match parse_bech32_address(bech32_address) {
+            Ok(erdrs_address) => erdrs_address,
+            Err(message) => return GatewayResponse::bad_request(&message),
+        };
+
+        let mut esdts = HashMap::new();
+        if let Some(account_data) = self
+            .world
+            .blockchain_mock
+            .accounts
+            .get(&erdrs_address_to_h256(erdrs_address))
+        {
+            for (token_identifier, esdt_data) in account_data.esdt.iter() {
+                let token_identifier = String::from_utf8_lossy(token_identifier);
+                for (nonce, instance) in esdt_data.instances.get_instances() {
+                    if instance.balance == BigUint::default() {
+                        continue;
+                    }
+
+                    // NFT and SFT instances are listed separately, with the nonce in the identifier
+                    let instance_identifier = if *nonce == 0 {
+                        token_identifier.to_string()
+                    } else {
+                        format!("{token_identifier}-{}", hex_nonce(*nonce))
+                    };
+                    esdts.insert(
+                        instance_identifier.clone(),
+                        EsdtBalance {
+                            token_identifier: instance_identifier,
+                            balance: instance.balance.to_string(),
+                        },
+                    );
+                }
+            }
+        }
+        GatewayResponse::ok(json!({ "esdts": esdts }))
+    }
+
+    fn send_transaction(&mut self, body: &[u8]) -> GatewayResponse {
+        let tx: Transaction = match serde_json::from_slice(body) {
+            Ok(tx) => tx,
+            Err(err) => {
+                return GatewayResponse::bad_request(&format!("invalid transaction: {err}"))
+            },
+        };
+
+        match self.process_transaction(tx) {
+            Ok(tx_hash) => GatewayResponse::ok(json!({ "txHash": tx_hash })),
+            Err(message) => GatewayResponse::bad_request(&message),
+        }
+    }
+
+    /// Rejected transactions are simply left out of the returned hashes, like on the real network.
+    fn send_transactions(&mut self, body: &[u8]) -> GatewayResponse {
+        let txs: Vec<Transaction> = match serde_json::from_slice(body) {
+            Ok(txs) => txs,
+            Err(err) => {
+                return GatewayResponse::bad_request(&format!("invalid transactions: {err}"))
+            },
+        };
+
+        let mut txs_hashes = HashMap::new();
+        for (index, tx) in txs.into_iter().enumerate() {
+            if let Ok(tx_hash) = self.process_transaction(tx) {
+                txs_hashes.insert(index, tx_hash);
+            }
+        }
+        GatewayResponse::ok(json!({
+            "numOfSentTxs": txs_hashes.len(),
+            "txsHashes": txs_hashes,
+        }))
+    }
+
+    fn get_transaction(&self, tx_hash: &str) -> GatewayResponse {
+        match self.transactions.get(tx_hash) {
+            Some(tx) => GatewayResponse::ok(json!({ "transaction": tx })),
+            None => GatewayResponse::error(
+                StatusCode::NOT_FOUND,
+                &format!("transaction not found: {tx_hash}"),
+            ),
+        }
+    }
+
+    fn get_transaction_status(&self, tx_hash: &str) -> GatewayResponse {
+        match self.transactions.get(tx_hash) {
+            Some(tx) => GatewayResponse::ok(json!({ "status": tx.status })),
+            None => GatewayResponse::error(
+                StatusCode::NOT_FOUND,
+                &format!("transaction not found: {tx_hash}"),
+            ),
+        }
+    }
+
+    fn vm_query(&mut self, body: &[u8]) -> GatewayResponse {
+        let request: VmValueRequest = match serde_json::from_slice(body) {
+            Ok(request) => request,
+            Err(err) => return GatewayResponse::bad_request(&format!("invalid query: {err}")),
+        };
+        let args: Result<Vec<Vec<u8>>, _> = request.args.iter().map(hex::decode).collect();
+        let (Ok(args), Ok(egld_value)) = (args, parse_biguint(&request.value)) else {
+            return GatewayResponse::bad_request("invalid query arguments");
+        };
+
+        let tx_input = TxInput {
+            from: erdrs_address_to_h256(request.caller),
+            to: erdrs_address_to_h256(request.sc_address),
+            egld_value,
+            func_name: request.func_name.into(),
+            args,
+            gas_limit: u64::MAX,
+            gas_price: 0,
+            ..Default::default()
+        };
+        let tx_result = self
+            .world
+            .blockchain_mock
+            .with_borrowed(|state| execute_sc_query(tx_input, state));
+        if tx_result.result_status != 0 {
+            return GatewayResponse::error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                &format!("executeQuery: {}", tx_result.result_message),
+            );
+        }
+
+        let vm_output = VMOutputApi {
+            return_data: tx_result.result_values.iter().map(base64::encode).collect(),
+            return_code: "ok".to_string(),
+            return_message: String::new(),
+            gas_remaining: 0,
+            gas_refund: 0,
+            output_accounts: HashMap::new(),
+            deleted_accounts: None,
+            touched_accounts: None,
+            logs: None,
+        };
+        GatewayResponse::ok(json!({ "data": vm_output }))
+    }
+
+    /// Validates a transaction the way the network would before accepting it, then executes it.
+    fn process_transaction(&mut self, tx: Transaction) -> Result<String, String> {
+        if tx.chain_id != self.network_config.chain_id {
+            return Err(format!("invalid chain ID: {}", tx.chain_id));
+        }
+        if !verify_tx_signature(&tx) {
+            return Err("invalid transaction signature".to_string());
+        }
+
+        let sender = erdrs_address_to_h256(tx.sender.clone());
+        let Some(sender_account) = self.world.blockchain_mock.accounts.get(&sender) else {
+            return Err("sender account not found".to_string());
+        };
+        if tx.nonce != sender_account.nonce {
+            return Err(format!(
+                "invalid transaction nonce: expected {}, got {}",
+                sender_account.nonce, tx.nonce
+            ));
+        }
+        let egld_value = parse_biguint(&tx.value)?;
+        // the VM charges the whole gas limit when executing the transaction, even if it fails,
+        // since it does not meter the gas actually used
+        let tx_cost = BigUint::from(tx.gas_limit) * BigUint::from(tx.gas_price) + &egld_value;
+        if sender_account.egld_balance < tx_cost {
+            return Err("insufficient funds".to_string());
+        }
+
+        let data = match &tx.data {
+            Some(encoded_data) => base64::decode(encoded_data)
+                .ok()
+                .and_then(|data| String::from_utf8(data).ok())
+                .ok_or_else(|| "invalid transaction data".to_string())?,
+            None => String::new(),
+        };
+        let mut data_parts = data.split('@');
+        let first_part = data_parts.next().unwrap_or_default().to_string();
+        let args = data_parts
+            .map(hex::decode)
+            .collect::<Result<Vec<Vec<u8>>, _>>()
+            .map_err(|_| "invalid transaction data arguments".to_string())?;
+
+        let tx_hash_bytes = compute_tx_hash(&tx);
+        let tx_hash = hex::encode(tx_hash_bytes);
+        let tx_input = TxInput {
+            from: sender.clone(),
+            to: erdrs_address_to_h256(tx.receiver.clone()),
+            egld_value,
+            func_name: TxFunctionName::EMPTY,
+            args: Vec::new(),
+            gas_limit: tx.gas_limit,
+            gas_price: tx.gas_price,
+            tx_hash: tx_hash_bytes.into(),
+            ..Default::default()
+        };
+
+        let tx_on_network = if tx_input.to == Address::zero() {
+            self.execute_deploy(&tx, tx_hash.clone(), tx_input, first_part, args)?
+        } else {
+            let tx_input = TxInput {
+                func_name: first_part.into(),
+                args,
+                ..tx_input
+            };
+            self.execute_call(&tx, tx_hash.clone(), tx_input)
+        };
+        self.transactions.insert(tx_hash.clone(), tx_on_network);
+        Ok(tx_hash)
+    }
+
+    fn execute_deploy(
+        &mut self,
+        tx: &Transaction,
+        tx_hash: String,
+        mut tx_input: TxInput,
+        code_hex: String,
+        mut args: Vec<Vec<u8>>,
+    ) -> Result<TransactionOnNetwork, String> {
+        let code = hex::decode(code_hex).map_err(|_| "invalid contract code".to_string())?;
+        if !self
+            .world
+            .blockchain_mock
+            .contract_map
+            .contains_contract(&code)
+        {
+            return Err(
+                "unknown contract code, it needs to be registered in the mock world".to_string(),
+            );
+        }
+        if args.len() < 2 {
+            return Err("missing VM type or code metadata".to_string());
+        }
+        // VM type and code metadata
+        tx_input.args = args.split_off(2);
+
+        // explicit new addresses from the world setup take precedence
+        let blockchain_mock = &mut self.world.blockchain_mock;
+        if blockchain_mock
+            .get_new_address(tx_input.from.clone(), tx.nonce)
+            .is_none()
+        {
+            let new_address = compute_new_address(&tx_input.from, tx.nonce);
+            blockchain_mock.put_new_address(tx_input.from.clone(), tx.nonce, new_address);
+        }
+
+        let (tx_result, new_address) = blockchain_mock.with_borrowed(|state| {
+            let (tx_result, new_address, state) = sc_create(tx_input, &code, state);
+            ((tx_result, new_address), state)
+        });
+
+        let mut tx_on_network = self.tx_on_network(tx, tx_hash, &tx_result, &new_address);
+        if tx_result.result_status == 0 {
+            let deploy_event = Events {
+                address: address_h256_to_erdrs(&new_address),
+                identifier: LOG_IDENTIFIER_SC_DEPLOY.to_string(),
+                topics: Some(vec![
+                    base64::encode(new_address.as_bytes()),
+                    base64::encode(tx.sender.to_bytes()),
+                ]),
+                data: None,
+            };
+            let logs = tx_on_network.logs.get_or_insert_with(|| ApiLogs {
+                address: address_h256_to_erdrs(&new_address),
+                events: Vec::new(),
+            });
+            logs.events.insert(0, deploy_event);
+        }
+        Ok(tx_on_network)
+    }
+
+    fn execute_call(
+        &mut self,
+        tx: &Transaction,
+        tx_hash: String,
+        tx_input: TxInput,
+    ) -> TransactionOnNetwork {
+        let receiver = tx_input.to.clone();
+        let blockchain_mock = &mut self.world.blockchain_mock;
+
+        // nonce gets increased irrespective of whether the tx fails or not
+        blockchain_mock.increase_account_nonce(&tx_input.from);
+        let tx_result =
+            blockchain_mock.with_borrowed(|state| sc_call_with_async_and_callback(tx_input, state));
+
+        self.tx_on_network(tx, tx_hash, &tx_result, &receiver)
+    }
+
+    fn tx_on_network(
+        &mut self,
+        tx: &Transaction,
+        tx_hash: String,
+        tx_result: &TxResult,
+        contract_address: &Address,
+    ) -> TransactionOnNetwork {
+        self.block_nonce += 1;
+        let block_hash = hex::encode(Keccak256::digest(self.block_nonce.to_be_bytes()));
+        let contract_address = address_h256_to_erdrs(contract_address);
+
+        let mut smart_contract_results = Vec::new();
+        let mut events: Vec<Events> = tx_result
+            .result_logs
+            .iter()
+            .map(|tx_log| Events {
+                address: address_h256_to_erdrs(&tx_log.address),
+                identifier: tx_log.endpoint.to_string(),
+                topics: Some(tx_log.topics.iter().map(base64::encode).collect()),
+                data: Some(base64::encode(&tx_log.data)),
+            })
+            .collect();
+
+        let status = if tx_result.result_status == 0 {
+            // simple transfers produce no results
+            if tx.data.is_some() {
+                let mut scr_data = format!("@{RESULT_CODE_OK}");
+                for result_value in &tx_result.result_values {
+                    scr_data.push('@');
+                    scr_data.push_str(&hex::encode(result_value));
+                }
+                smart_contract_results.push(self.scr(tx, &tx_hash, &contract_address, scr_data));
+            }
+            TX_STATUS_SUCCESS
+        } else {
+            let scr_data = format!(
+                "@{}@{}",
+                hex::encode([tx_result.result_status as u8]),
+                hex::encode(&tx_result.result_message)
+            );
+            let mut scr = self.scr(tx, &tx_hash, &contract_address, scr_data);
+            scr.return_message = Some(tx_result.result_message.clone());
+            smart_contract_results.push(scr);
+            events.push(Events {
+                address: contract_address.clone(),
+                identifier: LOG_IDENTIFIER_SIGNAL_ERROR.to_string(),
+                topics: Some(vec![
+                    base64::encode(tx.sender.to_bytes()),
+                    base64::encode(&tx_result.result_message),
+                ]),
+                data: None,
+            });
+            TX_STATUS_FAIL
+        };
+
+        TransactionOnNetwork {
+            kind: "normal".to_string(),
+            hash: Some(tx_hash),
+            nonce: tx.nonce,
+            round: self.block_nonce,
+            epoch: 0,
+            value: tx.value.clone(),
+            receiver: tx.receiver.clone(),
+            sender: tx.sender.clone(),
+            gas_price: tx.gas_price,
+            gas_limit: tx.gas_limit,
+            signature: tx.signature.clone().unwrap_or_default(),
+            source_shard: 0,
+            destination_shard: 0,
+            block_nonce: self.block_nonce,
+            block_hash: block_hash.clone(),
+            notarized_at_source_in_meta_nonce: self.block_nonce,
+            notarized_at_source_in_meta_hash: block_hash.clone(),
+            notarized_at_destination_in_meta_nonce: self.block_nonce,
+            notarized_at_destination_in_meta_hash: block_hash.clone(),
+            miniblock_type: "TxBlock".to_string(),
+            miniblock_hash: block_hash.clone(),
+            timestamp: 0,
+            data: tx.data.clone(),
+            status: status.to_string(),
+            hyperblock_nonce: self.block_nonce,
+            hyperblock_hash: block_hash,
+            smart_contract_results: Some(smart_contract_results),
+            logs: if events.is_empty() {
+                None
+            } else {
+                Some(ApiLogs {
+                    address: contract_address,
+                    events,
+                })
+            },
+        }
+    }
+
+    fn scr(
+        &self,
+        tx: &Transaction,
+        tx_hash: &str,
+        contract_address: &ErdrsAddress,
+        data: String,
+    ) -> ApiSmartContractResult {
+        ApiSmartContractResult {
+            hash: hex::encode(Keccak256::digest(format!("{tx_hash}{data}"))),
+            nonce: tx.nonce + 1,
+            value: 0,
+            receiver: tx.sender.clone(),
+            sender: contract_address.clone(),
+            data,
+            prev_tx_hash: tx_hash.to_string(),
+            original_tx_hash: tx_hash.to_string(),
+            gas_limit: 0,
+            gas_price: tx.gas_price,
+            call_type: CallType::DirectCall,
+            relayer_address: None,
+            relayed_value: None,
+            code: None,
+            code_metadata: None,
+            return_message: None,
+            original_sender: None,
+        }
+    }
+}
+
+fn mock_network_config() -> NetworkConfig {
+    NetworkConfig {
+        chain_id: MOCK_GATEWAY_CHAIN_ID.to_string(),
+        denomination: 18,
+        gas_per_data_byte: 1_500,
+        latest_tag_software_version: "mock".to_string(),
+        meta_consensus_group_size: 1,
+        min_gas_limit: 50_000,
+        min_gas_price: 1_000_000_000,
+        min_transaction_version: 1,
+        num_metachain_nodes: 1,
+        num_nodes_in_shard: 1,
+        num_shards_without_meta: 1,
+        round_duration: 6_000,
+        shard_consensus_group_size: 1,
+        start_time: 0,
+    }
+}
+
+fn parse_bech32_address(bech32_address: &str) -> Result<ErdrsAddress, String> {
+    ErdrsAddress::from_bech32_string(bech32_address)
+        .map_err(|_| format!("invalid address: {bech32_address}"))
+}
+
+fn parse_biguint(value: &str) -> Result<BigUint, String> {
+    if value.is_empty() {
+        return Ok(BigUint::default());
+    }
+    BigUint::from_str(value).map_err(|_| format!("invalid value: {value}"))
+}
+
+/// Top-encoded, as it appears in NFT identifiers.
+fn hex_nonce(nonce: u64) -> String {
+    let bytes = nonce.to_be_bytes();
+    let first_non_zero = bytes.iter().position(|b| *b != 0).unwrap_or(bytes.len());
+    hex::encode(&bytes[first_non_zero..])
+}
+
+/// Same message as the one produced by `Wallet::sign_tx`.
+fn verify_tx_signature(tx: &Transaction) -> bool {
+    let Some(signature) = tx.signature.as_ref().and_then(|s| hex::decode(s).ok()) else {
+        return false;
+    };
+
+    let mut unsigned_tx = tx.clone();
+    unsigned_tx.signature = None;
+    let mut tx_bytes = json!(unsigned_tx).to_string().into_bytes();
+    if unsigned_tx.version >= 2 && unsigned_tx.options & 1 > 0 {
+        tx_bytes = Keccak256::digest(&tx_bytes).to_vec();
+    }
+    PublicKey::from(&tx.sender).verify(&tx_bytes, &signature)
+}
+
+pub(crate) fn compute_tx_hash(tx: &Transaction) -> [u8; 32] {
+    Keccak256::digest(json!(tx).to_string()).into()
+}
+
+/// Same derivation as the protocol: 8 zero bytes, the VM type,
+/// part of the hash of the creator address and nonce, then the creator shard bytes.
+fn compute_new_address(creator: &Address, creator_nonce: u64) -> Address {
+    let mut hasher = Keccak256::new();
+    hasher.update(creator.as_bytes());
+    hasher.update(creator_nonce.to_le_bytes());
+    let hash = hasher.finalize();
+
+    let mut new_address = [0u8; 32];
+    new_address[8..10].copy_from_slice(&VM_TYPE_WASM);
+    new_address[10..30].copy_from_slice(&hash[10..30]);
+    new_address[30..].copy_from_slice(&creator.as_bytes()[30..]);
+    new_address.into()
+}
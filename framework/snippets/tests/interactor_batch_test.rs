@@ -0,0 +1,145 @@
+use multiversx_sc_snippets::{
+    erdrs::wallet::Wallet,
+    multiversx_sc::types::Address,
+    multiversx_sc_scenario::{scenario_model::*, ScenarioWorld},
+    tokio, MockGateway,
+};
+
+const FUNDED_PRIVATE_KEY: &str = "413f42575f7f26fad3317a778771212fdb80245850981e48b58a4f25e344e8f9";
+const UNFUNDED_PRIVATE_KEY: &str =
+    "1e9b8e6d1f4a3c2b0d9e8f7a6b5c4d3e2f1a0b9c8d7e6f5a4b3c2d1e0f9a8b7c";
+
+fn transfer(from: &Address, to: &Address, egld_value: &str) -> ScCallStep {
+    ScCallStep::new()
+        .from(from)
+        .to(to)
+        .egld_value(egld_value)
+        .gas_limit("100,000")
+}
+
+/// The first transaction of the unfunded sender is rejected, since it can only pay for gas
+/// after the transfer that comes after it in the same batch.
+/// Its second transaction is then rejected too, because of the nonce gap.
+/// Both get signed again with the released nonces and are accepted when resent.
+#[tokio::test]
+async fn interactor_batch_nonce_gap_test() {
+    let funded_wallet = Wallet::from_private_key(FUNDED_PRIVATE_KEY).unwrap();
+    let unfunded_wallet = Wallet::from_private_key(UNFUNDED_PRIVATE_KEY).unwrap();
+    let funded_address: Address = funded_wallet.address().to_bytes().into();
+    let unfunded_address: Address = unfunded_wallet.address().to_bytes().into();
+
+    let gateway = MockGateway::start(move || {
+        let mut world = ScenarioWorld::new();
+        world.set_state_step(
+            SetStateStep::new()
+                .put_account(
+                    &funded_address,
+                    Account::new().nonce(3).balance("1,000,000,000,000,000,000"),
+                )
+                .put_account(&unfunded_address, Account::new().nonce(7)),
+        );
+        world
+    });
+    let mut interactor = gateway.interactor().await;
+    let funded_address = interactor.register_wallet(funded_wallet);
+    let unfunded_address = interactor.register_wallet(unfunded_wallet);
+
+    let results = interactor
+        .multiple_sc_calls_get_raw_results(&[
+            transfer(&unfunded_address, &funded_address, "1"),
+            transfer(&funded_address, &unfunded_address, "1,000,000,000,000,000"),
+            transfer(&unfunded_address, &funded_address, "2"),
+        ])
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 3);
+    assert!(results
+        .iter()
+        .all(|result| result.error_message().is_none()));
+
+    let tx_hashes = interactor
+        .multiple_sc_calls(&[
+            transfer(&funded_address, &unfunded_address, "5"),
+            transfer(&unfunded_address, &funded_address, "5"),
+        ])
+        .await
+        .unwrap();
+    assert_eq!(tx_hashes.len(), 2);
+    assert_ne!(tx_hashes[0], tx_hashes[1]);
+
+    // each accepted transaction also pays 100,000 gas at the minimum gas price
+    let funded_address_clone = funded_address.clone();
+    gateway.with_world(move |world| {
+        world.check_state_step(
+            CheckStateStep::new()
+                .put_account(
+                    &funded_address_clone,
+                    CheckAccount::new()
+                        .nonce("5")
+                        .balance("998,800,000,000,000,003"),
+                )
+                .put_account(
+                    &unfunded_address,
+                    CheckAccount::new()
+                        .nonce("10")
+                        .balance("699,999,999,999,997"),
+                ),
+        );
+    });
+}
+
+/// The unfunded sender never gets the funds for its transfers, so they are rejected after all attempts.
+/// Its nonces are released, so there is no gap left and the next transactions go through.
+#[tokio::test]
+async fn interactor_batch_rejected_test() {
+    let funded_wallet = Wallet::from_private_key(FUNDED_PRIVATE_KEY).unwrap();
+    let unfunded_wallet = Wallet::from_private_key(UNFUNDED_PRIVATE_KEY).unwrap();
+    let funded_address: Address = funded_wallet.address().to_bytes().into();
+    let unfunded_address: Address = unfunded_wallet.address().to_bytes().into();
+
+    let gateway = MockGateway::start(move || {
+        let mut world = ScenarioWorld::new();
+        world.set_state_step(
+            SetStateStep::new()
+                .put_account(
+                    &funded_address,
+                    Account::new().nonce(3).balance("1,000,000,000,000,000,000"),
+                )
+                .put_account(&unfunded_address, Account::new().nonce(7)),
+        );
+        world
+    });
+    let mut interactor = gateway.interactor().await;
+    let funded_address = interactor.register_wallet(funded_wallet);
+    let unfunded_address = interactor.register_wallet(unfunded_wallet);
+
+    let err = interactor
+        .multiple_sc_calls(&[
+            transfer(
+                &unfunded_address,
+                &funded_address,
+                "1,000,000,000,000,000,000,000",
+            ),
+            transfer(&funded_address, &unfunded_address, "1,000,000,000,000,000"),
+            transfer(&unfunded_address, &funded_address, "2"),
+        ])
+        .await
+        .unwrap_err();
+    assert_eq!(err.failed_indices, vec![0, 2]);
+    assert!(err.tx_hashes[0].is_none());
+    assert!(err.tx_hashes[1].is_some());
+    assert!(err.tx_hashes[2].is_none());
+    assert!(err.nonce_gaps.is_empty());
+
+    let tx_hashes = interactor
+        .multiple_sc_calls(&[transfer(&unfunded_address, &funded_address, "2")])
+        .await
+        .unwrap();
+    assert_eq!(tx_hashes.len(), 1);
+
+    gateway.with_world(move |world| {
+        world.check_state_step(
+            CheckStateStep::new().put_account(&unfunded_address, CheckAccount::new().nonce("8")),
+        );
+    });
+}
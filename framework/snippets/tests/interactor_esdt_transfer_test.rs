@@ -0,0 +1,249 @@
+use multiversx_sc_snippets::{
+    erdrs::{data::address::Address as ErdrsAddress, wallet::Wallet},
+    multiversx_sc::types::Address,
+    multiversx_sc_scenario::{scenario_model::*, ScenarioWorld},
+    tokio, Interactor, InteractorResult, MockGateway,
+};
+
+mod deposit_mock {
+    multiversx_sc::imports!();
+
+    /// Keeps all the tokens it receives, like a staking contract.
+    #[multiversx_sc::contract]
+    pub trait DepositMock {
+        #[payable("EGLD")]
+        #[init]
+        fn init(&self) {}
+
+        #[payable("*")]
+        #[endpoint]
+        fn deposit(&self, lock_epochs: u64) {
+            let payments = self.call_value().all_esdt_transfers();
+            require!(!payments.is_empty(), "no tokens received");
+            self.last_lock_epochs().set(lock_epochs);
+        }
+
+        #[storage_mapper("lastLockEpochs")]
+        fn last_lock_epochs(&self) -> SingleValueMapper<u64>;
+    }
+}
+
+const OWNER_PRIVATE_KEY: &str = "413f42575f7f26fad3317a778771212fdb80245850981e48b58a4f25e344e8f9";
+const RECEIVER_ADDRESS_BYTES: [u8; 32] = [0x42; 32];
+const FUNGIBLE_TOKEN_ID: &str = "FUNG-123456";
+const NFT_TOKEN_ID: &str = "NFT-123456";
+const DEPOSIT_PATH_EXPR: &str = "file:deposit-mock.wasm";
+
+#[tokio::test]
+async fn interactor_esdt_transfers_test() {
+    let wallet = Wallet::from_private_key(OWNER_PRIVATE_KEY).unwrap();
+    let owner_address: Address = wallet.address().to_bytes().into();
+    let receiver_address: Address = RECEIVER_ADDRESS_BYTES.into();
+
+    let gateway = MockGateway::start(move || {
+        let mut world = ScenarioWorld::new();
+        world.set_state_step(
+            SetStateStep::new()
+                .put_account(&owner_address, owner_account())
+                .put_account(&receiver_address, Account::new()),
+        );
+        world
+    });
+    let mut interactor = gateway.interactor().await;
+    let owner_address = interactor.register_wallet(wallet);
+    let receiver_address: Address = RECEIVER_ADDRESS_BYTES.into();
+
+    // ESDTTransfer
+    interactor
+        .transfer_get_raw_result(
+            TransferStep::new()
+                .from(&owner_address)
+                .to(&receiver_address)
+                .esdt_transfer(format!("str:{FUNGIBLE_TOKEN_ID}").as_str(), 0, "100")
+                .gas_limit("1,000,000"),
+        )
+        .await;
+
+    // ESDTNFTTransfer
+    interactor
+        .transfer_get_raw_result(
+            TransferStep::new()
+                .from(&owner_address)
+                .to(&receiver_address)
+                .esdt_transfer(format!("str:{NFT_TOKEN_ID}").as_str(), 1, "2")
+                .gas_limit("2,000,000"),
+        )
+        .await;
+
+    // MultiESDTNFTTransfer
+    interactor
+        .transfer_get_raw_result(
+            TransferStep::new()
+                .from(&owner_address)
+                .to(&receiver_address)
+                .esdt_transfer(format!("str:{FUNGIBLE_TOKEN_ID}").as_str(), 0, "50")
+                .esdt_transfer(format!("str:{NFT_TOKEN_ID}").as_str(), 1, "1")
+                .gas_limit("2,000,000"),
+        )
+        .await;
+
+    let receiver_esdts = interactor
+        .proxy
+        .get_account_esdt_tokens(&ErdrsAddress::from_bytes(RECEIVER_ADDRESS_BYTES))
+        .await
+        .unwrap();
+    assert_eq!(receiver_esdts.len(), 2);
+    assert_eq!(receiver_esdts[FUNGIBLE_TOKEN_ID].balance, "150");
+    assert_eq!(receiver_esdts[&format!("{NFT_TOKEN_ID}-01")].balance, "3");
+}
+
+fn owner_account() -> Account {
+    Account::new()
+        .balance("1,000,000,000,000,000,000")
+        .esdt_balance(format!("str:{FUNGIBLE_TOKEN_ID}").as_str(), "1000")
+        .esdt_nft_balance(
+            format!("str:{NFT_TOKEN_ID}").as_str(),
+            1,
+            "5",
+            Option::<&str>::None,
+        )
+}
+
+/// The transaction as it was sent: its receiver and its decoded data field.
+async fn sent_tx(interactor: &Interactor, tx_hash: &str) -> (ErdrsAddress, String) {
+    let tx = interactor
+        .proxy
+        .get_transaction_info_with_results(tx_hash)
+        .await
+        .unwrap();
+    let data = base64::decode(tx.data.unwrap()).unwrap();
+    (tx.receiver, String::from_utf8(data).unwrap())
+}
+
+#[tokio::test]
+async fn interactor_sc_call_with_esdt_payments_test() {
+    let wallet = Wallet::from_private_key(OWNER_PRIVATE_KEY).unwrap();
+    let owner_address: Address = wallet.address().to_bytes().into();
+
+    let gateway = MockGateway::start(move || {
+        let mut world = ScenarioWorld::new();
+        world.register_contract(DEPOSIT_PATH_EXPR, deposit_mock::ContractBuilder);
+        world.set_state_step(SetStateStep::new().put_account(&owner_address, owner_account()));
+        world
+    });
+    let mut interactor = gateway.interactor().await;
+    let owner_address = interactor.register_wallet(wallet);
+
+    let deploy_result = interactor
+        .sc_deploy::<(), ()>(
+            ScDeployStep::new()
+                .from(&owner_address)
+                .contract_code(DEPOSIT_PATH_EXPR, gateway.interpreter_context())
+                .gas_limit("5,000,000")
+                .into(),
+        )
+        .await;
+    let deposit_address = deploy_result.new_deployed_address();
+    let deposit_hex = hex::encode(deposit_address.as_bytes());
+    let fungible_hex = hex::encode(FUNGIBLE_TOKEN_ID);
+    let nft_hex = hex::encode(NFT_TOKEN_ID);
+
+    // single fungible payment, sent directly to the contract
+    let tx_hash = interactor
+        .sc_call(
+            ScCallStep::new()
+                .from(&owner_address)
+                .to(&deposit_address)
+                .esdt_transfer(format!("str:{FUNGIBLE_TOKEN_ID}").as_str(), 0, "100")
+                .function("deposit")
+                .argument("30")
+                .gas_limit("5,000,000"),
+        )
+        .await;
+    let (receiver, data) = sent_tx(&interactor, &tx_hash).await;
+    assert_eq!(receiver.to_bytes(), *deposit_address.as_array());
+    assert_eq!(
+        data,
+        format!(
+            "ESDTTransfer@{fungible_hex}@64@{}@1e",
+            hex::encode("deposit")
+        )
+    );
+
+    // multiple payments, sent to the caller itself, with the contract in the data field
+    let tx_hash = interactor
+        .sc_call(
+            ScCallStep::new()
+                .from(&owner_address)
+                .to(&deposit_address)
+                .esdt_transfer(format!("str:{FUNGIBLE_TOKEN_ID}").as_str(), 0, "50")
+                .esdt_transfer(format!("str:{NFT_TOKEN_ID}").as_str(), 1, "2")
+                .function("deposit")
+                .argument("60")
+                .gas_limit("5,000,000"),
+        )
+        .await;
+    let (receiver, data) = sent_tx(&interactor, &tx_hash).await;
+    assert_eq!(receiver.to_bytes(), *owner_address.as_array());
+    assert_eq!(
+        data,
+        format!(
+            "MultiESDTNFTTransfer@{deposit_hex}@02@{fungible_hex}@@32@{nft_hex}@01@02@{}@3c",
+            hex::encode("deposit")
+        )
+    );
+
+    gateway.with_world(move |world| {
+        world.check_state_step(
+            CheckStateStep::new().put_account(
+                &deposit_address,
+                CheckAccount::new()
+                    .esdt_balance(format!("str:{FUNGIBLE_TOKEN_ID}").as_str(), "150")
+                    .check_storage("str:lastLockEpochs", "60"),
+            ),
+        );
+    });
+}
+
+#[tokio::test]
+async fn interactor_sc_deploy_with_egld_payment_test() {
+    let wallet = Wallet::from_private_key(OWNER_PRIVATE_KEY).unwrap();
+    let owner_address: Address = wallet.address().to_bytes().into();
+
+    let gateway = MockGateway::start(move || {
+        let mut world = ScenarioWorld::new();
+        world.register_contract(DEPOSIT_PATH_EXPR, deposit_mock::ContractBuilder);
+        world.set_state_step(SetStateStep::new().put_account(&owner_address, owner_account()));
+        world
+    });
+    let mut interactor = gateway.interactor().await;
+    let owner_address = interactor.register_wallet(wallet);
+
+    let sc_deploy_step = ScDeployStep::new()
+        .from(&owner_address)
+        .contract_code(DEPOSIT_PATH_EXPR, gateway.interpreter_context())
+        .egld_value("1,000")
+        .gas_limit("5,000,000");
+    let expected_data = sc_deploy_step.tx.to_tx_data();
+    let tx_hash = interactor.send_sc_deploy(sc_deploy_step).await;
+
+    // sent to the zero address, with the code, the VM type and the code metadata in the data field
+    let (receiver, data) = sent_tx(&interactor, &tx_hash).await;
+    assert_eq!(receiver.to_bytes(), [0u8; 32]);
+    assert_eq!(data, expected_data);
+    assert_eq!(data.split('@').nth(1), Some("0500"));
+
+    let deploy_tx = interactor
+        .proxy
+        .get_transaction_info_with_results(&tx_hash)
+        .await
+        .unwrap();
+    let deploy_result: InteractorResult<()> = InteractorResult::new(deploy_tx);
+    let deposit_address = deploy_result.new_deployed_address();
+    gateway.with_world(move |world| {
+        world.check_state_step(
+            CheckStateStep::new()
+                .put_account(&deposit_address, CheckAccount::new().balance("1,000")),
+        );
+    });
+}
@@ -57,6 +57,10 @@
 # 14. Write a release announcement in Confluence.
 #
 
+cd chain/core
+cargo publish || return 1
+cd ../..
+
 cd sdk/core
 cargo publish || return 1
 cd ../..
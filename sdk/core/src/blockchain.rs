@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use crate::data::{
     account::{Account, AccountResponse},
@@ -15,7 +15,6 @@ use crate::data::{
     vm::{ResponseVmValue, VmValueRequest, VmValuesResponseData},
 };
 use anyhow::{anyhow, Result};
-use itertools::Itertools;
 use reqwest::Client;
 
 pub const MAINNET_GATEWAY: &str = "https://gateway.multiversx.com";
@@ -306,7 +305,16 @@ impl CommunicationProxy {
         }
     }
 
-    pub async fn send_transactions(&self, txs: &Vec<Transaction>) -> Result<Vec<String>> {
+    pub async fn send_transactions(&self, txs: &[Transaction]) -> Result<Vec<String>> {
+        let tx_hashes = self.send_transactions_by_index(txs).await?;
+        Ok(tx_hashes.into_values().collect())
+    }
+
+    // send_transactions_by_index returns the hashes of the accepted transactions, by their index in the sent list
+    pub async fn send_transactions_by_index(
+        &self,
+        txs: &[Transaction],
+    ) -> Result<BTreeMap<usize, String>> {
         let endpoint = self.get_endpoint(SEND_MULTIPLE_TRANSACTIONS_ENDPOINT);
         let resp = self
             .client
@@ -319,14 +327,11 @@ impl CommunicationProxy {
 
         match resp.data {
             None => Err(anyhow!("{}", resp.error)),
-            Some(b) => {
-                let mut tx_hashs: Vec<String> = vec![];
-                for key in b.txs_hashes.keys().sorted() {
-                    tx_hashs.push(b.txs_hashes[key].clone());
-                }
-
-                Ok(tx_hashs)
-            },
+            Some(b) => Ok(b
+                .txs_hashes
+                .into_iter()
+                .map(|(index, tx_hash)| (index as usize, tx_hash))
+                .collect()),
         }
     }
 
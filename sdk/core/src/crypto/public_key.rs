@@ -1,4 +1,5 @@
 use super::private_key::PrivateKey;
+use crate::data::address::Address;
 use anyhow::Result;
 use bech32::{self, ToBase32, Variant};
 use serde::{
@@ -20,6 +21,23 @@ impl PublicKey {
         &self.0
     }
 
+    pub fn from_bytes(bytes: [u8; PUBLIC_KEY_LENGTH]) -> Self {
+        Self(bytes)
+    }
+
+    /// Checks an ed25519 signature, same as `verify_ed25519` in the Rust VM.
+    pub fn verify(&self, message: &[u8], signature: &[u8]) -> bool {
+        let public = match ed25519_dalek::PublicKey::from_bytes(&self.0) {
+            Ok(public) => public,
+            Err(_) => return false,
+        };
+        let signature = match ed25519_dalek::Signature::from_bytes(signature) {
+            Ok(signature) => signature,
+            Err(_) => return false,
+        };
+        ed25519_dalek::Verifier::verify(&public, message, &signature).is_ok()
+    }
+
     pub fn to_address(&self) -> Result<String> {
         let address = bech32::encode("erd", self.0.to_base32(), Variant::Bech32)?;
         Ok(address)
@@ -44,6 +62,12 @@ impl<'a> From<&'a PrivateKey> for PublicKey {
     }
 }
 
+impl From<&Address> for PublicKey {
+    fn from(address: &Address) -> PublicKey {
+        PublicKey(address.to_bytes())
+    }
+}
+
 impl ToString for PublicKey {
     fn to_string(&self) -> String {
         hex::encode(self.0)
@@ -0,0 +1,43 @@
+use sha3::{Digest, Keccak256};
+
+use super::public_key::PublicKey;
+use crate::data::address::Address;
+
+pub const MESSAGE_PREFIX: &str = "\x17Elrond Signed Message:\n";
+
+// SignableMessage is an arbitrary off-chain message, signed the same way as by the official wallets
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignableMessage {
+    pub message: Vec<u8>,
+}
+
+impl SignableMessage {
+    pub fn new(message: &[u8]) -> Self {
+        SignableMessage {
+            message: message.to_vec(),
+        }
+    }
+
+    /// The bytes that actually get signed: the Keccak-256 hash of the prefixed message,
+    /// i.e. `keccak256("\x17Elrond Signed Message:\n" + len(message) + message)`.
+    ///
+    /// This is also the message to pass to `verify_ed25519` in a contract.
+    pub fn serialize_for_signing(&self) -> [u8; 32] {
+        let mut hasher = Keccak256::new();
+        hasher.update(MESSAGE_PREFIX.as_bytes());
+        hasher.update(self.message.len().to_string().as_bytes());
+        hasher.update(&self.message);
+
+        let mut result = [0u8; 32];
+        result.copy_from_slice(hasher.finalize().as_slice());
+        result
+    }
+
+    pub fn verify(&self, public_key: &PublicKey, signature: &[u8]) -> bool {
+        public_key.verify(&self.serialize_for_signing(), signature)
+    }
+
+    pub fn verify_with_address(&self, address: &Address, signature: &[u8]) -> bool {
+        self.verify(&PublicKey::from(address), signature)
+    }
+}
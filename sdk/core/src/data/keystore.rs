@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+
+pub const KEYSTORE_VERSION: u32 = 4;
+pub const KEYSTORE_CIPHER: &str = "aes-128-ctr";
+pub const KEYSTORE_KDF: &str = "scrypt";
+
+// KeystoreKind tells what the keystore encrypts: a secret key or a mnemonic
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum KeystoreKind {
+    #[serde(rename = "secretKey")]
+    SecretKey,
+    #[serde(rename = "mnemonic")]
+    Mnemonic,
+}
+
+// Keystore holds an encrypted JSON wallet, as exported by the official wallets
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Keystore {
+    pub version: u32,
+    pub kind: KeystoreKind,
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bech32: Option<String>,
+    pub crypto: KeystoreCrypto,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeystoreCrypto {
+    pub ciphertext: String,
+    pub cipherparams: CipherParams,
+    pub cipher: String,
+    pub kdf: String,
+    pub kdfparams: KdfParams,
+    pub mac: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CipherParams {
+    pub iv: String,
+}
+
+// KdfParams holds the scrypt parameters used to derive the encryption key from the password
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KdfParams {
+    pub dklen: usize,
+    pub salt: String,
+    pub n: u32,
+    pub r: u32,
+    pub p: u32,
+}
+
+impl Default for KdfParams {
+    fn default() -> Self {
+        KdfParams {
+            dklen: 32,
+            salt: String::new(),
+            n: 4096,
+            r: 8,
+            p: 1,
+        }
+    }
+}
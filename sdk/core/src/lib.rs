@@ -1,4 +1,6 @@
 pub mod blockchain;
 pub mod crypto;
 pub mod data;
+pub mod native_auth;
+pub mod token_transfer;
 pub mod wallet;
@@ -0,0 +1,146 @@
+use anyhow::{anyhow, bail, Result};
+use serde_json::Value;
+
+use crate::{crypto::signable_message::SignableMessage, data::address::Address, wallet::Wallet};
+
+const TOKEN_SEPARATOR: char = '.';
+const DEFAULT_EXTRA_INFO: &str = "{}";
+
+fn encode_value(value: &str) -> String {
+    base64::encode_config(value, base64::URL_SAFE_NO_PAD)
+}
+
+fn decode_value(encoded: &str) -> Result<String> {
+    let bytes = base64::decode_config(encoded.trim_end_matches('='), base64::URL_SAFE_NO_PAD)?;
+    Ok(String::from_utf8(bytes)?)
+}
+
+/// Native authentication token, used by dApps and APIs to authenticate wallets off-chain.
+///
+/// Serialized as `base64(address).base64(body).hex(signature)`, where the body is
+/// `base64(origin).block_hash.ttl.base64(extra_info)`,
+/// and the signature is over the signable message `address + body`.
+#[derive(Debug, Clone)]
+pub struct NativeAuthToken {
+    pub address: Address,
+    pub origin: String,
+    pub block_hash: String,
+    pub ttl: u64,
+    pub extra_info: Value,
+    pub signature: Vec<u8>,
+    body: String,
+}
+
+impl NativeAuthToken {
+    /// Generates and signs a new token.
+    ///
+    /// The block hash should be a recent block hash, the token expires `ttl` seconds after that block.
+    pub fn generate(
+        wallet: &Wallet,
+        origin: &str,
+        block_hash: &str,
+        ttl: u64,
+        extra_info: Option<Value>,
+    ) -> Result<String> {
+        let extra_info = match extra_info {
+            Some(extra_info) => extra_info.to_string(),
+            None => DEFAULT_EXTRA_INFO.to_string(),
+        };
+        let body = format!(
+            "{}{TOKEN_SEPARATOR}{block_hash}{TOKEN_SEPARATOR}{ttl}{TOKEN_SEPARATOR}{}",
+            encode_value(origin),
+            encode_value(&extra_info)
+        );
+
+        let address = wallet.address().to_bech32_string()?;
+        let signature = wallet.sign_message(format!("{address}{body}").as_bytes());
+        Ok(format!(
+            "{}{TOKEN_SEPARATOR}{}{TOKEN_SEPARATOR}{}",
+            encode_value(&address),
+            encode_value(&body),
+            hex::encode(signature)
+        ))
+    }
+
+    /// Parses a token, without validating it.
+    pub fn decode(token: &str) -> Result<Self> {
+        let token_parts: Vec<&str> = token.split(TOKEN_SEPARATOR).collect();
+        let [encoded_address, encoded_body, signature] = token_parts[..] else {
+            bail!("invalid native auth token format");
+        };
+
+        let address = Address::from_bech32_string(&decode_value(encoded_address)?)?;
+        let body = decode_value(encoded_body)?;
+        let signature = hex::decode(signature)?;
+
+        let body_parts: Vec<&str> = body.split(TOKEN_SEPARATOR).collect();
+        let [encoded_origin, block_hash, ttl, encoded_extra_info] = body_parts[..] else {
+            bail!("invalid native auth token body format");
+        };
+
+        Ok(NativeAuthToken {
+            address,
+            origin: decode_value(encoded_origin)?,
+            block_hash: block_hash.to_string(),
+            ttl: ttl
+                .parse()
+                .map_err(|_| anyhow!("invalid native auth token ttl: {ttl}"))?,
+            extra_info: serde_json::from_str(&decode_value(encoded_extra_info)?)?,
+            signature,
+            body,
+        })
+    }
+
+    pub fn signable_message(&self) -> Result<SignableMessage> {
+        let address = self.address.to_bech32_string()?;
+        Ok(SignableMessage::new(
+            format!("{address}{}", self.body).as_bytes(),
+        ))
+    }
+
+    pub fn verify_signature(&self) -> bool {
+        match self.signable_message() {
+            Ok(signable_message) => {
+                signable_message.verify_with_address(&self.address, &self.signature)
+            },
+            Err(_) => false,
+        }
+    }
+}
+
+// NativeAuthValidator holds the checks a server performs on incoming native auth tokens
+#[derive(Debug, Clone)]
+pub struct NativeAuthValidator {
+    pub accepted_origins: Vec<String>,
+    pub max_expiry_seconds: u64,
+}
+
+impl NativeAuthValidator {
+    /// Decodes and fully validates a token.
+    ///
+    /// The caller provides the timestamp of the token's block (e.g. from the hyperblock with that hash)
+    /// and the current timestamp, both in seconds.
+    pub fn validate(
+        &self,
+        token: &str,
+        block_timestamp: u64,
+        current_timestamp: u64,
+    ) -> Result<NativeAuthToken> {
+        let token = NativeAuthToken::decode(token)?;
+
+        if !self.accepted_origins.is_empty() && !self.accepted_origins.contains(&token.origin) {
+            bail!("unhandled native auth token origin: {}", token.origin);
+        }
+        if token.ttl > self.max_expiry_seconds {
+            bail!("native auth token ttl exceeds the maximum expiry");
+        }
+        if block_timestamp + token.ttl < current_timestamp {
+            bail!("native auth token expired");
+        }
+        if !token.verify_signature() {
+            bail!("invalid native auth token signature");
+        }
+
+        Ok(token)
+    }
+}
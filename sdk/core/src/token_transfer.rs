@@ -0,0 +1,212 @@
+use crate::data::{address::Address, network_config::NetworkConfig, transaction::Transaction};
+use multiversx_chain_core::builtin_function_names::{
+    ESDT_MULTI_TRANSFER_FUNC_NAME, ESDT_NFT_TRANSFER_FUNC_NAME, ESDT_TRANSFER_FUNC_NAME,
+};
+use num_bigint::BigUint;
+
+/// Gas consumed by the transfer built-in functions themselves,
+/// on top of the move balance cost. Same defaults as the official JS SDK.
+pub const GAS_LIMIT_ESDT_TRANSFER: u64 = 200_000;
+pub const GAS_LIMIT_ESDT_NFT_TRANSFER: u64 = 200_000;
+pub const GAS_LIMIT_MULTI_ESDT_NFT_TRANSFER_PER_TOKEN: u64 = 200_000;
+
+/// Extra gas reserved for the built-in function execution on the destination shard.
+pub const ADDITIONAL_GAS_FOR_ESDT_TRANSFER: u64 = 100_000;
+pub const ADDITIONAL_GAS_FOR_ESDT_NFT_TRANSFER: u64 = 800_000;
+
+// TokenPayment holds a single token transfer: fungible if the nonce is 0, NFT/SFT/META otherwise
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenPayment {
+    pub token_identifier: String,
+    pub nonce: u64,
+    /// In the smallest denomination, like the transaction value.
+    pub amount: BigUint,
+}
+
+impl TokenPayment {
+    pub fn new(token_identifier: &str, nonce: u64, amount: BigUint) -> Self {
+        TokenPayment {
+            token_identifier: token_identifier.to_string(),
+            nonce,
+            amount,
+        }
+    }
+
+    pub fn fungible(token_identifier: &str, amount: BigUint) -> Self {
+        Self::new(token_identifier, 0, amount)
+    }
+}
+
+/// Builds token transfer transactions, optionally calling a contract endpoint with the tokens.
+///
+/// Single fungible transfers use `ESDTTransfer` and go directly to the receiver.
+/// NFT and multi-token transfers use `ESDTNFTTransfer` and `MultiESDTNFTTransfer`,
+/// which are sent to the sender itself, the real receiver being encoded in the data field.
+#[derive(Debug, Clone)]
+pub struct TokenTransferBuilder {
+    pub sender: Address,
+    pub receiver: Address,
+    pub payments: Vec<TokenPayment>,
+    pub function: Option<String>,
+    pub args: Vec<Vec<u8>>,
+    pub execution_gas_limit: u64,
+}
+
+impl TokenTransferBuilder {
+    pub fn new(sender: &Address, receiver: &Address) -> Self {
+        TokenTransferBuilder {
+            sender: sender.clone(),
+            receiver: receiver.clone(),
+            payments: Vec::new(),
+            function: None,
+            args: Vec::new(),
+            execution_gas_limit: 0,
+        }
+    }
+
+    pub fn with_payment(mut self, payment: TokenPayment) -> Self {
+        self.payments.push(payment);
+        self
+    }
+
+    pub fn with_payments(mut self, payments: Vec<TokenPayment>) -> Self {
+        self.payments.extend(payments);
+        self
+    }
+
+    /// Calls an endpoint of the receiver contract with the transferred tokens.
+    ///
+    /// The execution gas limit is the gas needed by the endpoint itself, it gets added to the estimate.
+    pub fn with_contract_call(
+        mut self,
+        function: &str,
+        args: Vec<Vec<u8>>,
+        execution_gas_limit: u64,
+    ) -> Self {
+        self.function = Some(function.to_string());
+        self.args = args;
+        self.execution_gas_limit = execution_gas_limit;
+        self
+    }
+
+    fn is_single_fungible_transfer(&self) -> bool {
+        self.payments.len() == 1 && self.payments[0].nonce == 0
+    }
+
+    /// The actual transaction receiver, which is the sender for NFT and multi-token transfers.
+    pub fn tx_receiver(&self) -> Address {
+        if self.payments.is_empty() || self.is_single_fungible_transfer() {
+            self.receiver.clone()
+        } else {
+            self.sender.clone()
+        }
+    }
+
+    /// The data field, not yet base64 encoded.
+    pub fn data(&self) -> String {
+        let mut data = String::new();
+        match self.payments.len() {
+            0 => {},
+            1 => {
+                let payment = &self.payments[0];
+                if payment.nonce == 0 {
+                    data.push_str(ESDT_TRANSFER_FUNC_NAME);
+                    push_arg(&mut data, payment.token_identifier.as_bytes());
+                    push_arg(&mut data, &amount_bytes(&payment.amount));
+                } else {
+                    data.push_str(ESDT_NFT_TRANSFER_FUNC_NAME);
+                    push_arg(&mut data, payment.token_identifier.as_bytes());
+                    push_arg(&mut data, &u64_bytes(payment.nonce));
+                    push_arg(&mut data, &amount_bytes(&payment.amount));
+                    push_arg(&mut data, &self.receiver.to_bytes());
+                }
+            },
+            _ => {
+                data.push_str(ESDT_MULTI_TRANSFER_FUNC_NAME);
+                push_arg(&mut data, &self.receiver.to_bytes());
+                push_arg(&mut data, &u64_bytes(self.payments.len() as u64));
+                for payment in &self.payments {
+                    push_arg(&mut data, payment.token_identifier.as_bytes());
+                    push_arg(&mut data, &u64_bytes(payment.nonce));
+                    push_arg(&mut data, &amount_bytes(&payment.amount));
+                }
+            },
+        }
+
+        if let Some(function) = &self.function {
+            if data.is_empty() {
+                data.push_str(function);
+            } else {
+                push_arg(&mut data, function.as_bytes());
+            }
+            for arg in &self.args {
+                push_arg(&mut data, arg);
+            }
+        }
+
+        data
+    }
+
+    /// Move balance cost of the data field, plus the cost of the transfer built-in function,
+    /// plus the execution gas limit of the contract call, if any.
+    pub fn estimate_gas_limit(&self, network_config: &NetworkConfig) -> u64 {
+        let data_len = self.data().len() as u64;
+        let move_balance_gas =
+            network_config.min_gas_limit + network_config.gas_per_data_byte * data_len;
+        let transfer_gas = match self.payments.len() {
+            0 => 0,
+            1 if self.is_single_fungible_transfer() => {
+                GAS_LIMIT_ESDT_TRANSFER + ADDITIONAL_GAS_FOR_ESDT_TRANSFER
+            },
+            1 => GAS_LIMIT_ESDT_NFT_TRANSFER + ADDITIONAL_GAS_FOR_ESDT_NFT_TRANSFER,
+            num_payments => {
+                GAS_LIMIT_MULTI_ESDT_NFT_TRANSFER_PER_TOKEN * num_payments as u64
+                    + ADDITIONAL_GAS_FOR_ESDT_NFT_TRANSFER
+            },
+        };
+        move_balance_gas + transfer_gas + self.execution_gas_limit
+    }
+
+    /// Builds the unsigned transaction, using the gas price, chain id and version of the network.
+    pub fn build(&self, nonce: u64, network_config: &NetworkConfig) -> Transaction {
+        let data = self.data();
+        Transaction {
+            nonce,
+            value: "0".to_string(),
+            receiver: self.tx_receiver(),
+            sender: self.sender.clone(),
+            gas_price: network_config.min_gas_price,
+            gas_limit: self.estimate_gas_limit(network_config),
+            data: if data.is_empty() {
+                None
+            } else {
+                Some(base64::encode(data))
+            },
+            signature: None,
+            chain_id: network_config.chain_id.clone(),
+            version: network_config.min_transaction_version,
+            options: 0,
+        }
+    }
+}
+
+fn push_arg(data: &mut String, arg: &[u8]) {
+    data.push('@');
+    data.push_str(&hex::encode(arg));
+}
+
+/// Top-encoded, like in contracts: big endian, without leading zeros, zero is empty.
+fn u64_bytes(value: u64) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    let first_non_zero = bytes.iter().position(|b| *b != 0).unwrap_or(bytes.len());
+    bytes[first_non_zero..].to_vec()
+}
+
+/// Top-encoded, like in contracts: big endian, zero is empty.
+fn amount_bytes(amount: &BigUint) -> Vec<u8> {
+    if amount.bits() == 0 {
+        Vec::new()
+    } else {
+        amount.to_bytes_be()
+    }
+}
@@ -1,11 +1,17 @@
 extern crate rand;
 
-use anyhow::Result;
+use aes::Aes128;
+use anyhow::{anyhow, bail, Result};
 use bip39::{Language, Mnemonic};
+use ctr::{
+    cipher::{NewCipher, StreamCipher},
+    Ctr128BE,
+};
 use hmac::{Hmac, Mac, NewMac};
 use pbkdf2::pbkdf2;
+use rand::RngCore;
 use serde_json::json;
-use sha2::{Digest, Sha512};
+use sha2::{Digest, Sha256, Sha512};
 use sha3::Keccak256;
 use zeroize::Zeroize;
 
@@ -13,14 +19,28 @@ use crate::{
     crypto::{
         private_key::{PrivateKey, PRIVATE_KEY_LENGTH},
         public_key::PublicKey,
+        signable_message::SignableMessage,
+    },
+    data::{
+        address::Address,
+        keystore::{
+            CipherParams, KdfParams, Keystore, KeystoreCrypto, KeystoreKind, KEYSTORE_CIPHER,
+            KEYSTORE_KDF, KEYSTORE_VERSION,
+        },
+        transaction::Transaction,
     },
-    data::{address::Address, transaction::Transaction},
 };
 
 const EGLD_COIN_TYPE: u32 = 508;
 const HARDENED: u32 = 0x80000000;
 
+const KEYSTORE_SALT_LENGTH: usize = 32;
+const KEYSTORE_IV_LENGTH: usize = 16;
+const KEYSTORE_DERIVED_KEY_LENGTH: usize = 32;
+
 type HmacSha521 = Hmac<Sha512>;
+type HmacSha256 = Hmac<Sha256>;
+type Aes128Ctr = Ctr128BE<Aes128>;
 
 #[derive(Copy, Clone, Debug)]
 pub struct Wallet {
@@ -107,6 +127,192 @@ impl Wallet {
         Ok(Self { priv_key: pri_key })
     }
 
+    /// Loads a wallet from an encrypted JSON keystore file.
+    ///
+    /// Mnemonic keystores yield the first address derived from the mnemonic.
+    pub fn from_keystore_file(file_path: &str, password: &str) -> Result<Self> {
+        let keystore = Self::read_keystore_file(file_path)?;
+        let priv_key = match keystore.kind {
+            KeystoreKind::SecretKey => Self::decrypt_secret_key(&keystore, password)?,
+            KeystoreKind::Mnemonic => {
+                let mnemonic = Self::decrypt_mnemonic(&keystore, password)?;
+                Self::get_private_key_from_mnemonic(mnemonic, 0, 0)
+            },
+        };
+        Ok(Self { priv_key })
+    }
+
+    pub fn read_keystore_file(file_path: &str) -> Result<Keystore> {
+        let json = std::fs::read_to_string(file_path)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    pub fn write_keystore_file(keystore: &Keystore, file_path: &str) -> Result<()> {
+        let json = serde_json::to_string_pretty(keystore)?;
+        std::fs::write(file_path, json)?;
+        Ok(())
+    }
+
+    /// Asks for the keystore password in the terminal, without echoing it.
+    pub fn get_keystore_password() -> Result<String> {
+        Ok(rpassword::prompt_password("Insert password: ")?)
+    }
+
+    pub fn decrypt_secret_key(keystore: &Keystore, password: &str) -> Result<PrivateKey> {
+        if keystore.kind != KeystoreKind::SecretKey {
+            bail!("keystore does not contain a secret key");
+        }
+
+        let mut plaintext = Self::decrypt_keystore(keystore, password)?;
+        let priv_key = PrivateKey::from_bytes(plaintext.as_slice());
+        plaintext.zeroize();
+        priv_key
+    }
+
+    pub fn decrypt_mnemonic(keystore: &Keystore, password: &str) -> Result<Mnemonic> {
+        if keystore.kind != KeystoreKind::Mnemonic {
+            bail!("keystore does not contain a mnemonic");
+        }
+
+        let mut plaintext = Self::decrypt_keystore(keystore, password)?;
+        let mnemonic = match std::str::from_utf8(plaintext.as_slice()) {
+            Ok(phrase) => Mnemonic::parse_normalized(phrase).map_err(|err| anyhow!(err)),
+            Err(_) => Err(anyhow!("keystore mnemonic is not valid UTF-8")),
+        };
+        plaintext.zeroize();
+        mnemonic
+    }
+
+    /// Encrypts the wallet secret key into a new keystore, with a random salt and IV.
+    pub fn encrypt_keystore(&self, password: &str) -> Result<Keystore> {
+        let public_key = PublicKey::from(&self.priv_key);
+        let address = Address::from(&public_key);
+        let mut keystore = Self::encrypt_keystore_data(
+            KeystoreKind::SecretKey,
+            self.priv_key.as_bytes(),
+            password,
+        )?;
+        keystore.address = Some(public_key.to_string());
+        keystore.bech32 = Some(address.to_bech32_string()?);
+        Ok(keystore)
+    }
+
+    /// Encrypts a mnemonic into a new keystore, with a random salt and IV.
+    pub fn encrypt_mnemonic(mnemonic: &Mnemonic, password: &str) -> Result<Keystore> {
+        let mut phrase = mnemonic.to_string();
+        let keystore =
+            Self::encrypt_keystore_data(KeystoreKind::Mnemonic, phrase.as_bytes(), password);
+        phrase.zeroize();
+        keystore
+    }
+
+    fn encrypt_keystore_data(
+        kind: KeystoreKind,
+        plaintext: &[u8],
+        password: &str,
+    ) -> Result<Keystore> {
+        let mut rng = rand::thread_rng();
+        let mut salt = [0u8; KEYSTORE_SALT_LENGTH];
+        rng.fill_bytes(&mut salt);
+        let mut iv = [0u8; KEYSTORE_IV_LENGTH];
+        rng.fill_bytes(&mut iv);
+
+        let kdfparams = KdfParams {
+            salt: hex::encode(salt),
+            ..Default::default()
+        };
+        let mut derived_key = Self::derive_keystore_key(password, &kdfparams)?;
+        let (encryption_key, mac_key) = derived_key.split_at(KEYSTORE_DERIVED_KEY_LENGTH / 2);
+
+        let mut ciphertext = plaintext.to_vec();
+        let mut cipher = Aes128Ctr::new_from_slices(encryption_key, &iv)
+            .map_err(|_| anyhow!("invalid keystore cipher parameters"))?;
+        cipher.apply_keystream(&mut ciphertext);
+
+        let mut mac = HmacSha256::new_from_slice(mac_key).expect("HMAC can take key of any size");
+        mac.update(&ciphertext);
+        let mac = mac.finalize().into_bytes();
+        derived_key.zeroize();
+
+        Ok(Keystore {
+            version: KEYSTORE_VERSION,
+            kind,
+            id: uuid::Uuid::new_v4().to_string(),
+            address: None,
+            bech32: None,
+            crypto: KeystoreCrypto {
+                ciphertext: hex::encode(ciphertext),
+                cipherparams: CipherParams {
+                    iv: hex::encode(iv),
+                },
+                cipher: KEYSTORE_CIPHER.to_string(),
+                kdf: KEYSTORE_KDF.to_string(),
+                kdfparams,
+                mac: hex::encode(mac),
+            },
+        })
+    }
+
+    fn decrypt_keystore(keystore: &Keystore, password: &str) -> Result<Vec<u8>> {
+        let crypto = &keystore.crypto;
+        if crypto.cipher != KEYSTORE_CIPHER {
+            bail!("unsupported keystore cipher: {}", crypto.cipher);
+        }
+        if crypto.kdf != KEYSTORE_KDF {
+            bail!(
+                "unsupported keystore key derivation function: {}",
+                crypto.kdf
+            );
+        }
+
+        let ciphertext = hex::decode(&crypto.ciphertext)?;
+        let iv = hex::decode(&crypto.cipherparams.iv)?;
+        let expected_mac = hex::decode(&crypto.mac)?;
+
+        let mut derived_key = Self::derive_keystore_key(password, &crypto.kdfparams)?;
+        let (encryption_key, mac_key) = derived_key.split_at(KEYSTORE_DERIVED_KEY_LENGTH / 2);
+
+        let mut mac = HmacSha256::new_from_slice(mac_key).expect("HMAC can take key of any size");
+        mac.update(&ciphertext);
+        let cipher = Aes128Ctr::new_from_slices(encryption_key, &iv);
+        let mac_result = mac.verify(&expected_mac);
+        derived_key.zeroize();
+
+        if mac_result.is_err() {
+            bail!("wrong keystore password");
+        }
+
+        let mut plaintext = ciphertext;
+        cipher
+            .map_err(|_| anyhow!("invalid keystore cipher parameters"))?
+            .apply_keystream(&mut plaintext);
+        Ok(plaintext)
+    }
+
+    fn derive_keystore_key(
+        password: &str,
+        kdfparams: &KdfParams,
+    ) -> Result<[u8; KEYSTORE_DERIVED_KEY_LENGTH]> {
+        if kdfparams.dklen != KEYSTORE_DERIVED_KEY_LENGTH {
+            bail!(
+                "unsupported keystore derived key length: {}",
+                kdfparams.dklen
+            );
+        }
+        if !kdfparams.n.is_power_of_two() {
+            bail!("keystore scrypt parameter n must be a power of 2");
+        }
+
+        let salt = hex::decode(&kdfparams.salt)?;
+        let params =
+            scrypt::Params::new(kdfparams.n.trailing_zeros() as u8, kdfparams.r, kdfparams.p)
+                .map_err(|_| anyhow!("invalid keystore scrypt parameters"))?;
+        let mut derived_key = [0u8; KEYSTORE_DERIVED_KEY_LENGTH];
+        scrypt::scrypt(password.as_bytes(), &salt, &params, &mut derived_key)
+            .map_err(|_| anyhow!("invalid keystore derived key length"))?;
+        Ok(derived_key)
+    }
+
     pub fn address(&self) -> Address {
         let public_key = PublicKey::from(&self.priv_key);
         Address::from(&public_key)
@@ -127,4 +333,11 @@ impl Wallet {
 
         self.priv_key.sign(tx_bytes)
     }
+
+    /// Signs an off-chain message, prefixed and hashed like the official wallets do.
+    pub fn sign_message(&self, message: &[u8]) -> [u8; 64] {
+        let signable_message = SignableMessage::new(message);
+        self.priv_key
+            .sign(signable_message.serialize_for_signing().to_vec())
+    }
 }
@@ -0,0 +1,93 @@
+use multiversx_sdk::{
+    crypto::{public_key::PublicKey, signable_message::SignableMessage},
+    native_auth::{NativeAuthToken, NativeAuthValidator},
+    wallet::Wallet,
+};
+use serde_json::json;
+
+const ALICE_HELLO_SIGNATURE: &str = "561bc58f1dc6b10de208b2d2c22c9a474ea5e8cabb59c3d3ce06bbda21cc46454aa71a85d5a60442bd7784effa2e062fcb8fb421c521f898abf7f5ec165e5d0f";
+
+#[test]
+fn test_serialize_message_for_signing() {
+    let message = SignableMessage::new(b"hello");
+    assert_eq!(
+        hex::encode(message.serialize_for_signing()),
+        "999194090cc45ebbb30c1d41c27ba10e4d7335d052b17fbc334a2a21736c535a"
+    );
+}
+
+#[test]
+fn test_sign_and_verify_message() {
+    let wallet = Wallet::from_pem_file("tests/alice.pem").unwrap();
+    let signature = wallet.sign_message(b"hello");
+    assert_eq!(hex::encode(signature), ALICE_HELLO_SIGNATURE);
+
+    let message = SignableMessage::new(b"hello");
+    let address = wallet.address();
+    assert!(message.verify_with_address(&address, &signature));
+    assert!(message.verify(&PublicKey::from(&address), &signature));
+
+    assert!(!SignableMessage::new(b"hello!").verify_with_address(&address, &signature));
+    assert!(!message.verify_with_address(&address, &signature[..63]));
+
+    let bob = Wallet::from_private_key(
+        "b8ca6f8203fb4b545a8e83c5384da033c415db155b53fb5b8eba7ff5a039d639",
+    )
+    .unwrap();
+    assert!(!message.verify_with_address(&bob.address(), &signature));
+}
+
+#[test]
+fn test_native_auth_token() {
+    let wallet = Wallet::from_pem_file("tests/alice.pem").unwrap();
+    let token = NativeAuthToken::generate(
+        &wallet,
+        "https://example.com",
+        "b3d07565293fd5684c97d2b96eb862d124fd698678f3f95b2515ed07178a27b4",
+        86400,
+        Some(json!({"timestamp": 1671468011})),
+    )
+    .unwrap();
+
+    let decoded = NativeAuthToken::decode(&token).unwrap();
+    assert_eq!(
+        decoded.address.to_bech32_string().unwrap(),
+        "erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6th"
+    );
+    assert_eq!(decoded.origin, "https://example.com");
+    assert_eq!(decoded.ttl, 86400);
+    assert_eq!(decoded.extra_info, json!({"timestamp": 1671468011}));
+    assert!(decoded.verify_signature());
+
+    let validator = NativeAuthValidator {
+        accepted_origins: vec!["https://example.com".to_string()],
+        max_expiry_seconds: 86400,
+    };
+    assert!(validator.validate(&token, 1_000_000, 1_050_000).is_ok());
+    assert_eq!(
+        validator
+            .validate(&token, 1_000_000, 1_100_000)
+            .unwrap_err()
+            .to_string(),
+        "native auth token expired"
+    );
+
+    let other_origin_validator = NativeAuthValidator {
+        accepted_origins: vec!["https://other.com".to_string()],
+        max_expiry_seconds: 86400,
+    };
+    assert!(other_origin_validator
+        .validate(&token, 1_000_000, 1_050_000)
+        .is_err());
+
+    let mut token_parts: Vec<&str> = token.split('.').collect();
+    let forged_signature = hex::encode([0u8; 64]);
+    token_parts[2] = &forged_signature;
+    assert_eq!(
+        validator
+            .validate(&token_parts.join("."), 1_000_000, 1_050_000)
+            .unwrap_err()
+            .to_string(),
+        "invalid native auth token signature"
+    );
+}
@@ -0,0 +1,142 @@
+use multiversx_sdk::{
+    data::{address::Address, network_config::NetworkConfig},
+    token_transfer::{TokenPayment, TokenTransferBuilder},
+};
+use num_bigint::BigUint;
+
+const ALICE: &str = "erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6th";
+const BOB: &str = "erd1spyavw0956vq68xj8y4tenjpq2wd5a9p2c6j8gsz7ztyrnpxrruqzu66jx";
+
+// "TKN-123456"
+const TOKEN_HEX: &str = "544b4e2d313233343536";
+// "NFT-123456"
+const NFT_HEX: &str = "4e46542d313233343536";
+
+fn network_config() -> NetworkConfig {
+    NetworkConfig {
+        chain_id: "D".to_string(),
+        denomination: 18,
+        gas_per_data_byte: 1_500,
+        latest_tag_software_version: String::new(),
+        meta_consensus_group_size: 0,
+        min_gas_limit: 50_000,
+        min_gas_price: 1_000_000_000,
+        min_transaction_version: 1,
+        num_metachain_nodes: 0,
+        num_nodes_in_shard: 0,
+        num_shards_without_meta: 3,
+        round_duration: 6_000,
+        shard_consensus_group_size: 0,
+        start_time: 0,
+    }
+}
+
+fn alice() -> Address {
+    Address::from_bech32_string(ALICE).unwrap()
+}
+
+fn bob() -> Address {
+    Address::from_bech32_string(BOB).unwrap()
+}
+
+#[test]
+fn test_esdt_transfer() {
+    let builder = TokenTransferBuilder::new(&alice(), &bob())
+        .with_payment(TokenPayment::fungible("TKN-123456", BigUint::from(1000u32)));
+    assert_eq!(builder.data(), format!("ESDTTransfer@{TOKEN_HEX}@03e8"));
+
+    let tx = builder.build(5, &network_config());
+    assert_eq!(tx.nonce, 5);
+    assert_eq!(tx.receiver.to_bech32_string().unwrap(), BOB);
+    assert_eq!(tx.value, "0");
+    assert_eq!(
+        tx.data,
+        Some(base64::encode(format!("ESDTTransfer@{TOKEN_HEX}@03e8")))
+    );
+    assert_eq!(tx.gas_limit, 50_000 + 1_500 * 38 + 200_000 + 100_000);
+    assert_eq!(tx.chain_id, "D");
+}
+
+#[test]
+fn test_esdt_transfer_with_contract_call() {
+    let builder = TokenTransferBuilder::new(&alice(), &bob())
+        .with_payment(TokenPayment::fungible("TKN-123456", BigUint::from(1000u32)))
+        .with_contract_call("deposit", vec![vec![0x05], Vec::new()], 5_000_000);
+    let data = format!("ESDTTransfer@{TOKEN_HEX}@03e8@6465706f736974@05@");
+    assert_eq!(builder.data(), data);
+    assert_eq!(
+        builder.estimate_gas_limit(&network_config()),
+        50_000 + 1_500 * data.len() as u64 + 200_000 + 100_000 + 5_000_000
+    );
+}
+
+#[test]
+fn test_nft_transfer() {
+    let builder = TokenTransferBuilder::new(&alice(), &bob()).with_payment(TokenPayment::new(
+        "NFT-123456",
+        0x1f,
+        BigUint::from(1u32),
+    ));
+    let data = format!(
+        "ESDTNFTTransfer@{NFT_HEX}@1f@01@{}",
+        hex::encode(bob().to_bytes())
+    );
+    assert_eq!(builder.data(), data);
+
+    let tx = builder.build(0, &network_config());
+    assert_eq!(tx.receiver.to_bech32_string().unwrap(), ALICE);
+    assert_eq!(tx.sender.to_bech32_string().unwrap(), ALICE);
+    assert_eq!(
+        tx.gas_limit,
+        50_000 + 1_500 * data.len() as u64 + 200_000 + 800_000
+    );
+}
+
+#[test]
+fn test_multi_transfer() {
+    let builder = TokenTransferBuilder::new(&alice(), &bob()).with_payments(vec![
+        TokenPayment::fungible("TKN-123456", BigUint::from(1000u32)),
+        TokenPayment::new("NFT-123456", 1, BigUint::from(1u32)),
+    ]);
+    let data = format!(
+        "MultiESDTNFTTransfer@{}@02@{TOKEN_HEX}@@03e8@{NFT_HEX}@01@01",
+        hex::encode(bob().to_bytes())
+    );
+    assert_eq!(builder.data(), data);
+
+    let tx = builder.build(0, &network_config());
+    assert_eq!(tx.receiver.to_bech32_string().unwrap(), ALICE);
+    assert_eq!(
+        tx.gas_limit,
+        50_000 + 1_500 * data.len() as u64 + 2 * 200_000 + 800_000
+    );
+}
+
+#[test]
+fn test_contract_call_without_payments() {
+    let builder = TokenTransferBuilder::new(&alice(), &bob()).with_contract_call(
+        "claim",
+        Vec::new(),
+        1_000_000,
+    );
+    assert_eq!(builder.data(), "claim");
+
+    let tx = builder.build(0, &network_config());
+    assert_eq!(tx.receiver.to_bech32_string().unwrap(), BOB);
+    assert_eq!(tx.gas_limit, 50_000 + 1_500 * 5 + 1_000_000);
+}
+
+#[test]
+fn test_amount_encoding() {
+    let builder = TokenTransferBuilder::new(&alice(), &bob()).with_payments(vec![
+        TokenPayment::fungible("TKN-123456", BigUint::from(10u32).pow(21)),
+        TokenPayment::fungible("TKN-123456", BigUint::from(0u32)),
+    ]);
+    assert_eq!(
+        builder.data(),
+        format!(
+            "MultiESDTNFTTransfer@{}@02@{TOKEN_HEX}@@3635c9adc5dea00000@{TOKEN_HEX}@@",
+            hex::encode(bob().to_bytes())
+        )
+    );
+}
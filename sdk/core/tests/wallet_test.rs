@@ -1,10 +1,19 @@
 use bip39::Mnemonic;
 
-use multiversx_sdk::{crypto::public_key::PublicKey, data::address::Address, wallet::Wallet};
+use multiversx_sdk::{
+    crypto::public_key::PublicKey,
+    data::{
+        address::Address,
+        keystore::{Keystore, KeystoreKind},
+    },
+    wallet::Wallet,
+};
+
+const TEST_MNEMONIC: &str = "acid twice post genre topic observe valid viable gesture fortune funny dawn around blood enemy page update reduce decline van bundle zebra rookie real";
 
 #[test]
 fn test_private_key_from_mnemonic() {
-    let mnemonic: Mnemonic = Mnemonic::parse_normalized("acid twice post genre topic observe valid viable gesture fortune funny dawn around blood enemy page update reduce decline van bundle zebra rookie real").unwrap();
+    let mnemonic: Mnemonic = Mnemonic::parse_normalized(TEST_MNEMONIC).unwrap();
 
     let private_key = Wallet::get_private_key_from_mnemonic(mnemonic.clone(), 0, 0);
     let public_key = PublicKey::from(&private_key);
@@ -48,3 +57,72 @@ fn test_load_from_pem() {
         "erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6th"
     );
 }
+
+#[test]
+fn test_load_from_keystore_secret_key() {
+    let wallet = Wallet::from_keystore_file("tests/alice.json", "password").unwrap();
+    assert_eq!(
+        wallet.address().to_bech32_string().unwrap(),
+        "erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6th"
+    );
+
+    let keystore = Wallet::read_keystore_file("tests/alice.json").unwrap();
+    let private_key = Wallet::decrypt_secret_key(&keystore, "password").unwrap();
+    assert_eq!(
+        private_key.to_string(),
+        "413f42575f7f26fad3317a778771212fdb80245850981e48b58a4f25e344e8f9"
+    );
+}
+
+#[test]
+fn test_load_from_keystore_mnemonic() {
+    let keystore = Wallet::read_keystore_file("tests/mnemonic.json").unwrap();
+    assert_eq!(keystore.kind, KeystoreKind::Mnemonic);
+    let mnemonic = Wallet::decrypt_mnemonic(&keystore, "password").unwrap();
+    assert_eq!(mnemonic.to_string(), TEST_MNEMONIC);
+
+    let wallet = Wallet::from_keystore_file("tests/mnemonic.json", "password").unwrap();
+    assert_eq!(
+        wallet.address().to_bech32_string().unwrap(),
+        "erd1mlh7q3fcgrjeq0et65vaaxcw6m5ky8jhu296pdxpk9g32zga6uhsemxx2a"
+    );
+}
+
+#[test]
+fn test_keystore_wrong_password() {
+    let err = Wallet::from_keystore_file("tests/alice.json", "wrong password").unwrap_err();
+    assert_eq!(err.to_string(), "wrong keystore password");
+
+    let keystore = Wallet::read_keystore_file("tests/alice.json").unwrap();
+    assert!(Wallet::decrypt_mnemonic(&keystore, "password").is_err());
+}
+
+#[test]
+fn test_keystore_encrypt_decrypt() {
+    let wallet = Wallet::from_pem_file("tests/alice.pem").unwrap();
+    let keystore = wallet.encrypt_keystore("new password").unwrap();
+    assert_eq!(keystore.kind, KeystoreKind::SecretKey);
+    assert_eq!(
+        keystore.bech32.as_deref(),
+        Some("erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6th")
+    );
+    let private_key = Wallet::decrypt_secret_key(&keystore, "new password").unwrap();
+    assert_eq!(
+        Wallet::from_private_key(&private_key.to_string())
+            .unwrap()
+            .address()
+            .to_bech32_string()
+            .unwrap(),
+        wallet.address().to_bech32_string().unwrap()
+    );
+
+    let mnemonic = Mnemonic::parse_normalized(TEST_MNEMONIC).unwrap();
+    let keystore = Wallet::encrypt_mnemonic(&mnemonic, "new password").unwrap();
+    assert!(keystore.address.is_none());
+    let serialized = serde_json::to_string(&keystore).unwrap();
+    let deserialized: Keystore = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(
+        Wallet::decrypt_mnemonic(&deserialized, "new password").unwrap(),
+        mnemonic
+    );
+}
@@ -10,6 +10,11 @@ pub struct AccountRaw {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub comment: Option<String>,
 
+    /// Only used by the Go VM, kept so that the file can be written back unchanged.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shard: Option<ValueSubTree>,
+
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub nonce: Option<ValueSubTree>,
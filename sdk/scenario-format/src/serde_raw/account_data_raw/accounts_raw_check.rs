@@ -16,7 +16,9 @@ impl Serialize for CheckAccountsRaw {
     where
         S: Serializer,
     {
-        let mut map = serializer.serialize_map(Some(self.accounts.len()))?;
+        let mut map = serializer.serialize_map(Some(
+            self.accounts.len() + self.other_accounts_allowed as usize,
+        ))?;
         for (k, v) in self.accounts.iter() {
             map.serialize_entry(k, v)?;
         }
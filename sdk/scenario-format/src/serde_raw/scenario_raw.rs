@@ -25,6 +25,11 @@ pub struct ScenarioRaw {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub gas_schedule: Option<String>,
 
+    /// Only used by the Go VM, kept so that the file can be written back unchanged.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trace_gas: Option<bool>,
+
     pub steps: Vec<StepRaw>,
 }
 
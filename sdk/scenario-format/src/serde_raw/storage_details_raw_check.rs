@@ -16,7 +16,9 @@ impl Serialize for CheckStorageDetailsRaw {
     where
         S: Serializer,
     {
-        let mut map = serializer.serialize_map(Some(self.storages.len()))?;
+        let mut map = serializer.serialize_map(Some(
+            self.storages.len() + self.other_storages_allowed as usize,
+        ))?;
         for (k, v) in self.storages.iter() {
             map.serialize_entry(k, v)?;
         }
@@ -43,6 +43,23 @@ pub fn bytes_to_string(bytes: &[u8]) -> String {
     String::from_utf8(bytes.to_vec()).unwrap_or_else(|_| verbose_hex(bytes))
 }
 
+/// Shows the likely meaning of an encoded value next to its hex, used when reporting mismatches.
+///
+/// Readable text is shown as `str:`, short values also as numbers, e.g. `0x03e8 (1000)`.
+pub fn decoded_bytes(bytes: &[u8]) -> String {
+    if bytes.is_empty() {
+        return "\"\"".to_string();
+    }
+    if bytes.iter().all(|b| b.is_ascii_graphic() || *b == b' ') {
+        return alloc::format!("str:{}", String::from_utf8_lossy(bytes));
+    }
+    if bytes.len() <= 16 {
+        let number = BigInt::from_bytes_be(num_bigint::Sign::Plus, bytes);
+        return alloc::format!("{} ({number})", verbose_hex(bytes));
+    }
+    verbose_hex(bytes)
+}
+
 impl<M: ManagedTypeApi> fmt::Debug for BigUintPrinter<M> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let handle = self.value.get_handle();
@@ -29,4 +29,7 @@ pub use alloc::{boxed::Box, vec::Vec};
 
 pub use multiversx_sc;
 
+// Re-exporting the ABI-based event decoder, so that tests can check logs as typed events.
+pub use multiversx_sc_meta::event_decoder;
+
 pub use std::collections::HashMap;
@@ -0,0 +1,96 @@
+use multiversx_sc::types::heap::Address;
+
+use crate::{
+    event_decoder::AbiValue,
+    tx_mock::{TxFunctionName, TxResult},
+    world_mock::{BlockchainMock, ContractContainer},
+};
+
+fn find_contract_container<'a>(
+    state: &'a BlockchainMock,
+    to: &Address,
+) -> Option<&'a ContractContainer> {
+    let contract_path = state.accounts.get(to)?.contract_path.as_ref()?;
+    state.contract_map.find_contract(contract_path)
+}
+
+/// Items are counted after decoding the results with the ABI, so that e.g. a `ManagedVec` counts as many items as it holds,
+/// while a `MultiValue2` counts as a single item, although it spans 2 results.
+///
+/// Each raw result counts as an item if the results cannot be decoded.
+fn count_returned_items(
+    contract_container: &ContractContainer,
+    function_name: &TxFunctionName,
+    tx_result: &TxResult,
+) -> usize {
+    let decoded_results = contract_container.event_decoder().and_then(|decoder| {
+        let endpoint_abi = decoder.find_endpoint_abi(function_name.as_str())?;
+        let output_types: Vec<&str> = endpoint_abi
+            .outputs
+            .iter()
+            .map(|output| output.type_name.as_str())
+            .collect();
+        decoder
+            .decode_multi_values(&output_types, &tx_result.result_values)
+            .ok()
+    });
+
+    match decoded_results {
+        Some(values) => values
+            .iter()
+            .map(|value| match value {
+                AbiValue::List(items) => items.len(),
+                _ => 1,
+            })
+            .sum(),
+        None => tx_result.result_values.len(),
+    }
+}
+
+/// Flags successful steps that exceed the limits declared with `#[max_gas]` and `#[max_return_items]`.
+///
+/// The Rust VM does not meter gas yet, so instead of the gas used, it checks the gas limit of the step:
+/// a step that gives an endpoint more gas than its budget is flagged,
+/// so that running the same scenario on the Go VM fails whenever the endpoint goes over its budget.
+/// Queries have no gas limit, so only their returned items are checked.
+pub fn check_endpoint_budget(
+    state: &BlockchainMock,
+    tx_id: &str,
+    to: &Address,
+    function_name: &TxFunctionName,
+    gas_limit: Option<u64>,
+    tx_result: &TxResult,
+) {
+    if tx_result.result_status != 0 {
+        return;
+    }
+    let Some(contract_container) = find_contract_container(state, to) else {
+        return;
+    };
+    let Some(budget) = contract_container.endpoint_budget(function_name) else {
+        return;
+    };
+
+    if let Some(max_return_items) = budget.max_return_items {
+        let returned_items = count_returned_items(contract_container, function_name, tx_result);
+        assert!(
+            returned_items <= max_return_items,
+            "endpoint budget exceeded. Tx id: '{}'. Endpoint `{}` returned {} items, max_return_items is {}",
+            tx_id,
+            function_name.as_str(),
+            returned_items,
+            max_return_items,
+        );
+    }
+
+    if let (Some(max_gas), Some(gas_limit)) = (budget.max_gas, gas_limit) {
+        assert!(
+            gas_limit <= max_gas,
+            "endpoint budget exceeded. Tx id: '{}'. Endpoint `{}` is called with a gas limit of {}, max_gas is {}",
+            tx_id,
+            function_name.as_str(),
+            gas_limit,
+            max_gas,
+        );
+    }
+}
@@ -1,4 +1,5 @@
 mod all_steps;
+mod budget_check;
 mod check_state;
 pub mod contract_info;
 pub mod sc_call;
@@ -9,5 +10,6 @@ mod transfer;
 mod tx_output_check;
 
 pub use all_steps::parse_execute_mandos_steps;
+use budget_check::*;
 pub use contract_info::*;
 use tx_output_check::*;
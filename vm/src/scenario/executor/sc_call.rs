@@ -1,13 +1,17 @@
 use crate::scenario::model::{ScCallStep, Step, TxESDT, TypedScCall, TypedScCallExecutor};
-use multiversx_sc::codec::{CodecFrom, PanicErrorHandler, TopEncodeMulti};
+use multiversx_sc::{
+    codec::{CodecFrom, PanicErrorHandler, TopEncodeMulti},
+    types::ContractError,
+};
 
 use crate::{
+    event_decoder::{DecodedEvent, EventDecoder},
     tx_execution::sc_call_with_async_and_callback,
     tx_mock::{generate_tx_hash_dummy, TxInput, TxResult, TxTokenTransfer},
     world_mock::BlockchainMock,
 };
 
-use super::check_tx_output;
+use super::{check_endpoint_budget, check_tx_output};
 
 impl BlockchainMock {
     /// Adds a SC call step, as specified in the `sc_call_step` argument, then executes it.
@@ -37,6 +41,34 @@ impl BlockchainMock {
         let mut raw_result = tx_result.result_values;
         RequestedResult::multi_decode_or_handle_err(&mut raw_result, PanicErrorHandler).unwrap()
     }
+
+    /// Adds a SC call step, executes it and decodes the logs of the events declared in the ABI.
+    ///
+    /// The expected output, if any, is checked before decoding.
+    pub fn perform_sc_call_get_events(
+        &mut self,
+        sc_call_step: ScCallStep,
+        decoder: &EventDecoder,
+    ) -> Vec<DecodedEvent> {
+        let tx_result = self.with_borrowed(|state| execute_and_check(state, &sc_call_step));
+        self.scenario_trace.steps.push(Step::ScCall(sc_call_step));
+        tx_result.decode_events(decoder)
+    }
+
+    /// Adds a SC call step, executes it and decodes the typed contract error it failed with.
+    ///
+    /// Panics if the transaction succeeded or failed with a message not produced by `E`.
+    /// Managed fields are decoded in the current `DebugApi` context.
+    pub fn perform_sc_call_get_error<E: ContractError>(&mut self, sc_call_step: ScCallStep) -> E {
+        let tx_result = self.with_borrowed(|state| execute_and_check(state, &sc_call_step));
+        self.scenario_trace.steps.push(Step::ScCall(sc_call_step));
+        tx_result.decode_contract_error().unwrap_or_else(|| {
+            panic!(
+                "contract error expected, got status {} and message \"{}\"",
+                tx_result.result_status, tx_result.result_message
+            )
+        })
+    }
 }
 
 impl TypedScCallExecutor for BlockchainMock {
@@ -86,8 +118,16 @@ fn execute_and_check(
 ) -> (TxResult, BlockchainMock) {
     let (tx_result, state) = execute(state, sc_call_step);
     if let Some(tx_expect) = &sc_call_step.expect {
-        check_tx_output(&sc_call_step.id, tx_expect, &tx_result);
+        check_tx_output(&state, &sc_call_step.id, tx_expect, &tx_result);
     }
+    check_endpoint_budget(
+        &state,
+        &sc_call_step.id,
+        &sc_call_step.tx.to.to_address(),
+        &sc_call_step.tx.function.clone().into(),
+        Some(sc_call_step.tx.gas_limit.value),
+        &tx_result,
+    );
     (tx_result, state)
 }
 
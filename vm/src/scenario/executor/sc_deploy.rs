@@ -103,7 +103,7 @@ fn execute_and_check(
 ) -> (TxResult, Address, BlockchainMock) {
     let (tx_result, address, state) = execute(state, sc_deploy_step);
     if let Some(tx_expect) = &sc_deploy_step.expect {
-        check_tx_output(&sc_deploy_step.id, tx_expect, &tx_result);
+        check_tx_output(&state, &sc_deploy_step.id, tx_expect, &tx_result);
     }
     (tx_result, address, state)
 }
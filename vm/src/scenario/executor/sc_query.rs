@@ -11,7 +11,7 @@ use multiversx_sc::{
     types::ContractCall,
 };
 
-use super::check_tx_output;
+use super::{check_endpoint_budget, check_tx_output};
 
 impl BlockchainMock {
     /// Adds a SC query step, as specified in the `sc_query_step` argument, then executes it.
@@ -117,8 +117,16 @@ fn execute_and_check(
 ) -> (TxResult, BlockchainMock) {
     let (tx_result, state) = execute(state, sc_query_step);
     if let Some(tx_expect) = &sc_query_step.expect {
-        check_tx_output(&sc_query_step.id, tx_expect, &tx_result);
+        check_tx_output(&state, &sc_query_step.id, tx_expect, &tx_result);
     }
+    check_endpoint_budget(
+        &state,
+        &sc_query_step.id,
+        &sc_query_step.tx.to.to_address(),
+        &sc_query_step.tx.function.clone().into(),
+        None,
+        &tx_result,
+    );
 
     (tx_result, state)
 }
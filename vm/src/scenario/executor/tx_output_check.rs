@@ -1,8 +1,23 @@
-use crate::scenario::model::{CheckLogs, Checkable, TxExpect};
+use std::fmt::Write;
 
-use crate::{address_hex, tx_mock::TxResult, verbose_hex, verbose_hex_list};
+use multiversx_sc::types::heap::Address;
 
-pub fn check_tx_output(tx_id: &str, tx_expect: &TxExpect, tx_result: &TxResult) {
+use crate::scenario::model::{BytesValue, CheckLog, CheckLogs, CheckValue, Checkable, TxExpect};
+
+use crate::{
+    address_hex, decoded_bytes,
+    event_decoder::{DecodedEvent, EventDecoder},
+    tx_mock::{TxLog, TxResult},
+    verbose_hex, verbose_hex_list,
+    world_mock::BlockchainMock,
+};
+
+pub fn check_tx_output(
+    state: &BlockchainMock,
+    tx_id: &str,
+    tx_expect: &TxExpect,
+    tx_result: &TxResult,
+) {
     let have_str = tx_result.result_message.as_str();
     assert!(
         tx_expect.status.check(tx_result.result_status),
@@ -45,15 +60,21 @@ pub fn check_tx_output(tx_id: &str, tx_expect: &TxExpect, tx_result: &TxResult)
                     let expected_log = &expected_logs.list[i];
                     assert!(
                         actual_log.scenario_check(expected_log),
-                        "Logs do not match. Tx id: '{}'. Index: {}.\nWant: Address: {}, Endpoint: {}, Topics: {:?}, Data: {}\nHave: Address: {}, Endpoint: {}, Topics: {:?}, Data: {}",
+                        "Logs do not match. Tx id: '{}'. Index: {}. Event: {}.\n{}Want: Address: {}, Endpoint: {}, Topics: {:?}, Data: {}\nHave: Address: {}, Endpoint: {}, Topics: {:?}, Data: {}",
                         tx_id,
                         i,
+                        event_identifier(actual_log),
+                        log_mismatch_report(
+                            expected_log,
+                            actual_log,
+                            find_event_decoder(state, &actual_log.address)
+                        ),
                         &expected_log.address,
                         &expected_log.endpoint,
                         &expected_log.topics.pretty_str(),
                         &expected_log.data,
                         address_hex(&actual_log.address),
-                    &actual_log.endpoint,
+                        &actual_log.endpoint,
                         verbose_hex_list(actual_log.topics.as_slice()),
                         verbose_hex(&actual_log.data),
                     );
@@ -72,3 +93,147 @@ pub fn check_tx_output(tx_id: &str, tx_expect: &TxExpect, tx_result: &TxResult)
         },
     }
 }
+
+fn event_identifier(log: &TxLog) -> String {
+    log.topics
+        .first()
+        .map(|identifier| String::from_utf8_lossy(identifier).to_string())
+        .unwrap_or_else(|| "none".to_string())
+}
+
+fn decoded_check_value(check_value: Option<&CheckValue<BytesValue>>) -> String {
+    match check_value {
+        Some(CheckValue::Star) => "*".to_string(),
+        Some(CheckValue::Equal(value)) => decoded_bytes(&value.value),
+        None => "nothing".to_string(),
+    }
+}
+
+/// Only contracts registered together with their ABI have one.
+fn find_event_decoder<'a>(
+    state: &'a BlockchainMock,
+    address: &Address,
+) -> Option<&'a EventDecoder> {
+    let contract_path = state.accounts.get(address)?.contract_path.as_ref()?;
+    state
+        .contract_map
+        .find_contract(contract_path)?
+        .event_decoder()
+}
+
+fn exact_check_value(check_value: &CheckValue<BytesValue>) -> Option<&[u8]> {
+    match check_value {
+        CheckValue::Star => None,
+        CheckValue::Equal(value) => Some(value.value.as_slice()),
+    }
+}
+
+/// Decodes both logs, if the expected one has no wildcards and the ABI declares the event.
+fn decode_log_pair(
+    decoder: &EventDecoder,
+    expected: &CheckLog,
+    actual: &TxLog,
+) -> Option<(DecodedEvent, DecodedEvent)> {
+    let CheckValue::Equal(expected_topics) = &expected.topics else {
+        return None;
+    };
+    let expected_topics = expected_topics
+        .iter()
+        .map(|topic| exact_check_value(topic).map(<[u8]>::to_vec))
+        .collect::<Option<Vec<Vec<u8>>>>()?;
+    let expected_data = exact_check_value(&expected.data)?;
+
+    let expected_event = decoder.decode(&expected_topics, expected_data).ok()?;
+    let actual_event = actual.decode_event(decoder).ok()?;
+    Some((expected_event, actual_event))
+}
+
+/// One line for each event field that differs from the expected one.
+fn decoded_event_mismatch_report(
+    report: &mut String,
+    expected: &DecodedEvent,
+    actual: &DecodedEvent,
+) {
+    if expected.identifier != actual.identifier {
+        writeln!(report, "    event: want {expected}, have {actual}").unwrap();
+        return;
+    }
+    for (expected_field, actual_field) in expected.fields.iter().zip(actual.fields.iter()) {
+        if expected_field.value != actual_field.value {
+            writeln!(
+                report,
+                "    {}.{}: want {}, have {}",
+                expected.identifier, expected_field.name, expected_field.value, actual_field.value
+            )
+            .unwrap();
+        }
+    }
+}
+
+/// One line for each part of the log that differs from the expected one.
+///
+/// The topics and data are shown as event fields when the ABI of the contract that wrote the log is known,
+/// otherwise topic by topic.
+fn log_mismatch_report(
+    expected: &CheckLog,
+    actual: &TxLog,
+    decoder: Option<&EventDecoder>,
+) -> String {
+    let mut report = String::new();
+    if !expected.address.check(actual.address.as_bytes()) {
+        writeln!(
+            report,
+            "    address: want {}, have {}",
+            expected.address,
+            address_hex(&actual.address)
+        )
+        .unwrap();
+    }
+    if !expected.endpoint.check(&actual.endpoint) {
+        writeln!(
+            report,
+            "    endpoint: want {}, have {}",
+            expected.endpoint, actual.endpoint
+        )
+        .unwrap();
+    }
+    if let Some((expected_event, actual_event)) =
+        decoder.and_then(|decoder| decode_log_pair(decoder, expected, actual))
+    {
+        decoded_event_mismatch_report(&mut report, &expected_event, &actual_event);
+        return report;
+    }
+    if let CheckValue::Equal(expected_topics) = &expected.topics {
+        for i in 0..expected_topics.len().max(actual.topics.len()) {
+            let expected_topic = expected_topics.get(i);
+            let actual_topic = actual.topics.get(i);
+            if let (Some(expected_topic), Some(actual_topic)) = (expected_topic, actual_topic) {
+                if expected_topic.check(actual_topic.as_slice()) {
+                    continue;
+                }
+            }
+            let name = if i == 0 {
+                "event identifier".to_string()
+            } else {
+                format!("topics[{i}]")
+            };
+            writeln!(
+                report,
+                "    {name}: want {}, have {}",
+                decoded_check_value(expected_topic),
+                actual_topic.map_or_else(|| "nothing".to_string(), |topic| decoded_bytes(topic))
+            )
+            .unwrap();
+        }
+    }
+    if !expected.data.check(actual.data.as_slice()) {
+        writeln!(
+            report,
+            "    data: want {}, have {}",
+            decoded_check_value(Some(&expected.data)),
+            decoded_bytes(&actual.data)
+        )
+        .unwrap();
+    }
+    report
+}
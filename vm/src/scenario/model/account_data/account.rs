@@ -169,6 +169,7 @@ impl IntoRaw<AccountRaw> for Account {
     fn into_raw(self) -> AccountRaw {
         AccountRaw {
             comment: self.comment,
+            shard: None,
             nonce: self.nonce.map(|n| n.original),
             balance: self.balance.map(|n| n.original),
             esdt: self
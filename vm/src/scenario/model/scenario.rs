@@ -35,6 +35,7 @@ impl IntoRaw<ScenarioRaw> for Scenario {
             comment: self.comment,
             check_gas: self.check_gas,
             gas_schedule: None,
+            trace_gas: None,
             steps: self.steps.into_iter().map(Step::into_raw).collect(),
         }
     }
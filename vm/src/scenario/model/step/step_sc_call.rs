@@ -1,13 +1,17 @@
 use crate::{
     scenario::model::{AddressValue, BigUintValue, BytesValue, TxCall, TxESDT, TxExpect, U64Value},
+    scenario_format::interpret_trait::{InterpretableFrom, InterpreterContext},
     DebugApi,
 };
 
 use multiversx_sc::{
+    api::UPGRADE_CONTRACT_FUNC_NAME,
     codec::{CodecFrom, PanicErrorHandler, TopEncodeMulti},
-    types::{ContractCall, ManagedArgBuffer},
+    types::{CodeMetadata, ContractCall, ContractDeploy, ManagedArgBuffer},
 };
 
+use super::process_contract_deploy;
+
 #[derive(Debug, Default)]
 pub struct ScCallStep {
     pub id: String,
@@ -112,6 +116,33 @@ impl ScCallStep {
         self
     }
 
+    /// Upgrades a contract via the `upgradeContract` built-in function.
+    ///
+    /// Sets following fields based on the smart contract proxy:
+    /// - "to"
+    /// - "function", always `upgradeContract`
+    /// - "arguments": the new code, the code metadata, followed by the upgrade arguments
+    pub fn upgrade_contract<OriginalResult>(
+        mut self,
+        contract_deploy: ContractDeploy<DebugApi, OriginalResult>,
+        code_expr: &str,
+        context: &InterpreterContext,
+        code_metadata: CodeMetadata,
+    ) -> Self {
+        let (to_str, scenario_args) = process_contract_deploy(contract_deploy);
+        let to_str = to_str.expect("the contract to upgrade needs an address");
+        self = self.to(to_str.as_str());
+        self = self.function(UPGRADE_CONTRACT_FUNC_NAME);
+        self.tx
+            .arguments
+            .push(BytesValue::interpret_from(code_expr, context));
+        self = self.argument(format!("0x{}", hex::encode(code_metadata.to_byte_array())).as_str());
+        for arg in scenario_args {
+            self = self.argument(arg.as_str());
+        }
+        self
+    }
+
     /// Sets following fields based on the smart contract proxy:
     /// - "to"
     /// - "function"
@@ -83,9 +83,9 @@ impl ScDeployStep {
 }
 
 /// Extracts
-/// - (optional) recipient (needed for contract upgrade, not yet used);
+/// - (optional) recipient (needed for contract upgrade);
 /// - the arguments.
-fn process_contract_deploy<OriginalResult>(
+pub(super) fn process_contract_deploy<OriginalResult>(
     contract_deploy: ContractDeploy<DebugApi, OriginalResult>,
 ) -> (Option<String>, Vec<String>) {
     let to_str = contract_deploy
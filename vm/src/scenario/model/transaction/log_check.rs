@@ -4,6 +4,7 @@ use crate::{
         interpret_trait::{InterpretableFrom, InterpreterContext, IntoRaw},
         serde_raw::CheckLogRaw,
     },
+    tx_mock::TxLog,
 };
 
 #[derive(Debug)]
@@ -14,6 +15,23 @@ pub struct CheckLog {
     pub data: CheckValue<BytesValue>,
 }
 
+impl CheckLog {
+    /// Expects exactly the given log, including the contract and endpoint that wrote it.
+    pub fn from_event_log(log: TxLog) -> Self {
+        CheckLog {
+            address: CheckValue::Equal(BytesValue::from(log.address.to_vec())),
+            endpoint: CheckValue::Equal(BytesValue::from(format!("str:{}", log.endpoint.as_str()))),
+            topics: CheckValue::Equal(
+                log.topics
+                    .into_iter()
+                    .map(|topic| CheckValue::Equal(BytesValue::from(topic)))
+                    .collect(),
+            ),
+            data: CheckValue::Equal(BytesValue::from(log.data)),
+        }
+    }
+}
+
 impl InterpretableFrom<CheckLogRaw> for CheckLog {
     fn interpret_from(from: CheckLogRaw, context: &InterpreterContext) -> Self {
         CheckLog {
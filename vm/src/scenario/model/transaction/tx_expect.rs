@@ -1,11 +1,21 @@
+use multiversx_sc::types::ContractError;
+
 use crate::{
-    scenario::model::{BytesValue, CheckLogs, CheckValue, CheckValueList, U64Value},
+    scenario::model::{
+        AddressValue, BytesValue, CheckLog, CheckLogList, CheckLogs, CheckValue, CheckValueList,
+        U64Value,
+    },
     scenario_format::{
         interpret_trait::{InterpretableFrom, InterpreterContext, IntoRaw},
-        serde_raw::TxExpectRaw,
+        serde_raw::{TxExpectRaw, ValueSubTree},
     },
+    tx_mock::capture_event_logs,
+    DebugApi,
 };
 
+/// Status code of errors signalled by the contract itself.
+const USER_ERROR_STATUS: u64 = 4;
+
 #[derive(Debug)]
 pub struct TxExpect {
     pub out: CheckValueList,
@@ -46,6 +56,24 @@ impl TxExpect {
         }
     }
 
+    /// Expects the transaction to fail with the given typed contract error.
+    ///
+    /// Formatting managed fields requires a `DebugApi` context, e.g. `DebugApi::dummy()`.
+    pub fn contract_error<E: ContractError>(error: &E) -> Self {
+        let message = error
+            .error_message::<DebugApi>()
+            .to_boxed_bytes()
+            .into_vec();
+        let original = ValueSubTree::Str(format!("str:{}", String::from_utf8_lossy(&message)));
+        Self::err(
+            USER_ERROR_STATUS,
+            BytesValue {
+                value: message,
+                original,
+            },
+        )
+    }
+
     pub fn no_result(mut self) -> Self {
         self.out = CheckValue::Equal(Vec::new());
         self
@@ -63,6 +91,47 @@ impl TxExpect {
         self.out = CheckValue::Equal(check_results);
         self
     }
+
+    /// Expects the logs written by the given `#[event]` method calls, in order, after the ones already expected.
+    ///
+    /// The events are encoded the same way the contract encodes them, e.g.
+    /// `TxExpect::ok().event("sc:token", "transfer", || contract.transfer_event(&from, &amount))`,
+    /// and are expected to come from the given contract address and endpoint.
+    /// Calling the event methods requires a `DebugApi` context, e.g. `DebugApi::dummy()`.
+    pub fn event<A, F>(mut self, address: A, endpoint: &str, emit: F) -> Self
+    where
+        AddressValue: From<A>,
+        F: FnOnce(),
+    {
+        let address = AddressValue::from(address).to_address();
+        let mut check_logs = match self.logs {
+            CheckLogs::Star => CheckLogList {
+                list: Vec::new(),
+                more_allowed_at_end: false,
+            },
+            CheckLogs::List(check_logs) => check_logs,
+        };
+        check_logs
+            .list
+            .extend(capture_event_logs(emit).into_iter().map(|mut log| {
+                log.address = address.clone();
+                log.endpoint = endpoint.into();
+                CheckLog::from_event_log(log)
+            }));
+        self.logs = CheckLogs::List(check_logs);
+        self
+    }
+
+    /// Expected remaining gas.
+    ///
+    /// The Rust VM does not meter gas, so it is only checked when running the scenario on the Go VM.
+    pub fn gas<V>(mut self, value: V) -> Self
+    where
+        U64Value: From<V>,
+    {
+        self.gas = CheckValue::Equal(U64Value::from(value));
+        self
+    }
 }
 
 impl InterpretableFrom<TxExpectRaw> for TxExpect {
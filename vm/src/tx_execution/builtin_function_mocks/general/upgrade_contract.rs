@@ -42,7 +42,7 @@ impl BuiltinFunction for UpgradeContract {
             to: tx_input.to,
             egld_value: tx_input.egld_value,
             esdt_values: Vec::new(),
-            func_name: TxFunctionName::INIT,
+            func_name: TxFunctionName::UPGRADE,
             args,
             gas_limit: tx_input.gas_limit,
             gas_price: tx_input.gas_price,
@@ -61,7 +61,11 @@ fn execute_contract_instance_endpoint(
     endpoint_name: &TxFunctionName,
 ) -> TxResult {
     let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-        let call_successful = contract_container.call(endpoint_name);
+        let mut call_successful = contract_container.call(endpoint_name);
+        if !call_successful && endpoint_name == &TxFunctionName::UPGRADE {
+            // contracts without an `#[upgrade]` endpoint get their constructor called instead
+            call_successful = contract_container.call(&TxFunctionName::INIT);
+        }
         if !call_successful {
             std::panic::panic_any(TxPanic {
                 status: 1,
@@ -53,6 +53,9 @@ impl TxFunctionName {
     /// The constructor name of any smart contract.
     pub const INIT: TxFunctionName = TxFunctionName::from_static("init");
 
+    /// The endpoint called on upgrade, if the contract has one. Otherwise, upgrades call the constructor.
+    pub const UPGRADE: TxFunctionName = TxFunctionName::from_static("upgrade");
+
     /// The the legacy async central callback name of any smart contract.
     pub const CALLBACK: TxFunctionName = TxFunctionName::from_static("callBack");
 
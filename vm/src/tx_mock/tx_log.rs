@@ -1,4 +1,8 @@
-use crate::scenario::model::Checkable;
+use crate::{
+    event_decoder::{DecodedEvent, EventDecodeError, EventDecoder},
+    scenario::model::Checkable,
+    DebugApi,
+};
 use alloc::vec::Vec;
 use multiversx_sc::types::heap::Address;
 
@@ -19,4 +23,20 @@ impl TxLog {
             && check_log.topics.check(self.topics.as_slice())
             && check_log.data.check(self.data.as_slice())
     }
+
+    /// Decodes the log into a typed event, based on the contract ABI.
+    pub fn decode_event(&self, decoder: &EventDecoder) -> Result<DecodedEvent, EventDecodeError> {
+        decoder.decode(self.topics.as_slice(), self.data.as_slice())
+    }
+}
+
+/// Calls `#[event]` methods and returns the logs they write, instead of adding them to the transaction result.
+///
+/// Runs in the current `DebugApi` context, e.g. `DebugApi::dummy()`, so that the event arguments can be created beforehand.
+pub fn capture_event_logs<F: FnOnce()>(emit: F) -> Vec<TxLog> {
+    let api = DebugApi::new_from_static();
+    let logs_before = api.result_borrow_mut().result_logs.len();
+    emit();
+    let logs = api.result_borrow_mut().result_logs.split_off(logs_before);
+    logs
 }
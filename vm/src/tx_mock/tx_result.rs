@@ -2,6 +2,10 @@ use alloc::vec::Vec;
 
 use std::fmt;
 
+use multiversx_sc::types::ContractError;
+
+use crate::event_decoder::{DecodedEvent, EventDecoder};
+
 use super::{AsyncCallTxData, TxLog, TxPanic, TxResultCalls};
 
 #[derive(Clone, Debug)]
@@ -94,6 +98,31 @@ impl TxResult {
         }
     }
 
+    /// Decodes the logs of all events declared in the ABI, in order.
+    /// Other logs, e.g. of built-in functions, are skipped.
+    ///
+    /// Panics if the log of a known event cannot be decoded.
+    pub fn decode_events(&self, decoder: &EventDecoder) -> Vec<DecodedEvent> {
+        self.result_logs
+            .iter()
+            .filter_map(|log| {
+                decoder
+                    .decode_if_known(log.topics.as_slice(), log.data.as_slice())
+                    .unwrap_or_else(|err| panic!("failed to decode log: {err}"))
+            })
+            .collect()
+    }
+
+    /// Decodes the error message into a typed contract error.
+    ///
+    /// Returns `None` if the transaction succeeded, or if it failed with some other error.
+    pub fn decode_contract_error<E: ContractError>(&self) -> Option<E> {
+        if self.result_status == 0 {
+            return None;
+        }
+        E::decode_error_message(self.result_message.as_bytes())
+    }
+
     pub fn assert_ok(&self) {
         assert!(
             self.result_status == 0,
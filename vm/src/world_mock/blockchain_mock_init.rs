@@ -3,9 +3,9 @@ use crate::scenario_format::{
 };
 use multiversx_sc::contract_base::{CallableContractBuilder, ContractAbiProvider};
 
-use crate::DebugApi;
+use crate::{event_decoder::EventDecoder, DebugApi};
 
-use super::{BlockchainMock, ContractContainer};
+use super::{endpoint_budgets_from_abi, BlockchainMock, ContractContainer};
 
 impl BlockchainMock {
     pub fn interpreter_context(&self) -> InterpreterContext {
@@ -34,6 +34,24 @@ impl BlockchainMock {
         )
     }
 
+    /// Same as `register_contract`, but also loads the endpoint budgets from the ABI,
+    /// so that scenario steps exceeding `#[max_gas]` or `#[max_return_items]` get flagged.
+    ///
+    /// The events declared in the ABI are also loaded, to decode the logs that do not match the expected ones.
+    pub fn register_contract_with_budgets<Abi, B>(&mut self, expression: &str, contract_builder: B)
+    where
+        Abi: ContractAbiProvider,
+        B: CallableContractBuilder,
+    {
+        let abi = Abi::abi();
+        self.register_contract_container(
+            expression,
+            ContractContainer::new(contract_builder.new_contract_obj::<DebugApi>(), None, false)
+                .with_endpoint_budgets(endpoint_budgets_from_abi(&abi))
+                .with_event_decoder(EventDecoder::from_abi(&abi)),
+        )
+    }
+
     /// Links a contract path in a test to a multi-contract output.
     ///
     /// This simulates the effects of building such a contract with only part of the endpoints.
@@ -65,7 +83,9 @@ impl BlockchainMock {
                 contract_obj,
                 Some(sub_contract.all_exported_function_names()),
                 sub_contract.settings.panic_message,
-            ),
+            )
+            .with_endpoint_budgets(endpoint_budgets_from_abi(&sub_contract.abi))
+            .with_event_decoder(EventDecoder::from_abi(&sub_contract.abi)),
         );
     }
 }
@@ -1,15 +1,22 @@
 use alloc::vec::Vec;
 use multiversx_sc::contract_base::CallableContract;
+use std::collections::HashMap;
 
-use crate::tx_mock::TxFunctionName;
+use crate::{event_decoder::EventDecoder, tx_mock::TxFunctionName};
+
+use super::EndpointBudget;
 
 /// Contains a reference to a contract implementation.
 ///
-/// It can optionally also contain an allowed endpoint whitelist, to simulate multi-contract.
+/// It can optionally also contain an allowed endpoint whitelist, to simulate multi-contract,
+/// the endpoint budgets declared in the ABI, checked by the scenario executors,
+/// and an event decoder, used to show the contract logs that do not match the expected ones.
 pub struct ContractContainer {
     callable: Box<dyn CallableContract>,
     function_whitelist: Option<Vec<String>>,
     pub panic_message: bool,
+    endpoint_budgets: HashMap<String, EndpointBudget>,
+    event_decoder: Option<EventDecoder>,
 }
 
 impl ContractContainer {
@@ -22,9 +29,32 @@ impl ContractContainer {
             callable,
             function_whitelist,
             panic_message,
+            endpoint_budgets: HashMap::new(),
+            event_decoder: None,
         }
     }
 
+    pub fn with_endpoint_budgets(
+        mut self,
+        endpoint_budgets: HashMap<String, EndpointBudget>,
+    ) -> Self {
+        self.endpoint_budgets = endpoint_budgets;
+        self
+    }
+
+    pub fn endpoint_budget(&self, function_name: &TxFunctionName) -> Option<&EndpointBudget> {
+        self.endpoint_budgets.get(function_name.as_str())
+    }
+
+    pub fn with_event_decoder(mut self, event_decoder: EventDecoder) -> Self {
+        self.event_decoder = Some(event_decoder);
+        self
+    }
+
+    pub fn event_decoder(&self) -> Option<&EventDecoder> {
+        self.event_decoder.as_ref()
+    }
+
     fn validate_function_name(&self, function_name: &TxFunctionName) -> bool {
         if let Some(function_whitelist) = &self.function_whitelist {
             function_whitelist
@@ -34,6 +34,11 @@ impl ContractMap {
         }
     }
 
+    /// Same as `get_contract`, but does not panic if the contract is missing.
+    pub fn find_contract(&self, contract_identifier: &[u8]) -> Option<&ContractContainer> {
+        self.contract_objs.get(contract_identifier)
+    }
+
     pub fn register_contract(
         &mut self,
         contract_bytes: Vec<u8>,
@@ -0,0 +1,34 @@
+use multiversx_sc::abi::ContractAbi;
+use std::collections::HashMap;
+
+/// Limits declared on an endpoint with `#[max_gas]` and `#[max_return_items]`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EndpointBudget {
+    pub max_gas: Option<u64>,
+    pub max_return_items: Option<usize>,
+}
+
+impl EndpointBudget {
+    pub fn is_empty(&self) -> bool {
+        self.max_gas.is_none() && self.max_return_items.is_none()
+    }
+}
+
+/// Collects the budgets of all endpoints in the ABI, indexed by endpoint name.
+///
+/// Endpoints without declared limits are left out.
+pub fn endpoint_budgets_from_abi(abi: &ContractAbi) -> HashMap<String, EndpointBudget> {
+    abi.endpoints
+        .iter()
+        .map(|endpoint| {
+            (
+                endpoint.name.to_string(),
+                EndpointBudget {
+                    max_gas: endpoint.max_gas,
+                    max_return_items: endpoint.max_return_items,
+                },
+            )
+        })
+        .filter(|(_, budget)| !budget.is_empty())
+        .collect()
+}
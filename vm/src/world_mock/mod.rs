@@ -7,6 +7,7 @@ mod blockchain_mock_scenario_trace;
 mod blockchain_tx_info;
 mod contract_container;
 mod contract_map;
+mod endpoint_budget;
 mod esdt_data;
 mod esdt_instance;
 mod esdt_instance_metadata;
@@ -21,6 +22,7 @@ pub use blockchain_mock_scenario_trace::*;
 pub use blockchain_tx_info::*;
 pub use contract_container::*;
 pub use contract_map::*;
+pub use endpoint_budget::*;
 pub use esdt_data::*;
 pub use esdt_instance::*;
 pub use esdt_instance_metadata::*;